@@ -5,7 +5,11 @@ import AppKit
 
 import CoreMedia
 
-// Simple command-line tool: parses args, starts capture, waits for stdin EOF, then stops.
+// Simple command-line tool: parses args, starts capture, then reads control commands from stdin
+// (one per line: "pause", "resume", "stop"). EOF also stops.
+//
+// The recording is written as consecutive segments (segment-00000.mov, ...) into a directory so
+// an interrupted session keeps every finished segment. `--concat` joins segments into one movie.
 
 final class Recorder: NSObject, SCStreamOutput {
     private var stream: SCStream!
@@ -18,15 +22,25 @@ final class Recorder: NSObject, SCStreamOutput {
     private let frameTimescale: Int32 = 30
     private lazy var frameDuration = CMTime(value: 1, timescale: frameTimescale)
     private var tickTimer: DispatchSourceTimer?
-    private var outputURL: URL
+    // Segment rotation, only touched on outputQueue
+    private let segmentDir: URL
+    private let segmentDuration: CMTime
+    private var segmentIndex = 0
+    private var segmentStart: CMTime = .zero
+    private var frameSize: (Int, Int)?
+    private let finishing = DispatchGroup()
+    // Pause state, only touched on outputQueue
+    private var paused = false
+    private var needsResync = false
+    private var pausedOffset: CMTime = .zero
     private let outputQueue = DispatchQueue(label: "sck.recorder.output")
 
-    init(windowId: UInt32, outputURL: URL) throws {
+    init(windowId: UInt32, segmentDir: URL, segmentSeconds: Int) throws {
         guard #available(macOS 12.3, *) else {
             throw NSError(domain: "sck", code: 1, userInfo: [NSLocalizedDescriptionKey: "Requires macOS 12.3+"])
         }
 
-        fputs("[sck] init windowId=\(windowId) output=\(outputURL.path)\n", stderr)
+        fputs("[sck] init windowId=\(windowId) segments=\(segmentDir.path) every \(segmentSeconds)s\n", stderr)
         var fetched: SCShareableContent?
         var fetchErr: NSError?
         let sem = DispatchSemaphore(value: 0)
@@ -57,7 +71,8 @@ final class Recorder: NSObject, SCStreamOutput {
         fputs("[sck] config width=\(cfg.width) height=\(cfg.height)\n", stderr)
 
         // Defer writer creation until first frame so we can bind exact pixel size
-        self.outputURL = outputURL
+        self.segmentDir = segmentDir
+        self.segmentDuration = CMTime(value: CMTimeValue(max(1, segmentSeconds)), timescale: 1)
 
         super.init()
 
@@ -90,26 +105,109 @@ final class Recorder: NSObject, SCStreamOutput {
         fputs("[sck] stopCapture...\n", stderr)
         let sem = DispatchSemaphore(value: 0)
         stream.stopCapture { _ in
-            if let vi = self.videoInput { vi.markAsFinished() }
-            if let w = self.writer {
-                w.finishWriting {
-                    fputs("[sck] finishWriting\n", stderr)
-                    sem.signal()
-                }
-            } else {
-                sem.signal()
-            }
+            sem.signal()
         }
         _ = sem.wait(timeout: .now() + 5)
+        outputQueue.sync { finishSegment() }
+        _ = finishing.wait(timeout: .now() + 10)
+    }
+
+    /// Path of segment `index` in the segment directory
+    private func segmentURL(_ index: Int) -> URL {
+        segmentDir.appendingPathComponent(String(format: "segment-%05d.mov", index))
+    }
+
+    /// Finish writing the current segment, if any, without waiting for it
+    private func finishSegment() {
+        guard let writer = writer else { return }
+        videoInput?.markAsFinished()
+        let index = segmentIndex
+        finishing.enter()
+        writer.finishWriting {
+            fputs("[sck] finishWriting segment=\(index)\n", stderr)
+            self.finishing.leave()
+        }
+        self.writer = nil
+        self.videoInput = nil
+        self.pixelAdaptor = nil
+        segmentIndex += 1
+    }
+
+    /// Start a new segment whose first frame is at `rel`
+    private func startSegment(width w: Int, height h: Int, at rel: CMTime) -> Bool {
+        do {
+            let writer = try AVAssetWriter(outputURL: segmentURL(segmentIndex), fileType: .mov)
+            let settings: [String: Any] = [
+                AVVideoCodecKey: AVVideoCodecType.h264,
+                AVVideoWidthKey: w,
+                AVVideoHeightKey: h,
+                AVVideoCompressionPropertiesKey: [AVVideoAverageBitRateKey: 6_000_000]
+            ]
+            let input = AVAssetWriterInput(mediaType: .video, outputSettings: settings)
+            input.expectsMediaDataInRealTime = true
+            guard writer.canAdd(input) else {
+                fputs("[sck] cannot add video input\n", stderr)
+                return false
+            }
+            writer.add(input)
+            let adaptor = AVAssetWriterInputPixelBufferAdaptor(
+                assetWriterInput: input,
+                sourcePixelBufferAttributes: [
+                    kCVPixelBufferPixelFormatTypeKey as String: kCVPixelFormatType_32BGRA,
+                    kCVPixelBufferWidthKey as String: w,
+                    kCVPixelBufferHeightKey as String: h,
+                    kCVPixelBufferIOSurfacePropertiesKey as String: [:]
+                ]
+            )
+            if !writer.startWriting() {
+                fputs("[sck] writer.startWriting failed\n", stderr)
+                return false
+            }
+            writer.startSession(atSourceTime: rel)
+            self.writer = writer
+            self.videoInput = input
+            self.pixelAdaptor = adaptor
+            segmentStart = rel
+            fputs("[sck] segment=\(segmentIndex) dims=\(w)x\(h) rel=\(rel.seconds)\n", stderr)
+            return true
+        } catch {
+            fputs("[sck] writer create error: \(error.localizedDescription)\n", stderr)
+            return false
+        }
+    }
+
+    func pause() {
+        outputQueue.sync {
+            guard !paused else { return }
+            paused = true
+            fputs("[sck] paused at rel=\(lastRelPTS.seconds)\n", stderr)
+        }
+    }
+
+    func resume() {
+        outputQueue.sync {
+            guard paused else { return }
+            paused = false
+            needsResync = true
+            fputs("[sck] resumed\n", stderr)
+        }
     }
 
     func stream(_ stream: SCStream, didOutputSampleBuffer sampleBuffer: CMSampleBuffer, of type: SCStreamOutputType) {
         guard type == .screen, CMSampleBufferIsValid(sampleBuffer) else { return }
+        // Frames captured while paused are dropped
+        if paused { return }
 
         let pts = CMSampleBufferGetPresentationTimeStamp(sampleBuffer)
         if basePTS == nil { basePTS = pts }
         guard let base = basePTS else { return }
-        let rel = CMTimeSubtract(pts, base)
+        if needsResync {
+            // Close the gap: the first frame after a pause follows the last written one
+            let expected = CMTimeAdd(lastRelPTS, frameDuration)
+            pausedOffset = CMTimeSubtract(CMTimeSubtract(pts, base), expected)
+            needsResync = false
+        }
+        let rel = CMTimeSubtract(CMTimeSubtract(pts, base), pausedOffset)
 
         var pb = CMSampleBufferGetImageBuffer(sampleBuffer)
         if pb == nil, let last = lastPixelBuffer {
@@ -123,47 +221,17 @@ final class Recorder: NSObject, SCStreamOutput {
         }
         lastPixelBuffer = pixelBuffer
 
-        // Lazy-create writer on first real/deduped frame and bind exact dimensions
+        // Rotate to a new segment once the current one is long enough
+        if writer != nil, CMTimeCompare(CMTimeSubtract(rel, segmentStart), segmentDuration) >= 0 {
+            finishSegment()
+        }
+
+        // Lazy-create writer on first real/deduped frame and bind exact dimensions; later
+        // segments keep the first frame's size
         if writer == nil {
-            let w = CVPixelBufferGetWidth(pixelBuffer)
-            let h = CVPixelBufferGetHeight(pixelBuffer)
-            do {
-                let writer = try AVAssetWriter(outputURL: outputURL, fileType: .mov)
-                let settings: [String: Any] = [
-                    AVVideoCodecKey: AVVideoCodecType.h264,
-                    AVVideoWidthKey: w,
-                    AVVideoHeightKey: h,
-                    AVVideoCompressionPropertiesKey: [AVVideoAverageBitRateKey: 6_000_000]
-                ]
-                let input = AVAssetWriterInput(mediaType: .video, outputSettings: settings)
-                input.expectsMediaDataInRealTime = true
-                guard writer.canAdd(input) else {
-                    fputs("[sck] cannot add video input\n", stderr)
-                    return
-                }
-                writer.add(input)
-                let adaptor = AVAssetWriterInputPixelBufferAdaptor(
-                    assetWriterInput: input,
-                    sourcePixelBufferAttributes: [
-                        kCVPixelBufferPixelFormatTypeKey as String: kCVPixelFormatType_32BGRA,
-                        kCVPixelBufferWidthKey as String: w,
-                        kCVPixelBufferHeightKey as String: h,
-                        kCVPixelBufferIOSurfacePropertiesKey as String: [:]
-                    ]
-                )
-                self.writer = writer
-                self.videoInput = input
-                self.pixelAdaptor = adaptor
-                if !writer.startWriting() {
-                    fputs("[sck] writer.startWriting failed\n", stderr)
-                    return
-                }
-                writer.startSession(atSourceTime: .zero)
-                fputs("[sck] first frame dims=\(w)x\(h) rel=\(rel.seconds)\n", stderr)
-            } catch {
-                fputs("[sck] writer create error: \(error.localizedDescription)\n", stderr)
-                return
-            }
+            let size = frameSize ?? (CVPixelBufferGetWidth(pixelBuffer), CVPixelBufferGetHeight(pixelBuffer))
+            frameSize = size
+            guard startSegment(width: size.0, height: size.1, at: rel) else { return }
         }
 
         guard let writer = self.writer, let input = self.videoInput, let adaptor = self.pixelAdaptor else { return }
@@ -173,21 +241,62 @@ final class Recorder: NSObject, SCStreamOutput {
         }
         guard input.isReadyForMoreMediaData, writer.status == .writing else { return }
 
-        if !adaptor.append(pixelBuffer, withPresentationTime: rel) {
+        if adaptor.append(pixelBuffer, withPresentationTime: rel) {
+            lastRelPTS = rel
+        } else {
             fputs("[sck] pixelAdaptor.append returned false\n", stderr)
             if let err = writer.error as NSError? { fputs("[sck] writer error after append: \(err.domain)#\(err.code) \(err.localizedDescription)\n", stderr) }
         }
     }
 }
 
+/// Join `inputs` in order into a single movie at `output` without re-encoding
+func concatSegments(_ inputs: [URL], into output: URL) throws {
+    let composition = AVMutableComposition()
+    var cursor = CMTime.zero
+    for url in inputs {
+        let asset = AVURLAsset(url: url)
+        let range = CMTimeRange(start: .zero, duration: asset.duration)
+        try composition.insertTimeRange(range, of: asset, at: cursor)
+        cursor = CMTimeAdd(cursor, asset.duration)
+    }
+
+    guard let export = AVAssetExportSession(asset: composition, presetName: AVAssetExportPresetPassthrough) else {
+        throw NSError(domain: "sck", code: 4, userInfo: [NSLocalizedDescriptionKey: "Cannot export joined segments"])
+    }
+    try? FileManager.default.removeItem(at: output)
+    export.outputURL = output
+    export.outputFileType = .mov
+    let sem = DispatchSemaphore(value: 0)
+    export.exportAsynchronously { sem.signal() }
+    sem.wait()
+    if export.status != .completed {
+        throw export.error ?? NSError(domain: "sck", code: 5, userInfo: [NSLocalizedDescriptionKey: "Export did not complete"])
+    }
+}
+
 // Entry point
+if CommandLine.arguments.count >= 4 && CommandLine.arguments[1] == "--concat" {
+    let output = URL(fileURLWithPath: CommandLine.arguments[2])
+    let inputs = CommandLine.arguments[3...].map { URL(fileURLWithPath: $0) }
+    do {
+        try concatSegments(inputs, into: output)
+        fputs("[sck] joined \(inputs.count) segments into \(output.path)\n", stderr)
+        exit(0)
+    } catch {
+        fputs("[sck] concat error: \(error.localizedDescription)\n", stderr)
+        exit(1)
+    }
+}
 if CommandLine.arguments.count < 3 {
-    fputs("[sck] usage: sck-recorder <windowId> <outputPath>\n", stderr)
+    fputs("[sck] usage: sck-recorder <windowId> <segmentDir> [segmentSeconds]\n", stderr)
+    fputs("[sck]        sck-recorder --concat <outputPath> <segment>...\n", stderr)
     exit(2)
 }
 let winStr = CommandLine.arguments[1]
-let outputPath = CommandLine.arguments[2]
-fputs("[sck] args windowId=\(winStr) output=\(outputPath)\n", stderr)
+let segmentPath = CommandLine.arguments[2]
+let segmentSeconds = CommandLine.arguments.count > 3 ? Int(CommandLine.arguments[3]) ?? 10 : 10
+fputs("[sck] args windowId=\(winStr) segments=\(segmentPath)\n", stderr)
 
 // Ensure CoreGraphics/AppKit are initialized for CGS (avoids CGS_REQUIRE_INIT aborts)
 let _ = NSApplication.shared
@@ -197,13 +306,25 @@ guard let windowId = UInt32(winStr) else {
     fputs("[sck] invalid window id: \(winStr)\n", stderr)
     exit(2)
 }
-let url = URL(fileURLWithPath: outputPath)
+let segmentDir = URL(fileURLWithPath: segmentPath, isDirectory: true)
 
 do {
-    let recorder = try Recorder(windowId: windowId, outputURL: url)
+    try FileManager.default.createDirectory(at: segmentDir, withIntermediateDirectories: true)
+    let recorder = try Recorder(windowId: windowId, segmentDir: segmentDir, segmentSeconds: segmentSeconds)
     try recorder.start()
-    // Block until stdin closes (EOF)
-    _ = try? FileHandle.standardInput.readToEnd()
+    // Handle control commands until "stop" or stdin closes (EOF)
+    commands: while let line = readLine() {
+        switch line.trimmingCharacters(in: .whitespaces) {
+        case "pause":
+            recorder.pause()
+        case "resume":
+            recorder.resume()
+        case "stop":
+            break commands
+        default:
+            fputs("[sck] unknown command: \(line)\n", stderr)
+        }
+    }
     recorder.stop()
     exit(0)
 } catch {
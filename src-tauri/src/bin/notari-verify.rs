@@ -0,0 +1,530 @@
+//! `notari-verify` - headless verification of `.notari` proof packs
+//!
+//! Runs the same offline checks as the desktop app (`Verifier::verify_proof_pack`)
+//! and prints the report.
+//!
+//! Exit codes:
+//! - `0` - verification passed (VERIFIED or WARNING)
+//! - `1` - verification failed (FAILED)
+//! - `2` - usage error, or the proof pack could not be read
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use app_lib::evidence::signer_certificate;
+use app_lib::evidence::x509::Certificate;
+use app_lib::evidence::{CheckResult, VerificationReport, VerificationStatus, Verifier};
+
+const USAGE: &str = "\
+Usage: notari-verify [--json] [--identity <public-key>] [--ca <file>]...
+                     [--block-header <hex>] <proof-pack.notari>
+
+Verify a Notari proof pack offline (signature, file hash and file index).
+
+Options:
+  --json       Print the verification report as JSON
+  --identity <public-key>
+               Require the signer's key to be, or be rotated in from, this
+               identity key (Ed25519, base64)
+  --ca <file>  Require the signer's certificate chain to lead to a CA
+               certificate in this PEM or DER file (repeatable); time-stamp
+               tokens only count as trusted if their TSA chains to one
+  --block-header <hex>
+               Check OpenTimestamps attestations against this raw Bitcoin
+               block header (80 bytes, e.g. from `bitcoin-cli getblockheader
+               <hash> false`); without it they are reported as unchecked
+  -h, --help   Print this help
+  -V, --version
+               Print version";
+
+/// Parsed command-line options
+struct Options {
+    json: bool,
+    identity: Option<String>,
+    ca_files: Vec<PathBuf>,
+    block_header: Option<Vec<u8>>,
+    proof_pack: PathBuf,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut json = false;
+    let mut identity = None;
+    let mut ca_files = Vec::new();
+    let mut block_header = None;
+    let mut proof_pack = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--identity" => {
+                identity = Some(args.next().ok_or("Missing public key after --identity")?)
+            }
+            "--ca" => ca_files.push(PathBuf::from(
+                args.next().ok_or("Missing certificate file after --ca")?,
+            )),
+            "--block-header" => {
+                let header = args
+                    .next()
+                    .ok_or("Missing header hex after --block-header")?;
+                block_header = Some(parse_block_header(&header)?);
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(None);
+            }
+            "-V" | "--version" => {
+                println!("notari-verify {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {}", flag)),
+            path => {
+                if proof_pack.replace(PathBuf::from(path)).is_some() {
+                    return Err("Only one proof pack can be verified at a time".to_string());
+                }
+            }
+        }
+    }
+
+    let proof_pack = proof_pack.ok_or("Missing proof pack path")?;
+    Ok(Some(Options {
+        json,
+        identity,
+        ca_files,
+        block_header,
+        proof_pack,
+    }))
+}
+
+/// Decode a raw Bitcoin block header from hex
+fn parse_block_header(header: &str) -> Result<Vec<u8>, String> {
+    let bytes =
+        hex::decode(header.trim()).map_err(|e| format!("Invalid block header hex: {}", e))?;
+    if bytes.len() != 80 {
+        return Err(format!(
+            "Block header must be 80 bytes, got {}",
+            bytes.len()
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Read the CA certificates in `ca_files`
+fn load_trust_anchors(ca_files: &[PathBuf]) -> Result<Vec<Certificate>, String> {
+    let mut anchors = Vec::new();
+    for path in ca_files {
+        let data =
+            std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let certificates = signer_certificate::parse_certificates(&data)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        if certificates.is_empty() {
+            return Err(format!("No certificates found in {}", path.display()));
+        }
+        anchors.extend(certificates);
+    }
+    Ok(anchors)
+}
+
+/// Verify the proof pack, turning errors into messages
+///
+/// With `identity`, the signer also has to be linked to that identity key. With
+/// `trust_anchors`, the signer's certificate chain has to lead to one of them.
+/// Time-stamp tokens only count as trusted if their TSA chains to one of them.
+/// With `block_header`, OpenTimestamps attestations are checked against it.
+fn verify_proof_pack(
+    proof_pack: &Path,
+    identity: Option<&str>,
+    trust_anchors: &[Certificate],
+    block_header: Option<&[u8]>,
+) -> Result<VerificationReport, String> {
+    if !proof_pack.is_file() {
+        return Err(format!("Proof pack not found: {}", proof_pack.display()));
+    }
+
+    let mut report = Verifier::verify_proof_pack(proof_pack)
+        .map_err(|e| format!("Verification failed: {}", e))?;
+    if let Some(identity) = identity {
+        Verifier::check_identity(&mut report, identity);
+    }
+    Verifier::check_timestamp_authorities(proof_pack, &mut report, trust_anchors)
+        .map_err(|e| format!("Verification failed: {}", e))?;
+    if let Some(block_header) = block_header {
+        Verifier::check_block_header(proof_pack, &mut report, block_header)
+            .map_err(|e| format!("Verification failed: {}", e))?;
+    }
+    if !trust_anchors.is_empty() {
+        Verifier::check_certificate(proof_pack, &mut report, trust_anchors)
+            .map_err(|e| format!("Verification failed: {}", e))?;
+        let info = &mut report.verification;
+        let trusted = info.checks.certificate.as_ref().map(|check| &check.trusted);
+        if trusted != Some(&CheckResult::Pass) {
+            info.status = VerificationStatus::Failed;
+        }
+    }
+    Ok(report)
+}
+
+fn status_label(status: &VerificationStatus) -> &'static str {
+    match status {
+        VerificationStatus::Verified => "VERIFIED",
+        VerificationStatus::Failed => "FAILED",
+        VerificationStatus::Warning => "WARNING",
+    }
+}
+
+fn check_label(check: &CheckResult) -> &'static str {
+    match check {
+        CheckResult::Pass => "PASS",
+        CheckResult::Fail => "FAIL",
+        CheckResult::Skip => "SKIP",
+    }
+}
+
+/// Render the report for humans
+fn format_text(proof_pack: &Path, report: &VerificationReport) -> String {
+    let info = &report.verification;
+    let mut out = String::new();
+
+    out.push_str(&format!("Proof pack:  {}\n", proof_pack.display()));
+    out.push_str(&format!("Status:      {}\n", status_label(&info.status)));
+    out.push_str(&format!("Verified at: {}\n", info.timestamp.to_rfc3339()));
+
+    out.push_str("\nChecks:\n");
+    out.push_str(&format!(
+        "  Manifest structure: {}\n",
+        check_label(&info.checks.manifest_structure)
+    ));
+    out.push_str(&format!(
+        "  Signature:          {}\n",
+        check_label(&info.checks.signature_valid)
+    ));
+    out.push_str(&format!(
+        "  File hash:          {}\n",
+        check_label(&info.checks.hash_match)
+    ));
+    if let Some(ref pack) = info.checks.proof_pack {
+        out.push_str(&format!(
+            "  File index:         {} (proof pack v{})\n",
+            check_label(&pack.index_signature),
+            pack.format_version
+        ));
+        out.push_str(&format!(
+            "  Pack contents:      {}\n",
+            check_label(&pack.files_match)
+        ));
+        for (label, files) in [
+            ("modified", &pack.modified_files),
+            ("not in index", &pack.unlisted_files),
+            ("missing", &pack.missing_files),
+        ] {
+            for file in files {
+                out.push_str(&format!("                      {} ({})\n", file, label));
+            }
+        }
+    }
+    if let Some(ref checkpoints) = info.checks.checkpoints {
+        match checkpoints.error {
+            None => out.push_str(&format!(
+                "  Checkpoints:        PASS ({} signed, {} time-stamped, longest gap {}s)\n",
+                checkpoints.count, checkpoints.timestamped, checkpoints.max_gap_seconds
+            )),
+            Some(ref error) => {
+                out.push_str(&format!("  Checkpoints:        FAIL ({})\n", error));
+            }
+        }
+        if checkpoints.dropped_segments > 0 {
+            out.push_str(&format!(
+                "                      {} checkpointed segment(s) missing from the video\n",
+                checkpoints.dropped_segments
+            ));
+        }
+    }
+    if let Some(ref key_history) = info.checks.key_history {
+        match key_history.error {
+            None if key_history.rotations > 0 => out.push_str(&format!(
+                "  Key history:        PASS ({} rotation(s) from identity key {})\n",
+                key_history.rotations, key_history.identity_key
+            )),
+            None => {}
+            Some(ref error) => {
+                out.push_str(&format!("  Key history:        FAIL ({})\n", error));
+            }
+        }
+        if key_history.identity_pinned != CheckResult::Skip {
+            out.push_str(&format!(
+                "  Pinned identity:    {}\n",
+                check_label(&key_history.identity_pinned)
+            ));
+        }
+    }
+    if let Some(ref certificate) = info.checks.certificate {
+        let sound = certificate.chain_valid == CheckResult::Pass
+            && certificate.valid_at_signing == CheckResult::Pass;
+        match certificate.error {
+            Some(ref error) if !sound => {
+                out.push_str(&format!("  Certificate:        FAIL ({})\n", error));
+            }
+            _ => out.push_str(&format!(
+                "  Certificate:        PASS ({}, issued by {})\n",
+                certificate.subject, certificate.issuer
+            )),
+        }
+        match (&certificate.trusted, &certificate.trust_anchor) {
+            (CheckResult::Pass, Some(anchor)) => {
+                out.push_str(&format!("  Trusted CA:         PASS ({})\n", anchor));
+            }
+            (CheckResult::Skip, _) => {}
+            _ => out.push_str("  Trusted CA:         FAIL\n"),
+        }
+    }
+    for anchor in &info.checks.anchors {
+        out.push_str(&format!(
+            "  Blockchain anchor:  {} (anchored at {})\n",
+            anchor.algorithm, anchor.anchored_at
+        ));
+        if let Some(ref url) = anchor.explorer_url {
+            out.push_str(&format!("                      {}\n", url));
+        }
+        if anchor.hash_match == CheckResult::Fail {
+            out.push_str("  Anchored hash:      FAIL (doesn't match this manifest)\n");
+        }
+        if let Some(ref inclusion) = anchor.merkle_inclusion {
+            if inclusion.verified {
+                out.push_str(&format!(
+                    "  Batch inclusion:    PASS (leaf {} of {}, root {})\n",
+                    inclusion.leaf_index + 1,
+                    inclusion.leaf_count,
+                    inclusion.merkle_root
+                ));
+            } else {
+                out.push_str(&format!(
+                    "  Batch inclusion:    FAIL ({})\n",
+                    inclusion.error.as_deref().unwrap_or("invalid proof")
+                ));
+            }
+        }
+        if let Some(ref attestation) = anchor.bitcoin_attestation {
+            match (&attestation.header_match, attestation.complete) {
+                (CheckResult::Pass, _) => out.push_str(&format!(
+                    "  Bitcoin block:      PASS (height {}, hash {}, {})\n",
+                    attestation.height.unwrap_or_default(),
+                    attestation.block_hash.as_deref().unwrap_or_default(),
+                    attestation
+                        .block_time
+                        .map(|t| t.to_rfc3339())
+                        .unwrap_or_default()
+                )),
+                (CheckResult::Fail, _) => out.push_str(&format!(
+                    "  Bitcoin block:      FAIL ({})\n",
+                    attestation
+                        .error
+                        .as_deref()
+                        .unwrap_or("no matching attestation")
+                )),
+                (CheckResult::Skip, true) => {
+                    out.push_str("  Bitcoin block:      COMPLETE (attestation not checked)\n");
+                }
+                (CheckResult::Skip, false) => {
+                    out.push_str("  Bitcoin block:      PENDING (not yet in a Bitcoin block)\n");
+                }
+            }
+        }
+        if let Some(ref token) = anchor.timestamp_token {
+            if token.verified {
+                let tsa_name = token.tsa_name.as_deref().unwrap_or("unknown TSA");
+                let gen_time = token.gen_time.map(|t| t.to_rfc3339()).unwrap_or_default();
+                if token.passed() {
+                    out.push_str(&format!(
+                        "  Timestamp token:    PASS ({}, {})\n",
+                        tsa_name, gen_time
+                    ));
+                } else {
+                    out.push_str(&format!(
+                        "  Timestamp token:    UNTRUSTED (valid signature, untrusted TSA {}, {})\n",
+                        tsa_name, gen_time
+                    ));
+                }
+                if let Some(ref trust_anchor) = token.trust_anchor {
+                    out.push_str(&format!(
+                        "                      TSA trusted via {}\n",
+                        trust_anchor
+                    ));
+                }
+                if let Some(ref fingerprint) = token.tsa_certificate_sha256 {
+                    out.push_str(&format!(
+                        "                      TSA certificate SHA-256 {}\n",
+                        fingerprint
+                    ));
+                }
+            } else {
+                out.push_str(&format!(
+                    "  Timestamp token:    FAIL ({})\n",
+                    token.error.as_deref().unwrap_or("invalid token")
+                ));
+            }
+        }
+    }
+
+    let recording = &info.recording_info;
+    out.push_str("\nRecording:\n");
+    out.push_str(&format!("  Session ID: {}\n", recording.session_id));
+    out.push_str(&format!(
+        "  Created at: {}\n",
+        recording.created_at.to_rfc3339()
+    ));
+    out.push_str(&format!(
+        "  Duration:   {:.1}s\n",
+        recording.duration_seconds
+    ));
+    out.push_str(&format!("  Window:     {}\n", recording.window_title));
+    if let Some(ref title) = recording.title {
+        out.push_str(&format!("  Title:      {}\n", title));
+    }
+    if let Some(ref description) = recording.description {
+        out.push_str(&format!("  Description: {}\n", description));
+    }
+    if let Some(ref tags) = recording.tags {
+        if !tags.is_empty() {
+            out.push_str(&format!("  Tags:       {}\n", tags.join(", ")));
+        }
+    }
+
+    let signature = &info.signature_info;
+    out.push_str("\nSignature:\n");
+    out.push_str(&format!("  Algorithm:   {}\n", signature.algorithm));
+    out.push_str(&format!("  Public key:  {}\n", signature.public_key));
+    out.push_str(&format!("  Signer:      {}\n", signature.verified_by));
+
+    out
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let report = load_trust_anchors(&options.ca_files).and_then(|trust_anchors| {
+        verify_proof_pack(
+            &options.proof_pack,
+            options.identity.as_deref(),
+            &trust_anchors,
+            options.block_header.as_deref(),
+        )
+    });
+    let report = match report {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    if options.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("error: Failed to serialize report: {}", e);
+                return ExitCode::from(2);
+            }
+        }
+    } else {
+        print!("{}", format_text(&options.proof_pack, &report));
+    }
+
+    match report.verification.status {
+        VerificationStatus::Failed => ExitCode::from(1),
+        VerificationStatus::Verified | VerificationStatus::Warning => ExitCode::SUCCESS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_args_text_mode() {
+        let options = parse_args(args(&["recording.notari"])).unwrap().unwrap();
+        assert!(!options.json);
+        assert_eq!(options.proof_pack, PathBuf::from("recording.notari"));
+    }
+
+    #[test]
+    fn test_parse_args_json_mode() {
+        let options = parse_args(args(&["--json", "recording.notari"]))
+            .unwrap()
+            .unwrap();
+        assert!(options.json);
+        assert_eq!(options.identity, None);
+    }
+
+    #[test]
+    fn test_parse_args_identity() {
+        let options = parse_args(args(&["--identity", "a2V5", "recording.notari"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.identity.as_deref(), Some("a2V5"));
+        assert!(parse_args(args(&["recording.notari", "--identity"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_ca_files() {
+        let options = parse_args(args(&[
+            "--ca",
+            "root.pem",
+            "--ca",
+            "intermediate.der",
+            "recording.notari",
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            options.ca_files,
+            vec![PathBuf::from("root.pem"), PathBuf::from("intermediate.der")]
+        );
+        assert!(parse_args(args(&["recording.notari", "--ca"])).is_err());
+        assert!(load_trust_anchors(&[PathBuf::from("/nonexistent/ca.pem")]).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_block_header() {
+        let header = "00".repeat(80);
+        let options = parse_args(args(&["--block-header", &header, "recording.notari"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.block_header, Some(vec![0; 80]));
+        assert_eq!(
+            parse_args(args(&["recording.notari"]))
+                .unwrap()
+                .unwrap()
+                .block_header,
+            None
+        );
+        assert!(parse_args(args(&["--block-header", "00", "recording.notari"])).is_err());
+        assert!(parse_args(args(&["--block-header", "zz", "recording.notari"])).is_err());
+        assert!(parse_args(args(&["recording.notari", "--block-header"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_rejects_missing_and_extra_paths() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["a.notari", "b.notari"])).is_err());
+        assert!(parse_args(args(&["--bogus", "a.notari"])).is_err());
+    }
+
+    #[test]
+    fn test_verify_missing_proof_pack() {
+        let result = verify_proof_pack(Path::new("/nonexistent/recording.notari"), None, &[], None);
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::app_log;
 use crate::events::EventEmitter;
+use crate::evidence::blockchain::AnchorQueue;
 use crate::evidence::{
     BlockchainAnchorerFactory, BlockchainConfig, BlockchainEnvironment, ChainConfig, WalletManager,
 };
@@ -10,12 +11,15 @@ use tauri::{AppHandle, State};
 /// Blockchain configuration state
 pub struct BlockchainState {
     pub config: Mutex<Option<BlockchainConfig>>,
+    /// Recordings waiting to be anchored together in the next batch
+    pub queue: Mutex<AnchorQueue>,
 }
 
 impl BlockchainState {
     pub fn new() -> Self {
         Self {
             config: Mutex::new(None),
+            queue: Mutex::new(AnchorQueue::new()),
         }
     }
 }
@@ -24,12 +28,14 @@ impl BlockchainState {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockchainConfigDto {
     pub enabled: bool,
-    pub environment: String, // "Mock", "Testnet", "Mainnet"
+    pub environment: String, // "Mock", "Testnet", "Mainnet", "OpenTimestamps", "Rfc3161"
     pub chain_id: u64,
     pub chain_name: String,
     pub auto_anchor: bool,
     pub has_wallet: bool,
     pub wallet_address: Option<String>,
+    /// Chain or service new anchors go to, as in `AnchorProof::service_id`
+    pub service_id: String,
 }
 
 /// Chain information for frontend
@@ -78,6 +84,7 @@ pub async fn get_blockchain_config(
             auto_anchor: config.auto_anchor,
             has_wallet,
             wallet_address: config.wallet.as_ref().map(|w| w.address.clone()),
+            service_id: config.service_id(),
         })
     } else {
         // Return default config
@@ -89,6 +96,7 @@ pub async fn get_blockchain_config(
             auto_anchor: false,
             has_wallet: false,
             wallet_address: None,
+            service_id: BlockchainConfig::default().service_id(),
         })
     }
 }
@@ -108,6 +116,8 @@ pub async fn set_blockchain_config(
         "Mock" => BlockchainEnvironment::Mock,
         "Testnet" => BlockchainEnvironment::Testnet,
         "Mainnet" => BlockchainEnvironment::Mainnet,
+        "OpenTimestamps" => BlockchainEnvironment::OpenTimestamps,
+        "Rfc3161" => BlockchainEnvironment::Rfc3161,
         _ => return Err("Invalid environment".to_string()),
     };
 
@@ -259,13 +269,15 @@ pub async fn has_private_key(state: State<'_, BlockchainState>) -> Result<bool,
 #[tauri::command]
 pub async fn get_balance(state: State<'_, BlockchainState>) -> Result<f64, String> {
     // Extract config data before any async operations
-    let (environment, chain_config, wallet_config) = {
+    let (environment, chain_config, wallet_config, ots_config, tsa_config) = {
         let config_lock = state.config.lock().map_err(|e| e.to_string())?;
         let config = config_lock.as_ref().ok_or("No blockchain config")?;
         (
             config.environment.clone(),
             config.chain.clone(),
             config.wallet.clone(),
+            config.opentimestamps.clone(),
+            config.tsa.clone(),
         )
     };
 
@@ -274,6 +286,8 @@ pub async fn get_balance(state: State<'_, BlockchainState>) -> Result<f64, Strin
         &environment,
         &chain_config,
         &wallet_config,
+        &ots_config,
+        &tsa_config,
     )
     .map_err(|e| e.to_string())?;
 
@@ -284,13 +298,15 @@ pub async fn get_balance(state: State<'_, BlockchainState>) -> Result<f64, Strin
 #[tauri::command]
 pub async fn estimate_anchor_cost(state: State<'_, BlockchainState>) -> Result<f64, String> {
     // Extract config data before any async operations
-    let (environment, chain_config, wallet_config) = {
+    let (environment, chain_config, wallet_config, ots_config, tsa_config) = {
         let config_lock = state.config.lock().map_err(|e| e.to_string())?;
         let config = config_lock.as_ref().ok_or("No blockchain config")?;
         (
             config.environment.clone(),
             config.chain.clone(),
             config.wallet.clone(),
+            config.opentimestamps.clone(),
+            config.tsa.clone(),
         )
     };
 
@@ -299,6 +315,8 @@ pub async fn estimate_anchor_cost(state: State<'_, BlockchainState>) -> Result<f
         &environment,
         &chain_config,
         &wallet_config,
+        &ots_config,
+        &tsa_config,
     )
     .map_err(|e| e.to_string())?;
 
@@ -309,24 +327,36 @@ pub async fn estimate_anchor_cost(state: State<'_, BlockchainState>) -> Result<f
 #[tauri::command]
 pub async fn test_connection(state: State<'_, BlockchainState>) -> Result<String, String> {
     // Extract config data before any async operations
-    let (environment, chain_config, wallet_config) = {
+    let (environment, chain_config, wallet_config, ots_config, tsa_config) = {
         let config_lock = state.config.lock().map_err(|e| e.to_string())?;
         let config = config_lock.as_ref().ok_or("No blockchain config")?;
         (
             config.environment.clone(),
             config.chain.clone(),
             config.wallet.clone(),
+            config.opentimestamps.clone(),
+            config.tsa.clone(),
         )
     };
 
     match environment {
         BlockchainEnvironment::Mock => Ok("Mock environment - always connected".to_string()),
+        BlockchainEnvironment::OpenTimestamps => Ok(format!(
+            "OpenTimestamps - {} calendar(s) configured, no wallet required",
+            ots_config.calendars.len()
+        )),
+        BlockchainEnvironment::Rfc3161 => Ok(format!(
+            "RFC 3161 - time-stamp authority {}, no wallet required",
+            tsa_config.url
+        )),
         _ => {
             // Create anchorer using factory
             let anchorer = BlockchainAnchorerFactory::create_from_components(
                 &environment,
                 &chain_config,
                 &wallet_config,
+                &ots_config,
+                &tsa_config,
             )
             .map_err(|e| e.to_string())?;
 
@@ -350,10 +380,9 @@ pub async fn anchor_recording(
 ) -> Result<AnchorResult, String> {
     use crate::evidence::{BlockchainAnchor, EvidenceManifest};
     use chrono::Utc;
-    use std::fs;
 
     // Extract config data before any async operations
-    let (environment, chain_config, wallet_config) = {
+    let (environment, chain_config, wallet_config, ots_config, tsa_config, service) = {
         let config_lock = state.config.lock().map_err(|e| e.to_string())?;
         let config = config_lock.as_ref().ok_or("Blockchain not configured")?;
 
@@ -365,65 +394,31 @@ pub async fn anchor_recording(
             config.environment.clone(),
             config.chain.clone(),
             config.wallet.clone(),
+            config.opentimestamps.clone(),
+            config.tsa.clone(),
+            config.service_id(),
         )
     };
 
-    // Load manifest from .notari ZIP file
-    use std::io::{Read, Write};
-    use zip::ZipArchive;
-
-    let notari_file = fs::File::open(&manifest_path)
-        .map_err(|e| format!("Failed to open .notari file: {}", e))?;
-    let mut archive = ZipArchive::new(notari_file)
-        .map_err(|e| format!("Failed to read .notari archive: {}", e))?;
-
-    // Find the manifest file in the evidence/ directory (it has .json extension)
-    let mut manifest_filename = None;
-    for i in 0..archive.len() {
-        let file = archive
-            .by_index(i)
-            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
-        let name = file.name();
-        if name.starts_with("evidence/") && name.ends_with(".json") {
-            manifest_filename = Some(name.to_string());
-            break;
-        }
-    }
-
-    let manifest_filename =
-        manifest_filename.ok_or("Failed to find manifest file in .notari archive")?;
-
-    // Read the manifest file
-    let mut manifest_file = archive
-        .by_name(&manifest_filename)
-        .map_err(|e| format!("Failed to open manifest file: {}", e))?;
-    let mut manifest_json = String::new();
-    manifest_file
-        .read_to_string(&mut manifest_json)
-        .map_err(|e| format!("Failed to read manifest: {}", e))?;
-    drop(manifest_file); // Release the borrow
-
-    // Store the manifest filename for later use
-    let manifest_filename_for_write = manifest_filename.clone();
-    drop(archive); // Close the archive so we can modify the file
+    // Load manifest from .notari ZIP file (validated and size-bounded)
+    let (_, manifest_json) = crate::evidence::proof_pack::read_proof_pack_manifest(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest from .notari file: {}", e))?;
 
     let mut manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
         .map_err(|e| format!("Failed to parse manifest: {}", e))?;
 
-    // Check if already anchored
-    if manifest.blockchain_anchor.is_some() {
-        return Err("Recording is already anchored to blockchain".to_string());
+    // Further anchors are welcome, but only on other chains or services
+    if manifest.is_anchored_to(&service) {
+        return Err(format!("Recording is already anchored to {}", service));
     }
 
     // Get session ID for events
     let session_id = uuid::Uuid::parse_str(&manifest.recording.session_id)
         .map_err(|e| format!("Invalid session ID: {}", e))?;
 
-    // Compute the manifest hash (SHA256 of the manifest JSON)
-    use sha2::{Digest, Sha256};
-    let mut hasher = Sha256::new();
-    hasher.update(manifest_json.as_bytes());
-    let manifest_hash = format!("{:x}", hasher.finalize());
+    // Compute the manifest hash over its canonical encoding, so the anchor does not
+    // depend on how the manifest text happens to be formatted inside the archive
+    let manifest_hash = manifest.compute_pre_anchor_hash();
 
     // Emit anchor started event
     let _ = EventEmitter::blockchain_anchor_started(&app, session_id, &manifest_hash);
@@ -433,6 +428,8 @@ pub async fn anchor_recording(
         &environment,
         &chain_config,
         &wallet_config,
+        &ots_config,
+        &tsa_config,
     )
     .map_err(|e| e.to_string())?;
 
@@ -452,9 +449,10 @@ pub async fn anchor_recording(
     })?;
     app_log!(crate::logger::LogLevel::Info, "Anchoring successful");
 
-    // Update manifest with anchor
-    manifest.blockchain_anchor = Some(BlockchainAnchor {
-        anchored_at: Utc::now(),
+    // Add the anchor to the manifest (alongside any anchors on other services)
+    let anchored_at = Utc::now();
+    manifest.add_anchor(BlockchainAnchor {
+        anchored_at,
         anchored_hash: manifest_hash.clone(),
         manifest_hash: manifest_hash.clone(),
         proof: proof.clone(),
@@ -462,96 +460,340 @@ pub async fn anchor_recording(
 
     // Re-sign the manifest to include the blockchain anchor in the signature
     // This provides offline verification of anchor metadata
-    use crate::evidence::keychain;
-    use crate::evidence::KeyManager;
+    let key_manager = load_manifest_signing_key(&manifest)?;
+    manifest.sign(&key_manager);
 
-    // Check if signing key exists
-    if !keychain::has_signing_key() {
-        return Err("No signing key found in keychain. The signing key may have been deleted or the app may have been reinstalled. Cannot anchor recording without the original signing key.".to_string());
+    // Save updated manifest back to .notari ZIP file (re-signs the file index)
+    crate::evidence::proof_pack::replace_proof_pack_manifest(
+        &manifest_path,
+        &manifest,
+        &key_manager,
+    )
+    .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+
+    // Emit anchor completed event
+    emit_anchor_completed(&app, session_id, &proof);
+
+    // Return result
+    Ok(AnchorResult {
+        success: true,
+        anchored_at: anchored_at.to_rfc3339(),
+        proof: proof,
+    })
+}
+
+/// Queue a recording to be anchored in the next batch
+///
+/// Returns the number of recordings now waiting in the queue.
+#[tauri::command]
+pub async fn queue_anchor(
+    state: State<'_, BlockchainState>,
+    manifest_path: String,
+) -> Result<usize, String> {
+    use crate::evidence::EvidenceManifest;
+
+    let (_, manifest_json) = crate::evidence::proof_pack::read_proof_pack_manifest(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest from .notari file: {}", e))?;
+    let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    let service = {
+        let config_lock = state.config.lock().map_err(|e| e.to_string())?;
+        let config = config_lock.as_ref().ok_or("Blockchain not configured")?;
+        config.service_id()
+    };
+    if manifest.is_anchored_to(&service) {
+        return Err(format!("Recording is already anchored to {}", service));
     }
 
-    let key_bytes = keychain::retrieve_signing_key()
-        .map_err(|e| format!("Failed to retrieve signing key: {}", e))?;
-    let key_manager = KeyManager::from_bytes(&key_bytes)
-        .map_err(|e| format!("Failed to load signing key: {}", e))?;
+    let mut queue = state.queue.lock().map_err(|e| e.to_string())?;
+    let queued = queue
+        .enqueue(&manifest_path, &manifest.compute_pre_anchor_hash())
+        .map_err(|e| e.to_string())?;
+    save_queue(&queue);
+    Ok(queued)
+}
+
+/// Save the anchor queue so it survives a restart
+///
+/// Failing to save is logged rather than returned: the queue in memory is still
+/// correct, only a restart would lose it.
+fn save_queue(queue: &AnchorQueue) {
+    use crate::repository::AnchorQueueRepository;
 
-    // Verify that this key matches the manifest's public key
-    let manifest_public_key = &manifest.signature.public_key;
-    let current_public_key = {
-        use base64::{engine::general_purpose, Engine as _};
-        general_purpose::STANDARD.encode(key_manager.public_key().as_bytes())
+    let repo_manager = crate::repository::get_repository_manager();
+    if let Err(e) = repo_manager.anchors().save_queue(queue.pending()) {
+        app_log!(
+            crate::logger::LogLevel::Warn,
+            "Failed to save anchor queue: {}",
+            e
+        );
+    }
+}
+
+/// List recordings waiting for the next batched anchor
+#[tauri::command]
+pub async fn get_anchor_queue(
+    state: State<'_, BlockchainState>,
+) -> Result<Vec<crate::evidence::blockchain::QueuedAnchor>, String> {
+    let queue = state.queue.lock().map_err(|e| e.to_string())?;
+    Ok(queue.pending().to_vec())
+}
+
+/// Anchor every queued recording with a single Merkle root
+///
+/// Each recording's manifest gets its own inclusion proof and is re-signed. Recordings
+/// that cannot be updated (e.g. modified since they were queued) are reported in
+/// `failed`; if anchoring itself fails, the whole batch stays queued. The saved queue
+/// keeps the batch until it has been anchored, so it is queued again if the app quits
+/// in between.
+#[tauri::command]
+pub async fn flush_anchor_queue(
+    state: State<'_, BlockchainState>,
+    app: AppHandle,
+) -> Result<BatchAnchorResult, String> {
+    use crate::evidence::blockchain::anchor_batch;
+    use crate::evidence::AnchorProof;
+    use chrono::Utc;
+
+    // Extract config data before any async operations
+    let (environment, chain_config, wallet_config, ots_config, tsa_config) = {
+        let config_lock = state.config.lock().map_err(|e| e.to_string())?;
+        let config = config_lock.as_ref().ok_or("Blockchain not configured")?;
+
+        if !config.enabled {
+            return Err("Blockchain anchoring is not enabled".to_string());
+        }
+
+        (
+            config.environment.clone(),
+            config.chain.clone(),
+            config.wallet.clone(),
+            config.opentimestamps.clone(),
+            config.tsa.clone(),
+        )
     };
 
-    if manifest_public_key != &current_public_key {
-        return Err("Signing key mismatch. The key in the keychain does not match the key used to sign this recording. Cannot anchor recording with a different key.".to_string());
+    let batch = state.queue.lock().map_err(|e| e.to_string())?.take();
+    if batch.is_empty() {
+        return Err("No recordings are queued for anchoring".to_string());
     }
+    let restore = |batch: Vec<crate::evidence::blockchain::QueuedAnchor>| {
+        if let Ok(mut queue) = state.queue.lock() {
+            queue.restore(batch);
+            save_queue(&queue);
+        }
+    };
 
-    manifest.sign(&key_manager);
+    let anchorer = match BlockchainAnchorerFactory::create_from_components(
+        &environment,
+        &chain_config,
+        &wallet_config,
+        &ots_config,
+        &tsa_config,
+    ) {
+        Ok(anchorer) => anchorer,
+        Err(e) => {
+            restore(batch);
+            return Err(e.to_string());
+        }
+    };
+
+    app_log!(
+        crate::logger::LogLevel::Info,
+        "Anchoring batch of {} manifest hashes",
+        batch.len()
+    );
+    let hashes: Vec<String> = batch.iter().map(|q| q.manifest_hash.clone()).collect();
+    let proofs = match anchor_batch(anchorer.as_ref(), &hashes).await {
+        Ok(proofs) => proofs,
+        Err(e) => {
+            restore(batch);
+            return Err(format!("Failed to anchor batch: {}", e));
+        }
+    };
+    let (merkle_root, root_proof) = match proofs.first() {
+        Some(AnchorProof::MerkleBatch {
+            merkle_root,
+            root_proof,
+            ..
+        }) => (merkle_root.clone(), root_proof.as_ref().clone()),
+        _ => return Err("Batch anchoring returned no proofs".to_string()),
+    };
 
-    // Save updated manifest back to .notari ZIP file
-    let updated_json = serde_json::to_string_pretty(&manifest)
-        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
-
-    // Create a temporary file for the new ZIP
-    let temp_path = format!("{}.tmp", manifest_path);
-
-    // Open the original archive for reading
-    let original_file = fs::File::open(&manifest_path)
-        .map_err(|e| format!("Failed to open original .notari file: {}", e))?;
-    let mut original_archive = ZipArchive::new(original_file)
-        .map_err(|e| format!("Failed to read original archive: {}", e))?;
-
-    // Create new archive for writing
-    let new_file =
-        fs::File::create(&temp_path).map_err(|e| format!("Failed to create temp file: {}", e))?;
-    let mut new_archive = zip::ZipWriter::new(new_file);
-
-    // Copy all files except the manifest file
-    for i in 0..original_archive.len() {
-        let mut file = original_archive
-            .by_index(i)
-            .map_err(|e| format!("Failed to read file from archive: {}", e))?;
-        let name = file.name().to_string();
-
-        if name != manifest_filename_for_write {
-            // Copy this file as-is
-            let options =
-                zip::write::FileOptions::<()>::default().compression_method(file.compression());
-            new_archive
-                .start_file(&name, options)
-                .map_err(|e| format!("Failed to start file in new archive: {}", e))?;
-
-            let mut buffer = Vec::new();
-            file.read_to_end(&mut buffer)
-                .map_err(|e| format!("Failed to read file contents: {}", e))?;
-            new_archive
-                .write_all(&buffer)
-                .map_err(|e| format!("Failed to write file to new archive: {}", e))?;
+    let anchored_at = Utc::now();
+    let mut anchored = Vec::new();
+    let mut failed = Vec::new();
+    for (queued, proof) in batch.into_iter().zip(proofs) {
+        match apply_batch_anchor(&queued, &proof, anchored_at) {
+            Ok(session_id) => {
+                emit_anchor_completed(&app, session_id, &proof);
+                anchored.push(queued.manifest_path);
+            }
+            Err(error) => {
+                app_log!(
+                    crate::logger::LogLevel::Error,
+                    "Failed to store batch anchor for {}: {}",
+                    queued.manifest_path,
+                    error
+                );
+                failed.push(BatchAnchorFailure {
+                    manifest_path: queued.manifest_path,
+                    error,
+                });
+            }
         }
     }
-    drop(original_archive); // Close original archive
-
-    // Add the updated manifest file
-    let options = zip::write::FileOptions::<()>::default()
-        .compression_method(zip::CompressionMethod::Deflated);
-    new_archive
-        .start_file(&manifest_filename_for_write, options)
-        .map_err(|e| format!("Failed to start manifest file: {}", e))?;
-    new_archive
-        .write_all(updated_json.as_bytes())
-        .map_err(|e| format!("Failed to write manifest: {}", e))?;
-
-    // Finalize the new archive
-    new_archive
-        .finish()
-        .map_err(|e| format!("Failed to finalize archive: {}", e))?;
-
-    // Replace the original file with the new one
-    fs::rename(&temp_path, &manifest_path)
-        .map_err(|e| format!("Failed to replace original file: {}", e))?;
+    if let Ok(queue) = state.queue.lock() {
+        save_queue(&queue);
+    }
 
-    // Emit anchor completed event
-    match &proof {
+    Ok(BatchAnchorResult {
+        merkle_root,
+        anchored_at: anchored_at.to_rfc3339(),
+        anchored,
+        failed,
+        root_proof,
+    })
+}
+
+/// Store a batch inclusion proof in a queued recording's manifest and re-sign it
+fn apply_batch_anchor(
+    queued: &crate::evidence::blockchain::QueuedAnchor,
+    proof: &crate::evidence::AnchorProof,
+    anchored_at: chrono::DateTime<chrono::Utc>,
+) -> Result<uuid::Uuid, String> {
+    use crate::evidence::{BlockchainAnchor, EvidenceManifest};
+
+    let (_, manifest_json) =
+        crate::evidence::proof_pack::read_proof_pack_manifest(&queued.manifest_path)
+            .map_err(|e| format!("Failed to read manifest from .notari file: {}", e))?;
+    let mut manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    if manifest.is_anchored_to(&proof.service_id()) {
+        return Err(format!(
+            "Recording is already anchored to {}",
+            proof.service_id()
+        ));
+    }
+    if manifest.compute_pre_anchor_hash() != queued.manifest_hash {
+        return Err("Manifest changed after it was queued".to_string());
+    }
+    let session_id = uuid::Uuid::parse_str(&manifest.recording.session_id)
+        .map_err(|e| format!("Invalid session ID: {}", e))?;
+
+    manifest.add_anchor(BlockchainAnchor {
+        anchored_at,
+        anchored_hash: queued.manifest_hash.clone(),
+        manifest_hash: queued.manifest_hash.clone(),
+        proof: proof.clone(),
+    });
+
+    let key_manager = load_manifest_signing_key(&manifest)?;
+    manifest.sign(&key_manager);
+    crate::evidence::proof_pack::replace_proof_pack_manifest(
+        &queued.manifest_path,
+        &manifest,
+        &key_manager,
+    )
+    .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+
+    Ok(session_id)
+}
+
+/// Upgrade a pending OpenTimestamps anchor to a complete Bitcoin attestation
+///
+/// Asks the configured calendars for the completed proof; if one is available the
+/// manifest is re-signed and saved. Returns the (possibly unchanged) anchor proof.
+#[tauri::command]
+pub async fn upgrade_anchor(
+    state: State<'_, BlockchainState>,
+    manifest_path: String,
+) -> Result<crate::evidence::AnchorProof, String> {
+    use crate::evidence::{AnchorProof, EvidenceManifest, OpenTimestampsAnchorer};
+
+    let ots_config = {
+        let config_lock = state.config.lock().map_err(|e| e.to_string())?;
+        config_lock
+            .as_ref()
+            .map(|config| config.opentimestamps.clone())
+            .unwrap_or_default()
+    };
+
+    let (_, manifest_json) = crate::evidence::proof_pack::read_proof_pack_manifest(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest from .notari file: {}", e))?;
+    let mut manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    // A batched anchor is upgraded through the proof of its Merkle root
+    let is_pending = |proof: &AnchorProof| {
+        matches!(
+            proof.root_proof(),
+            AnchorProof::OpenTimestamps {
+                bitcoin_block: None,
+                ..
+            }
+        )
+    };
+    manifest.migrate_legacy_anchor();
+    let Some(index) = manifest
+        .anchors
+        .iter()
+        .position(|anchor| is_pending(&anchor.proof))
+    else {
+        // Nothing pending; report an already complete OpenTimestamps anchor if there is one
+        return manifest
+            .anchors
+            .iter()
+            .find(|anchor| {
+                matches!(
+                    anchor.proof.root_proof(),
+                    AnchorProof::OpenTimestamps { .. }
+                )
+            })
+            .map(|anchor| anchor.proof.clone())
+            .ok_or_else(|| "Recording has no OpenTimestamps anchor to upgrade".to_string());
+    };
+    let anchor = &mut manifest.anchors[index];
+    let pending = anchor.proof.root_proof_mut();
+
+    let anchorer = OpenTimestampsAnchorer::new(&ots_config).map_err(|e| e.to_string())?;
+    let upgraded = anchorer
+        .upgrade(pending)
+        .await
+        .map_err(|e| format!("Failed to upgrade anchor: {}", e))?;
+    if is_pending(&upgraded) {
+        // Still pending
+        return Ok(anchor.proof.clone());
+    }
+    *pending = upgraded;
+    let upgraded = anchor.proof.clone();
+
+    let key_manager = load_manifest_signing_key(&manifest)?;
+    manifest.sign(&key_manager);
+    crate::evidence::proof_pack::replace_proof_pack_manifest(
+        &manifest_path,
+        &manifest,
+        &key_manager,
+    )
+    .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+
+    app_log!(
+        crate::logger::LogLevel::Info,
+        "OpenTimestamps anchor upgraded: {}",
+        upgraded.description()
+    );
+    Ok(upgraded)
+}
+
+/// Emit the completed event for `proof`, reporting what the anchor can be looked up by
+fn emit_anchor_completed(
+    app: &AppHandle,
+    session_id: uuid::Uuid,
+    proof: &crate::evidence::AnchorProof,
+) {
+    match proof {
         crate::evidence::AnchorProof::Ethereum {
             tx_hash,
             block_number,
@@ -559,7 +801,7 @@ pub async fn anchor_recording(
             ..
         } => {
             let _ = EventEmitter::blockchain_anchor_completed(
-                &app,
+                app,
                 session_id,
                 tx_hash,
                 *block_number,
@@ -569,35 +811,49 @@ pub async fn anchor_recording(
         crate::evidence::AnchorProof::Mock { .. } => {
             // For mock, just emit with placeholder values
             let _ = EventEmitter::blockchain_anchor_completed(
-                &app,
+                app,
                 session_id,
                 "mock_tx_hash",
                 0,
                 "mock_explorer_url",
             );
         }
-        _ => {
-            // For other proof types, emit with generic values
-            let _ = EventEmitter::blockchain_anchor_completed(
-                &app,
-                session_id,
-                "unknown_tx_hash",
-                0,
-                "unknown_explorer_url",
-            );
+        crate::evidence::AnchorProof::OpenTimestamps { .. } => {
+            // Pending until a calendar's Bitcoin transaction confirms
+            let _ =
+                EventEmitter::blockchain_anchor_completed(app, session_id, "ots_pending", 0, "");
+        }
+        crate::evidence::AnchorProof::Rfc3161 { serial_number, .. } => {
+            // No transaction; report the token serial number instead
+            let _ =
+                EventEmitter::blockchain_anchor_completed(app, session_id, serial_number, 0, "");
         }
+        crate::evidence::AnchorProof::MerkleBatch { root_proof, .. } => {
+            // The batch shares the root's transaction
+            emit_anchor_completed(app, session_id, root_proof);
+        }
+    }
+}
+
+/// Load the keychain signing key that signed `manifest`
+///
+/// That's the current key, or for recordings signed before a key rotation the
+/// key the rotation retired.
+pub(crate) fn load_manifest_signing_key(
+    manifest: &crate::evidence::EvidenceManifest,
+) -> Result<crate::evidence::KeyManager, String> {
+    use crate::evidence::{key_history, keychain};
+
+    // Check if signing key exists
+    if !keychain::has_signing_key() {
+        return Err("No signing key found in keychain. The signing key may have been deleted or the app may have been reinstalled. Cannot re-sign the recording without the original signing key.".to_string());
     }
 
-    // Return result
-    Ok(AnchorResult {
-        success: true,
-        anchored_at: manifest
-            .blockchain_anchor
-            .as_ref()
-            .unwrap()
-            .anchored_at
-            .to_rfc3339(),
-        proof: proof,
+    key_history::signing_key_for(&manifest.signature.public_key).map_err(|e| {
+        format!(
+            "Signing key mismatch. No key in the keychain matches the key used to sign this recording, so it can't be re-signed: {}",
+            e
+        )
     })
 }
 
@@ -608,3 +864,22 @@ pub struct AnchorResult {
     pub anchored_at: String,
     pub proof: crate::evidence::AnchorProof,
 }
+
+/// Batch anchoring result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchAnchorResult {
+    pub merkle_root: String,
+    pub anchored_at: String,
+    /// Proof packs now carrying an inclusion proof
+    pub anchored: Vec<String>,
+    pub failed: Vec<BatchAnchorFailure>,
+    /// Proof anchoring the Merkle root
+    pub root_proof: crate::evidence::AnchorProof,
+}
+
+/// A queued recording whose manifest could not be updated after the batch was anchored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchAnchorFailure {
+    pub manifest_path: String,
+    pub error: String,
+}
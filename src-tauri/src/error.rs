@@ -90,6 +90,19 @@ pub enum NotariError {
     #[error("Invalid proof pack format: {0}")]
     InvalidProofPack(String),
 
+    /// Proof pack rejected during extraction (untrusted archive failed validation)
+    #[error("Invalid proof pack format: {0}")]
+    ProofPackRejected(#[from] ProofPackRejection),
+
+    #[error("Malformed DER data: {0}")]
+    InvalidDer(String),
+
+    #[error("Certificate error: {0}")]
+    CertificateError(String),
+
+    #[error("Invalid trusted signer: {0}")]
+    InvalidTrustedSigner(String),
+
     // ============================================================================
     // Keychain Errors
     // ============================================================================
@@ -108,6 +121,9 @@ pub enum NotariError {
     #[error("Keychain not supported on this platform")]
     KeychainNotSupported,
 
+    #[error("Failed to rotate signing key: {0}")]
+    KeyRotationFailed(String),
+
     // ============================================================================
     // Storage Errors
     // ============================================================================
@@ -135,6 +151,9 @@ pub enum NotariError {
     #[error("Failed to verify blockchain anchor: {0}")]
     BlockchainVerificationFailed(String),
 
+    #[error("Invalid Merkle proof: {0}")]
+    InvalidMerkleProof(String),
+
     #[error("Wallet error: {0}")]
     WalletError(String),
 
@@ -204,6 +223,46 @@ pub enum NotariError {
     PipelineError(String),
 }
 
+/// Reason an untrusted proof pack was rejected before extraction
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ProofPackRejection {
+    #[error("entry '{0}' has an absolute path or escapes the extraction directory")]
+    UnsafePath(String),
+
+    #[error("entry '{0}' is a symbolic link")]
+    Symlink(String),
+
+    #[error("unexpected entry '{0}'")]
+    UnexpectedEntry(String),
+
+    #[error("duplicate entry '{0}'")]
+    DuplicateEntry(String),
+
+    #[error("more than one video file ('{0}')")]
+    MultipleVideos(String),
+
+    #[error("more than one manifest file ('{0}')")]
+    MultipleManifests(String),
+
+    #[error("No video file found in proof pack")]
+    MissingVideo,
+
+    #[error("No manifest file found in proof pack")]
+    MissingManifest,
+
+    #[error("archive has {count} entries (limit {limit})")]
+    TooManyEntries { count: usize, limit: usize },
+
+    #[error("entry '{name}' is {size} bytes (limit {limit})")]
+    EntryTooLarge { name: String, size: u64, limit: u64 },
+
+    #[error("entry '{name}' is larger than its declared size of {declared} bytes")]
+    SizeMismatch { name: String, declared: u64 },
+
+    #[error("archive expands to more than {limit} bytes")]
+    ArchiveTooLarge { limit: u64 },
+}
+
 // Implement conversion from PoisonError for Mutex locks
 impl<T> From<std::sync::PoisonError<T>> for NotariError {
     fn from(_: std::sync::PoisonError<T>) -> Self {
@@ -273,6 +332,17 @@ mod tests {
         assert!(matches!(notari_err, NotariError::Io(_)));
     }
 
+    #[test]
+    fn test_proof_pack_rejection_conversion() {
+        let err: NotariError = ProofPackRejection::UnsafePath("../evil".to_string()).into();
+        assert!(matches!(
+            err,
+            NotariError::ProofPackRejected(ProofPackRejection::UnsafePath(_))
+        ));
+        assert!(err.to_string().starts_with("Invalid proof pack format:"));
+        assert!(err.to_string().contains("../evil"));
+    }
+
     #[test]
     fn test_insufficient_disk_space() {
         let err = NotariError::InsufficientDiskSpace {
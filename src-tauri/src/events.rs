@@ -9,6 +9,7 @@ pub mod event_names {
     pub const RECORDING_ERROR: &str = "recording:error";
     pub const PIPELINE_STARTED: &str = "pipeline:started";
     pub const PIPELINE_STAGE_STARTED: &str = "pipeline:stage-started";
+    pub const PIPELINE_STAGE_PROGRESS: &str = "pipeline:stage-progress";
     pub const PIPELINE_STAGE_COMPLETED: &str = "pipeline:stage-completed";
     pub const PIPELINE_STAGE_SKIPPED: &str = "pipeline:stage-skipped";
     pub const PIPELINE_COMPLETED: &str = "pipeline:completed";
@@ -71,6 +72,21 @@ pub struct PipelineStageStartedEvent {
     pub timestamp: String,
 }
 
+/// Pipeline stage progress event (emitted while a long-running stage works)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineStageProgressEvent {
+    pub session_id: Uuid,
+    pub pipeline_name: String,
+    pub stage_name: String,
+    pub stage_index: usize,
+    pub total_stages: usize,
+    pub processed: u64,
+    pub total: u64,
+    pub percent: f64,
+    pub timestamp: String,
+}
+
 /// Pipeline stage completed event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -367,6 +383,40 @@ impl EventEmitter {
             .map_err(|e| format!("Failed to emit pipeline stage started event: {}", e))
     }
 
+    /// Emit pipeline stage progress event
+    #[allow(clippy::too_many_arguments)]
+    pub fn pipeline_stage_progress(
+        app: &AppHandle,
+        session_id: Uuid,
+        pipeline_name: &str,
+        stage_name: &str,
+        stage_index: usize,
+        total_stages: usize,
+        processed: u64,
+        total: u64,
+    ) -> Result<(), String> {
+        let percent = if total == 0 {
+            100.0
+        } else {
+            (processed as f64 / total as f64 * 100.0).min(100.0)
+        };
+
+        let event = PipelineStageProgressEvent {
+            session_id,
+            pipeline_name: pipeline_name.to_string(),
+            stage_name: stage_name.to_string(),
+            stage_index,
+            total_stages,
+            processed,
+            total,
+            percent,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+
+        app.emit(event_names::PIPELINE_STAGE_PROGRESS, event)
+            .map_err(|e| format!("Failed to emit pipeline stage progress event: {}", e))
+    }
+
     /// Emit pipeline stage completed event
     pub fn pipeline_stage_completed(
         app: &AppHandle,
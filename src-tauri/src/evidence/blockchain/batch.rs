@@ -0,0 +1,186 @@
+//! Batched anchoring
+//!
+//! Manifest hashes from many recordings are queued, then a single Merkle root is
+//! anchored for all of them. Each recording gets an `AnchorProof::MerkleBatch` holding
+//! its inclusion proof, so one transaction (or timestamp) covers the whole batch.
+
+use super::merkle::{self, MerkleTree};
+use super::{AnchorProof, BlockchainAnchorer};
+use crate::error::{NotariError, NotariResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A recording waiting to be anchored in the next batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedAnchor {
+    /// Path of the `.notari` proof pack
+    pub manifest_path: String,
+    /// Pre-anchor manifest hash (hex)
+    pub manifest_hash: String,
+    pub queued_at: DateTime<Utc>,
+}
+
+/// Queue of recordings awaiting batched anchoring
+#[derive(Debug, Default)]
+pub struct AnchorQueue {
+    pending: Vec<QueuedAnchor>,
+}
+
+impl AnchorQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a recording to the queue, returning the new queue length
+    pub fn enqueue(&mut self, manifest_path: &str, manifest_hash: &str) -> NotariResult<usize> {
+        merkle::decode_hash(manifest_hash)?;
+        if self
+            .pending
+            .iter()
+            .any(|p| p.manifest_path == manifest_path || p.manifest_hash == manifest_hash)
+        {
+            return Err(NotariError::BlockchainAnchorFailed(format!(
+                "{} is already queued for anchoring",
+                manifest_path
+            )));
+        }
+
+        self.pending.push(QueuedAnchor {
+            manifest_path: manifest_path.to_string(),
+            manifest_hash: manifest_hash.to_string(),
+            queued_at: Utc::now(),
+        });
+        Ok(self.pending.len())
+    }
+
+    pub fn pending(&self) -> &[QueuedAnchor] {
+        &self.pending
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Remove and return everything queued, to be anchored as one batch
+    pub fn take(&mut self) -> Vec<QueuedAnchor> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Put back a batch that failed to anchor, ahead of anything queued since
+    pub fn restore(&mut self, mut batch: Vec<QueuedAnchor>) {
+        batch.append(&mut self.pending);
+        self.pending = batch;
+    }
+}
+
+/// Anchor `hashes` as one Merkle batch
+///
+/// Only the Merkle root is submitted to `anchorer`. Returns one `AnchorProof::MerkleBatch`
+/// per input hash, in the same order.
+pub async fn anchor_batch(
+    anchorer: &dyn BlockchainAnchorer,
+    hashes: &[String],
+) -> NotariResult<Vec<AnchorProof>> {
+    let leaves = hashes
+        .iter()
+        .map(|hash| merkle::decode_hash(hash))
+        .collect::<NotariResult<Vec<_>>>()?;
+    let tree = MerkleTree::new(&leaves)?;
+    let merkle_root = hex::encode(tree.root());
+
+    let root_proof = anchorer.anchor(&merkle_root).await?;
+
+    (0..tree.len())
+        .map(|index| {
+            Ok(AnchorProof::MerkleBatch {
+                merkle_root: merkle_root.clone(),
+                leaf_index: index as u64,
+                leaf_count: tree.len() as u64,
+                path: tree.proof(index)?,
+                root_proof: Box::new(root_proof.clone()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use sha2::{Digest, Sha256};
+    use std::sync::Mutex;
+
+    /// Records anchored hashes instead of touching the shared mock storage
+    #[derive(Default)]
+    struct RecordingAnchorer {
+        anchored: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl BlockchainAnchorer for RecordingAnchorer {
+        async fn anchor(&self, hash: &str) -> NotariResult<AnchorProof> {
+            self.anchored.lock().unwrap().push(hash.to_string());
+            Ok(AnchorProof::Mock {
+                hash: hash.to_string(),
+                timestamp: Utc::now(),
+            })
+        }
+
+        async fn verify(&self, hash: &str, _proof: &AnchorProof) -> NotariResult<bool> {
+            Ok(self.anchored.lock().unwrap().iter().any(|h| h == hash))
+        }
+
+        async fn estimate_cost(&self) -> NotariResult<f64> {
+            Ok(0.0)
+        }
+
+        async fn get_balance(&self) -> NotariResult<f64> {
+            Ok(0.0)
+        }
+    }
+
+    fn hash(i: u8) -> String {
+        hex::encode(Sha256::digest([i]))
+    }
+
+    #[tokio::test]
+    async fn test_batch_anchors_root_once() {
+        let anchorer = RecordingAnchorer::default();
+        let hashes: Vec<String> = (0..5).map(hash).collect();
+
+        let proofs = anchor_batch(&anchorer, &hashes).await.unwrap();
+        assert_eq!(proofs.len(), 5);
+        assert_eq!(anchorer.anchored.lock().unwrap().len(), 1);
+
+        for (hash, proof) in hashes.iter().zip(&proofs) {
+            let (root, root_proof) = proof.resolve_root(hash).unwrap();
+            assert!(anchorer.verify(&root, root_proof).await.unwrap());
+        }
+        assert!(proofs[0].resolve_root(&hashes[1]).is_err());
+    }
+
+    #[test]
+    fn test_queue_rejects_duplicates_and_restores_failed_batch() {
+        let mut queue = AnchorQueue::new();
+        assert_eq!(queue.enqueue("a.notari", &hash(1)).unwrap(), 1);
+        assert!(queue.enqueue("a.notari", &hash(2)).is_err());
+        assert!(queue.enqueue("b.notari", &hash(1)).is_err());
+        assert!(queue.enqueue("b.notari", "not-a-hash").is_err());
+
+        let batch = queue.take();
+        assert!(queue.is_empty());
+        queue.enqueue("c.notari", &hash(3)).unwrap();
+        queue.restore(batch);
+
+        let paths: Vec<_> = queue
+            .pending()
+            .iter()
+            .map(|p| p.manifest_path.as_str())
+            .collect();
+        assert_eq!(paths, ["a.notari", "c.notari"]);
+    }
+}
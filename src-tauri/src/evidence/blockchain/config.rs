@@ -18,6 +18,14 @@ pub struct BlockchainConfig {
 
     /// Automatically anchor recordings after completion
     pub auto_anchor: bool,
+
+    /// OpenTimestamps calendars (used when environment is OpenTimestamps)
+    #[serde(default)]
+    pub opentimestamps: OpenTimestampsConfig,
+
+    /// Time-stamp authority (used when environment is Rfc3161)
+    #[serde(default)]
+    pub tsa: TsaConfig,
 }
 
 /// Blockchain environment
@@ -31,6 +39,12 @@ pub enum BlockchainEnvironment {
 
     /// Mainnet for production (real costs)
     Mainnet,
+
+    /// Bitcoin via OpenTimestamps calendars (free, no wallet, confirms in hours)
+    OpenTimestamps,
+
+    /// RFC 3161 trusted timestamp authority (no wallet, immediate)
+    Rfc3161,
 }
 
 /// Chain configuration
@@ -44,6 +58,49 @@ pub struct ChainConfig {
     pub currency_symbol: String,
 }
 
+/// Public Bitcoin calendar servers run by the OpenTimestamps project and partners
+pub const DEFAULT_OTS_CALENDARS: &[&str] = &[
+    "https://alice.btc.calendar.opentimestamps.org",
+    "https://bob.btc.calendar.opentimestamps.org",
+    "https://finney.calendar.eternitywall.com",
+];
+
+/// OpenTimestamps configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpenTimestampsConfig {
+    /// Calendar server base URLs; digests are submitted to each of them
+    pub calendars: Vec<String>,
+}
+
+impl Default for OpenTimestampsConfig {
+    fn default() -> Self {
+        Self {
+            calendars: DEFAULT_OTS_CALENDARS
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Public RFC 3161 time-stamp authority used by default
+pub const DEFAULT_TSA_URL: &str = "https://freetsa.org/tsr";
+
+/// RFC 3161 time-stamp authority configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TsaConfig {
+    /// TSA endpoint accepting `application/timestamp-query` POSTs
+    pub url: String,
+}
+
+impl Default for TsaConfig {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_TSA_URL.to_string(),
+        }
+    }
+}
+
 /// Wallet configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletConfig {
@@ -60,6 +117,27 @@ impl Default for BlockchainConfig {
             chain: ChainConfig::polygon_amoy(),
             wallet: None,
             auto_anchor: false,
+            opentimestamps: OpenTimestampsConfig::default(),
+            tsa: TsaConfig::default(),
+        }
+    }
+}
+
+impl BlockchainConfig {
+    /// Identifier of the chain or service this configuration anchors to
+    ///
+    /// Matches `AnchorProof::service_id` of the proofs it produces, so a recording
+    /// can be checked for an existing anchor before paying for another.
+    pub fn service_id(&self) -> String {
+        match self.environment {
+            BlockchainEnvironment::Mock => "mock".to_string(),
+            BlockchainEnvironment::Testnet | BlockchainEnvironment::Mainnet => format!(
+                "eip155:{}:{}",
+                self.chain.chain_id,
+                self.chain.contract_address.to_lowercase()
+            ),
+            BlockchainEnvironment::OpenTimestamps => "opentimestamps".to_string(),
+            BlockchainEnvironment::Rfc3161 => format!("rfc3161:{}", self.tsa.url.trim()),
         }
     }
 }
@@ -176,6 +254,20 @@ mod tests {
         assert!(invalid.is_none());
     }
 
+    #[test]
+    fn test_service_id() {
+        let mut config = BlockchainConfig::default();
+        assert_eq!(config.service_id(), "mock");
+
+        config.environment = BlockchainEnvironment::Testnet;
+        config.chain.contract_address = "0xABCdef".to_string();
+        assert_eq!(config.service_id(), "eip155:80002:0xabcdef");
+
+        config.environment = BlockchainEnvironment::Rfc3161;
+        config.tsa.url = " https://tsa.example/tsr ".to_string();
+        assert_eq!(config.service_id(), "rfc3161:https://tsa.example/tsr");
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = BlockchainConfig::default();
@@ -1,4 +1,7 @@
-use super::config::{BlockchainConfig, BlockchainEnvironment, ChainConfig, WalletConfig};
+use super::config::{
+    BlockchainConfig, BlockchainEnvironment, ChainConfig, OpenTimestampsConfig, TsaConfig,
+    WalletConfig,
+};
 use crate::error::{NotariError, NotariResult};
 
 /// Builder for constructing BlockchainConfig instances with a fluent API
@@ -23,6 +26,8 @@ pub struct BlockchainConfigBuilder {
     chain: Option<ChainConfig>,
     wallet: Option<WalletConfig>,
     auto_anchor: bool,
+    opentimestamps: OpenTimestampsConfig,
+    tsa: TsaConfig,
 }
 
 impl BlockchainConfigBuilder {
@@ -34,6 +39,8 @@ impl BlockchainConfigBuilder {
             chain: None,
             wallet: None,
             auto_anchor: false,
+            opentimestamps: OpenTimestampsConfig::default(),
+            tsa: TsaConfig::default(),
         }
     }
 
@@ -77,6 +84,18 @@ impl BlockchainConfigBuilder {
         self
     }
 
+    /// Set the OpenTimestamps calendar servers
+    pub fn ots_calendars(mut self, calendars: Vec<String>) -> Self {
+        self.opentimestamps = OpenTimestampsConfig { calendars };
+        self
+    }
+
+    /// Set the RFC 3161 time-stamp authority URL
+    pub fn tsa_url(mut self, url: impl Into<String>) -> Self {
+        self.tsa = TsaConfig { url: url.into() };
+        self
+    }
+
     /// Set whether to automatically anchor recordings
     pub fn auto_anchor(mut self, auto_anchor: bool) -> Self {
         self.auto_anchor = auto_anchor;
@@ -107,6 +126,22 @@ impl BlockchainConfigBuilder {
                 (BlockchainEnvironment::Mock, _) => {
                     // Mock accepts any chain ID
                 }
+                (BlockchainEnvironment::OpenTimestamps, _) => {
+                    // Chain is unused; calendars are required instead
+                    if self.opentimestamps.calendars.is_empty() {
+                        return Err(NotariError::BuilderError(
+                            "at least one OpenTimestamps calendar is required".to_string(),
+                        ));
+                    }
+                }
+                (BlockchainEnvironment::Rfc3161, _) => {
+                    // Chain is unused; a TSA URL is required instead
+                    if self.tsa.url.trim().is_empty() {
+                        return Err(NotariError::BuilderError(
+                            "a time-stamp authority URL is required".to_string(),
+                        ));
+                    }
+                }
                 (BlockchainEnvironment::Testnet, chain_id) => {
                     // Validate testnet chain IDs
                     if ![80002, 11155111, 421614].contains(&chain_id) {
@@ -133,6 +168,8 @@ impl BlockchainConfigBuilder {
                 chain,
                 wallet: self.wallet,
                 auto_anchor: self.auto_anchor,
+                opentimestamps: self.opentimestamps,
+                tsa: self.tsa,
             })
         } else {
             // If disabled, use defaults
@@ -142,6 +179,8 @@ impl BlockchainConfigBuilder {
                 chain: self.chain.unwrap_or_else(ChainConfig::polygon_amoy),
                 wallet: self.wallet,
                 auto_anchor: false,
+                opentimestamps: self.opentimestamps,
+                tsa: self.tsa,
             })
         }
     }
@@ -271,6 +310,46 @@ mod tests {
         assert_eq!(config.chain.chain_id, 137);
     }
 
+    #[test]
+    fn test_builder_opentimestamps_requires_calendar() {
+        let config = BlockchainConfigBuilder::new()
+            .enabled(true)
+            .environment(BlockchainEnvironment::OpenTimestamps)
+            .chain(ChainConfig::polygon_amoy())
+            .ots_calendars(vec!["http://127.0.0.1:14788".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(config.opentimestamps.calendars.len(), 1);
+
+        let result = BlockchainConfigBuilder::new()
+            .enabled(true)
+            .environment(BlockchainEnvironment::OpenTimestamps)
+            .chain(ChainConfig::polygon_amoy())
+            .ots_calendars(vec![])
+            .build();
+        assert!(matches!(result.unwrap_err(), NotariError::BuilderError(_)));
+    }
+
+    #[test]
+    fn test_builder_rfc3161_requires_tsa_url() {
+        let config = BlockchainConfigBuilder::new()
+            .enabled(true)
+            .environment(BlockchainEnvironment::Rfc3161)
+            .chain(ChainConfig::polygon_amoy())
+            .tsa_url("http://127.0.0.1:3180/tsr")
+            .build()
+            .unwrap();
+        assert_eq!(config.tsa.url, "http://127.0.0.1:3180/tsr");
+
+        let result = BlockchainConfigBuilder::new()
+            .enabled(true)
+            .environment(BlockchainEnvironment::Rfc3161)
+            .chain(ChainConfig::polygon_amoy())
+            .tsa_url("")
+            .build();
+        assert!(matches!(result.unwrap_err(), NotariError::BuilderError(_)));
+    }
+
     #[test]
     fn test_builder_unknown_chain_id() {
         let result = BlockchainConfigBuilder::new()
@@ -35,16 +35,56 @@ impl EthereumAnchorer {
         chain_id: u64,
         chain_name: &str,
         explorer_url: &str,
+    ) -> NotariResult<Self> {
+        // Create wallet from private key
+        let wallet: LocalWallet = private_key.parse().map_err(|e| {
+            NotariError::BlockchainNotConfigured(format!("Invalid private key: {}", e))
+        })?;
+
+        Self::with_wallet(
+            rpc_url,
+            wallet,
+            contract_address,
+            chain_id,
+            chain_name,
+            explorer_url,
+        )
+    }
+
+    /// Create an anchorer that can only verify anchors
+    ///
+    /// Uses a throwaway key, so `verify` works without a configured wallet but
+    /// `anchor` has no funds to pay for transactions.
+    pub fn read_only(
+        rpc_url: &str,
+        contract_address: &str,
+        chain_id: u64,
+        chain_name: &str,
+        explorer_url: &str,
+    ) -> NotariResult<Self> {
+        Self::with_wallet(
+            rpc_url,
+            LocalWallet::new(&mut rand::thread_rng()),
+            contract_address,
+            chain_id,
+            chain_name,
+            explorer_url,
+        )
+    }
+
+    fn with_wallet(
+        rpc_url: &str,
+        wallet: LocalWallet,
+        contract_address: &str,
+        chain_id: u64,
+        chain_name: &str,
+        explorer_url: &str,
     ) -> NotariResult<Self> {
         // Create provider
         let provider = Provider::<Http>::try_from(rpc_url)
             .map_err(|e| NotariError::BlockchainNotConfigured(format!("Invalid RPC URL: {}", e)))?;
         let provider = Arc::new(provider);
 
-        // Create wallet from private key
-        let wallet: LocalWallet = private_key.parse().map_err(|e| {
-            NotariError::BlockchainNotConfigured(format!("Invalid private key: {}", e))
-        })?;
         let wallet = wallet.with_chain_id(chain_id);
 
         // Parse contract address
@@ -106,7 +146,10 @@ impl BlockchainAnchorer for EthereumAnchorer {
         // Call anchor function and wait for receipt
         let call = contract.anchor(hash_bytes);
         let pending_tx = call.send().await.map_err(|e| {
-            NotariError::BlockchainAnchorFailed(format!("Failed to send transaction: {}", e))
+            // Surface the contract's revert reason (e.g. "Hash already anchored") rather
+            // than the ABI-encoded revert data
+            let reason = e.decode_revert::<String>().unwrap_or_else(|| e.to_string());
+            NotariError::BlockchainAnchorFailed(format!("Failed to send transaction: {}", reason))
         })?;
         let receipt = pending_tx
             .await
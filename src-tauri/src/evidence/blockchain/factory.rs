@@ -1,6 +1,7 @@
 use super::{
-    BlockchainAnchorer, BlockchainConfig, BlockchainEnvironment, EthereumAnchorer, MockAnchorer,
-    WalletManager,
+    AnchorProof, BlockchainAnchorer, BlockchainConfig, BlockchainEnvironment, ChainConfig,
+    EthereumAnchorer, MockAnchorer, OpenTimestampsAnchorer, OpenTimestampsConfig, TsaAnchorer,
+    TsaConfig, WalletManager,
 };
 use std::error::Error;
 
@@ -23,7 +24,7 @@ impl BlockchainAnchorerFactory {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Wallet is not configured for non-Mock environments
+    /// - Wallet is not configured for Testnet/Mainnet environments
     /// - Private key cannot be retrieved
     /// - Anchorer initialization fails
     pub fn create_anchorer(
@@ -31,6 +32,10 @@ impl BlockchainAnchorerFactory {
     ) -> Result<Box<dyn BlockchainAnchorer>, Box<dyn Error>> {
         match config.environment {
             BlockchainEnvironment::Mock => Ok(Box::new(MockAnchorer::new())),
+            BlockchainEnvironment::OpenTimestamps => Ok(Box::new(OpenTimestampsAnchorer::new(
+                &config.opentimestamps,
+            )?)),
+            BlockchainEnvironment::Rfc3161 => Ok(Box::new(TsaAnchorer::new(&config.tsa)?)),
             BlockchainEnvironment::Testnet | BlockchainEnvironment::Mainnet => {
                 let wallet = config
                     .wallet
@@ -60,9 +65,11 @@ impl BlockchainAnchorerFactory {
     /// destructured into its components.
     ///
     /// # Arguments
-    /// * `environment` - The blockchain environment (Mock, Testnet, Mainnet)
+    /// * `environment` - The blockchain environment (Mock, Testnet, Mainnet, OpenTimestamps, Rfc3161)
     /// * `chain_config` - The chain configuration
     /// * `wallet_config` - Optional wallet configuration
+    /// * `ots_config` - OpenTimestamps calendar configuration
+    /// * `tsa_config` - RFC 3161 time-stamp authority configuration
     ///
     /// # Returns
     /// A boxed trait object implementing BlockchainAnchorer
@@ -70,9 +77,15 @@ impl BlockchainAnchorerFactory {
         environment: &BlockchainEnvironment,
         chain_config: &super::ChainConfig,
         wallet_config: &Option<super::WalletConfig>,
+        ots_config: &OpenTimestampsConfig,
+        tsa_config: &TsaConfig,
     ) -> Result<Box<dyn BlockchainAnchorer>, Box<dyn Error>> {
         match environment {
             BlockchainEnvironment::Mock => Ok(Box::new(MockAnchorer::new())),
+            BlockchainEnvironment::OpenTimestamps => {
+                Ok(Box::new(OpenTimestampsAnchorer::new(ots_config)?))
+            }
+            BlockchainEnvironment::Rfc3161 => Ok(Box::new(TsaAnchorer::new(tsa_config)?)),
             BlockchainEnvironment::Testnet | BlockchainEnvironment::Mainnet => {
                 let wallet = wallet_config
                     .as_ref()
@@ -94,6 +107,55 @@ impl BlockchainAnchorerFactory {
             }
         }
     }
+
+    /// Create an anchorer able to verify `proof`, whichever backend is configured
+    ///
+    /// A manifest can carry anchors from several chains and services, so deep
+    /// verification needs a verifier per anchor. Ethereum anchors are checked
+    /// read-only (no wallet) through the configured RPC endpoint when it serves the
+    /// proof's chain, otherwise through the built-in endpoint for that chain.
+    ///
+    /// # Errors
+    /// Returns an error if no RPC endpoint is known for an Ethereum proof's chain
+    pub fn create_verifier(
+        proof: &AnchorProof,
+        config: &BlockchainConfig,
+    ) -> Result<Box<dyn BlockchainAnchorer>, Box<dyn Error>> {
+        match proof {
+            AnchorProof::Mock { .. } => Ok(Box::new(MockAnchorer::new())),
+            AnchorProof::OpenTimestamps { .. } => Ok(Box::new(OpenTimestampsAnchorer::new(
+                &config.opentimestamps,
+            )?)),
+            AnchorProof::Rfc3161 { tsa_url, .. } => Ok(Box::new(TsaAnchorer::new(&TsaConfig {
+                url: tsa_url.clone(),
+            })?)),
+            AnchorProof::Ethereum {
+                chain_id,
+                contract_address,
+                ..
+            } => {
+                let chain = if config.chain.chain_id == *chain_id {
+                    Some(config.chain.clone())
+                } else {
+                    ChainConfig::from_chain_id(*chain_id)
+                }
+                .ok_or_else(|| format!("No RPC endpoint known for chain {}", chain_id))?;
+
+                let anchorer = EthereumAnchorer::read_only(
+                    &chain.rpc_url,
+                    contract_address,
+                    *chain_id,
+                    &chain.name,
+                    &chain.explorer_url,
+                )?;
+
+                Ok(Box::new(anchorer))
+            }
+            AnchorProof::MerkleBatch { root_proof, .. } => {
+                Self::create_verifier(root_proof, config)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +178,8 @@ mod tests {
             },
             auto_anchor: false,
             wallet: None,
+            opentimestamps: OpenTimestampsConfig::default(),
+            tsa: TsaConfig::default(),
         };
 
         let result = BlockchainAnchorerFactory::create_anchorer(&config);
@@ -137,6 +201,8 @@ mod tests {
             },
             auto_anchor: false,
             wallet: None,
+            opentimestamps: OpenTimestampsConfig::default(),
+            tsa: TsaConfig::default(),
         };
 
         let result = BlockchainAnchorerFactory::create_anchorer(&config);
@@ -163,6 +229,8 @@ mod tests {
             &environment,
             &chain_config,
             &wallet_config,
+            &OpenTimestampsConfig::default(),
+            &TsaConfig::default(),
         );
         assert!(result.is_ok());
     }
@@ -184,10 +252,87 @@ mod tests {
             &environment,
             &chain_config,
             &wallet_config,
+            &OpenTimestampsConfig::default(),
+            &TsaConfig::default(),
         );
         assert!(result.is_err());
         if let Err(e) = result {
             assert!(e.to_string().contains("No wallet configured"));
         }
     }
+
+    #[test]
+    fn test_create_opentimestamps_anchorer_without_wallet() {
+        let config = BlockchainConfig {
+            environment: BlockchainEnvironment::OpenTimestamps,
+            enabled: true,
+            ..BlockchainConfig::default()
+        };
+        assert!(BlockchainAnchorerFactory::create_anchorer(&config).is_ok());
+
+        let no_calendars = OpenTimestampsConfig { calendars: vec![] };
+        let result = BlockchainAnchorerFactory::create_from_components(
+            &config.environment,
+            &config.chain,
+            &None,
+            &no_calendars,
+            &config.tsa,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_create_tsa_anchorer_without_wallet() {
+        let config = BlockchainConfig {
+            environment: BlockchainEnvironment::Rfc3161,
+            enabled: true,
+            ..BlockchainConfig::default()
+        };
+        assert!(BlockchainAnchorerFactory::create_anchorer(&config).is_ok());
+
+        let no_url = TsaConfig { url: String::new() };
+        let result = BlockchainAnchorerFactory::create_from_components(
+            &config.environment,
+            &config.chain,
+            &None,
+            &config.opentimestamps,
+            &no_url,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_create_verifier_for_other_backends() {
+        // Configured for Mock, but anchors on other services must still be verifiable
+        let config = BlockchainConfig::default();
+        let ethereum = |chain_id| AnchorProof::Ethereum {
+            chain_id,
+            chain_name: "Polygon".to_string(),
+            tx_hash: "0xabc".to_string(),
+            contract_address: "0x0000000000000000000000000000000000000000".to_string(),
+            block_number: 1,
+            explorer_url: String::new(),
+        };
+
+        assert!(BlockchainAnchorerFactory::create_verifier(&ethereum(137), &config).is_ok());
+        assert!(BlockchainAnchorerFactory::create_verifier(&ethereum(99999), &config).is_err());
+
+        let tsa = AnchorProof::Rfc3161 {
+            tsa_url: "https://tsa.example/tsr".to_string(),
+            tsa_name: "CN=TSA".to_string(),
+            token: String::new(),
+            gen_time: chrono::Utc::now(),
+            serial_number: "01".to_string(),
+        };
+        assert!(BlockchainAnchorerFactory::create_verifier(&tsa, &config).is_ok());
+
+        let batch = AnchorProof::MerkleBatch {
+            merkle_root: String::new(),
+            leaf_index: 0,
+            leaf_count: 1,
+            path: vec![],
+            root_proof: Box::new(ethereum(99999)),
+        };
+        assert!(BlockchainAnchorerFactory::create_verifier(&batch, &config).is_err());
+    }
 }
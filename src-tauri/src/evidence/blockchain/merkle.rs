@@ -0,0 +1,180 @@
+//! Merkle trees over manifest hashes for batched anchoring
+//!
+//! Leaves and interior nodes are domain-separated (`0x00 || leaf`, `0x01 || left || right`,
+//! as in RFC 6962) so an inclusion proof for one level can never be replayed as another.
+//! An odd node at the end of a level is carried up unchanged.
+
+use crate::error::{NotariError, NotariResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Which side of the running hash a sibling sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// One step of an inclusion proof, from the leaf towards the root
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerklePathStep {
+    /// Sibling node hash (hex)
+    pub sibling: String,
+    pub side: Side,
+}
+
+/// Hash of a leaf (a 32-byte manifest hash)
+pub fn leaf_hash(hash: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(hash);
+    hasher.finalize().into()
+}
+
+/// Hash of an interior node
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Decode a 32-byte hex hash (with or without `0x`)
+pub fn decode_hash(hex_hash: &str) -> NotariResult<[u8; 32]> {
+    let bytes = hex::decode(hex_hash.trim_start_matches("0x"))
+        .map_err(|e| NotariError::InvalidMerkleProof(format!("Invalid hex: {}", e)))?;
+    bytes
+        .try_into()
+        .map_err(|_| NotariError::InvalidMerkleProof("Hash must be 32 bytes".to_string()))
+}
+
+/// Binary Merkle tree over a batch of manifest hashes
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// `levels[0]` are the leaf hashes, the last level holds only the root
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build a tree over `hashes`, in order
+    pub fn new(hashes: &[[u8; 32]]) -> NotariResult<Self> {
+        if hashes.is_empty() {
+            return Err(NotariError::InvalidMerkleProof(
+                "Cannot build a Merkle tree without leaves".to_string(),
+            ));
+        }
+
+        let mut levels = vec![hashes.iter().map(leaf_hash).collect::<Vec<_>>()];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [odd] => *odd,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self { levels })
+    }
+
+    /// Number of leaves
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Always false; a tree has at least one leaf
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Inclusion proof for the leaf at `index`
+    pub fn proof(&self, index: usize) -> NotariResult<Vec<MerklePathStep>> {
+        if index >= self.len() {
+            return Err(NotariError::InvalidMerkleProof(format!(
+                "Leaf index {} out of range",
+                index
+            )));
+        }
+
+        let mut path = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                path.push(MerklePathStep {
+                    sibling: hex::encode(level[sibling]),
+                    side: if sibling < position {
+                        Side::Left
+                    } else {
+                        Side::Right
+                    },
+                });
+            }
+            position /= 2;
+        }
+
+        Ok(path)
+    }
+}
+
+/// Recompute the root committed to by `hash` and its inclusion `path`
+pub fn compute_root(hash: &[u8; 32], path: &[MerklePathStep]) -> NotariResult<[u8; 32]> {
+    path.iter().try_fold(leaf_hash(hash), |node, step| {
+        let sibling = decode_hash(&step.sibling)?;
+        Ok(match step.side {
+            Side::Left => node_hash(&sibling, &node),
+            Side::Right => node_hash(&node, &sibling),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(count: u8) -> Vec<[u8; 32]> {
+        (0..count).map(|i| Sha256::digest([i]).into()).collect()
+    }
+
+    #[test]
+    fn test_every_leaf_proves_inclusion() {
+        for count in 1..=9 {
+            let leaves = hashes(count);
+            let tree = MerkleTree::new(&leaves).unwrap();
+            assert_eq!(tree.len(), leaves.len());
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let path = tree.proof(index).unwrap();
+                assert_eq!(compute_root(leaf, &path).unwrap(), tree.root());
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_leaf_root_is_leaf_hash() {
+        let leaves = hashes(1);
+        let tree = MerkleTree::new(&leaves).unwrap();
+        assert_eq!(tree.root(), leaf_hash(&leaves[0]));
+        assert!(tree.proof(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_proof_rejects_other_leaf() {
+        let leaves = hashes(5);
+        let tree = MerkleTree::new(&leaves).unwrap();
+        let path = tree.proof(2).unwrap();
+
+        assert_ne!(compute_root(&leaves[3], &path).unwrap(), tree.root());
+        assert!(tree.proof(5).is_err());
+        assert!(MerkleTree::new(&[]).is_err());
+    }
+}
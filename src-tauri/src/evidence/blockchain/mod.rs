@@ -1,19 +1,32 @@
+pub mod batch;
 pub mod config;
 pub mod config_builder;
 pub mod ethereum;
 pub mod factory;
+pub mod merkle;
 pub mod mock;
+pub mod opentimestamps;
+pub mod ots;
+pub mod rfc3161;
+pub mod tsa;
 pub mod types;
 pub mod wallet;
 
 #[cfg(test)]
 mod tests;
 
-pub use config::{BlockchainConfig, BlockchainEnvironment, ChainConfig, WalletConfig};
+pub use batch::{anchor_batch, AnchorQueue, QueuedAnchor};
+pub use config::{
+    BlockchainConfig, BlockchainEnvironment, ChainConfig, OpenTimestampsConfig, TsaConfig,
+    WalletConfig,
+};
 pub use config_builder::BlockchainConfigBuilder;
 pub use ethereum::EthereumAnchorer;
 pub use factory::BlockchainAnchorerFactory;
+pub use merkle::{MerklePathStep, MerkleTree};
 pub use mock::MockAnchorer;
+pub use opentimestamps::{BitcoinAttestation, OpenTimestampsAnchorer};
+pub use tsa::TsaAnchorer;
 pub use types::{AnchorProof, BlockchainAnchor};
 pub use wallet::WalletManager;
 
@@ -0,0 +1,533 @@
+use super::config::OpenTimestampsConfig;
+use super::ots::{Attestation, DetachedTimestamp, Op, Timestamp};
+use super::{AnchorProof, BlockchainAnchorer};
+use crate::app_log;
+use crate::error::{NotariError, NotariResult};
+use async_trait::async_trait;
+use base64::{engine::general_purpose, Engine as _};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// Largest calendar response accepted (real responses are well under 1 KiB)
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// Bitcoin block attestation confirmed against a block header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinAttestation {
+    /// Block height claimed by the proof
+    pub height: u64,
+    /// Hash of the supplied block header (big-endian hex, as shown by explorers)
+    pub block_hash: String,
+    /// Block timestamp from the header (Unix seconds)
+    pub block_time: u32,
+}
+
+/// OpenTimestamps anchorer (Bitcoin, via calendar servers)
+///
+/// Anchoring is free and needs no wallet: calendars aggregate submitted digests and
+/// commit them to Bitcoin. The proof returned by `anchor` is *pending* until the
+/// calendar's transaction confirms (usually a few hours); `upgrade` then fetches the
+/// complete proof, which `verify_with_block_header` checks offline.
+pub struct OpenTimestampsAnchorer {
+    calendars: Vec<String>,
+    client: reqwest::Client,
+}
+
+impl OpenTimestampsAnchorer {
+    /// Create an anchorer for the given calendar servers
+    pub fn new(config: &OpenTimestampsConfig) -> NotariResult<Self> {
+        let calendars: Vec<String> = config
+            .calendars
+            .iter()
+            .map(|c| c.trim().trim_end_matches('/').to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if calendars.is_empty() {
+            return Err(NotariError::BlockchainNotConfigured(
+                "No OpenTimestamps calendar configured".to_string(),
+            ));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| {
+                NotariError::BlockchainNotConfigured(format!("Failed to create HTTP client: {}", e))
+            })?;
+
+        Ok(Self { calendars, client })
+    }
+
+    /// Submit a digest to one calendar and return its (pending) timestamp
+    async fn submit(&self, calendar: &str, digest: &[u8]) -> NotariResult<Timestamp> {
+        let response = self
+            .client
+            .post(format!("{}/digest", calendar))
+            .header("Accept", "application/vnd.opentimestamps.v1")
+            .body(digest.to_vec())
+            .send()
+            .await
+            .map_err(|e| NotariError::BlockchainAnchorFailed(format!("{}: {}", calendar, e)))?;
+
+        let body = Self::read_response(calendar, response).await?;
+        Timestamp::deserialize(&body, digest.to_vec())
+    }
+
+    /// Fetch the timestamp a calendar now has for a pending commitment
+    ///
+    /// Returns `None` while the calendar has not yet been confirmed in a block.
+    async fn fetch(&self, calendar: &str, commitment: &[u8]) -> NotariResult<Option<Timestamp>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/timestamp/{}",
+                calendar,
+                hex::encode(commitment)
+            ))
+            .header("Accept", "application/vnd.opentimestamps.v1")
+            .send()
+            .await
+            .map_err(|e| {
+                NotariError::BlockchainVerificationFailed(format!("{}: {}", calendar, e))
+            })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = Self::read_response(calendar, response).await?;
+        Ok(Some(Timestamp::deserialize(&body, commitment.to_vec())?))
+    }
+
+    async fn read_response(calendar: &str, response: reqwest::Response) -> NotariResult<Vec<u8>> {
+        if !response.status().is_success() {
+            return Err(NotariError::BlockchainAnchorFailed(format!(
+                "{}: HTTP {}",
+                calendar,
+                response.status()
+            )));
+        }
+        if response.content_length().unwrap_or(0) > MAX_RESPONSE_SIZE as u64 {
+            return Err(NotariError::BlockchainAnchorFailed(format!(
+                "{}: response too large",
+                calendar
+            )));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| NotariError::BlockchainAnchorFailed(format!("{}: {}", calendar, e)))?;
+        if body.len() > MAX_RESPONSE_SIZE {
+            return Err(NotariError::BlockchainAnchorFailed(format!(
+                "{}: response too large",
+                calendar
+            )));
+        }
+        Ok(body.to_vec())
+    }
+
+    /// Try to complete a pending proof by asking its calendars for a Bitcoin attestation
+    ///
+    /// Only calendars configured on this anchorer are contacted, whatever URIs the
+    /// proof names. Returns the proof unchanged if nothing new is available yet.
+    pub async fn upgrade(&self, proof: &AnchorProof) -> NotariResult<AnchorProof> {
+        let mut file = decode_proof(proof)?;
+
+        let pending: Vec<(Vec<u8>, String)> = file
+            .timestamp
+            .all_attestations()
+            .into_iter()
+            .filter_map(|(msg, attestation)| match attestation {
+                Attestation::Pending { uri } => Some((msg.to_vec(), uri.clone())),
+                _ => None,
+            })
+            .collect();
+
+        for (commitment, uri) in pending {
+            let calendar = uri.trim_end_matches('/');
+            if !self.calendars.iter().any(|c| c == calendar) {
+                app_log!(
+                    crate::logger::LogLevel::Warn,
+                    "OpenTimestamps: skipping unconfigured calendar {}",
+                    calendar
+                );
+                continue;
+            }
+
+            match self.fetch(calendar, &commitment).await {
+                Ok(Some(upgraded)) => {
+                    if let Some(node) = file.timestamp.find_mut(&commitment) {
+                        node.merge(upgraded)?;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => app_log!(
+                    crate::logger::LogLevel::Warn,
+                    "OpenTimestamps: upgrade from {} failed: {}",
+                    calendar,
+                    e
+                ),
+            }
+        }
+
+        Ok(encode_proof(&file))
+    }
+
+    /// Verify a complete proof for `hash` against a Bitcoin block header (80 bytes)
+    ///
+    /// Fully offline: the caller supplies the header for the attested block from a
+    /// source it trusts (own node, several explorers) and should compare the
+    /// returned height and block hash with it.
+    pub fn verify_with_block_header(
+        hash: &str,
+        proof: &AnchorProof,
+        block_header: &[u8],
+    ) -> NotariResult<BitcoinAttestation> {
+        let file = decode_proof(proof)?;
+        check_digest(&file, hash)?;
+
+        if block_header.len() != 80 {
+            return Err(NotariError::BlockchainVerificationFailed(
+                "Bitcoin block header must be 80 bytes".to_string(),
+            ));
+        }
+        // Merkle root in the header's internal byte order, as OTS commits to it
+        let merkle_root = &block_header[36..68];
+        let block_time = u32::from_le_bytes(block_header[68..72].try_into().unwrap());
+        let mut block_hash = Sha256::digest(Sha256::digest(block_header)).to_vec();
+        block_hash.reverse();
+
+        file.timestamp
+            .all_attestations()
+            .into_iter()
+            .find_map(|(msg, attestation)| match attestation {
+                Attestation::Bitcoin { height } if msg == merkle_root => Some(BitcoinAttestation {
+                    height: *height,
+                    block_hash: hex::encode(&block_hash),
+                    block_time,
+                }),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                NotariError::BlockchainVerificationFailed(
+                    "No Bitcoin attestation in the proof matches this block header".to_string(),
+                )
+            })
+    }
+}
+
+/// Decode the `.ots` file stored in an OpenTimestamps anchor proof
+pub fn decode_proof(proof: &AnchorProof) -> NotariResult<DetachedTimestamp> {
+    let AnchorProof::OpenTimestamps { ots_proof, .. } = proof else {
+        return Err(NotariError::BlockchainVerificationFailed(
+            "Invalid proof type for OpenTimestamps anchorer".to_string(),
+        ));
+    };
+    let bytes = general_purpose::STANDARD.decode(ots_proof).map_err(|e| {
+        NotariError::BlockchainVerificationFailed(format!("Invalid OpenTimestamps proof: {}", e))
+    })?;
+    DetachedTimestamp::deserialize(&bytes)
+}
+
+fn encode_proof(file: &DetachedTimestamp) -> AnchorProof {
+    let bitcoin_block = file
+        .timestamp
+        .all_attestations()
+        .into_iter()
+        .filter_map(|(_, attestation)| match attestation {
+            Attestation::Bitcoin { height } => Some(*height),
+            _ => None,
+        })
+        .min();
+
+    AnchorProof::OpenTimestamps {
+        ots_proof: general_purpose::STANDARD.encode(file.serialize()),
+        bitcoin_block,
+    }
+}
+
+/// Check that the proof timestamps the given hex hash
+fn check_digest(file: &DetachedTimestamp, hash: &str) -> NotariResult<()> {
+    let expected = hex::decode(hash.trim_start_matches("0x")).map_err(|e| {
+        NotariError::BlockchainVerificationFailed(format!("Invalid hash hex: {}", e))
+    })?;
+    if file.digest() != expected.as_slice() {
+        return Err(NotariError::BlockchainVerificationFailed(
+            "OpenTimestamps proof is for a different hash".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl BlockchainAnchorer for OpenTimestampsAnchorer {
+    async fn anchor(&self, hash: &str) -> NotariResult<AnchorProof> {
+        let digest = hex::decode(hash.trim_start_matches("0x"))
+            .map_err(|e| NotariError::BlockchainAnchorFailed(format!("Invalid hex: {}", e)))?;
+        if digest.len() != 32 {
+            return Err(NotariError::BlockchainAnchorFailed(
+                "Hash must be 32 bytes".to_string(),
+            ));
+        }
+
+        // Calendars are public: submit sha256(hash || nonce) so they never see the hash
+        let mut nonce = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let salted = Op::Append(nonce.to_vec()).apply(&digest)?;
+        let commitment = Op::Sha256.apply(&salted)?;
+
+        let mut commitment_node = Timestamp::new(commitment.clone());
+        let mut errors = Vec::new();
+        for calendar in &self.calendars {
+            match self.submit(calendar, &commitment).await {
+                Ok(timestamp) => commitment_node.merge(timestamp)?,
+                Err(e) => {
+                    app_log!(
+                        crate::logger::LogLevel::Warn,
+                        "OpenTimestamps: submission to {} failed: {}",
+                        calendar,
+                        e
+                    );
+                    errors.push(e.to_string());
+                }
+            }
+        }
+        if commitment_node.attestations.is_empty() && commitment_node.ops.is_empty() {
+            return Err(NotariError::BlockchainAnchorFailed(format!(
+                "No calendar accepted the digest: {}",
+                errors.join("; ")
+            )));
+        }
+
+        let mut salted_node = Timestamp::new(salted);
+        salted_node.ops.push((Op::Sha256, commitment_node));
+        let mut root = Timestamp::new(digest);
+        root.ops.push((Op::Append(nonce.to_vec()), salted_node));
+
+        Ok(encode_proof(&DetachedTimestamp::new(root)?))
+    }
+
+    /// Check that the proof commits to `hash` and carries a Bitcoin attestation,
+    /// upgrading it from the calendars first if it is still pending
+    ///
+    /// `true` only means the proof is complete: the attested block header itself
+    /// is checked by `verify_with_block_header`, and callers without a header must
+    /// report the anchor as complete with its attestation unchecked.
+    async fn verify(&self, hash: &str, proof: &AnchorProof) -> NotariResult<bool> {
+        let file = decode_proof(proof)?;
+        if check_digest(&file, hash).is_err() {
+            return Ok(false);
+        }
+        if file.timestamp.is_complete() {
+            return Ok(true);
+        }
+
+        let upgraded = decode_proof(&self.upgrade(proof).await?)?;
+        Ok(upgraded.timestamp.is_complete())
+    }
+
+    async fn estimate_cost(&self) -> NotariResult<f64> {
+        // Calendars pay the Bitcoin fees
+        Ok(0.0)
+    }
+
+    async fn get_balance(&self) -> NotariResult<f64> {
+        // No wallet involved
+        Ok(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use axum::extract::{Path, State};
+    use axum::http::StatusCode;
+    use axum::routing::{get, post};
+    use axum::Router;
+
+    const BLOCK_HEIGHT: u64 = 840_000;
+    const BLOCK_TIME: u32 = 1_713_571_767;
+
+    /// Local stand-in for a calendar server
+    ///
+    /// `POST /digest` answers with `prepend("cal") -> sha256 -> pending(<self>)`;
+    /// `GET /timestamp/<commitment>` with `append("block") -> sha256 -> bitcoin(840000)`,
+    /// or 404 when `confirmed` is false.
+    async fn start_calendar(confirmed: bool) -> String {
+        #[derive(Clone)]
+        struct Calendar {
+            url: String,
+            confirmed: bool,
+        }
+
+        async fn digest(State(calendar): State<Calendar>, body: Bytes) -> Vec<u8> {
+            let mut pending =
+                Timestamp::new(Sha256::digest([b"cal".as_slice(), &body[..]].concat()).to_vec());
+            pending
+                .attestations
+                .push(Attestation::Pending { uri: calendar.url });
+            let mut timestamp = Timestamp::new(body.to_vec());
+            timestamp.ops.push((Op::Prepend(b"cal".to_vec()), {
+                let mut prepended = Timestamp::new([b"cal".as_slice(), &body[..]].concat());
+                prepended.ops.push((Op::Sha256, pending));
+                prepended
+            }));
+            timestamp.serialize()
+        }
+
+        async fn timestamp(
+            State(calendar): State<Calendar>,
+            Path(commitment): Path<String>,
+        ) -> Result<Vec<u8>, StatusCode> {
+            if !calendar.confirmed {
+                return Err(StatusCode::NOT_FOUND);
+            }
+            let commitment = hex::decode(commitment).map_err(|_| StatusCode::BAD_REQUEST)?;
+            let appended = [commitment.as_slice(), b"block".as_slice()].concat();
+            let mut block = Timestamp::new(Sha256::digest(&appended).to_vec());
+            block.attestations.push(Attestation::Bitcoin {
+                height: BLOCK_HEIGHT,
+            });
+            let mut appended_node = Timestamp::new(appended);
+            appended_node.ops.push((Op::Sha256, block));
+            let mut timestamp = Timestamp::new(commitment);
+            timestamp
+                .ops
+                .push((Op::Append(b"block".to_vec()), appended_node));
+            Ok(timestamp.serialize())
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let app = Router::new()
+            .route("/digest", post(digest))
+            .route("/timestamp/:commitment", get(timestamp))
+            .with_state(Calendar {
+                url: url.clone(),
+                confirmed,
+            });
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        url
+    }
+
+    fn anchorer(calendars: &[&str]) -> OpenTimestampsAnchorer {
+        OpenTimestampsAnchorer::new(&OpenTimestampsConfig {
+            calendars: calendars.iter().map(|c| c.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    /// 80-byte header whose merkle root is the proof's Bitcoin commitment
+    fn block_header_for(proof: &AnchorProof) -> Vec<u8> {
+        let file = decode_proof(proof).unwrap();
+        let (merkle_root, _) = file
+            .timestamp
+            .all_attestations()
+            .into_iter()
+            .find(|(_, a)| matches!(a, Attestation::Bitcoin { .. }))
+            .unwrap();
+
+        let mut header = vec![0u8; 80];
+        header[36..68].copy_from_slice(merkle_root);
+        header[68..72].copy_from_slice(&BLOCK_TIME.to_le_bytes());
+        header
+    }
+
+    fn manifest_hash() -> String {
+        hex::encode(Sha256::digest(b"manifest"))
+    }
+
+    #[tokio::test]
+    async fn test_anchor_returns_pending_proof() {
+        let calendar = start_calendar(false).await;
+        let anchorer = anchorer(&[&calendar]);
+
+        let proof = anchorer.anchor(&manifest_hash()).await.unwrap();
+        let AnchorProof::OpenTimestamps { bitcoin_block, .. } = &proof else {
+            panic!("wrong proof type");
+        };
+        assert_eq!(*bitcoin_block, None);
+
+        let file = decode_proof(&proof).unwrap();
+        assert_eq!(hex::encode(file.digest()), manifest_hash());
+        assert!(!file.timestamp.is_complete());
+
+        // Not yet confirmed: verification cannot succeed and upgrade changes nothing
+        assert!(!anchorer.verify(&manifest_hash(), &proof).await.unwrap());
+        let upgraded = anchorer.upgrade(&proof).await.unwrap();
+        assert!(!decode_proof(&upgraded).unwrap().timestamp.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_and_verify_against_block_header() {
+        let calendar = start_calendar(true).await;
+        let anchorer = anchorer(&[&calendar]);
+
+        let pending = anchorer.anchor(&manifest_hash()).await.unwrap();
+        let proof = anchorer.upgrade(&pending).await.unwrap();
+        let AnchorProof::OpenTimestamps { bitcoin_block, .. } = &proof else {
+            panic!("wrong proof type");
+        };
+        assert_eq!(*bitcoin_block, Some(BLOCK_HEIGHT));
+        assert!(anchorer.verify(&manifest_hash(), &proof).await.unwrap());
+
+        let header = block_header_for(&proof);
+        let attestation =
+            OpenTimestampsAnchorer::verify_with_block_header(&manifest_hash(), &proof, &header)
+                .unwrap();
+        assert_eq!(attestation.height, BLOCK_HEIGHT);
+        assert_eq!(attestation.block_time, BLOCK_TIME);
+        assert_eq!(attestation.block_hash.len(), 64);
+
+        // Wrong header or wrong hash
+        let mut other_header = header.clone();
+        other_header[40] ^= 0xff;
+        assert!(OpenTimestampsAnchorer::verify_with_block_header(
+            &manifest_hash(),
+            &proof,
+            &other_header
+        )
+        .is_err());
+        let other_hash = hex::encode(Sha256::digest(b"other"));
+        assert!(
+            OpenTimestampsAnchorer::verify_with_block_header(&other_hash, &proof, &header).is_err()
+        );
+        assert!(!anchorer.verify(&other_hash, &proof).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_anchor_tolerates_unreachable_calendar() {
+        let calendar = start_calendar(false).await;
+        // Nothing listens on port 9 locally
+        let anchorer = anchorer(&["http://127.0.0.1:9", &calendar]);
+        assert!(anchorer.anchor(&manifest_hash()).await.is_ok());
+
+        let anchorer = self::anchorer(&["http://127.0.0.1:9"]);
+        assert!(anchorer.anchor(&manifest_hash()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_ignores_unconfigured_calendars() {
+        let calendar = start_calendar(true).await;
+        let pending = anchorer(&[&calendar])
+            .anchor(&manifest_hash())
+            .await
+            .unwrap();
+
+        // A different anchorer must not follow URIs embedded in the proof
+        let other = anchorer(&["http://127.0.0.1:9"]);
+        let upgraded = other.upgrade(&pending).await.unwrap();
+        assert!(!decode_proof(&upgraded).unwrap().timestamp.is_complete());
+    }
+
+    #[test]
+    fn test_new_requires_calendar() {
+        let result = OpenTimestampsAnchorer::new(&OpenTimestampsConfig { calendars: vec![] });
+        assert!(result.is_err());
+        assert_eq!(OpenTimestampsConfig::default().calendars.len(), 3);
+    }
+}
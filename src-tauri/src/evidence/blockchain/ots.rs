@@ -0,0 +1,550 @@
+//! OpenTimestamps proof format
+//!
+//! Minimal reader/writer for `.ots` detached timestamp files, following the
+//! reference implementation (python-opentimestamps). A timestamp is a tree: each
+//! node holds a message, the attestations made about it, and operations deriving
+//! child messages. Only the operations used by Bitcoin calendars are evaluated
+//! (sha256, append, prepend, reverse, hexlify); others are rejected.
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{NotariError, NotariResult};
+
+/// Magic bytes at the start of every detached timestamp file
+const HEADER_MAGIC: &[u8] = b"\x00OpenTimestamps\x00\x00Proof\x00\xbf\x89\xe2\xe8\x84\xe8\x92\x94";
+const MAJOR_VERSION: u64 = 1;
+
+const TAG_ATTESTATION: u8 = 0x00;
+const TAG_SHA1: u8 = 0x02;
+const TAG_RIPEMD160: u8 = 0x03;
+const TAG_SHA256: u8 = 0x08;
+const TAG_KECCAK256: u8 = 0x67;
+const TAG_APPEND: u8 = 0xf0;
+const TAG_PREPEND: u8 = 0xf1;
+const TAG_REVERSE: u8 = 0xf2;
+const TAG_HEXLIFY: u8 = 0xf3;
+const TAG_FORK: u8 = 0xff;
+
+const BITCOIN_ATTESTATION_TAG: [u8; 8] = [0x05, 0x88, 0x96, 0x0d, 0x73, 0xd7, 0x19, 0x01];
+const PENDING_ATTESTATION_TAG: [u8; 8] = [0x83, 0xdf, 0xe3, 0x0d, 0x2e, 0xf9, 0x0c, 0x8e];
+
+/// Limits from the reference implementation; proofs are untrusted input
+const MAX_MSG_LENGTH: usize = 4096;
+const MAX_PAYLOAD_LENGTH: usize = 8192;
+const MAX_URI_LENGTH: usize = 1000;
+const MAX_RECURSION_DEPTH: usize = 256;
+
+fn invalid(message: impl Into<String>) -> NotariError {
+    NotariError::BlockchainVerificationFailed(format!(
+        "Invalid OpenTimestamps proof: {}",
+        message.into()
+    ))
+}
+
+/// Commitment operation transforming a message into the next one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    Sha1,
+    Ripemd160,
+    Sha256,
+    Keccak256,
+    Append(Vec<u8>),
+    Prepend(Vec<u8>),
+    Reverse,
+    Hexlify,
+}
+
+impl Op {
+    fn tag(&self) -> u8 {
+        match self {
+            Op::Sha1 => TAG_SHA1,
+            Op::Ripemd160 => TAG_RIPEMD160,
+            Op::Sha256 => TAG_SHA256,
+            Op::Keccak256 => TAG_KECCAK256,
+            Op::Append(_) => TAG_APPEND,
+            Op::Prepend(_) => TAG_PREPEND,
+            Op::Reverse => TAG_REVERSE,
+            Op::Hexlify => TAG_HEXLIFY,
+        }
+    }
+
+    fn read(tag: u8, reader: &mut Reader) -> NotariResult<Self> {
+        match tag {
+            TAG_SHA1 => Ok(Op::Sha1),
+            TAG_RIPEMD160 => Ok(Op::Ripemd160),
+            TAG_SHA256 => Ok(Op::Sha256),
+            TAG_KECCAK256 => Ok(Op::Keccak256),
+            TAG_APPEND => Ok(Op::Append(reader.read_varbytes(1, MAX_MSG_LENGTH)?)),
+            TAG_PREPEND => Ok(Op::Prepend(reader.read_varbytes(1, MAX_MSG_LENGTH)?)),
+            TAG_REVERSE => Ok(Op::Reverse),
+            TAG_HEXLIFY => Ok(Op::Hexlify),
+            other => Err(invalid(format!("unknown operation tag 0x{:02x}", other))),
+        }
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(self.tag());
+        if let Op::Append(arg) | Op::Prepend(arg) = self {
+            write_varbytes(arg, out);
+        }
+    }
+
+    /// Apply the operation to a message
+    pub fn apply(&self, msg: &[u8]) -> NotariResult<Vec<u8>> {
+        let result = match self {
+            Op::Sha256 => Sha256::digest(msg).to_vec(),
+            Op::Append(arg) => [msg, arg.as_slice()].concat(),
+            Op::Prepend(arg) => [arg.as_slice(), msg].concat(),
+            Op::Reverse => msg.iter().rev().copied().collect(),
+            Op::Hexlify => hex::encode(msg).into_bytes(),
+            Op::Sha1 | Op::Ripemd160 | Op::Keccak256 => {
+                return Err(invalid(format!(
+                    "unsupported operation 0x{:02x}",
+                    self.tag()
+                )))
+            }
+        };
+        if result.len() > MAX_MSG_LENGTH {
+            return Err(invalid("message exceeds maximum length"));
+        }
+        Ok(result)
+    }
+}
+
+/// Statement that a message existed at some point in time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Attestation {
+    /// Message is the merkle root of the Bitcoin block at this height
+    Bitcoin { height: u64 },
+    /// Calendar promises to produce a complete attestation later
+    Pending { uri: String },
+    /// Attestation type this implementation does not understand
+    Unknown { tag: [u8; 8], payload: Vec<u8> },
+}
+
+impl Attestation {
+    fn read(reader: &mut Reader) -> NotariResult<Self> {
+        let tag: [u8; 8] = reader.read_bytes(8)?.try_into().unwrap();
+        let payload = reader.read_varbytes(0, MAX_PAYLOAD_LENGTH)?;
+        let mut payload_reader = Reader::new(&payload);
+
+        let attestation = match tag {
+            BITCOIN_ATTESTATION_TAG => Attestation::Bitcoin {
+                height: payload_reader.read_varuint()?,
+            },
+            PENDING_ATTESTATION_TAG => {
+                let uri = payload_reader.read_varbytes(0, MAX_URI_LENGTH)?;
+                let uri =
+                    String::from_utf8(uri).map_err(|_| invalid("calendar URI is not UTF-8"))?;
+                // Same character whitelist as the reference implementation
+                if !uri
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-._/:".contains(c))
+                {
+                    return Err(invalid("calendar URI contains invalid characters"));
+                }
+                Attestation::Pending { uri }
+            }
+            _ => return Ok(Attestation::Unknown { tag, payload }),
+        };
+
+        if !payload_reader.is_empty() {
+            return Err(invalid("trailing bytes in attestation payload"));
+        }
+        Ok(attestation)
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(TAG_ATTESTATION);
+        let (tag, payload) = match self {
+            Attestation::Bitcoin { height } => {
+                let mut payload = Vec::new();
+                write_varuint(*height, &mut payload);
+                (BITCOIN_ATTESTATION_TAG, payload)
+            }
+            Attestation::Pending { uri } => {
+                let mut payload = Vec::new();
+                write_varbytes(uri.as_bytes(), &mut payload);
+                (PENDING_ATTESTATION_TAG, payload)
+            }
+            Attestation::Unknown { tag, payload } => (*tag, payload.clone()),
+        };
+        out.extend_from_slice(&tag);
+        write_varbytes(&payload, out);
+    }
+}
+
+/// Timestamp tree rooted at `msg`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timestamp {
+    pub msg: Vec<u8>,
+    pub attestations: Vec<Attestation>,
+    pub ops: Vec<(Op, Timestamp)>,
+}
+
+impl Timestamp {
+    pub fn new(msg: Vec<u8>) -> Self {
+        Self {
+            msg,
+            attestations: Vec::new(),
+            ops: Vec::new(),
+        }
+    }
+
+    /// Parse a serialized timestamp for `msg` (the body of a calendar response)
+    pub fn deserialize(data: &[u8], msg: Vec<u8>) -> NotariResult<Self> {
+        let mut reader = Reader::new(data);
+        let timestamp = Self::read(&mut reader, msg, 0)?;
+        if !reader.is_empty() {
+            return Err(invalid("trailing bytes after timestamp"));
+        }
+        Ok(timestamp)
+    }
+
+    fn read(reader: &mut Reader, msg: Vec<u8>, depth: usize) -> NotariResult<Self> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(invalid("timestamp nested too deeply"));
+        }
+
+        let mut timestamp = Timestamp::new(msg);
+        let mut tag = reader.read_byte()?;
+        while tag == TAG_FORK {
+            let item_tag = reader.read_byte()?;
+            timestamp.read_item(reader, item_tag, depth)?;
+            tag = reader.read_byte()?;
+        }
+        timestamp.read_item(reader, tag, depth)?;
+        Ok(timestamp)
+    }
+
+    fn read_item(&mut self, reader: &mut Reader, tag: u8, depth: usize) -> NotariResult<()> {
+        if tag == TAG_ATTESTATION {
+            self.attestations.push(Attestation::read(reader)?);
+        } else {
+            let op = Op::read(tag, reader)?;
+            let child_msg = op.apply(&self.msg)?;
+            let child = Timestamp::read(reader, child_msg, depth + 1)?;
+            self.ops.push((op, child));
+        }
+        Ok(())
+    }
+
+    /// Serialize the timestamp (without the detached file header)
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write(&mut out);
+        out
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        let item_count = self.attestations.len() + self.ops.len();
+        let mut written = 0;
+        let mut fork = |out: &mut Vec<u8>| {
+            written += 1;
+            if written < item_count {
+                out.push(TAG_FORK);
+            }
+        };
+
+        for attestation in &self.attestations {
+            fork(out);
+            attestation.write(out);
+        }
+        for (op, child) in &self.ops {
+            fork(out);
+            op.write(out);
+            child.write(out);
+        }
+    }
+
+    /// Merge another timestamp for the same message into this one
+    pub fn merge(&mut self, other: Timestamp) -> NotariResult<()> {
+        if other.msg != self.msg {
+            return Err(invalid("cannot merge timestamps for different messages"));
+        }
+        for attestation in other.attestations {
+            if !self.attestations.contains(&attestation) {
+                self.attestations.push(attestation);
+            }
+        }
+        for (op, child) in other.ops {
+            match self.ops.iter_mut().find(|(existing, _)| *existing == op) {
+                Some((_, existing_child)) => existing_child.merge(child)?,
+                None => self.ops.push((op, child)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Every `(message, attestation)` pair in the tree
+    pub fn all_attestations(&self) -> Vec<(&[u8], &Attestation)> {
+        let mut result: Vec<(&[u8], &Attestation)> = self
+            .attestations
+            .iter()
+            .map(|attestation| (self.msg.as_slice(), attestation))
+            .collect();
+        for (_, child) in &self.ops {
+            result.extend(child.all_attestations());
+        }
+        result
+    }
+
+    /// Find the node for `msg`
+    pub fn find_mut(&mut self, msg: &[u8]) -> Option<&mut Timestamp> {
+        if self.msg == msg {
+            return Some(self);
+        }
+        self.ops
+            .iter_mut()
+            .find_map(|(_, child)| child.find_mut(msg))
+    }
+
+    /// Whether the tree contains a Bitcoin attestation
+    pub fn is_complete(&self) -> bool {
+        self.all_attestations()
+            .iter()
+            .any(|(_, attestation)| matches!(attestation, Attestation::Bitcoin { .. }))
+    }
+}
+
+/// `.ots` file: a SHA-256 digest and the timestamp proving when it existed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetachedTimestamp {
+    pub timestamp: Timestamp,
+}
+
+impl DetachedTimestamp {
+    pub fn new(timestamp: Timestamp) -> NotariResult<Self> {
+        if timestamp.msg.len() != 32 {
+            return Err(invalid("detached timestamps must be for a SHA-256 digest"));
+        }
+        Ok(Self { timestamp })
+    }
+
+    /// The timestamped SHA-256 digest
+    pub fn digest(&self) -> &[u8] {
+        &self.timestamp.msg
+    }
+
+    pub fn deserialize(data: &[u8]) -> NotariResult<Self> {
+        let mut reader = Reader::new(data);
+        if reader.read_bytes(HEADER_MAGIC.len())? != HEADER_MAGIC {
+            return Err(invalid("missing file header"));
+        }
+        let version = reader.read_varuint()?;
+        if version != MAJOR_VERSION {
+            return Err(invalid(format!("unsupported major version {}", version)));
+        }
+        if reader.read_byte()? != TAG_SHA256 {
+            return Err(invalid("only SHA-256 file digests are supported"));
+        }
+        let digest = reader.read_bytes(32)?.to_vec();
+
+        let timestamp = Timestamp::read(&mut reader, digest, 0)?;
+        if !reader.is_empty() {
+            return Err(invalid("trailing bytes after timestamp"));
+        }
+        Ok(Self { timestamp })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = HEADER_MAGIC.to_vec();
+        write_varuint(MAJOR_VERSION, &mut out);
+        out.push(TAG_SHA256);
+        out.extend_from_slice(&self.timestamp.msg);
+        self.timestamp.write(&mut out);
+        out
+    }
+}
+
+/// Bounds-checked cursor over untrusted bytes
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.data.len()
+    }
+
+    fn read_bytes(&mut self, n: usize) -> NotariResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_byte(&mut self) -> NotariResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    /// Unsigned LEB128
+    fn read_varuint(&mut self) -> NotariResult<u64> {
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_byte()?;
+            if shift >= 64 || (shift == 63 && byte & 0x7e != 0) {
+                return Err(invalid("varuint overflow"));
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_varbytes(&mut self, min: usize, max: usize) -> NotariResult<Vec<u8>> {
+        let len = self.read_varuint()?;
+        if len > max as u64 || len < min as u64 {
+            return Err(invalid(format!("length {} out of range", len)));
+        }
+        Ok(self.read_bytes(len as usize)?.to_vec())
+    }
+}
+
+fn write_varuint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_varbytes(data: &[u8], out: &mut Vec<u8>) {
+    write_varuint(data.len() as u64, out);
+    out.extend_from_slice(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_timestamp() -> Timestamp {
+        let digest = Sha256::digest(b"manifest").to_vec();
+        let mut root = Timestamp::new(digest.clone());
+
+        let nonce = vec![7u8; 16];
+        let appended = Op::Append(nonce.clone()).apply(&digest).unwrap();
+        let mut appended_node = Timestamp::new(appended.clone());
+        let commitment = Op::Sha256.apply(&appended).unwrap();
+        let mut commitment_node = Timestamp::new(commitment);
+        commitment_node.attestations.push(Attestation::Pending {
+            uri: "https://alice.btc.calendar.opentimestamps.org".to_string(),
+        });
+        commitment_node
+            .attestations
+            .push(Attestation::Bitcoin { height: 840_000 });
+
+        appended_node.ops.push((Op::Sha256, commitment_node));
+        root.ops.push((Op::Append(nonce), appended_node));
+        root
+    }
+
+    #[test]
+    fn test_varuint_roundtrip() {
+        for value in [0u64, 1, 127, 128, 300, 840_000, u32::MAX as u64, u64::MAX] {
+            let mut out = Vec::new();
+            write_varuint(value, &mut out);
+            assert_eq!(Reader::new(&out).read_varuint().unwrap(), value);
+        }
+
+        let mut out = Vec::new();
+        write_varuint(300, &mut out);
+        assert_eq!(out, vec![0xac, 0x02]);
+    }
+
+    #[test]
+    fn test_detached_timestamp_roundtrip() {
+        let file = DetachedTimestamp::new(sample_timestamp()).unwrap();
+        let bytes = file.serialize();
+        assert!(bytes.starts_with(HEADER_MAGIC));
+
+        let parsed = DetachedTimestamp::deserialize(&bytes).unwrap();
+        assert_eq!(parsed, file);
+        assert!(parsed.timestamp.is_complete());
+        assert_eq!(parsed.timestamp.all_attestations().len(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_recomputes_messages() {
+        let timestamp = sample_timestamp();
+        let bytes = timestamp.serialize();
+
+        // Messages are derived from the operations, never read from the proof
+        let parsed = Timestamp::deserialize(&bytes, timestamp.msg.clone()).unwrap();
+        assert_eq!(parsed, timestamp);
+
+        let other = Timestamp::deserialize(&bytes, vec![0u8; 32]).unwrap();
+        assert_ne!(
+            other.all_attestations()[0].0,
+            timestamp.all_attestations()[0].0
+        );
+    }
+
+    #[test]
+    fn test_merge_combines_calendar_responses() {
+        let msg = vec![1u8; 32];
+        let mut a = Timestamp::new(msg.clone());
+        a.attestations.push(Attestation::Pending {
+            uri: "https://a.example".to_string(),
+        });
+        let mut b = Timestamp::new(msg.clone());
+        b.attestations.push(Attestation::Pending {
+            uri: "https://b.example".to_string(),
+        });
+
+        a.merge(b).unwrap();
+        assert_eq!(a.attestations.len(), 2);
+        assert!(a.merge(Timestamp::new(vec![2u8; 32])).is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_proofs() {
+        let file = DetachedTimestamp::new(sample_timestamp()).unwrap();
+        let bytes = file.serialize();
+
+        // Truncated
+        assert!(DetachedTimestamp::deserialize(&bytes[..bytes.len() - 1]).is_err());
+        // Trailing garbage
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(DetachedTimestamp::deserialize(&extended).is_err());
+        // Wrong magic
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[1] = b'X';
+        assert!(DetachedTimestamp::deserialize(&wrong_magic).is_err());
+        // Unknown operation
+        assert!(Timestamp::deserialize(&[0x42], vec![0u8; 32]).is_err());
+        // Unsupported (but known) operation
+        assert!(Timestamp::deserialize(&[TAG_RIPEMD160, 0x00], vec![0u8; 32]).is_err());
+        // Invalid calendar URI
+        let mut pending = vec![TAG_ATTESTATION];
+        pending.extend_from_slice(&PENDING_ATTESTATION_TAG);
+        write_varbytes(&[5, b'a', b'?', b'x', b'=', b'1'], &mut pending);
+        assert!(Timestamp::deserialize(&pending, vec![0u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_recursion_depth_is_bounded() {
+        let mut nested = vec![TAG_REVERSE; MAX_RECURSION_DEPTH + 2];
+        nested.push(TAG_ATTESTATION);
+        nested.extend_from_slice(&BITCOIN_ATTESTATION_TAG);
+        write_varbytes(&[1], &mut nested);
+        assert!(Timestamp::deserialize(&nested, vec![0u8; 32]).is_err());
+    }
+}
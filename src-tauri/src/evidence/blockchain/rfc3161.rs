@@ -0,0 +1,533 @@
+//! RFC 3161 time-stamp protocol
+//!
+//! Builds `TimeStampReq`s, unpacks `TimeStampResp`s and verifies time-stamp tokens
+//! (CMS SignedData over a `TSTInfo`) offline: message imprint, signed attributes,
+//! signature, and the TSA certificate's key usage and validity period.
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{NotariError, NotariResult};
+use crate::evidence::der::{self, Element, TAG_GENERALIZED_TIME, TAG_SEQUENCE, TAG_SET};
+use crate::evidence::x509::{self, Certificate, OID_KP_TIME_STAMPING, OID_SHA256};
+
+pub const OID_SIGNED_DATA: &str = "1.2.840.113549.1.7.2";
+pub const OID_CT_TST_INFO: &str = "1.2.840.113549.1.9.16.1.4";
+pub const OID_CONTENT_TYPE: &str = "1.2.840.113549.1.9.3";
+pub const OID_MESSAGE_DIGEST: &str = "1.2.840.113549.1.9.4";
+
+fn invalid(msg: impl std::fmt::Display) -> NotariError {
+    NotariError::BlockchainVerificationFailed(format!("Invalid time-stamp token: {}", msg))
+}
+
+/// DER `TimeStampReq` for a SHA-256 digest, asking for the TSA certificate
+pub fn build_request(digest: &[u8], nonce: u64) -> Vec<u8> {
+    der::sequence(&[
+        der::integer_u64(1),
+        der::sequence(&[der::algorithm(OID_SHA256, None), der::octet_string(digest)]),
+        der::integer_u64(nonce),
+        der::boolean(true),
+    ])
+}
+
+/// Extract the DER `TimeStampToken` from a `TimeStampResp`
+pub fn parse_response(data: &[u8]) -> NotariResult<Vec<u8>> {
+    let mut response = der::parse(data)?.expect(TAG_SEQUENCE)?.reader();
+    let mut status_info = response.read_tag(TAG_SEQUENCE)?.reader();
+
+    // granted (0) or grantedWithMods (1)
+    let status = status_info.read()?.as_u64()?;
+    if status > 1 {
+        let mut reason = String::new();
+        if let Some(text) = status_info.read_optional(TAG_SEQUENCE)? {
+            let mut strings = text.reader();
+            while !strings.is_empty() {
+                reason.push_str(&strings.read()?.as_string()?);
+            }
+        }
+        return Err(NotariError::BlockchainAnchorFailed(format!(
+            "TSA rejected the request (status {}){}",
+            status,
+            if reason.is_empty() {
+                String::new()
+            } else {
+                format!(": {}", reason)
+            }
+        )));
+    }
+
+    let token = response
+        .read_optional(TAG_SEQUENCE)?
+        .ok_or_else(|| NotariError::BlockchainAnchorFailed("TSA returned no token".to_string()))?;
+    response.finish()?;
+    Ok(token.raw.to_vec())
+}
+
+/// Time-stamp information signed by the TSA
+#[derive(Debug, Clone)]
+pub struct TstInfo {
+    pub policy: String,
+    /// Digest algorithm of the message imprint (OID)
+    pub hash_algorithm: String,
+    pub hashed_message: Vec<u8>,
+    pub serial_number: Vec<u8>,
+    pub gen_time: DateTime<Utc>,
+    /// Nonce echoed from the request (big-endian magnitude)
+    pub nonce: Option<Vec<u8>>,
+}
+
+impl TstInfo {
+    fn from_der(data: &[u8]) -> NotariResult<Self> {
+        let mut fields = der::parse(data)?.expect(TAG_SEQUENCE)?.reader();
+        if fields.read()?.as_u64()? != 1 {
+            return Err(invalid("unsupported TSTInfo version"));
+        }
+        let policy = fields.read()?.as_oid()?;
+
+        let mut imprint = fields.read_tag(TAG_SEQUENCE)?.reader();
+        let (hash_algorithm, _) = der::algorithm_identifier(imprint.read()?)?;
+        let hashed_message = imprint.read()?.as_octets()?.to_vec();
+        imprint.finish()?;
+
+        let serial_number = fields.read()?.integer_bytes()?.to_vec();
+        let gen_time = fields.read_tag(TAG_GENERALIZED_TIME)?.as_time()?;
+        fields.read_optional(TAG_SEQUENCE)?; // accuracy
+        fields.read_optional(der::TAG_BOOLEAN)?; // ordering
+        let nonce = match fields.read_optional(der::TAG_INTEGER)? {
+            Some(nonce) => Some(nonce.integer_bytes()?.to_vec()),
+            None => None,
+        };
+        // tsa name and extensions are not needed
+
+        Ok(Self {
+            policy,
+            hash_algorithm,
+            hashed_message,
+            serial_number,
+            gen_time,
+            nonce,
+        })
+    }
+}
+
+/// How the signer's certificate is identified
+#[derive(Debug, Clone)]
+enum SignerId {
+    IssuerSerial { issuer: Vec<u8>, serial: Vec<u8> },
+    KeyId(Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+struct SignerInfo {
+    sid: SignerId,
+    digest_algorithm: String,
+    /// Complete `[0] IMPLICIT` signed attributes element
+    signed_attrs: Vec<u8>,
+    signature_algorithm: String,
+    signature: Vec<u8>,
+}
+
+impl SignerInfo {
+    fn parse(element: Element<'_>) -> NotariResult<Self> {
+        let mut fields = element.expect(TAG_SEQUENCE)?.reader();
+        fields.read()?.as_u64()?; // version
+
+        let sid = fields.read()?;
+        let sid = match sid.tag {
+            TAG_SEQUENCE => {
+                let mut issuer_serial = sid.reader();
+                let issuer = issuer_serial.read_tag(TAG_SEQUENCE)?.raw.to_vec();
+                let serial = issuer_serial.read()?.integer_bytes()?.to_vec();
+                issuer_serial.finish()?;
+                SignerId::IssuerSerial { issuer, serial }
+            }
+            tag if tag == der::context_primitive(0) => SignerId::KeyId(sid.value.to_vec()),
+            _ => return Err(invalid("unsupported signer identifier")),
+        };
+
+        let (digest_algorithm, _) = der::algorithm_identifier(fields.read()?)?;
+        let signed_attrs = fields
+            .read_optional(der::context(0))?
+            .ok_or_else(|| invalid("missing signed attributes"))?
+            .raw
+            .to_vec();
+        let (signature_algorithm, _) = der::algorithm_identifier(fields.read()?)?;
+        let signature = fields.read()?.as_octets()?.to_vec();
+        fields.read_optional(der::context(1))?; // unsigned attributes
+        fields.finish()?;
+
+        Ok(Self {
+            sid,
+            digest_algorithm,
+            signed_attrs,
+            signature_algorithm,
+            signature,
+        })
+    }
+}
+
+/// Result of a successful token verification
+#[derive(Debug, Clone)]
+pub struct TokenVerification {
+    pub gen_time: DateTime<Utc>,
+    /// TSTInfo serial number (hex)
+    pub serial_number: String,
+    /// Subject of the TSA certificate
+    pub tsa_name: String,
+    /// SHA-256 fingerprint of the TSA certificate (hex)
+    pub tsa_certificate_sha256: String,
+    /// TSA certificate followed by the included CA certificates it chains to
+    pub tsa_chain: Vec<Certificate>,
+}
+
+/// Parsed RFC 3161 `TimeStampToken`
+#[derive(Debug, Clone)]
+pub struct TimeStampToken {
+    pub tst_info: TstInfo,
+    /// Certificates included in the token
+    pub certificates: Vec<Certificate>,
+    /// DER-encoded TSTInfo, as signed
+    e_content: Vec<u8>,
+    signer: SignerInfo,
+}
+
+impl TimeStampToken {
+    /// Parse a DER-encoded token (a CMS `ContentInfo`)
+    pub fn from_der(data: &[u8]) -> NotariResult<Self> {
+        let mut content_info = der::parse(data)?.expect(TAG_SEQUENCE)?.reader();
+        if content_info.read()?.as_oid()? != OID_SIGNED_DATA {
+            return Err(invalid("not CMS signed data"));
+        }
+        let content = content_info.read_tag(der::context(0))?;
+        content_info.finish()?;
+
+        let mut signed_data = der::parse(content.value)?.expect(TAG_SEQUENCE)?.reader();
+        signed_data.read()?.as_u64()?; // version
+        signed_data.read_tag(TAG_SET)?; // digest algorithms
+
+        let mut encapsulated = signed_data.read_tag(TAG_SEQUENCE)?.reader();
+        if encapsulated.read()?.as_oid()? != OID_CT_TST_INFO {
+            return Err(invalid("content is not a TSTInfo"));
+        }
+        let e_content = encapsulated.read_tag(der::context(0))?;
+        encapsulated.finish()?;
+        let e_content = der::parse(e_content.value)?.as_octets()?.to_vec();
+
+        let certificates = match signed_data.read_optional(der::context(0))? {
+            Some(certificates) => x509::parse_certificates(certificates.value)?,
+            None => Vec::new(),
+        };
+        signed_data.read_optional(der::context(1))?; // CRLs
+
+        let mut signer_infos = signed_data.read_tag(TAG_SET)?.reader();
+        let signer = SignerInfo::parse(signer_infos.read()?)?;
+        if !signer_infos.is_empty() {
+            return Err(invalid("more than one signer"));
+        }
+        signed_data.finish()?;
+
+        Ok(Self {
+            tst_info: TstInfo::from_der(&e_content)?,
+            certificates,
+            e_content,
+            signer,
+        })
+    }
+
+    /// The included certificate matching the signer identifier
+    pub fn signer_certificate(&self) -> Option<&Certificate> {
+        self.certificates.iter().find(|c| match &self.signer.sid {
+            SignerId::IssuerSerial { issuer, serial } => &c.issuer == issuer && &c.serial == serial,
+            SignerId::KeyId(id) => c.subject_key_id.as_ref() == Some(id),
+        })
+    }
+
+    /// `certificate` followed by the included certificates it chains to
+    ///
+    /// Stops at a self-signed certificate or one whose issuer isn't included.
+    fn chain_from(&self, certificate: &Certificate) -> Vec<Certificate> {
+        let mut chain = vec![certificate.clone()];
+        loop {
+            let last = &chain[chain.len() - 1];
+            if last.issuer == last.subject {
+                break;
+            }
+            let issuer = self
+                .certificates
+                .iter()
+                .find(|c| {
+                    !chain.iter().any(|link| link.der == c.der)
+                        && last.is_issued_by(c).unwrap_or(false)
+                })
+                .cloned();
+            match issuer {
+                Some(issuer) => chain.push(issuer),
+                None => break,
+            }
+        }
+        chain
+    }
+
+    /// Verify the token for a SHA-256 `digest`, entirely offline
+    ///
+    /// Checks the message imprint, the content-type and message-digest signed
+    /// attributes, the signature, and that the TSA certificate is authorized for
+    /// time-stamping and valid at `genTime`. Whether the TSA itself is trusted is
+    /// left to the caller, who checks `TokenVerification::tsa_chain` against its
+    /// trust anchors.
+    pub fn verify(&self, digest: &[u8]) -> NotariResult<TokenVerification> {
+        if self.tst_info.hash_algorithm != OID_SHA256 || self.tst_info.hashed_message != digest {
+            return Err(invalid("message imprint does not match the anchored hash"));
+        }
+
+        let certificate = self
+            .signer_certificate()
+            .ok_or_else(|| invalid("TSA certificate is not included in the token"))?;
+        if !certificate.has_extended_key_usage(OID_KP_TIME_STAMPING) {
+            return Err(invalid(
+                "TSA certificate is not authorized for time-stamping",
+            ));
+        }
+        if !certificate.is_valid_at(&self.tst_info.gen_time) {
+            return Err(invalid(
+                "genTime is outside the TSA certificate validity period",
+            ));
+        }
+
+        let mut content_type = None;
+        let mut message_digest = None;
+        let mut attributes = der::parse(&self.signer.signed_attrs)?.reader();
+        while !attributes.is_empty() {
+            let mut attribute = attributes.read_tag(TAG_SEQUENCE)?.reader();
+            let oid = attribute.read()?.as_oid()?;
+            let value = attribute.read_tag(TAG_SET)?.reader().read()?;
+            match oid.as_str() {
+                OID_CONTENT_TYPE => content_type = Some(value.as_oid()?),
+                OID_MESSAGE_DIGEST => message_digest = Some(value.as_octets()?.to_vec()),
+                _ => {}
+            }
+        }
+        if content_type.as_deref() != Some(OID_CT_TST_INFO) {
+            return Err(invalid("content-type attribute does not name TSTInfo"));
+        }
+        let expected_digest = x509::digest(&self.signer.digest_algorithm, &self.e_content)?;
+        if message_digest.as_deref() != Some(expected_digest.as_slice()) {
+            return Err(invalid("message-digest attribute does not match TSTInfo"));
+        }
+
+        // The signature covers the attributes encoded as a SET OF, not [0] IMPLICIT
+        let mut signed = self.signer.signed_attrs.clone();
+        signed[0] = TAG_SET;
+        let signature_valid = certificate.verify_signature(
+            &self.signer.signature_algorithm,
+            Some(&self.signer.digest_algorithm),
+            &signed,
+            &self.signer.signature,
+        )?;
+        if !signature_valid {
+            return Err(invalid("TSA signature is invalid"));
+        }
+
+        Ok(TokenVerification {
+            gen_time: self.tst_info.gen_time,
+            serial_number: hex::encode(&self.tst_info.serial_number),
+            tsa_name: certificate.subject_name(),
+            tsa_certificate_sha256: certificate.fingerprint(),
+            tsa_chain: self.chain_from(certificate),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    //! Local stand-in TSA with a self-signed ECDSA certificate
+
+    use super::*;
+    use crate::evidence::x509::testing::{issue, CertificateParams, TestKey};
+    use crate::evidence::x509::OID_ECDSA_WITH_SHA256;
+    use sha2::{Digest, Sha256};
+
+    pub struct TestTsa {
+        key: TestKey,
+        pub certificate: Vec<u8>,
+    }
+
+    impl TestTsa {
+        pub fn new() -> Self {
+            Self::with_usage(&[OID_KP_TIME_STAMPING])
+        }
+
+        /// TSA whose certificate carries the given extended key usages
+        pub fn with_usage(extended_key_usage: &[&str]) -> Self {
+            let key = TestKey::generate();
+            let mut params = CertificateParams::new("Notari Test TSA");
+            params.serial = 7;
+            params.extended_key_usage = extended_key_usage;
+            let certificate = issue(&params, &key, None);
+            Self { key, certificate }
+        }
+
+        /// Issue a token over a SHA-256 `digest`
+        pub fn token(&self, digest: &[u8], nonce: Option<&[u8]>) -> Vec<u8> {
+            let mut tst_info = vec![
+                der::integer_u64(1),
+                der::oid("1.3.6.1.4.1.4146.2.3"),
+                der::sequence(&[der::algorithm(OID_SHA256, None), der::octet_string(digest)]),
+                der::integer_u64(1234),
+                der::generalized_time(&Utc::now()),
+            ];
+            if let Some(nonce) = nonce {
+                tst_info.push(der::integer(nonce));
+            }
+            let tst_info = der::sequence(&tst_info);
+
+            let signed_attrs = der::set(&[
+                der::sequence(&[
+                    der::oid(OID_CONTENT_TYPE),
+                    der::set(&[der::oid(OID_CT_TST_INFO)]),
+                ]),
+                der::sequence(&[
+                    der::oid(OID_MESSAGE_DIGEST),
+                    der::set(&[der::octet_string(&Sha256::digest(&tst_info))]),
+                ]),
+            ]);
+            let signature = self.key.sign(&signed_attrs);
+            let mut implicit_attrs = signed_attrs;
+            implicit_attrs[0] = der::context(0);
+
+            let certificate = Certificate::from_der(&self.certificate).unwrap();
+            let signer_info = der::sequence(&[
+                der::integer_u64(1),
+                der::sequence(&[
+                    certificate.issuer.clone(),
+                    der::integer(&certificate.serial),
+                ]),
+                der::algorithm(OID_SHA256, None),
+                implicit_attrs,
+                der::algorithm(OID_ECDSA_WITH_SHA256, None),
+                der::octet_string(&signature),
+            ]);
+
+            let signed_data = der::sequence(&[
+                der::integer_u64(3),
+                der::set(&[der::algorithm(OID_SHA256, None)]),
+                der::sequence(&[
+                    der::oid(OID_CT_TST_INFO),
+                    der::explicit(0, &der::octet_string(&tst_info)),
+                ]),
+                der::encode(der::context(0), &self.certificate),
+                der::set(&[signer_info]),
+            ]);
+            der::sequence(&[der::oid(OID_SIGNED_DATA), der::explicit(0, &signed_data)])
+        }
+
+        /// Answer a DER `TimeStampReq` with a granted `TimeStampResp`
+        pub fn respond(&self, request: &[u8]) -> Vec<u8> {
+            let mut fields = der::parse(request).unwrap().reader();
+            fields.read().unwrap(); // version
+            let mut imprint = fields.read_tag(TAG_SEQUENCE).unwrap().reader();
+            imprint.read().unwrap(); // algorithm
+            let digest = imprint.read().unwrap().as_octets().unwrap().to_vec();
+            fields.read_optional(der::TAG_OID).unwrap(); // policy
+            let nonce = fields
+                .read_optional(der::TAG_INTEGER)
+                .unwrap()
+                .map(|n| n.integer_bytes().unwrap().to_vec());
+
+            der::sequence(&[
+                der::sequence(&[der::integer_u64(0)]),
+                self.token(&digest, nonce.as_deref()),
+            ])
+        }
+
+        /// A `TimeStampResp` rejecting the request
+        pub fn rejection() -> Vec<u8> {
+            der::sequence(&[der::sequence(&[
+                der::integer_u64(2),
+                der::sequence(&[der::utf8_string("bad message digest")]),
+            ])])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::TestTsa;
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    fn digest(data: &[u8]) -> Vec<u8> {
+        Sha256::digest(data).to_vec()
+    }
+
+    #[test]
+    fn test_verify_token() {
+        let tsa = TestTsa::new();
+        let token = TimeStampToken::from_der(&tsa.token(&digest(b"manifest"), None)).unwrap();
+
+        let result = token.verify(&digest(b"manifest")).unwrap();
+        assert_eq!(result.tsa_name, "CN=Notari Test TSA");
+        assert_eq!(result.serial_number, "04d2");
+        assert_eq!(
+            result.tsa_certificate_sha256,
+            Certificate::from_der(&tsa.certificate)
+                .unwrap()
+                .fingerprint()
+        );
+
+        assert!(token.verify(&digest(b"other")).is_err());
+    }
+
+    #[test]
+    fn test_request_response_roundtrip() {
+        let tsa = TestTsa::new();
+        let request = build_request(&digest(b"manifest"), 0x0102_0304);
+        let token = parse_response(&tsa.respond(&request)).unwrap();
+
+        let token = TimeStampToken::from_der(&token).unwrap();
+        assert_eq!(token.tst_info.nonce, Some(vec![1, 2, 3, 4]));
+        assert!(token.verify(&digest(b"manifest")).is_ok());
+
+        let rejected = parse_response(&TestTsa::rejection()).unwrap_err();
+        assert!(rejected.to_string().contains("bad message digest"));
+    }
+
+    #[test]
+    fn test_modified_tst_info_fails() {
+        // Swap the imprint inside the signed TSTInfo: parses, but the
+        // message-digest attribute no longer matches
+        let tsa = TestTsa::new();
+        let original = digest(b"manifest");
+        let forged = digest(b"forged");
+        let mut der = tsa.token(&original, None);
+        let at = der
+            .windows(original.len())
+            .position(|w| w == original.as_slice())
+            .unwrap();
+        der[at..at + forged.len()].copy_from_slice(&forged);
+
+        let token = TimeStampToken::from_der(&der).unwrap();
+        let err = token.verify(&forged).unwrap_err();
+        assert!(err.to_string().contains("message-digest"));
+    }
+
+    #[test]
+    fn test_bad_signature_fails() {
+        let tsa = TestTsa::new();
+        let mut der = tsa.token(&digest(b"manifest"), None);
+        // The token ends with the signature octets
+        let last = der.len() - 1;
+        der[last] ^= 0x01;
+
+        let token = TimeStampToken::from_der(&der).unwrap();
+        let err = token.verify(&digest(b"manifest")).unwrap_err();
+        assert!(err.to_string().contains("signature"));
+    }
+
+    #[test]
+    fn test_certificate_without_time_stamping_usage_fails() {
+        let tsa = TestTsa::with_usage(&[]);
+        let token = TimeStampToken::from_der(&tsa.token(&digest(b"manifest"), None)).unwrap();
+        let err = token.verify(&digest(b"manifest")).unwrap_err();
+        assert!(err.to_string().contains("time-stamping"));
+    }
+}
@@ -136,9 +136,9 @@ mod integration_tests {
         // Verify
         let report = Verifier::verify(manifest_file.path(), video_path).unwrap();
 
-        // Check blockchain anchor in report
-        assert!(report.verification.checks.blockchain_anchor.is_some());
-        let anchor_check = report.verification.checks.blockchain_anchor.unwrap();
+        // Check blockchain anchor in report (a legacy single anchor is still reported)
+        assert_eq!(report.verification.checks.anchors.len(), 1);
+        let anchor_check = &report.verification.checks.anchors[0];
         assert!(anchor_check.present);
         assert_eq!(anchor_check.algorithm, "Mock (Development)");
 
@@ -0,0 +1,258 @@
+use super::config::TsaConfig;
+use super::rfc3161::{self, TimeStampToken, TokenVerification};
+use super::{AnchorProof, BlockchainAnchorer};
+use crate::error::{NotariError, NotariResult};
+use async_trait::async_trait;
+use base64::{engine::general_purpose, Engine as _};
+use rand::RngCore;
+use std::time::Duration;
+
+/// Largest TSA response accepted (tokens with a certificate chain are a few KiB)
+const MAX_RESPONSE_SIZE: usize = 256 * 1024;
+
+/// RFC 3161 time-stamp authority anchorer
+///
+/// Sends a `TimeStampReq` for the manifest hash to the configured TSA and stores
+/// the signed token in the proof. No wallet or fees are involved, and tokens are
+/// verified offline (`verify_token`) against the TSA certificate they embed.
+pub struct TsaAnchorer {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl TsaAnchorer {
+    /// Create an anchorer for the configured TSA
+    pub fn new(config: &TsaConfig) -> NotariResult<Self> {
+        let url = config.url.trim().to_string();
+        if url.is_empty() {
+            return Err(NotariError::BlockchainNotConfigured(
+                "No time-stamp authority URL configured".to_string(),
+            ));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| {
+                NotariError::BlockchainNotConfigured(format!("Failed to create HTTP client: {}", e))
+            })?;
+
+        Ok(Self { url, client })
+    }
+
+    async fn request_token(&self, request: Vec<u8>) -> NotariResult<Vec<u8>> {
+        let failed =
+            |e: String| NotariError::BlockchainAnchorFailed(format!("{}: {}", self.url, e));
+
+        let response = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/timestamp-query")
+            .header("Accept", "application/timestamp-reply")
+            .body(request)
+            .send()
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(failed(format!("HTTP {}", response.status())));
+        }
+        if response.content_length().unwrap_or(0) > MAX_RESPONSE_SIZE as u64 {
+            return Err(failed("response too large".to_string()));
+        }
+        let body = response.bytes().await.map_err(|e| failed(e.to_string()))?;
+        if body.len() > MAX_RESPONSE_SIZE {
+            return Err(failed("response too large".to_string()));
+        }
+
+        rfc3161::parse_response(&body)
+    }
+
+    /// Verify an RFC 3161 proof for `hash` offline
+    ///
+    /// Checks the token's message imprint against `hash` and its signature against
+    /// the embedded TSA certificate. Whether that TSA is trusted is up to the
+    /// caller, who checks the returned chain against its trust anchors.
+    pub fn verify_token(hash: &str, proof: &AnchorProof) -> NotariResult<TokenVerification> {
+        let AnchorProof::Rfc3161 { token, .. } = proof else {
+            return Err(NotariError::BlockchainVerificationFailed(
+                "Invalid proof type for RFC 3161 anchorer".to_string(),
+            ));
+        };
+
+        let digest = hex::decode(hash.trim_start_matches("0x")).map_err(|e| {
+            NotariError::BlockchainVerificationFailed(format!("Invalid hash hex: {}", e))
+        })?;
+        let der = general_purpose::STANDARD.decode(token).map_err(|e| {
+            NotariError::BlockchainVerificationFailed(format!("Invalid time-stamp token: {}", e))
+        })?;
+
+        TimeStampToken::from_der(&der)?.verify(&digest)
+    }
+}
+
+#[async_trait]
+impl BlockchainAnchorer for TsaAnchorer {
+    async fn anchor(&self, hash: &str) -> NotariResult<AnchorProof> {
+        let digest = hex::decode(hash.trim_start_matches("0x"))
+            .map_err(|e| NotariError::BlockchainAnchorFailed(format!("Invalid hex: {}", e)))?;
+        if digest.len() != 32 {
+            return Err(NotariError::BlockchainAnchorFailed(
+                "Hash must be 32 bytes".to_string(),
+            ));
+        }
+
+        // Non-zero nonce so its DER magnitude is never empty
+        let nonce = rand::thread_rng().next_u64() | 1;
+        let der = self
+            .request_token(rfc3161::build_request(&digest, nonce))
+            .await?;
+
+        let token = TimeStampToken::from_der(&der)?;
+        let expected_nonce: Vec<u8> = nonce
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+        if token.tst_info.nonce.as_deref() != Some(expected_nonce.as_slice()) {
+            return Err(NotariError::BlockchainAnchorFailed(
+                "TSA response nonce does not match the request".to_string(),
+            ));
+        }
+        let verified = token
+            .verify(&digest)
+            .map_err(|e| NotariError::BlockchainAnchorFailed(e.to_string()))?;
+
+        Ok(AnchorProof::Rfc3161 {
+            tsa_url: self.url.clone(),
+            tsa_name: verified.tsa_name,
+            token: general_purpose::STANDARD.encode(&der),
+            gen_time: verified.gen_time,
+            serial_number: verified.serial_number,
+        })
+    }
+
+    async fn verify(&self, hash: &str, proof: &AnchorProof) -> NotariResult<bool> {
+        // Tokens are self-contained; no need to contact the TSA
+        match Self::verify_token(hash, proof) {
+            Ok(_) => Ok(true),
+            Err(
+                NotariError::BlockchainVerificationFailed(_)
+                | NotariError::InvalidDer(_)
+                | NotariError::CertificateError(_),
+            ) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn estimate_cost(&self) -> NotariResult<f64> {
+        Ok(0.0)
+    }
+
+    async fn get_balance(&self) -> NotariResult<f64> {
+        // No wallet involved
+        Ok(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+    use axum::body::Bytes;
+    use axum::extract::State;
+    use axum::routing::post;
+    use axum::Router;
+    use sha2::{Digest, Sha256};
+    use std::sync::Arc;
+
+    /// Serve `TestTsa` on a local port; `reject` answers every request with a rejection
+    async fn start_tsa(reject: bool) -> String {
+        async fn handle(State((tsa, reject)): State<(Arc<TestTsa>, bool)>, body: Bytes) -> Vec<u8> {
+            if reject {
+                TestTsa::rejection()
+            } else {
+                tsa.respond(&body)
+            }
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/tsr", listener.local_addr().unwrap());
+        let app = Router::new()
+            .route("/tsr", post(handle))
+            .with_state((Arc::new(TestTsa::new()), reject));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        url
+    }
+
+    fn anchorer(url: &str) -> TsaAnchorer {
+        TsaAnchorer::new(&TsaConfig {
+            url: url.to_string(),
+        })
+        .unwrap()
+    }
+
+    fn manifest_hash() -> String {
+        hex::encode(Sha256::digest(b"manifest"))
+    }
+
+    #[tokio::test]
+    async fn test_anchor_and_verify() {
+        let url = start_tsa(false).await;
+        let anchorer = anchorer(&url);
+
+        let proof = anchorer.anchor(&manifest_hash()).await.unwrap();
+        let AnchorProof::Rfc3161 {
+            tsa_url, tsa_name, ..
+        } = &proof
+        else {
+            panic!("wrong proof type");
+        };
+        assert_eq!(tsa_url, &url);
+        assert_eq!(tsa_name, "CN=Notari Test TSA");
+
+        assert!(anchorer.verify(&manifest_hash(), &proof).await.unwrap());
+        let verified = TsaAnchorer::verify_token(&manifest_hash(), &proof).unwrap();
+        assert_eq!(verified.tsa_name, "CN=Notari Test TSA");
+
+        let other_hash = hex::encode(Sha256::digest(b"other"));
+        assert!(!anchorer.verify(&other_hash, &proof).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_anchor_fails_when_tsa_rejects() {
+        let url = start_tsa(true).await;
+        let err = anchorer(&url).anchor(&manifest_hash()).await.unwrap_err();
+        assert!(err.to_string().contains("bad message digest"));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_token_fails_verification() {
+        let url = start_tsa(false).await;
+        let anchorer = anchorer(&url);
+        let proof = anchorer.anchor(&manifest_hash()).await.unwrap();
+
+        let AnchorProof::Rfc3161 { token, .. } = &proof else {
+            panic!("wrong proof type");
+        };
+        let mut der = general_purpose::STANDARD.decode(token).unwrap();
+        let last = der.len() - 1;
+        der[last] ^= 0x01;
+        let tampered = AnchorProof::Rfc3161 {
+            token: general_purpose::STANDARD.encode(&der),
+            ..proof.clone()
+        };
+
+        assert!(!anchorer.verify(&manifest_hash(), &tampered).await.unwrap());
+    }
+
+    #[test]
+    fn test_new_requires_url() {
+        let result = TsaAnchorer::new(&TsaConfig {
+            url: " ".to_string(),
+        });
+        assert!(result.is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use super::merkle::{self, MerklePathStep};
+use crate::error::{NotariError, NotariResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -37,11 +39,41 @@ pub enum AnchorProof {
         explorer_url: String,
     },
 
-    /// OpenTimestamps proof (future)
-    #[allow(dead_code)]
+    /// OpenTimestamps proof (Bitcoin, via calendar servers)
     OpenTimestamps {
+        /// Serialized `.ots` file (base64)
         ots_proof: String,
-        bitcoin_block: u64,
+        /// Attested Bitcoin block height, `None` while the proof is pending
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bitcoin_block: Option<u64>,
+    },
+
+    /// RFC 3161 trusted timestamp from a time-stamp authority
+    Rfc3161 {
+        /// TSA endpoint the token was requested from
+        tsa_url: String,
+        /// Subject of the TSA signing certificate
+        tsa_name: String,
+        /// DER `TimeStampToken` (base64)
+        token: String,
+        /// Time asserted by the TSA
+        gen_time: DateTime<Utc>,
+        /// Token serial number (hex)
+        serial_number: String,
+    },
+
+    /// Membership in a batch whose Merkle root was anchored once for many recordings
+    MerkleBatch {
+        /// Anchored Merkle root (hex)
+        merkle_root: String,
+        /// Position of this manifest hash among the batch leaves
+        leaf_index: u64,
+        /// Number of manifests in the batch
+        leaf_count: u64,
+        /// Inclusion proof from the manifest hash to `merkle_root`
+        path: Vec<MerklePathStep>,
+        /// Proof anchoring `merkle_root` itself
+        root_proof: Box<AnchorProof>,
     },
 }
 
@@ -51,7 +83,17 @@ impl AnchorProof {
         match self {
             AnchorProof::Mock { .. } => "Mock (Development)".to_string(),
             AnchorProof::Ethereum { chain_name, .. } => format!("Ethereum ({})", chain_name),
-            AnchorProof::OpenTimestamps { .. } => "OpenTimestamps (Bitcoin)".to_string(),
+            AnchorProof::OpenTimestamps {
+                bitcoin_block: Some(height),
+                ..
+            } => format!("OpenTimestamps (Bitcoin block {})", height),
+            AnchorProof::OpenTimestamps { .. } => "OpenTimestamps (Bitcoin, pending)".to_string(),
+            AnchorProof::Rfc3161 { tsa_name, .. } => format!("RFC 3161 ({})", tsa_name),
+            AnchorProof::MerkleBatch {
+                leaf_count,
+                root_proof,
+                ..
+            } => format!("{} - batch of {}", root_proof.description(), leaf_count),
         }
     }
 
@@ -59,6 +101,11 @@ impl AnchorProof {
     pub fn explorer_url(&self) -> Option<String> {
         match self {
             AnchorProof::Ethereum { explorer_url, .. } => Some(explorer_url.clone()),
+            AnchorProof::OpenTimestamps {
+                bitcoin_block: Some(height),
+                ..
+            } => Some(format!("https://blockstream.info/block-height/{}", height)),
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof.explorer_url(),
             _ => None,
         }
     }
@@ -69,6 +116,8 @@ impl AnchorProof {
             AnchorProof::Mock { .. } => "Mock".to_string(),
             AnchorProof::Ethereum { chain_name, .. } => chain_name.clone(),
             AnchorProof::OpenTimestamps { .. } => "Bitcoin".to_string(),
+            AnchorProof::Rfc3161 { .. } => "RFC 3161 TSA".to_string(),
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof.chain_name(),
         }
     }
 
@@ -80,6 +129,66 @@ impl AnchorProof {
                 contract_address, ..
             } => contract_address.clone(),
             AnchorProof::OpenTimestamps { .. } => "N/A".to_string(),
+            AnchorProof::Rfc3161 { .. } => "N/A".to_string(),
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof.contract_address(),
+        }
+    }
+
+    /// Identifier of the chain or service that holds the anchor
+    ///
+    /// Two anchors with the same service ID are redundant; anchors with different IDs
+    /// are independent. Matches `BlockchainConfig::service_id`.
+    pub fn service_id(&self) -> String {
+        match self {
+            AnchorProof::Mock { .. } => "mock".to_string(),
+            AnchorProof::Ethereum {
+                chain_id,
+                contract_address,
+                ..
+            } => format!("eip155:{}:{}", chain_id, contract_address.to_lowercase()),
+            AnchorProof::OpenTimestamps { .. } => "opentimestamps".to_string(),
+            AnchorProof::Rfc3161 { tsa_url, .. } => format!("rfc3161:{}", tsa_url),
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof.service_id(),
+        }
+    }
+
+    /// The proof anchoring the hash on chain: the root proof of a batch, otherwise itself
+    pub fn root_proof(&self) -> &AnchorProof {
+        match self {
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof,
+            proof => proof,
+        }
+    }
+
+    /// Mutable `root_proof`, e.g. to upgrade a pending batch anchor
+    pub fn root_proof_mut(&mut self) -> &mut AnchorProof {
+        match self {
+            AnchorProof::MerkleBatch { root_proof, .. } => root_proof,
+            proof => proof,
+        }
+    }
+
+    /// The hash and proof actually anchored for `hash`
+    ///
+    /// For batched anchors this checks the inclusion proof and returns the Merkle
+    /// root with its proof; other proofs are returned unchanged.
+    pub fn resolve_root(&self, hash: &str) -> NotariResult<(String, &AnchorProof)> {
+        match self {
+            AnchorProof::MerkleBatch {
+                merkle_root,
+                path,
+                root_proof,
+                ..
+            } => {
+                let root = merkle::compute_root(&merkle::decode_hash(hash)?, path)?;
+                if merkle::decode_hash(merkle_root)? != root {
+                    return Err(NotariError::InvalidMerkleProof(
+                        "Inclusion proof does not lead to the anchored Merkle root".to_string(),
+                    ));
+                }
+                Ok((hex::encode(root), root_proof.as_ref()))
+            }
+            proof => Ok((hash.to_string(), proof)),
         }
     }
 }
@@ -122,4 +231,53 @@ mod tests {
         assert_eq!(deserialized.anchored_hash, "hash123");
         assert_eq!(deserialized.manifest_hash, "manifest456");
     }
+
+    #[test]
+    fn test_merkle_batch_resolves_to_root() {
+        use super::super::merkle::MerkleTree;
+        use sha2::{Digest, Sha256};
+
+        let leaves: Vec<[u8; 32]> = (0..3u8).map(|i| Sha256::digest([i]).into()).collect();
+        let tree = MerkleTree::new(&leaves).unwrap();
+        let root_proof = AnchorProof::Mock {
+            hash: hex::encode(tree.root()),
+            timestamp: Utc::now(),
+        };
+        let proof = AnchorProof::MerkleBatch {
+            merkle_root: hex::encode(tree.root()),
+            leaf_index: 1,
+            leaf_count: 3,
+            path: tree.proof(1).unwrap(),
+            root_proof: Box::new(root_proof),
+        };
+
+        let json = serde_json::to_string(&proof).unwrap();
+        let proof: AnchorProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(proof.description(), "Mock (Development) - batch of 3");
+
+        let (root, anchored) = proof.resolve_root(&hex::encode(leaves[1])).unwrap();
+        assert_eq!(root, hex::encode(tree.root()));
+        assert!(matches!(anchored, AnchorProof::Mock { .. }));
+
+        let err = proof.resolve_root(&hex::encode(leaves[2])).unwrap_err();
+        assert!(matches!(err, NotariError::InvalidMerkleProof(_)));
+    }
+
+    #[test]
+    fn test_pending_opentimestamps_proof() {
+        let proof = AnchorProof::OpenTimestamps {
+            ots_proof: "AE9wZW5UaW1lc3RhbXBz".to_string(),
+            bitcoin_block: None,
+        };
+
+        let json = serde_json::to_string(&proof).unwrap();
+        assert!(!json.contains("bitcoin_block"));
+        let deserialized: AnchorProof = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            deserialized.description(),
+            "OpenTimestamps (Bitcoin, pending)"
+        );
+        assert_eq!(deserialized.explorer_url(), None);
+    }
 }
@@ -0,0 +1,244 @@
+//! RFC 8785 JSON Canonicalization Scheme (JCS)
+//!
+//! Produces a byte-exact, implementation-independent encoding of a JSON value:
+//! - object members sorted by the UTF-16 code units of their keys
+//! - no insignificant whitespace
+//! - strings escaped with the minimal ECMAScript `JSON.stringify` escapes
+//! - numbers formatted with the ECMAScript `Number.prototype.toString` algorithm
+//!
+//! Signing, verification and anchoring all hash this form, so a manifest can be
+//! re-serialized (pretty-printed, re-ordered, parsed by another language) without
+//! invalidating its signature or anchor.
+
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+/// Largest integer that an IEEE 754 double represents exactly (2^53)
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+/// Serialize a value and return its canonical (JCS) byte representation
+pub fn to_vec<T: Serialize>(value: &T) -> serde_json::Result<Vec<u8>> {
+    let value = serde_json::to_value(value)?;
+    Ok(canonicalize(&value).into_bytes())
+}
+
+/// Canonicalize an already-parsed JSON value
+pub fn canonicalize(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            // JCS orders keys by UTF-16 code units, which differs from Rust's
+            // UTF-8 byte order for characters outside the Basic Multilingual Plane
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    // JSON numbers are IEEE 754 doubles in JCS; integers that a double can hold
+    // exactly are printed as-is, larger ones go through the double conversion
+    if let Some(u) = n.as_u64() {
+        if u <= MAX_SAFE_INTEGER {
+            out.push_str(&u.to_string());
+            return;
+        }
+        out.push_str(&format_f64(u as f64));
+    } else if let Some(i) = n.as_i64() {
+        if i.unsigned_abs() <= MAX_SAFE_INTEGER {
+            out.push_str(&i.to_string());
+            return;
+        }
+        out.push_str(&format_f64(i as f64));
+    } else if let Some(f) = n.as_f64() {
+        out.push_str(&format_f64(f));
+    }
+}
+
+/// Format a finite double using the ECMAScript `Number.prototype.toString` rules
+///
+/// Rust's `{:e}` formatting already yields the shortest digit string that
+/// round-trips, which is exactly what ECMAScript requires; only the placement
+/// of the decimal point and exponent differs.
+fn format_f64(value: f64) -> String {
+    // serde_json cannot hold NaN or infinities, so only zero needs special care
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let sign = if value < 0.0 { "-" } else { "" };
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("LowerExp output always contains an exponent");
+    let exponent: i32 = exponent
+        .parse()
+        .expect("LowerExp exponent is always an integer");
+
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    // Position of the decimal point relative to the start of the digits
+    let n = exponent + 1;
+
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat((-n) as usize), digits)
+    } else {
+        let exp_sign = if n - 1 < 0 { "-" } else { "+" };
+        let exp = (n - 1).abs();
+        if k == 1 {
+            format!("{}e{}{}", digits, exp_sign, exp)
+        } else {
+            format!("{}.{}e{}{}", &digits[..1], &digits[1..], exp_sign, exp)
+        }
+    };
+
+    format!("{}{}", sign, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn canonical_number(value: f64) -> String {
+        canonicalize(&json!(value))
+    }
+
+    #[test]
+    fn test_object_keys_sorted_without_whitespace() {
+        let value = json!({ "b": 1, "a": [true, null, "x"], "c": { "z": 0, "y": -1 } });
+        assert_eq!(
+            canonicalize(&value),
+            r#"{"a":[true,null,"x"],"b":1,"c":{"y":-1,"z":0}}"#
+        );
+    }
+
+    #[test]
+    fn test_key_order_uses_utf16_code_units() {
+        // Sorting example from RFC 8785 section 3.2.3
+        let value: Value = serde_json::from_str(
+            r#"{
+                "\u20ac": "Euro Sign",
+                "\r": "Carriage Return",
+                "\ufb33": "Hebrew Letter Dalet With Dagesh",
+                "1": "One",
+                "\ud83d\ude00": "Emoji: Grinning Face",
+                "\u0080": "Control",
+                "\u00f6": "Latin Small Letter O With Diaeresis"
+            }"#,
+        )
+        .unwrap();
+
+        let canonical = canonicalize(&value);
+        let expected_order = [
+            "Carriage Return",
+            "One",
+            "Control",
+            "Latin Small Letter O With Diaeresis",
+            "Euro Sign",
+            "Emoji: Grinning Face",
+            "Hebrew Letter Dalet With Dagesh",
+        ];
+
+        let positions: Vec<usize> = expected_order
+            .iter()
+            .map(|label| canonical.find(label).unwrap())
+            .collect();
+        let mut sorted = positions.clone();
+        sorted.sort();
+        assert_eq!(positions, sorted);
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        let value = json!("quote\" backslash\\ tab\t nl\n bell\u{07} euro\u{20ac} slash/");
+        assert_eq!(
+            canonicalize(&value),
+            "\"quote\\\" backslash\\\\ tab\\t nl\\n bell\\u0007 euro\u{20ac} slash/\""
+        );
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        // Vectors from RFC 8785 appendix B
+        assert_eq!(canonical_number(0.0), "0");
+        assert_eq!(canonical_number(-0.0), "0");
+        assert_eq!(canonical_number(1e30), "1e+30");
+        assert_eq!(canonical_number(4.50), "4.5");
+        assert_eq!(canonical_number(2e-3), "0.002");
+        assert_eq!(canonical_number(0.000001), "0.000001");
+        assert_eq!(canonical_number(1e-7), "1e-7");
+        assert_eq!(canonical_number(333333333.33333329), "333333333.3333333");
+        assert_eq!(canonical_number(1e21), "1e+21");
+        assert_eq!(canonical_number(1e20), "100000000000000000000");
+        assert_eq!(canonical_number(-1.5e-9), "-1.5e-9");
+        assert_eq!(canonical_number(5e-324), "5e-324");
+        assert_eq!(canonical_number(60.0), "60");
+    }
+
+    #[test]
+    fn test_large_integers_use_double_precision() {
+        assert_eq!(canonicalize(&json!(9007199254740992u64)), "9007199254740992");
+        assert_eq!(canonicalize(&json!(u64::MAX)), "18446744073709552000");
+        assert_eq!(canonicalize(&json!(-42i64)), "-42");
+    }
+
+    #[test]
+    fn test_independent_of_input_formatting() {
+        let compact: Value = serde_json::from_str(r#"{"b":2,"a":1}"#).unwrap();
+        let pretty: Value = serde_json::from_str("{\n  \"a\": 1,\n  \"b\": 2\n}").unwrap();
+        assert_eq!(canonicalize(&compact), canonicalize(&pretty));
+    }
+}
@@ -0,0 +1,590 @@
+//! Signed checkpoints taken while a recording is in progress
+//!
+//! The manifest is only signed once a recording stops, so on its own it can't
+//! show the video was captured in real time rather than assembled later. While
+//! recording, a checkpoint of the segments finished so far is signed every
+//! `CHECKPOINT_INTERVAL`: their hashes folded into a running hash, the wall-clock
+//! time and optionally an RFC 3161 token from a time-stamp authority. The log
+//! is packed as `checkpoints.json` and the manifest commits to it.
+//!
+//! Joining the segments into the video remuxes them, so the video's bytes
+//! can't be matched against the log. The segments that went into the video are
+//! therefore packed next to it under `segments/`, and the manifest lists their
+//! hashes, and those of any left out, for the verifier to match against the log
+//! and the packed files. Encrypted recordings don't carry their segments, since
+//! those aren't encrypted.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::Duration;
+
+use super::blockchain::{AnchorProof, TsaAnchorer};
+use super::canonical;
+use super::signature::KeyManager;
+use super::HashInfo;
+use crate::error::{NotariError, NotariResult};
+
+/// Current checkpoint log format version
+pub const CHECKPOINT_LOG_VERSION: u32 = 1;
+
+/// Name of the checkpoint log, in the segment directory and the proof pack
+pub const CHECKPOINT_LOG_FILE: &str = "checkpoints.json";
+
+/// Directory the segments are packed under in the proof pack
+pub const SEGMENTS_DIR: &str = "segments";
+
+/// Time between checkpoints while recording
+pub const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Largest accepted difference between a checkpoint's time and its RFC 3161
+/// token, or the recording's start and stop
+const MAX_CLOCK_SKEW_SECONDS: i64 = 60;
+
+/// A signed checkpoint of the segments finished so far
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub sequence: u64,
+    pub created_at: DateTime<Utc>,
+    /// Hashes of the segments finished since the previous checkpoint
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<String>,
+    /// Segments finished since the recording started
+    pub segment_count: u64,
+    /// Bytes in those segments
+    pub bytes_written: u64,
+    /// SHA-256 over the previous running hash followed by `segments`
+    pub running_hash: String,
+    /// Digest of the previous checkpoint (empty for the first)
+    pub previous: String,
+    /// Ed25519 signature over the signed fields (base64)
+    pub signature: String,
+    /// RFC 3161 token over the checkpoint's digest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<AnchorProof>,
+}
+
+/// Fields of a checkpoint covered by its signature and digest
+#[derive(Serialize)]
+struct SignedFields<'a> {
+    session_id: &'a str,
+    sequence: u64,
+    created_at: &'a DateTime<Utc>,
+    segments: &'a [String],
+    segment_count: u64,
+    bytes_written: u64,
+    running_hash: &'a str,
+    previous: &'a str,
+}
+
+impl Checkpoint {
+    /// Canonical JSON of the signed fields
+    fn signable_data(&self, session_id: &str) -> Vec<u8> {
+        canonical::to_vec(&SignedFields {
+            session_id,
+            sequence: self.sequence,
+            created_at: &self.created_at,
+            segments: &self.segments,
+            segment_count: self.segment_count,
+            bytes_written: self.bytes_written,
+            running_hash: &self.running_hash,
+            previous: &self.previous,
+        })
+        .unwrap()
+    }
+
+    /// SHA-256 of the signed fields (hex); what the RFC 3161 token and the next
+    /// checkpoint's `previous` refer to
+    pub fn digest(&self, session_id: &str) -> String {
+        hex::encode(Sha256::digest(self.signable_data(session_id)))
+    }
+}
+
+/// Running hash after folding `segments` into `previous`
+fn next_running_hash(previous: &str, segments: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(previous.as_bytes());
+    for segment in segments {
+        hasher.update(segment.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Checkpoints of one recording, in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointLog {
+    pub version: u32,
+    pub session_id: String,
+    /// Key every checkpoint is signed with (Ed25519, base64)
+    pub public_key: String,
+    pub interval_seconds: u64,
+    #[serde(default)]
+    pub checkpoints: Vec<Checkpoint>,
+}
+
+impl CheckpointLog {
+    /// Start an empty log for `session_id`, signed with `key`
+    pub fn new(session_id: &str, key: &KeyManager, interval: Duration) -> Self {
+        use base64::{engine::general_purpose, Engine as _};
+
+        Self {
+            version: CHECKPOINT_LOG_VERSION,
+            session_id: session_id.to_string(),
+            public_key: general_purpose::STANDARD.encode(key.public_key().as_bytes()),
+            interval_seconds: interval.as_secs(),
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Read a log from `path`
+    pub fn load(path: &Path) -> NotariResult<Self> {
+        let data = std::fs::read(path)
+            .map_err(|e| NotariError::StorageLoadFailed(format!("{}: {}", path.display(), e)))?;
+        let log: Self = serde_json::from_slice(&data)?;
+        if log.version > CHECKPOINT_LOG_VERSION {
+            return Err(NotariError::StorageLoadFailed(format!(
+                "Unsupported checkpoint log version {} in {}",
+                log.version,
+                path.display()
+            )));
+        }
+        Ok(log)
+    }
+
+    /// Write the log to `path`, replacing the previous one atomically
+    pub fn save(&self, path: &Path) -> NotariResult<()> {
+        let tmp_path = path.with_extension("json.tmp");
+        let data = serde_json::to_vec_pretty(self)?;
+        std::fs::write(&tmp_path, data)
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .map_err(|e| NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Running hash before the first checkpoint, bound to the session
+    pub fn initial_running_hash(session_id: &str) -> String {
+        hex::encode(Sha256::digest(session_id.as_bytes()))
+    }
+
+    /// Segments covered by the checkpoints so far
+    pub fn segment_count(&self) -> u64 {
+        self.checkpoints
+            .last()
+            .map_or(0, |checkpoint| checkpoint.segment_count)
+    }
+
+    /// Sign a checkpoint covering `segments` (hex SHA-256 each), finished since
+    /// the previous checkpoint and holding `bytes` bytes
+    pub fn append(&mut self, key: &KeyManager, segments: Vec<String>, bytes: u64) -> &Checkpoint {
+        let (running_hash, previous, segment_count, bytes_written) = match self.checkpoints.last() {
+            Some(last) => (
+                next_running_hash(&last.running_hash, &segments),
+                last.digest(&self.session_id),
+                last.segment_count,
+                last.bytes_written,
+            ),
+            None => (
+                next_running_hash(&Self::initial_running_hash(&self.session_id), &segments),
+                String::new(),
+                0,
+                0,
+            ),
+        };
+
+        let mut checkpoint = Checkpoint {
+            sequence: self.checkpoints.len() as u64,
+            created_at: Utc::now(),
+            segment_count: segment_count + segments.len() as u64,
+            segments,
+            bytes_written: bytes_written + bytes,
+            running_hash,
+            previous,
+            signature: String::new(),
+            timestamp: None,
+        };
+        checkpoint.signature = key
+            .sign(&checkpoint.signable_data(&self.session_id))
+            .signature;
+
+        self.checkpoints.push(checkpoint);
+        self.checkpoints.last().unwrap()
+    }
+
+    /// Attach the RFC 3161 token for the checkpoint numbered `sequence`
+    ///
+    /// Tokens are requested after the checkpoint is signed and aren't covered by
+    /// its signature; they are checked against its digest instead.
+    pub fn attach_timestamp(&mut self, sequence: u64, proof: AnchorProof) -> NotariResult<()> {
+        let checkpoint = self
+            .checkpoints
+            .get_mut(sequence as usize)
+            .ok_or_else(|| NotariError::Internal(format!("No checkpoint {}", sequence)))?;
+        checkpoint.timestamp = Some(proof);
+        Ok(())
+    }
+
+    /// Summary of the log as written to `path`, for the manifest
+    pub fn summarize(path: &Path) -> NotariResult<CheckpointSummary> {
+        let data = std::fs::read(path)?;
+        let log: Self = serde_json::from_slice(&data)?;
+        Ok(CheckpointSummary {
+            count: log.checkpoints.len(),
+            interval_seconds: log.interval_seconds,
+            final_running_hash: log.final_running_hash(),
+            log_hash: HashInfo::from_bytes(&data),
+            segments: Vec::new(),
+            dropped_segments: Vec::new(),
+        })
+    }
+
+    /// Running hash of the last checkpoint
+    pub fn final_running_hash(&self) -> String {
+        self.checkpoints
+            .last()
+            .map(|checkpoint| checkpoint.running_hash.clone())
+            .unwrap_or_else(|| Self::initial_running_hash(&self.session_id))
+    }
+
+    /// Whether every checkpoint is signed by the log's key
+    pub fn signatures_valid(&self) -> bool {
+        self.checkpoints.iter().all(|checkpoint| {
+            KeyManager::verify(
+                &self.public_key,
+                &checkpoint.signature,
+                &checkpoint.signable_data(&self.session_id),
+            )
+            .unwrap_or(false)
+        })
+    }
+
+    /// Check that sequence numbers, links, counts and running hashes follow on
+    /// from one checkpoint to the next
+    pub fn verify_chain(&self) -> Result<(), String> {
+        let mut running_hash = Self::initial_running_hash(&self.session_id);
+        let mut previous = String::new();
+        let mut segment_count = 0;
+        let mut bytes_written = 0;
+
+        for (index, checkpoint) in self.checkpoints.iter().enumerate() {
+            if checkpoint.sequence != index as u64 {
+                return Err(format!(
+                    "Checkpoint {} has sequence number {}",
+                    index, checkpoint.sequence
+                ));
+            }
+            if checkpoint.previous != previous {
+                return Err(format!(
+                    "Checkpoint {} doesn't link to the one before it",
+                    index
+                ));
+            }
+            running_hash = next_running_hash(&running_hash, &checkpoint.segments);
+            if checkpoint.running_hash != running_hash {
+                return Err(format!("Checkpoint {} has the wrong running hash", index));
+            }
+            segment_count += checkpoint.segments.len() as u64;
+            if checkpoint.segment_count != segment_count {
+                return Err(format!("Checkpoint {} has the wrong segment count", index));
+            }
+            if checkpoint.bytes_written < bytes_written {
+                return Err(format!("Checkpoint {} has fewer bytes than before", index));
+            }
+
+            bytes_written = checkpoint.bytes_written;
+            previous = checkpoint.digest(&self.session_id);
+        }
+        Ok(())
+    }
+
+    /// Check that checkpoint times increase and fall within the recording
+    pub fn verify_times(
+        &self,
+        started_at: DateTime<Utc>,
+        stopped_at: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let skew = chrono::Duration::seconds(MAX_CLOCK_SKEW_SECONDS);
+        let mut last = started_at - skew;
+
+        for checkpoint in &self.checkpoints {
+            if checkpoint.created_at <= last {
+                return Err(format!(
+                    "Checkpoint {} is not after the one before it or the recording start",
+                    checkpoint.sequence
+                ));
+            }
+            if checkpoint.created_at > stopped_at + skew {
+                return Err(format!(
+                    "Checkpoint {} is after the recording stopped",
+                    checkpoint.sequence
+                ));
+            }
+            last = checkpoint.created_at;
+        }
+        Ok(())
+    }
+
+    /// Check the RFC 3161 tokens against each checkpoint's digest and time
+    ///
+    /// Returns `None` if no checkpoint has a token.
+    pub fn verify_timestamps(&self) -> Option<Result<(), String>> {
+        let skew = chrono::Duration::seconds(MAX_CLOCK_SKEW_SECONDS);
+        let mut stamped = self
+            .checkpoints
+            .iter()
+            .filter_map(|checkpoint| Some((checkpoint, checkpoint.timestamp.as_ref()?)))
+            .peekable();
+        stamped.peek()?;
+
+        Some(stamped.try_for_each(|(checkpoint, proof)| {
+            let token = TsaAnchorer::verify_token(&checkpoint.digest(&self.session_id), proof)
+                .map_err(|e| format!("Checkpoint {}: {}", checkpoint.sequence, e))?;
+            if (token.gen_time - checkpoint.created_at).abs() > skew {
+                return Err(format!(
+                    "Checkpoint {} was time-stamped at {}, not {}",
+                    checkpoint.sequence, token.gen_time, checkpoint.created_at
+                ));
+            }
+            Ok(())
+        }))
+    }
+
+    /// Check that the log ends at `summary`'s final running hash and that its
+    /// segments are the ones `summary` lists as joined into the video, then dropped
+    ///
+    /// Segments finished after the last checkpoint of an interrupted recording
+    /// aren't in the log. Returns the number of checkpointed segments that were
+    /// dropped.
+    pub fn verify_segments(&self, summary: &CheckpointSummary) -> Result<usize, String> {
+        if summary.final_running_hash != self.final_running_hash() {
+            return Err("Checkpoint log doesn't end at the manifest's running hash".to_string());
+        }
+
+        let checkpointed: Vec<&String> = self
+            .checkpoints
+            .iter()
+            .flat_map(|checkpoint| &checkpoint.segments)
+            .collect();
+        let packed: Vec<&String> = summary
+            .segments
+            .iter()
+            .chain(&summary.dropped_segments)
+            .collect();
+        if !packed.starts_with(&checkpointed) {
+            return Err("Checkpointed segments aren't the ones joined into the video".to_string());
+        }
+        Ok(checkpointed.len().saturating_sub(summary.segments.len()))
+    }
+
+    /// Longest stretch of the recording without a checkpoint
+    pub fn max_gap(
+        &self,
+        started_at: DateTime<Utc>,
+        stopped_at: DateTime<Utc>,
+    ) -> chrono::Duration {
+        let mut last = started_at;
+        let mut gap = chrono::Duration::zero();
+        for checkpoint in &self.checkpoints {
+            gap = gap.max(checkpoint.created_at - last);
+            last = checkpoint.created_at;
+        }
+        gap.max(stopped_at - last)
+    }
+}
+
+/// Commitment to the checkpoint log, stored in the manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointSummary {
+    pub count: usize,
+    pub interval_seconds: u64,
+    /// Running hash of the last checkpoint
+    pub final_running_hash: String,
+    /// Hash of `checkpoints.json` as packed
+    pub log_hash: HashInfo,
+    /// Hashes of the segments joined into the video, in order; also packed
+    /// under `segments/` unless the recording is encrypted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<String>,
+    /// Hashes of the segments left out of the video because they, or one before
+    /// them, were missing or damaged when it was joined
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dropped_segments: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn segment(data: &[u8]) -> Vec<String> {
+        vec![HashInfo::from_bytes(data).value]
+    }
+
+    fn log_with_checkpoints(key: &KeyManager) -> CheckpointLog {
+        let mut log = CheckpointLog::new("session-1", key, CHECKPOINT_INTERVAL);
+        log.append(key, segment(b"first"), 5);
+        log.append(key, Vec::new(), 0);
+        log.append(key, segment(b"second"), 6);
+        log
+    }
+
+    #[test]
+    fn test_append_chains_checkpoints() {
+        let key = KeyManager::generate();
+        let log = log_with_checkpoints(&key);
+
+        assert_eq!(log.segment_count(), 2);
+        assert_eq!(log.checkpoints[2].bytes_written, 11);
+        assert!(log.checkpoints[0].previous.is_empty());
+        assert_eq!(
+            log.checkpoints[1].previous,
+            log.checkpoints[0].digest("session-1")
+        );
+        // A checkpoint without new segments keeps the running hash
+        assert_eq!(
+            log.checkpoints[1].running_hash,
+            next_running_hash(&log.checkpoints[0].running_hash, &[])
+        );
+        assert!(log.signatures_valid());
+        assert_eq!(log.verify_chain(), Ok(()));
+        assert_eq!(log.verify_timestamps(), None);
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let key = KeyManager::generate();
+        let log = log_with_checkpoints(&key);
+
+        let mut altered = log.clone();
+        altered.checkpoints[0].segments = segment(b"other");
+        assert!(!altered.signatures_valid());
+        assert!(altered.verify_chain().is_err());
+
+        // Dropping a checkpoint breaks the sequence even though each is signed
+        let mut dropped = log.clone();
+        dropped.checkpoints.remove(1);
+        assert!(dropped.signatures_valid());
+        assert!(dropped.verify_chain().is_err());
+
+        let mut foreign = log.clone();
+        foreign.public_key =
+            CheckpointLog::new("session-1", &KeyManager::generate(), CHECKPOINT_INTERVAL)
+                .public_key;
+        assert!(!foreign.signatures_valid());
+    }
+
+    #[test]
+    fn test_verify_times() {
+        let key = KeyManager::generate();
+        let log = log_with_checkpoints(&key);
+        let first = log.checkpoints[0].created_at;
+        let last = log.checkpoints[2].created_at;
+
+        assert_eq!(log.verify_times(first, last), Ok(()));
+        assert!(log
+            .verify_times(
+                first + chrono::Duration::minutes(5),
+                last + chrono::Duration::minutes(5)
+            )
+            .is_err());
+        assert!(log
+            .verify_times(
+                first - chrono::Duration::minutes(10),
+                first - chrono::Duration::minutes(5)
+            )
+            .is_err());
+
+        let mut reordered = log.clone();
+        reordered.checkpoints.swap(0, 2);
+        assert!(reordered.verify_times(first, last).is_err());
+
+        assert_eq!(
+            log.max_gap(first, last + chrono::Duration::seconds(30)),
+            chrono::Duration::seconds(30)
+        );
+    }
+
+    #[test]
+    fn test_verify_timestamps() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use base64::{engine::general_purpose, Engine as _};
+
+        let key = KeyManager::generate();
+        let mut log = log_with_checkpoints(&key);
+        let tsa = TestTsa::new();
+        let stamp = |digest: &str| AnchorProof::Rfc3161 {
+            tsa_url: "http://127.0.0.1/tsr".to_string(),
+            tsa_name: "CN=Notari Test TSA".to_string(),
+            token: general_purpose::STANDARD.encode(tsa.token(&hex::decode(digest).unwrap(), None)),
+            gen_time: Utc::now(),
+            serial_number: "04d2".to_string(),
+        };
+
+        let digest = log.checkpoints[1].digest("session-1");
+        log.attach_timestamp(1, stamp(&digest)).unwrap();
+        assert_eq!(log.verify_timestamps(), Some(Ok(())));
+        assert!(log.attach_timestamp(3, stamp(&digest)).is_err());
+
+        // A token for another checkpoint doesn't count
+        log.attach_timestamp(2, stamp(&digest)).unwrap();
+        assert!(log.verify_timestamps().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_verify_segments() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CHECKPOINT_LOG_FILE);
+        let key = KeyManager::generate();
+        let log = log_with_checkpoints(&key);
+        log.save(&path).unwrap();
+        let first = segment(b"first");
+        let second = segment(b"second");
+
+        // A manifest that lists no segments doesn't skip the check
+        let mut summary = CheckpointLog::summarize(&path).unwrap();
+        assert!(log.verify_segments(&summary).is_err());
+
+        summary.segments = [first.clone(), second.clone()].concat();
+        assert_eq!(log.verify_segments(&summary), Ok(0));
+
+        // The second segment was damaged and left out of the video
+        summary.segments = first.clone();
+        summary.dropped_segments = second.clone();
+        assert_eq!(log.verify_segments(&summary), Ok(1));
+
+        // Segments finished after the last checkpoint aren't in the log
+        summary.segments = [first.clone(), second.clone(), segment(b"third")].concat();
+        summary.dropped_segments = Vec::new();
+        assert_eq!(log.verify_segments(&summary), Ok(0));
+
+        // A video joined from other segments doesn't match
+        summary.segments = [second.clone(), first.clone()].concat();
+        assert!(log.verify_segments(&summary).is_err());
+        summary.segments = first;
+        assert!(log.verify_segments(&summary).is_err());
+
+        summary.segments = [segment(b"first"), second].concat();
+        summary.final_running_hash = log.checkpoints[0].running_hash.clone();
+        assert!(log.verify_segments(&summary).is_err());
+    }
+
+    #[test]
+    fn test_save_and_summarize() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CHECKPOINT_LOG_FILE);
+        let key = KeyManager::generate();
+        let log = log_with_checkpoints(&key);
+        log.save(&path).unwrap();
+
+        let loaded = CheckpointLog::load(&path).unwrap();
+        assert_eq!(loaded.checkpoints.len(), 3);
+        assert_eq!(loaded.verify_chain(), Ok(()));
+
+        let summary = CheckpointLog::summarize(&path).unwrap();
+        assert_eq!(summary.count, 3);
+        assert_eq!(summary.final_running_hash, log.checkpoints[2].running_hash);
+        assert_eq!(
+            summary.log_hash.value,
+            HashInfo::from_file(&path).unwrap().value
+        );
+    }
+}
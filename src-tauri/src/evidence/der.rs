@@ -0,0 +1,503 @@
+//! Minimal ASN.1 DER reader and writer
+//!
+//! Covers the subset needed for X.509 certificates, CMS signed data and RFC 3161
+//! time-stamp tokens. Only definite-length encodings with single-byte tags are
+//! accepted; every length is checked against the remaining input.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::error::{NotariError, NotariResult};
+
+pub const TAG_BOOLEAN: u8 = 0x01;
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_NULL: u8 = 0x05;
+pub const TAG_OID: u8 = 0x06;
+pub const TAG_UTF8_STRING: u8 = 0x0c;
+pub const TAG_PRINTABLE_STRING: u8 = 0x13;
+pub const TAG_IA5_STRING: u8 = 0x16;
+pub const TAG_UTC_TIME: u8 = 0x17;
+pub const TAG_GENERALIZED_TIME: u8 = 0x18;
+pub const TAG_SEQUENCE: u8 = 0x30;
+pub const TAG_SET: u8 = 0x31;
+
+/// Context-specific constructed tag `[n]`
+pub const fn context(n: u8) -> u8 {
+    0xa0 | n
+}
+
+/// Context-specific primitive tag `[n] IMPLICIT`
+pub const fn context_primitive(n: u8) -> u8 {
+    0x80 | n
+}
+
+fn invalid(msg: impl std::fmt::Display) -> NotariError {
+    NotariError::InvalidDer(msg.to_string())
+}
+
+/// A single DER element borrowed from the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Element<'a> {
+    pub tag: u8,
+    /// Contents octets
+    pub value: &'a [u8],
+    /// Complete encoding (tag, length and contents)
+    pub raw: &'a [u8],
+}
+
+impl<'a> Element<'a> {
+    /// Check the element has the given tag
+    pub fn expect(self, tag: u8) -> NotariResult<Self> {
+        if self.tag != tag {
+            return Err(invalid(format!(
+                "expected tag 0x{:02x}, found 0x{:02x}",
+                tag, self.tag
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Reader over the contents of a constructed element
+    pub fn reader(&self) -> Reader<'a> {
+        Reader::new(self.value)
+    }
+
+    /// Contents of an INTEGER, without a leading sign byte
+    pub fn integer_bytes(&self) -> NotariResult<&'a [u8]> {
+        self.expect(TAG_INTEGER)?;
+        match self.value {
+            [] => Err(invalid("empty INTEGER")),
+            [0, rest @ ..] if !rest.is_empty() => Ok(rest),
+            value => Ok(value),
+        }
+    }
+
+    /// Value of a non-negative INTEGER that fits in a u64
+    pub fn as_u64(&self) -> NotariResult<u64> {
+        if self.value.first().is_some_and(|b| b & 0x80 != 0) {
+            return Err(invalid("negative INTEGER"));
+        }
+        let bytes = self.integer_bytes()?;
+        if bytes.len() > 8 {
+            return Err(invalid("INTEGER too large"));
+        }
+        Ok(bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+    }
+
+    pub fn as_bool(&self) -> NotariResult<bool> {
+        self.expect(TAG_BOOLEAN)?;
+        match self.value {
+            [0x00] => Ok(false),
+            [0xff] => Ok(true),
+            _ => Err(invalid("invalid BOOLEAN")),
+        }
+    }
+
+    /// Dotted form of an OBJECT IDENTIFIER (e.g. "2.5.4.3")
+    pub fn as_oid(&self) -> NotariResult<String> {
+        self.expect(TAG_OID)?;
+        if self.value.is_empty() || self.value.last().unwrap() & 0x80 != 0 {
+            return Err(invalid("truncated OBJECT IDENTIFIER"));
+        }
+
+        let mut arcs = Vec::new();
+        let mut arc: u64 = 0;
+        for &byte in self.value {
+            if arc > u64::MAX >> 7 {
+                return Err(invalid("OBJECT IDENTIFIER arc too large"));
+            }
+            arc = (arc << 7) | (byte & 0x7f) as u64;
+            if byte & 0x80 == 0 {
+                if arcs.is_empty() {
+                    let first = (arc / 40).min(2);
+                    arcs.push(first);
+                    arcs.push(arc - first * 40);
+                } else {
+                    arcs.push(arc);
+                }
+                arc = 0;
+            }
+        }
+
+        Ok(arcs
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join("."))
+    }
+
+    /// Contents of an OCTET STRING
+    pub fn as_octets(&self) -> NotariResult<&'a [u8]> {
+        Ok(self.expect(TAG_OCTET_STRING)?.value)
+    }
+
+    /// Contents of a BIT STRING with no unused bits
+    pub fn as_bits(&self) -> NotariResult<&'a [u8]> {
+        match self.expect(TAG_BIT_STRING)?.value {
+            [0, bits @ ..] => Ok(bits),
+            _ => Err(invalid("BIT STRING with unused bits")),
+        }
+    }
+
+    /// Text of a UTF8String, PrintableString or IA5String
+    pub fn as_string(&self) -> NotariResult<String> {
+        match self.tag {
+            TAG_UTF8_STRING | TAG_PRINTABLE_STRING | TAG_IA5_STRING => {
+                String::from_utf8(self.value.to_vec()).map_err(|_| invalid("invalid string"))
+            }
+            tag => Err(invalid(format!("unsupported string type 0x{:02x}", tag))),
+        }
+    }
+
+    /// Value of a UTCTime or GeneralizedTime (UTC, "Z" suffix required)
+    pub fn as_time(&self) -> NotariResult<DateTime<Utc>> {
+        let text = std::str::from_utf8(self.value).map_err(|_| invalid("invalid time"))?;
+        let text = text
+            .strip_suffix('Z')
+            .ok_or_else(|| invalid("time must be UTC"))?;
+
+        let (text, fraction) = match (self.tag, text.split_once('.')) {
+            (TAG_UTC_TIME, _) => {
+                let year: u32 = text
+                    .get(..2)
+                    .and_then(|y| y.parse().ok())
+                    .ok_or_else(|| invalid("invalid UTCTime"))?;
+                let century = if year >= 50 { "19" } else { "20" };
+                (format!("{}{}", century, text), None)
+            }
+            (TAG_GENERALIZED_TIME, Some((main, fraction))) => (main.to_string(), Some(fraction)),
+            (TAG_GENERALIZED_TIME, None) => (text.to_string(), None),
+            (tag, _) => return Err(invalid(format!("expected time, found 0x{:02x}", tag))),
+        };
+
+        let mut time = NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%S")
+            .map_err(|e| invalid(format!("invalid time '{}': {}", text, e)))?
+            .and_utc();
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || fraction.len() > 9 || fraction.ends_with('0') {
+                return Err(invalid("invalid fractional seconds"));
+            }
+            let nanos: i64 = format!("{:0<9}", fraction)
+                .parse()
+                .map_err(|_| invalid("invalid fractional seconds"))?;
+            time += chrono::Duration::nanoseconds(nanos);
+        }
+        Ok(time)
+    }
+}
+
+/// Sequential reader over DER elements
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Tag of the next element, if any
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    /// Read the next element
+    pub fn read(&mut self) -> NotariResult<Element<'a>> {
+        let start = self.pos;
+        let tag = self.take(1)?[0];
+        if tag & 0x1f == 0x1f {
+            return Err(invalid("multi-byte tags are not supported"));
+        }
+
+        let first = self.take(1)?[0];
+        let len = match first {
+            0x00..=0x7f => first as usize,
+            0x80 => return Err(invalid("indefinite length")),
+            0x81..=0x84 => {
+                let bytes = self.take((first & 0x7f) as usize)?;
+                if bytes[0] == 0 {
+                    return Err(invalid("non-minimal length"));
+                }
+                let len = bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize);
+                if len < 0x80 {
+                    return Err(invalid("non-minimal length"));
+                }
+                len
+            }
+            _ => return Err(invalid("length too large")),
+        };
+
+        let value = self.take(len)?;
+        Ok(Element {
+            tag,
+            value,
+            raw: &self.data[start..self.pos],
+        })
+    }
+
+    /// Read the next element and check its tag
+    pub fn read_tag(&mut self, tag: u8) -> NotariResult<Element<'a>> {
+        self.read()?.expect(tag)
+    }
+
+    /// Read the next element only if it has the given tag
+    pub fn read_optional(&mut self, tag: u8) -> NotariResult<Option<Element<'a>>> {
+        if self.peek_tag() == Some(tag) {
+            Ok(Some(self.read()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fail if any input is left
+    pub fn finish(&self) -> NotariResult<()> {
+        if !self.is_empty() {
+            return Err(invalid("trailing data"));
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> NotariResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+}
+
+/// Parse input that consists of exactly one element
+pub fn parse(data: &[u8]) -> NotariResult<Element<'_>> {
+    let mut reader = Reader::new(data);
+    let element = reader.read()?;
+    reader.finish()?;
+    Ok(element)
+}
+
+/// Parse an AlgorithmIdentifier, returning the algorithm OID and, if the
+/// parameters are an OID (named curve), that OID
+pub fn algorithm_identifier(element: Element<'_>) -> NotariResult<(String, Option<String>)> {
+    let mut reader = element.expect(TAG_SEQUENCE)?.reader();
+    let algorithm = reader.read()?.as_oid()?;
+    let parameters = match reader.read_optional(TAG_OID)? {
+        Some(oid) => Some(oid.as_oid()?),
+        None => {
+            // NULL or absent parameters
+            reader.read_optional(TAG_NULL)?;
+            None
+        }
+    };
+    reader.finish()?;
+    Ok((algorithm, parameters))
+}
+
+// ============================================================================
+// Writer
+// ============================================================================
+
+/// Encode a single element
+pub fn encode(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = value.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend_from_slice(&bytes);
+    }
+    out.extend_from_slice(value);
+    out
+}
+
+/// SEQUENCE of already-encoded elements
+pub fn sequence(items: &[Vec<u8>]) -> Vec<u8> {
+    encode(TAG_SEQUENCE, &items.concat())
+}
+
+/// SET OF already-encoded elements (sorted, as DER requires)
+pub fn set(items: &[Vec<u8>]) -> Vec<u8> {
+    let mut items = items.to_vec();
+    items.sort();
+    encode(TAG_SET, &items.concat())
+}
+
+/// `[n] EXPLICIT` wrapper around an encoded element
+pub fn explicit(n: u8, inner: &[u8]) -> Vec<u8> {
+    encode(context(n), inner)
+}
+
+/// Non-negative INTEGER from big-endian magnitude bytes
+pub fn integer(magnitude: &[u8]) -> Vec<u8> {
+    let trimmed: Vec<u8> = magnitude.iter().copied().skip_while(|b| *b == 0).collect();
+    let mut value = Vec::with_capacity(trimmed.len() + 1);
+    if trimmed.first().map_or(true, |b| b & 0x80 != 0) {
+        value.push(0);
+    }
+    value.extend_from_slice(&trimmed);
+    encode(TAG_INTEGER, &value)
+}
+
+pub fn integer_u64(value: u64) -> Vec<u8> {
+    integer(&value.to_be_bytes())
+}
+
+pub fn boolean(value: bool) -> Vec<u8> {
+    encode(TAG_BOOLEAN, &[if value { 0xff } else { 0x00 }])
+}
+
+pub fn null() -> Vec<u8> {
+    encode(TAG_NULL, &[])
+}
+
+pub fn octet_string(value: &[u8]) -> Vec<u8> {
+    encode(TAG_OCTET_STRING, value)
+}
+
+/// BIT STRING with no unused bits
+pub fn bit_string(bits: &[u8]) -> Vec<u8> {
+    encode(TAG_BIT_STRING, &[&[0u8][..], bits].concat())
+}
+
+pub fn utf8_string(value: &str) -> Vec<u8> {
+    encode(TAG_UTF8_STRING, value.as_bytes())
+}
+
+/// OBJECT IDENTIFIER from its dotted form
+///
+/// # Panics
+/// Panics on a malformed OID; callers pass constants.
+pub fn oid(dotted: &str) -> Vec<u8> {
+    let arcs: Vec<u64> = dotted
+        .split('.')
+        .map(|arc| arc.parse().expect("invalid OID constant"))
+        .collect();
+    assert!(arcs.len() >= 2, "invalid OID constant");
+
+    let mut value = Vec::new();
+    for arc in std::iter::once(arcs[0] * 40 + arcs[1]).chain(arcs[2..].iter().copied()) {
+        let mut chunk = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            chunk.push(0x80 | (rest & 0x7f) as u8);
+            rest >>= 7;
+        }
+        chunk.reverse();
+        value.extend(chunk);
+    }
+    encode(TAG_OID, &value)
+}
+
+/// GeneralizedTime with second precision
+pub fn generalized_time(time: &DateTime<Utc>) -> Vec<u8> {
+    encode(
+        TAG_GENERALIZED_TIME,
+        time.format("%Y%m%d%H%M%SZ").to_string().as_bytes(),
+    )
+}
+
+/// AlgorithmIdentifier with optional OID parameters (e.g. named curve)
+pub fn algorithm(algorithm: &str, parameters: Option<&str>) -> Vec<u8> {
+    match parameters {
+        Some(parameters) => sequence(&[oid(algorithm), oid(parameters)]),
+        None => sequence(&[oid(algorithm)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_length_forms_roundtrip() {
+        for len in [0usize, 1, 127, 128, 255, 256, 70_000] {
+            let encoded = octet_string(&vec![0xab; len]);
+            let element = parse(&encoded).unwrap();
+            assert_eq!(element.as_octets().unwrap().len(), len);
+            assert_eq!(element.raw, encoded.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_oid_roundtrip() {
+        for dotted in [
+            "1.2.840.113549.1.1.11",
+            "2.16.840.1.101.3.4.2.1",
+            "1.3.101.112",
+        ] {
+            assert_eq!(parse(&oid(dotted)).unwrap().as_oid().unwrap(), dotted);
+        }
+        // sha256WithRSAEncryption
+        assert_eq!(
+            hex::encode(oid("1.2.840.113549.1.1.11")),
+            "06092a864886f70d01010b"
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integer_u64(0), vec![0x02, 0x01, 0x00]);
+        assert_eq!(integer_u64(0x80), vec![0x02, 0x02, 0x00, 0x80]);
+        for value in [0u64, 1, 0x7f, 0x80, 0xffff, u64::MAX] {
+            assert_eq!(parse(&integer_u64(value)).unwrap().as_u64().unwrap(), value);
+        }
+        assert!(parse(&[0x02, 0x01, 0xff]).unwrap().as_u64().is_err());
+    }
+
+    #[test]
+    fn test_times() {
+        let time = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 5).unwrap();
+        assert_eq!(
+            parse(&generalized_time(&time)).unwrap().as_time().unwrap(),
+            time
+        );
+
+        let fractional = encode(TAG_GENERALIZED_TIME, b"20240301123005.25Z");
+        assert_eq!(
+            parse(&fractional).unwrap().as_time().unwrap(),
+            time + chrono::Duration::milliseconds(250)
+        );
+
+        let utc = encode(TAG_UTC_TIME, b"991231235959Z");
+        assert_eq!(
+            parse(&utc).unwrap().as_time().unwrap(),
+            Utc.with_ymd_and_hms(1999, 12, 31, 23, 59, 59).unwrap()
+        );
+
+        let local = encode(TAG_GENERALIZED_TIME, b"20240301123005");
+        assert!(parse(&local).unwrap().as_time().is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        // Indefinite length, truncated contents, non-minimal length, trailing data
+        assert!(parse(&[0x30, 0x80, 0x00, 0x00]).is_err());
+        assert!(parse(&[0x04, 0x05, 0x01]).is_err());
+        assert!(parse(&[0x04, 0x81, 0x01, 0x00]).is_err());
+        assert!(parse(&[0x05, 0x00, 0x00]).is_err());
+        // Length larger than the address space
+        assert!(parse(&[0x04, 0x84, 0xff, 0xff, 0xff, 0xff]).is_err());
+    }
+
+    #[test]
+    fn test_set_is_sorted() {
+        let encoded = set(&[integer_u64(2), integer_u64(1)]);
+        let mut reader = parse(&encoded).unwrap().reader();
+        assert_eq!(reader.read().unwrap().as_u64().unwrap(), 1);
+        assert_eq!(reader.read().unwrap().as_u64().unwrap(), 2);
+    }
+}
@@ -1,18 +1,23 @@
 use aes_gcm::{
-    aead::{Aead, KeyInit, OsRng},
+    aead::{Aead, KeyInit, OsRng, Payload},
     Aes256Gcm, Key, Nonce,
 };
+use argon2::{Argon2, Params, Version};
+use chrono::Utc;
 use pbkdf2::pbkdf2_hmac;
 use rand::RngCore;
 use sha2::Sha256;
 use std::fs::File;
 use std::io::{Read, Seek, Write};
 use std::path::Path;
+use std::sync::Arc;
+use zeroize::Zeroizing;
 
 // Re-export types from manifest module
 use crate::error::{NotariError, NotariResult};
 use crate::evidence::manifest::{
-    ChunkInfo, ChunkedEncryptionInfo, EncryptionInfo, KeyDerivationInfo,
+    ChunkInfo, ChunkedEncryptionInfo, EncryptedKey, EncryptionInfo, KeyDerivationInfo,
+    RecipientAction, RecipientChange,
 };
 use crate::logger::{LogLevel, LOGGER};
 
@@ -20,9 +25,60 @@ use crate::logger::{LogLevel, LOGGER};
 const SALT_SIZE: usize = 32;
 const NONCE_SIZE: usize = 12;
 const KEY_SIZE: usize = 32;
-const PBKDF2_ITERATIONS: u32 = 600_000; // OWASP recommendation for 2024
 const CHUNK_SIZE: usize = 1024 * 1024; // 1MB chunks for streaming
 
+/// Password key derivation of recordings made before Argon2id
+pub const PBKDF2_ALGORITHM: &str = "PBKDF2-HMAC-SHA256";
+/// Password key derivation for new recordings
+pub const ARGON2ID_ALGORITHM: &str = "Argon2id";
+// RFC 9106 second recommended option: 64 MiB, 3 passes, 4 lanes
+const ARGON2_MEMORY_KIB: u32 = 64 * 1024;
+const ARGON2_TIME_COST: u32 = 3;
+const ARGON2_PARALLELISM: u32 = 4;
+// Refuse parameters from a manifest that would take more memory or time than this
+const ARGON2_MAX_MEMORY_KIB: u32 = 1024 * 1024;
+const ARGON2_MAX_TIME_COST: u32 = 16;
+const ARGON2_MAX_PARALLELISM: u32 = 16;
+// Recordings made before Argon2id used 600,000 iterations
+const PBKDF2_MAX_ITERATIONS: u32 = 10_000_000;
+
+/// Chunked encryption with each chunk bound to its position (STREAM)
+pub const STREAM_ALGORITHM: &str = "AES-256-GCM-STREAM";
+/// Chunked encryption with independent chunks, still decrypted for old recordings
+pub const LEGACY_CHUNKED_ALGORITHM: &str = "AES-256-GCM-CHUNKED";
+const STREAM_AAD_CONTEXT: &[u8] = b"notari-stream-v1";
+
+/// AES-256 key a video's chunks are encrypted with
+///
+/// Derived from a password or unwrapped with a private key once, e.g. when
+/// playback starts, rather than for every chunk. Zeroized on drop.
+pub struct ContentKey(Zeroizing<[u8; KEY_SIZE]>);
+
+impl ContentKey {
+    fn from_slice(bytes: &[u8]) -> NotariResult<Self> {
+        let bytes: [u8; KEY_SIZE] = bytes.try_into().map_err(|_| {
+            NotariError::DecryptionFailed("Invalid video key size after decryption".to_string())
+        })?;
+        Ok(Self(Zeroizing::new(bytes)))
+    }
+
+    fn generate() -> Self {
+        let mut bytes = Zeroizing::new([0u8; KEY_SIZE]);
+        OsRng.fill_bytes(&mut bytes[..]);
+        Self(bytes)
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0[..]))
+    }
+}
+
+impl std::fmt::Debug for ContentKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ContentKey(..)")
+    }
+}
+
 /// Video encryption handler
 pub struct VideoEncryptor;
 
@@ -33,19 +89,10 @@ impl VideoEncryptor {
         output_path: P,
         password: &str,
     ) -> NotariResult<EncryptionInfo> {
-        // Generate random salt
-        let mut salt = [0u8; SALT_SIZE];
-        OsRng.fill_bytes(&mut salt);
-
-        // Derive key from password using PBKDF2
-        let mut key_bytes = [0u8; KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            &salt,
-            PBKDF2_ITERATIONS,
-            &mut key_bytes,
-        );
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
+        // Derive key from password using Argon2id
+        let key_derivation = new_key_derivation();
+        let key_bytes = derive_password_key(password, &key_derivation)?;
+        let key = Key::<Aes256Gcm>::from_slice(&key_bytes[..]);
 
         // Generate random nonce
         let mut nonce_bytes = [0u8; NONCE_SIZE];
@@ -78,15 +125,12 @@ impl VideoEncryptor {
         use base64::{engine::general_purpose, Engine as _};
         Ok(EncryptionInfo {
             algorithm: "AES-256-GCM".to_string(),
-            key_derivation: Some(KeyDerivationInfo {
-                algorithm: "PBKDF2-HMAC-SHA256".to_string(),
-                iterations: PBKDF2_ITERATIONS,
-                salt: general_purpose::STANDARD.encode(&salt),
-            }),
+            key_derivation: Some(key_derivation),
             encrypted_keys: None,
             nonce: Some(general_purpose::STANDARD.encode(&nonce_bytes)),
             tag: Some(general_purpose::STANDARD.encode(tag_bytes)),
             chunked: None,
+            recipient_changes: Vec::new(),
         })
     }
 
@@ -96,66 +140,58 @@ impl VideoEncryptor {
         output_path: P,
         password: &str,
     ) -> NotariResult<EncryptionInfo> {
-        use base64::{engine::general_purpose, Engine as _};
-
-        // Generate random salt
-        let mut salt = [0u8; SALT_SIZE];
-        OsRng.fill_bytes(&mut salt);
-
-        // Derive key from password using PBKDF2
-        let mut key_bytes = [0u8; KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            &salt,
-            PBKDF2_ITERATIONS,
-            &mut key_bytes,
-        );
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
+        // Derive key from password using Argon2id
+        let key_derivation = new_key_derivation();
+        let key_bytes = derive_password_key(password, &key_derivation)?;
+        let key = Key::<Aes256Gcm>::from_slice(&key_bytes[..]);
 
         // Create cipher
         let cipher = Aes256Gcm::new(key);
 
-        // Open input file
+        // Encrypt the file chunk by chunk
         let mut input_file = File::open(&input_path)?;
-        let file_size = input_file.metadata()?.len();
-
-        // Open output file
         let mut output_file = File::create(&output_path)?;
+        let chunked = Self::encrypt_chunks(&cipher, &mut input_file, &mut output_file)?;
 
-        // Calculate number of chunks
-        let total_chunks = ((file_size + CHUNK_SIZE as u64 - 1) / CHUNK_SIZE as u64) as usize;
-        let mut chunks = Vec::with_capacity(total_chunks);
+        // Return encryption info with chunked metadata
+        Ok(EncryptionInfo {
+            algorithm: STREAM_ALGORITHM.to_string(),
+            key_derivation: Some(key_derivation),
+            encrypted_keys: None,
+            nonce: None,
+            tag: None,
+            chunked: Some(chunked),
+            recipient_changes: Vec::new(),
+        })
+    }
 
-        // Encrypt each chunk
-        let mut plaintext_offset = 0u64;
+    /// Encrypt `input_file` into `output_file` in STREAM chunks
+    ///
+    /// Every chunk gets a random nonce and is bound to its position by
+    /// `stream_aad`. An empty file still gets one (empty) final chunk, so a file
+    /// truncated to nothing doesn't decrypt either.
+    fn encrypt_chunks(
+        cipher: &Aes256Gcm,
+        input_file: &mut File,
+        output_file: &mut File,
+    ) -> NotariResult<ChunkedEncryptionInfo> {
+        let file_size = input_file.metadata()?.len();
+        let total_chunks = (file_size.div_ceil(CHUNK_SIZE as u64) as usize).max(1);
+        let mut chunks = Vec::with_capacity(total_chunks);
         let mut ciphertext_offset = 0u64;
-        let mut chunk_index = 0;
 
-        while plaintext_offset < file_size {
+        for chunk_index in 0..total_chunks {
             // Calculate chunk size (last chunk may be smaller)
-            let remaining = file_size - plaintext_offset;
+            let remaining = file_size - chunk_index as u64 * CHUNK_SIZE as u64;
             let current_chunk_size = std::cmp::min(CHUNK_SIZE as u64, remaining) as usize;
 
             // Read chunk
             let mut plaintext_chunk = vec![0u8; current_chunk_size];
             input_file.read_exact(&mut plaintext_chunk)?;
 
-            // Generate unique nonce for this chunk
-            let mut nonce_bytes = [0u8; NONCE_SIZE];
-            OsRng.fill_bytes(&mut nonce_bytes);
-            let nonce = Nonce::from_slice(&nonce_bytes);
-
-            // Encrypt chunk
-            let ciphertext = cipher
-                .encrypt(nonce, plaintext_chunk.as_ref())
-                .map_err(|e| {
-                    NotariError::EncryptionFailed(format!(
-                        "Encryption failed for chunk {}: {}",
-                        chunk_index, e
-                    ))
-                })?;
-
-            // Write encrypted chunk
+            // Encrypt chunk, bound to its position, and write it
+            let (ciphertext, nonce) =
+                seal_chunk(cipher, chunk_index, total_chunks, &plaintext_chunk)?;
             output_file.write_all(&ciphertext)?;
 
             // Store chunk info with ciphertext offset (for seeking in encrypted file)
@@ -163,30 +199,16 @@ impl VideoEncryptor {
                 index: chunk_index,
                 offset: ciphertext_offset,
                 size: ciphertext.len() as u64,
-                nonce: general_purpose::STANDARD.encode(&nonce_bytes),
+                nonce,
             });
 
-            plaintext_offset += current_chunk_size as u64;
             ciphertext_offset += ciphertext.len() as u64;
-            chunk_index += 1;
         }
 
-        // Return encryption info with chunked metadata
-        Ok(EncryptionInfo {
-            algorithm: "AES-256-GCM-CHUNKED".to_string(),
-            key_derivation: Some(KeyDerivationInfo {
-                algorithm: "PBKDF2-HMAC-SHA256".to_string(),
-                iterations: PBKDF2_ITERATIONS,
-                salt: general_purpose::STANDARD.encode(&salt),
-            }),
-            encrypted_keys: None,
-            nonce: None,
-            tag: None,
-            chunked: Some(ChunkedEncryptionInfo {
-                chunk_size: CHUNK_SIZE as u64,
-                total_chunks,
-                chunks,
-            }),
+        Ok(ChunkedEncryptionInfo {
+            chunk_size: CHUNK_SIZE as u64,
+            total_chunks,
+            chunks,
         })
     }
 
@@ -198,44 +220,27 @@ impl VideoEncryptor {
         encryption_info: &EncryptionInfo,
     ) -> NotariResult<()> {
         // Check if chunked encryption
-        if let Some(chunked_info) = &encryption_info.chunked {
-            return Self::decrypt_file_chunked(
-                input_path,
-                output_path,
-                password,
-                encryption_info,
-                chunked_info,
-            );
+        if encryption_info.chunked.is_some() {
+            let key = Self::derive_content_key(password, encryption_info)?;
+            return Self::decrypt_file_with_key(input_path, output_path, &key, encryption_info);
         }
 
         // Legacy file-level decryption
         use base64::{engine::general_purpose, Engine as _};
 
-        let key_derivation = encryption_info.key_derivation.as_ref().ok_or_else(|| {
-            NotariError::DecryptionFailed(
-                "Missing key derivation info for password-based encryption".to_string(),
-            )
-        })?;
-
-        let salt = general_purpose::STANDARD.decode(&key_derivation.salt)?;
         let nonce_bytes = general_purpose::STANDARD.decode(
             encryption_info.nonce.as_ref().ok_or_else(|| {
                 NotariError::DecryptionFailed("Missing nonce for file-level encryption".to_string())
             })?,
         )?;
+        if nonce_bytes.len() != NONCE_SIZE {
+            return Err(NotariError::DecryptionFailed(
+                "Invalid nonce for file-level encryption".to_string(),
+            ));
+        }
 
         // Derive key from password using same parameters
-        let mut key_bytes = [0u8; KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            &salt,
-            key_derivation.iterations,
-            &mut key_bytes,
-        );
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
-
-        // Create cipher
-        let cipher = Aes256Gcm::new(key);
+        let cipher = Self::derive_content_key(password, encryption_info)?.cipher();
         let nonce = Nonce::from_slice(&nonce_bytes);
 
         // Read encrypted file
@@ -257,14 +262,109 @@ impl VideoEncryptor {
         Ok(())
     }
 
-    /// Decrypt a chunked video file
-    fn decrypt_file_chunked<P: AsRef<Path>>(
+    /// Decrypt a chunked video file with its content key
+    pub fn decrypt_file_with_key<P: AsRef<Path>>(
         input_path: P,
         output_path: P,
-        password: &str,
+        key: &ContentKey,
         encryption_info: &EncryptionInfo,
-        chunked_info: &ChunkedEncryptionInfo,
     ) -> NotariResult<()> {
+        let chunked_info = encryption_info
+            .chunked
+            .as_ref()
+            .ok_or_else(|| NotariError::DecryptionFailed("Not a chunked encryption".to_string()))?;
+        let cipher = key.cipher();
+
+        // Open input and output files
+        let mut input_file = File::open(&input_path)?;
+        let mut output_file = File::create(&output_path)?;
+
+        // Decrypt each chunk
+        check_chunk_table(encryption_info, chunked_info)?;
+        for position in 0..chunked_info.chunks.len() {
+            let plaintext = open_chunk(
+                &cipher,
+                &mut input_file,
+                encryption_info,
+                chunked_info,
+                position,
+            )?;
+            output_file.write_all(&plaintext)?;
+        }
+
+        Ok(())
+    }
+
+    /// Re-encrypt a chunked video under a new random content key
+    ///
+    /// Works chunk by chunk, so no plaintext reaches the disk, and keeps the
+    /// chunk boundaries. The output is always STREAM, also for a legacy input.
+    /// Returns the new key and chunk table; the key slots are the caller's.
+    pub fn reencrypt_file_with_key<P: AsRef<Path>>(
+        input_path: P,
+        output_path: P,
+        key: &ContentKey,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<(ContentKey, ChunkedEncryptionInfo)> {
+        let chunked_info = encryption_info
+            .chunked
+            .as_ref()
+            .ok_or_else(|| NotariError::DecryptionFailed("Not a chunked encryption".to_string()))?;
+        check_chunk_table(encryption_info, chunked_info)?;
+        let cipher = key.cipher();
+        let new_key = ContentKey::generate();
+        let new_cipher = new_key.cipher();
+
+        let mut input_file = File::open(&input_path)?;
+        let mut output_file = File::create(&output_path)?;
+
+        // A legacy empty video has no chunks; STREAM needs one final chunk
+        let total_chunks = chunked_info.chunks.len().max(1);
+        let mut chunks = Vec::with_capacity(total_chunks);
+        let mut ciphertext_offset = 0u64;
+        for chunk_index in 0..total_chunks {
+            let plaintext = if chunk_index < chunked_info.chunks.len() {
+                open_chunk(
+                    &cipher,
+                    &mut input_file,
+                    encryption_info,
+                    chunked_info,
+                    chunk_index,
+                )?
+            } else {
+                Vec::new()
+            };
+            let (ciphertext, nonce) =
+                seal_chunk(&new_cipher, chunk_index, total_chunks, &plaintext)?;
+            output_file.write_all(&ciphertext)?;
+
+            chunks.push(ChunkInfo {
+                index: chunk_index,
+                offset: ciphertext_offset,
+                size: ciphertext.len() as u64,
+                nonce,
+            });
+            ciphertext_offset += ciphertext.len() as u64;
+        }
+
+        Ok((
+            new_key,
+            ChunkedEncryptionInfo {
+                chunk_size: chunked_info.chunk_size,
+                total_chunks,
+                chunks,
+            },
+        ))
+    }
+
+    /// Derive the content key of a password-encrypted video
+    ///
+    /// Deriving the key is the slow part of decryption, so derive it once and
+    /// pass it to the `*_with_key` methods when decrypting many chunks.
+    pub fn derive_content_key(
+        password: &str,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<ContentKey> {
         use base64::{engine::general_purpose, Engine as _};
 
         let key_derivation = encryption_info.key_derivation.as_ref().ok_or_else(|| {
@@ -273,53 +373,117 @@ impl VideoEncryptor {
             )
         })?;
 
-        // Decode salt
-        let salt = general_purpose::STANDARD.decode(&key_derivation.salt)?;
+        // Derive key from password with the recorded algorithm
+        let key_bytes = derive_password_key(password, key_derivation)?;
+        let wrapped_key = match &key_derivation.wrapped_key {
+            Some(wrapped_key) => general_purpose::STANDARD.decode(wrapped_key)?,
+            None => return Ok(ContentKey(key_bytes)),
+        };
 
-        // Derive key from password
-        let mut key_bytes = [0u8; KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            &salt,
-            key_derivation.iterations,
-            &mut key_bytes,
+        // Re-keyed recording: the derived key wraps the content key
+        if wrapped_key.len() < NONCE_SIZE {
+            return Err(NotariError::DecryptionFailed(
+                "Wrapped content key is too short".to_string(),
+            ));
+        }
+        let (nonce_bytes, ciphertext) = wrapped_key.split_at(NONCE_SIZE);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key_bytes[..]));
+        let content_key = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+                .map_err(|_| {
+                    NotariError::DecryptionFailed(
+                        "Failed to unwrap content key: incorrect password".to_string(),
+                    )
+                })?,
         );
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
+        ContentKey::from_slice(&content_key)
+    }
 
-        // Create cipher
-        let cipher = Aes256Gcm::new(key);
+    /// Wrap `key` under `password` with a fresh Argon2id derivation
+    ///
+    /// The returned key derivation replaces the video's, so the password (or a
+    /// new one) opens the video without re-encrypting it. Check `key` with
+    /// [`Self::check_content_key`] first; a wrong key would lock the video out.
+    pub fn wrap_content_key(key: &ContentKey, password: &str) -> NotariResult<KeyDerivationInfo> {
+        use base64::{engine::general_purpose, Engine as _};
 
-        // Open input and output files
-        let mut input_file = File::open(&input_path)?;
-        let mut output_file = File::create(&output_path)?;
+        let mut key_derivation = new_key_derivation();
+        let kek = derive_password_key(password, &key_derivation)?;
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&kek[..]));
 
-        // Decrypt each chunk
-        for chunk_info in &chunked_info.chunks {
-            // Decode nonce for this chunk
-            let nonce_bytes = general_purpose::STANDARD.decode(&chunk_info.nonce)?;
-            let nonce = Nonce::from_slice(&nonce_bytes);
-
-            // Seek to chunk position
-            use std::io::Seek;
-            input_file.seek(std::io::SeekFrom::Start(chunk_info.offset))?;
-
-            // Read encrypted chunk
-            let mut ciphertext = vec![0u8; chunk_info.size as usize];
-            input_file.read_exact(&mut ciphertext)?;
-
-            // Decrypt chunk
-            let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
-                NotariError::DecryptionFailed(format!(
-                    "Decryption failed for chunk {}: incorrect password or corrupted file",
-                    chunk_info.index
-                ))
-            })?;
+        let mut nonce_bytes = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), &key.0[..])
+            .map_err(|e| NotariError::EncryptionFailed(format!("Key wrapping failed: {}", e)))?;
 
-            // Write decrypted chunk
-            output_file.write_all(&plaintext)?;
+        let mut wrapped_key = nonce_bytes.to_vec();
+        wrapped_key.extend_from_slice(&ciphertext);
+        key_derivation.wrapped_key = Some(general_purpose::STANDARD.encode(wrapped_key));
+        Ok(key_derivation)
+    }
+
+    /// Check that `key` opens a video, by decrypting its first chunk
+    ///
+    /// `ciphertext` is the start of the encrypted file: at least the first chunk
+    /// of a chunked video, or the whole of a file-level one.
+    pub fn check_content_key(
+        ciphertext: &[u8],
+        key: &ContentKey,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<()> {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let (nonce, msg, aad) = match &encryption_info.chunked {
+            Some(chunked_info) => {
+                let chunk_info = chunked_info.chunks.first().ok_or_else(|| {
+                    NotariError::DecryptionFailed("Chunk table is empty".to_string())
+                })?;
+                let msg = usize::try_from(chunk_info.offset)
+                    .ok()
+                    .zip(usize::try_from(chunk_info.size).ok())
+                    .and_then(|(start, size)| ciphertext.get(start..start.checked_add(size)?))
+                    .ok_or_else(|| {
+                        NotariError::DecryptionFailed("Encrypted video is truncated".to_string())
+                    })?;
+                let aad = chunk_aad(encryption_info, chunked_info, 0)?;
+                (chunk_info.nonce.as_str(), msg, aad)
+            }
+            None => {
+                let nonce = encryption_info.nonce.as_deref().ok_or_else(|| {
+                    NotariError::DecryptionFailed(
+                        "Missing nonce for file-level encryption".to_string(),
+                    )
+                })?;
+                (nonce, ciphertext, Vec::new())
+            }
+        };
+
+        let nonce_bytes = general_purpose::STANDARD.decode(nonce)?;
+        if nonce_bytes.len() != NONCE_SIZE {
+            return Err(NotariError::DecryptionFailed("Invalid nonce".to_string()));
         }
+        key.cipher()
+            .decrypt(Nonce::from_slice(&nonce_bytes), Payload { msg, aad: &aad })
+            .map(|_| ())
+            .map_err(|_| {
+                NotariError::DecryptionFailed(
+                    "Decryption failed: incorrect password or corrupted file".to_string(),
+                )
+            })
+    }
 
-        Ok(())
+    /// Unwrap the content key of a video encrypted for `private_key`
+    pub fn unwrap_content_key(
+        private_key: &crypto_box::SecretKey,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<ContentKey> {
+        let video_key_bytes = Zeroizing::new(Self::decrypt_video_key_with_private_key(
+            private_key,
+            encryption_info,
+        )?);
+        ContentKey::from_slice(&video_key_bytes)
     }
 
     /// Decrypt a specific chunk by index (for streaming)
@@ -328,6 +492,28 @@ impl VideoEncryptor {
         chunk_index: usize,
         password: &str,
         encryption_info: &EncryptionInfo,
+    ) -> NotariResult<Vec<u8>> {
+        let key = Self::derive_content_key(password, encryption_info)?;
+        Self::decrypt_chunk_with_key(input_path, chunk_index, &key, encryption_info)
+    }
+
+    /// Decrypt a specific chunk by index with private key (for streaming with public key encryption)
+    pub fn decrypt_chunk_by_index_with_private_key<P: AsRef<Path>>(
+        input_path: P,
+        chunk_index: usize,
+        private_key: &crypto_box::SecretKey,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<Vec<u8>> {
+        let key = Self::unwrap_content_key(private_key, encryption_info)?;
+        Self::decrypt_chunk_with_key(input_path, chunk_index, &key, encryption_info)
+    }
+
+    /// Decrypt a specific chunk by index with a content key derived beforehand
+    pub fn decrypt_chunk_with_key<P: AsRef<Path>>(
+        input_path: P,
+        chunk_index: usize,
+        key: &ContentKey,
+        encryption_info: &EncryptionInfo,
     ) -> NotariResult<Vec<u8>> {
         use base64::{engine::general_purpose, Engine as _};
 
@@ -365,27 +551,8 @@ impl VideoEncryptor {
             "encryption",
         );
 
-        let key_derivation = encryption_info.key_derivation.as_ref().ok_or_else(|| {
-            NotariError::DecryptionFailed(
-                "Missing key derivation info for password-based encryption".to_string(),
-            )
-        })?;
-
-        // Decode salt
-        let salt = general_purpose::STANDARD.decode(&key_derivation.salt)?;
-
-        // Derive key from password
-        let mut key_bytes = [0u8; KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            &salt,
-            key_derivation.iterations,
-            &mut key_bytes,
-        );
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
-
         // Create cipher
-        let cipher = Aes256Gcm::new(key);
+        let cipher = key.cipher();
 
         // Decode nonce for this chunk
         let nonce_bytes = general_purpose::STANDARD
@@ -445,14 +612,19 @@ impl VideoEncryptor {
         );
 
         // Decrypt chunk
-        let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|e| {
+        let aad = chunk_aad(encryption_info, chunked_info, chunk_index)?;
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: &aad,
+        };
+        let plaintext = cipher.decrypt(nonce, payload).map_err(|e| {
             LOGGER.log(
                 LogLevel::Error,
                 &format!("Decryption error for chunk {}: {:?}", chunk_index, e),
                 "encryption",
             );
             NotariError::DecryptionFailed(format!(
-                "Decryption failed for chunk {}: incorrect password or corrupted file",
+                "Decryption failed for chunk {}: incorrect key or corrupted file",
                 chunk_index
             ))
         })?;
@@ -470,159 +642,46 @@ impl VideoEncryptor {
         Ok(plaintext)
     }
 
-    /// Decrypt a specific chunk by index with private key (for streaming with public key encryption)
-    pub fn decrypt_chunk_by_index_with_private_key<P: AsRef<Path>>(
-        input_path: P,
-        chunk_index: usize,
+    /// Helper method to decrypt video key with private key
+    fn decrypt_video_key_with_private_key(
         private_key: &crypto_box::SecretKey,
         encryption_info: &EncryptionInfo,
     ) -> NotariResult<Vec<u8>> {
         use base64::{engine::general_purpose, Engine as _};
 
-        let chunked_info = encryption_info
-            .chunked
-            .as_ref()
-            .ok_or_else(|| NotariError::DecryptionFailed("Not a chunked encryption".to_string()))?;
+        // Get encrypted keys
+        let encrypted_keys = encryption_info.encrypted_keys.as_ref().ok_or_else(|| {
+            NotariError::DecryptionFailed("No encrypted keys found in encryption info".to_string())
+        })?;
 
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "Decrypting chunk {} of {} total chunks with private key",
-                chunk_index, chunked_info.total_chunks
-            ),
-            "encryption",
-        );
+        // Find our encrypted key entry
+        let my_public_key = private_key.public_key();
+        let my_public_key_b64 = general_purpose::STANDARD.encode(my_public_key.as_bytes());
 
-        let chunk_info = chunked_info.chunks.get(chunk_index).ok_or_else(|| {
-            NotariError::DecryptionFailed(format!(
-                "Chunk index {} out of bounds (total chunks: {})",
-                chunk_index,
-                chunked_info.chunks.len()
-            ))
-        })?;
+        let encrypted_key_entry = encrypted_keys
+            .iter()
+            .find(|ek| ek.recipient_public_key == my_public_key_b64)
+            .ok_or_else(|| {
+                NotariError::DecryptionFailed(
+                    "No encrypted key found for this recipient".to_string(),
+                )
+            })?;
 
-        // Get video key by decrypting with private key (only need to do this once, but for simplicity we do it per chunk)
-        // In a real implementation, you'd cache the video key
-        let video_key_bytes =
-            Self::decrypt_video_key_with_private_key(private_key, encryption_info)?;
-        let key = Key::<Aes256Gcm>::from_slice(&video_key_bytes);
+        // Decode ephemeral public key
+        let ephemeral_public_key_bytes =
+            general_purpose::STANDARD.decode(&encrypted_key_entry.ephemeral_public_key)?;
+        let ephemeral_public_key = crypto_box::PublicKey::from(
+            <[u8; 32]>::try_from(ephemeral_public_key_bytes.as_slice()).map_err(|_| {
+                NotariError::DecryptionFailed("Invalid ephemeral public key length".to_string())
+            })?,
+        );
 
-        // Create cipher
-        let cipher = Aes256Gcm::new(key);
+        // Create shared secret box
+        let salsa_box = crypto_box::SalsaBox::new(&ephemeral_public_key, private_key);
 
-        // Decode nonce for this chunk
-        let nonce_bytes = general_purpose::STANDARD
-            .decode(&chunk_info.nonce)
-            .map_err(|e| {
-                LOGGER.log(
-                    LogLevel::Error,
-                    &format!("Failed to decode nonce for chunk {}: {}", chunk_index, e),
-                    "encryption",
-                );
-                format!("Failed to decode nonce for chunk {}: {}", chunk_index, e)
-            })?;
-
-        if nonce_bytes.len() != NONCE_SIZE {
-            let err_msg = format!(
-                "Invalid nonce size for chunk {}: expected {}, got {}",
-                chunk_index,
-                NONCE_SIZE,
-                nonce_bytes.len()
-            );
-            LOGGER.log(LogLevel::Error, &err_msg, "encryption");
-            return Err(NotariError::DecryptionFailed(err_msg));
-        }
-
-        let nonce = Nonce::from_slice(&nonce_bytes);
-
-        // Open input file and seek to chunk
-        let mut input_file = File::open(&input_path)?;
-        use std::io::Seek;
-        input_file.seek(std::io::SeekFrom::Start(chunk_info.offset))?;
-
-        // Read encrypted chunk
-        let mut ciphertext = vec![0u8; chunk_info.size as usize];
-        input_file.read_exact(&mut ciphertext).map_err(|e| {
-            LOGGER.log(
-                LogLevel::Error,
-                &format!(
-                    "Failed to read chunk {} at offset {}: {}",
-                    chunk_index, chunk_info.offset, e
-                ),
-                "encryption",
-            );
-            NotariError::DecryptionFailed(format!(
-                "Failed to read chunk {} at offset {}: {}",
-                chunk_index, chunk_info.offset, e
-            ))
-        })?;
-
-        // Decrypt chunk
-        let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|e| {
-            LOGGER.log(
-                LogLevel::Error,
-                &format!("Decryption error for chunk {}: {:?}", chunk_index, e),
-                "encryption",
-            );
-            NotariError::DecryptionFailed(format!(
-                "Decryption failed for chunk {}: incorrect key or corrupted file",
-                chunk_index
-            ))
-        })?;
-
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "Successfully decrypted chunk {} with private key - plaintext size: {}",
-                chunk_index,
-                plaintext.len()
-            ),
-            "encryption",
-        );
-
-        Ok(plaintext)
-    }
-
-    /// Helper method to decrypt video key with private key
-    fn decrypt_video_key_with_private_key(
-        private_key: &crypto_box::SecretKey,
-        encryption_info: &EncryptionInfo,
-    ) -> NotariResult<Vec<u8>> {
-        use base64::{engine::general_purpose, Engine as _};
-
-        // Get encrypted keys
-        let encrypted_keys = encryption_info.encrypted_keys.as_ref().ok_or_else(|| {
-            NotariError::DecryptionFailed("No encrypted keys found in encryption info".to_string())
-        })?;
-
-        // Find our encrypted key entry
-        let my_public_key = private_key.public_key();
-        let my_public_key_b64 = general_purpose::STANDARD.encode(my_public_key.as_bytes());
-
-        let encrypted_key_entry = encrypted_keys
-            .iter()
-            .find(|ek| ek.recipient_public_key == my_public_key_b64)
-            .ok_or_else(|| {
-                NotariError::DecryptionFailed(
-                    "No encrypted key found for this recipient".to_string(),
-                )
-            })?;
-
-        // Decode ephemeral public key
-        let ephemeral_public_key_bytes =
-            general_purpose::STANDARD.decode(&encrypted_key_entry.ephemeral_public_key)?;
-        let ephemeral_public_key = crypto_box::PublicKey::from(
-            <[u8; 32]>::try_from(ephemeral_public_key_bytes.as_slice()).map_err(|_| {
-                NotariError::DecryptionFailed("Invalid ephemeral public key length".to_string())
-            })?,
-        );
-
-        // Create shared secret box
-        let salsa_box = crypto_box::SalsaBox::new(&ephemeral_public_key, private_key);
-
-        // Decode encrypted video key (format: nonce || ciphertext)
-        let encrypted_video_key_data =
-            general_purpose::STANDARD.decode(&encrypted_key_entry.encrypted_video_key)?;
+        // Decode encrypted video key (format: nonce || ciphertext)
+        let encrypted_video_key_data =
+            general_purpose::STANDARD.decode(&encrypted_key_entry.encrypted_video_key)?;
 
         if encrypted_video_key_data.len() < 24 {
             return Err(NotariError::DecryptionFailed(
@@ -650,84 +709,8 @@ impl VideoEncryptor {
         password: &str,
         encryption_info: &EncryptionInfo,
     ) -> NotariResult<Vec<u8>> {
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "decrypt_byte_range: requested bytes {}-{} (total {} bytes)",
-                start,
-                end,
-                end - start + 1
-            ),
-            "encryption",
-        );
-
-        let chunked_info = encryption_info
-            .chunked
-            .as_ref()
-            .ok_or_else(|| NotariError::DecryptionFailed("Not a chunked encryption".to_string()))?;
-
-        // Calculate which chunks we need
-        let chunk_size = chunked_info.chunk_size;
-        let start_chunk = (start / chunk_size) as usize;
-        let end_chunk = (end / chunk_size) as usize;
-
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "decrypt_byte_range: need chunks {}-{} (chunk_size={})",
-                start_chunk, end_chunk, chunk_size
-            ),
-            "encryption",
-        );
-
-        let mut result = Vec::new();
-
-        // Decrypt needed chunks
-        for chunk_idx in start_chunk..=end_chunk {
-            if chunk_idx >= chunked_info.total_chunks {
-                break;
-            }
-
-            let chunk_data =
-                Self::decrypt_chunk_by_index(&input_path, chunk_idx, password, encryption_info)?;
-
-            // Calculate which bytes from this chunk we need
-            let chunk_start_offset = chunk_idx as u64 * chunk_size;
-            let chunk_end_offset = chunk_start_offset + chunk_data.len() as u64 - 1;
-
-            let copy_start = if start > chunk_start_offset {
-                (start - chunk_start_offset) as usize
-            } else {
-                0
-            };
-
-            let copy_end = if end < chunk_end_offset {
-                (end - chunk_start_offset + 1) as usize
-            } else {
-                chunk_data.len()
-            };
-
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("decrypt_byte_range: chunk {} - chunk_offset={}-{}, copy_range={}..{}, copying {} bytes",
-                    chunk_idx, chunk_start_offset, chunk_end_offset, copy_start, copy_end, copy_end - copy_start),
-                "encryption",
-            );
-
-            result.extend_from_slice(&chunk_data[copy_start..copy_end]);
-        }
-
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "decrypt_byte_range: returning {} bytes (requested {})",
-                result.len(),
-                end - start + 1
-            ),
-            "encryption",
-        );
-
-        Ok(result)
+        let key = Self::derive_content_key(password, encryption_info)?;
+        Self::decrypt_byte_range_with_key(input_path, start, end, &key, encryption_info)
     }
 
     /// Decrypt a byte range with private key (for HTTP range requests with public key encryption)
@@ -738,10 +721,40 @@ impl VideoEncryptor {
         private_key: &crypto_box::SecretKey,
         encryption_info: &EncryptionInfo,
     ) -> NotariResult<Vec<u8>> {
+        let key = Self::unwrap_content_key(private_key, encryption_info)?;
+        Self::decrypt_byte_range_with_key(input_path, start, end, &key, encryption_info)
+    }
+
+    /// Decrypt a byte range with a content key derived beforehand
+    pub fn decrypt_byte_range_with_key<P: AsRef<Path>>(
+        input_path: P,
+        start: u64,
+        end: u64,
+        key: &ContentKey,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<Vec<u8>> {
+        Self::assemble_byte_range(start, end, encryption_info, |chunk_index| {
+            Self::decrypt_chunk_with_key(&input_path, chunk_index, key, encryption_info)
+                .map(|chunk| Arc::new(Zeroizing::new(chunk)))
+        })
+    }
+
+    /// Assemble a plaintext byte range from the decrypted chunks covering it
+    ///
+    /// `chunk` returns decrypted chunks by index, e.g. from a cache.
+    pub fn assemble_byte_range<F>(
+        start: u64,
+        end: u64,
+        encryption_info: &EncryptionInfo,
+        mut chunk: F,
+    ) -> NotariResult<Vec<u8>>
+    where
+        F: FnMut(usize) -> NotariResult<Arc<Zeroizing<Vec<u8>>>>,
+    {
         LOGGER.log(
             LogLevel::Info,
             &format!(
-                "decrypt_byte_range_with_private_key: requested bytes {}-{} (total {} bytes)",
+                "decrypt_byte_range: requested bytes {}-{} (total {} bytes)",
                 start,
                 end,
                 end - start + 1
@@ -762,7 +775,7 @@ impl VideoEncryptor {
         LOGGER.log(
             LogLevel::Info,
             &format!(
-                "decrypt_byte_range_with_private_key: need chunks {}-{} (chunk_size={})",
+                "decrypt_byte_range: need chunks {}-{} (chunk_size={})",
                 start_chunk, end_chunk, chunk_size
             ),
             "encryption",
@@ -776,12 +789,12 @@ impl VideoEncryptor {
                 break;
             }
 
-            let chunk_data = Self::decrypt_chunk_by_index_with_private_key(
-                &input_path,
-                chunk_idx,
-                private_key,
-                encryption_info,
-            )?;
+            let chunk_data = chunk(chunk_idx)?;
+
+            // The final chunk of an empty file is empty
+            if chunk_data.is_empty() {
+                break;
+            }
 
             // Calculate which bytes from this chunk we need
             let chunk_start_offset = chunk_idx as u64 * chunk_size;
@@ -801,7 +814,7 @@ impl VideoEncryptor {
 
             LOGGER.log(
                 LogLevel::Info,
-                &format!("decrypt_byte_range_with_private_key: chunk {} - chunk_offset={}-{}, copy_range={}..{}, copying {} bytes",
+                &format!("decrypt_byte_range: chunk {} - chunk_offset={}-{}, copy_range={}..{}, copying {} bytes",
                     chunk_idx, chunk_start_offset, chunk_end_offset, copy_start, copy_end, copy_end - copy_start),
                 "encryption",
             );
@@ -812,7 +825,7 @@ impl VideoEncryptor {
         LOGGER.log(
             LogLevel::Info,
             &format!(
-                "decrypt_byte_range_with_private_key: returning {} bytes (requested {})",
+                "decrypt_byte_range: returning {} bytes (requested {})",
                 result.len(),
                 end - start + 1
             ),
@@ -838,9 +851,6 @@ impl VideoEncryptor {
         output_path: P,
         recipient_public_keys: Vec<(String, crypto_box::PublicKey)>, // (recipient_id, public_key)
     ) -> NotariResult<EncryptionInfo> {
-        use crate::evidence::EncryptedKey;
-        use base64::{engine::general_purpose, Engine as _};
-
         // Validate recipients
         if recipient_public_keys.is_empty() {
             return Err(NotariError::EncryptionFailed(
@@ -848,139 +858,319 @@ impl VideoEncryptor {
             ));
         }
 
-        // 1. Generate random video encryption key (256-bit)
-        let mut video_key_bytes = [0u8; KEY_SIZE];
-        OsRng.fill_bytes(&mut video_key_bytes);
-        let video_key = Key::<Aes256Gcm>::from_slice(&video_key_bytes);
+        Self::encrypt_file_with_key_slots(input_path, output_path, recipient_public_keys, None)
+    }
+
+    /// Encrypt a video file under a random content key with a key slot per
+    /// recipient and, given a password, a password slot
+    ///
+    /// Any one credential opens the video: a recipient's private key unwraps its
+    /// `encrypted_keys` entry, the password unwraps `key_derivation.wrapped_key`.
+    pub fn encrypt_file_with_key_slots<P: AsRef<Path>>(
+        input_path: P,
+        output_path: P,
+        recipient_public_keys: Vec<(String, crypto_box::PublicKey)>, // (recipient_id, public_key)
+        password: Option<&str>,
+    ) -> NotariResult<EncryptionInfo> {
+        if recipient_public_keys.is_empty() && password.is_none() {
+            return Err(NotariError::EncryptionFailed(
+                "At least one recipient or a password is required".to_string(),
+            ));
+        }
 
         LOGGER.log(
             LogLevel::Info,
             &format!(
-                "Encrypting video with public key encryption for {} recipients",
-                recipient_public_keys.len()
+                "Encrypting video for {} recipients (password slot: {})",
+                recipient_public_keys.len(),
+                password.is_some()
             ),
             "encryption",
         );
 
+        // 1. Generate random video encryption key (256-bit)
+        let content_key = ContentKey::generate();
+
         // 2. Encrypt video with AES-256-GCM using video key (chunked)
-        let cipher = Aes256Gcm::new(video_key);
         let mut input_file = File::open(&input_path)?;
         let mut output_file = File::create(&output_path)?;
+        let chunked =
+            Self::encrypt_chunks(&content_key.cipher(), &mut input_file, &mut output_file)?;
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Video encrypted into {} chunks", chunked.total_chunks),
+            "encryption",
+        );
 
-        let file_size = input_file.metadata()?.len();
-        let mut chunks = Vec::new();
-        let mut plaintext_offset = 0u64;
-        let mut ciphertext_offset = 0u64;
-        let mut chunk_index = 0;
-
-        while plaintext_offset < file_size {
-            // Calculate chunk size (last chunk may be smaller)
-            let remaining = file_size - plaintext_offset;
-            let current_chunk_size = std::cmp::min(CHUNK_SIZE as u64, remaining) as usize;
+        // 3. Wrap video key for each recipient, and for the password
+        let encrypted_keys = recipient_public_keys
+            .iter()
+            .map(|(recipient_id, public_key)| {
+                Self::wrap_content_key_for_recipient(&content_key, recipient_id, public_key)
+            })
+            .collect::<NotariResult<Vec<_>>>()?;
+        let key_derivation = password
+            .map(|password| Self::wrap_content_key(&content_key, password))
+            .transpose()?;
 
-            // Read chunk
-            let mut plaintext_chunk = vec![0u8; current_chunk_size];
-            input_file.read_exact(&mut plaintext_chunk)?;
+        // 4. Return encryption info
+        let algorithm = if encrypted_keys.is_empty() {
+            STREAM_ALGORITHM.to_string()
+        } else {
+            format!("{}-PUBKEY", STREAM_ALGORITHM)
+        };
+        Ok(EncryptionInfo {
+            algorithm,
+            key_derivation,
+            encrypted_keys: Some(encrypted_keys).filter(|keys| !keys.is_empty()),
+            nonce: None,
+            tag: None,
+            chunked: Some(chunked),
+            recipient_changes: Vec::new(),
+        })
+    }
 
-            // Generate unique nonce for this chunk
-            let mut nonce_bytes = [0u8; NONCE_SIZE];
-            OsRng.fill_bytes(&mut nonce_bytes);
-            let nonce = Nonce::from_slice(&nonce_bytes);
+    /// Wrap `key` for a recipient's X25519 public key
+    ///
+    /// Uses a fresh ephemeral key pair, so only the recipient's private key
+    /// unwraps it.
+    pub fn wrap_content_key_for_recipient(
+        key: &ContentKey,
+        recipient_id: &str,
+        recipient_public_key: &crypto_box::PublicKey,
+    ) -> NotariResult<EncryptedKey> {
+        use base64::{engine::general_purpose, Engine as _};
 
-            // Encrypt chunk
-            let ciphertext = cipher
-                .encrypt(nonce, plaintext_chunk.as_ref())
-                .map_err(|e| {
-                    NotariError::EncryptionFailed(format!(
-                        "Encryption failed for chunk {}: {}",
-                        chunk_index, e
-                    ))
-                })?;
+        // Generate ephemeral keypair for this encryption
+        let ephemeral_secret = crypto_box::SecretKey::generate(&mut OsRng);
+        let ephemeral_public = ephemeral_secret.public_key();
 
-            // Write encrypted chunk
-            output_file.write_all(&ciphertext)?;
+        // Create crypto_box for encryption
+        let salsa_box = crypto_box::SalsaBox::new(recipient_public_key, &ephemeral_secret);
 
-            // Store chunk info
-            chunks.push(ChunkInfo {
-                index: chunk_index,
-                offset: ciphertext_offset,
-                size: ciphertext.len() as u64,
-                nonce: general_purpose::STANDARD.encode(&nonce_bytes),
-            });
+        // Generate nonce for key encryption
+        let mut key_nonce_bytes = [0u8; 24]; // XSalsa20 uses 24-byte nonce
+        OsRng.fill_bytes(&mut key_nonce_bytes);
+        let key_nonce = crypto_box::Nonce::from(key_nonce_bytes);
 
-            plaintext_offset += current_chunk_size as u64;
-            ciphertext_offset += ciphertext.len() as u64;
-            chunk_index += 1;
-        }
+        // Encrypt video key
+        let ciphertext = salsa_box.encrypt(&key_nonce, &key.0[..]).map_err(|e| {
+            NotariError::EncryptionFailed(format!(
+                "Failed to encrypt video key for recipient {}: {}",
+                recipient_id, e
+            ))
+        })?;
 
-        let total_chunks = chunks.len();
+        // Prepend nonce to ciphertext (nonce || ciphertext)
+        let mut encrypted_video_key = Vec::with_capacity(24 + ciphertext.len());
+        encrypted_video_key.extend_from_slice(&key_nonce_bytes);
+        encrypted_video_key.extend_from_slice(&ciphertext);
 
         LOGGER.log(
             LogLevel::Info,
-            &format!("Video encrypted into {} chunks", total_chunks),
+            &format!("Encrypted video key for recipient: {}", recipient_id),
             "encryption",
         );
 
-        // 3. Encrypt video key for each recipient using their public key
-        let mut encrypted_keys = Vec::new();
+        Ok(EncryptedKey {
+            recipient_id: recipient_id.to_string(),
+            recipient_public_key: general_purpose::STANDARD.encode(recipient_public_key.as_bytes()),
+            ephemeral_public_key: general_purpose::STANDARD.encode(ephemeral_public.as_bytes()),
+            encrypted_video_key: general_purpose::STANDARD.encode(&encrypted_video_key),
+            algorithm: "X25519-XSalsa20-Poly1305".to_string(),
+        })
+    }
+
+    /// Recover the content key with whichever credential is supplied
+    ///
+    /// The private key is tried first if the video has recipient key slots, then
+    /// the password if it has a password slot. A password slot without a wrapped
+    /// key can't tell a wrong password apart; that shows when a chunk is decrypted.
+    pub fn open_content_key(
+        password: Option<&str>,
+        private_key: Option<&crypto_box::SecretKey>,
+        encryption_info: &EncryptionInfo,
+    ) -> NotariResult<ContentKey> {
+        let mut error = None;
+
+        if let Some(private_key) = private_key {
+            if encryption_info.encrypted_keys.is_some() {
+                match Self::unwrap_content_key(private_key, encryption_info) {
+                    Ok(key) => return Ok(key),
+                    Err(e) => error = Some(e),
+                }
+            }
+        }
+        if let Some(password) = password {
+            if encryption_info.key_derivation.is_some() {
+                match Self::derive_content_key(password, encryption_info) {
+                    Ok(key) => return Ok(key),
+                    Err(e) => error = Some(e),
+                }
+            }
+        }
 
-        for (recipient_id, recipient_public_key) in recipient_public_keys {
-            // Generate ephemeral keypair for this encryption
-            let ephemeral_secret = crypto_box::SecretKey::generate(&mut OsRng);
-            let ephemeral_public = ephemeral_secret.public_key();
+        Err(error.unwrap_or_else(|| {
+            NotariError::DecryptionFailed(
+                "No key slot of this video matches the supplied credentials".to_string(),
+            )
+        }))
+    }
 
-            // Create crypto_box for encryption
-            let salsa_box = crypto_box::SalsaBox::new(&recipient_public_key, &ephemeral_secret);
+    /// Give a recipient a key slot on an already encrypted video
+    ///
+    /// Only the encryption info changes: `key` is wrapped for the recipient and
+    /// the addition recorded in `recipient_changes`. Check `key` against the
+    /// video first; a wrong key would give the recipient a slot that opens nothing.
+    pub fn add_recipient(
+        encryption_info: &mut EncryptionInfo,
+        key: &ContentKey,
+        recipient_id: &str,
+        recipient_public_key: &crypto_box::PublicKey,
+    ) -> NotariResult<()> {
+        use base64::{engine::general_purpose, Engine as _};
 
-            // Generate nonce for key encryption
-            let mut key_nonce_bytes = [0u8; 24]; // XSalsa20 uses 24-byte nonce
-            OsRng.fill_bytes(&mut key_nonce_bytes);
-            let key_nonce = crypto_box::Nonce::from(key_nonce_bytes);
+        let encoded_key = general_purpose::STANDARD.encode(recipient_public_key.as_bytes());
+        if let Some(existing) = encryption_info
+            .encrypted_keys
+            .iter()
+            .flatten()
+            .find(|slot| slot.recipient_public_key == encoded_key)
+        {
+            return Err(NotariError::EncryptionFailed(format!(
+                "This public key already has a key slot (recipient {})",
+                existing.recipient_id
+            )));
+        }
 
-            // Encrypt video key
-            let ciphertext = salsa_box
-                .encrypt(&key_nonce, &video_key_bytes[..])
-                .map_err(|e| {
-                    NotariError::EncryptionFailed(format!(
-                        "Failed to encrypt video key for recipient {}: {}",
-                        recipient_id, e
-                    ))
-                })?;
+        let slot = Self::wrap_content_key_for_recipient(key, recipient_id, recipient_public_key)?;
+        encryption_info
+            .encrypted_keys
+            .get_or_insert_with(Vec::new)
+            .push(slot);
+        encryption_info.recipient_changes.push(RecipientChange {
+            changed_at: Utc::now(),
+            action: RecipientAction::Added,
+            recipient_id: recipient_id.to_string(),
+            recipient_public_key: encoded_key,
+            key_rotated: false,
+        });
+        set_stream_algorithm(encryption_info);
+        Ok(())
+    }
 
-            // Prepend nonce to ciphertext (nonce || ciphertext)
-            let mut encrypted_video_key = Vec::with_capacity(24 + ciphertext.len());
-            encrypted_video_key.extend_from_slice(&key_nonce_bytes);
-            encrypted_video_key.extend_from_slice(&ciphertext);
-
-            encrypted_keys.push(EncryptedKey {
-                recipient_id: recipient_id.clone(),
-                recipient_public_key: general_purpose::STANDARD
-                    .encode(recipient_public_key.as_bytes()),
-                ephemeral_public_key: general_purpose::STANDARD.encode(ephemeral_public.as_bytes()),
-                encrypted_video_key: general_purpose::STANDARD.encode(&encrypted_video_key),
-                algorithm: "X25519-XSalsa20-Poly1305".to_string(),
-            });
+    /// Remove the key slot of the recipient with `recipient_public_key`
+    ///
+    /// The last key slot of a video can't be removed. The removal is recorded in
+    /// `recipient_changes`, with `key_rotated` saying whether the caller follows
+    /// up with [`Self::rotate_content_key`]; without a rotation the recipient can
+    /// still open the video with a copy of the content key.
+    pub fn remove_recipient(
+        encryption_info: &mut EncryptionInfo,
+        recipient_public_key: &str,
+        key_rotated: bool,
+    ) -> NotariResult<EncryptedKey> {
+        let recipient_public_key = recipient_public_key.trim();
+        let encrypted_keys = encryption_info.encrypted_keys.as_mut().ok_or_else(|| {
+            NotariError::EncryptionFailed("Video has no recipient key slots".to_string())
+        })?;
+        let position = encrypted_keys
+            .iter()
+            .position(|slot| slot.recipient_public_key == recipient_public_key)
+            .ok_or_else(|| {
+                NotariError::EncryptionFailed("No key slot for this public key".to_string())
+            })?;
+        if encrypted_keys.len() == 1 && encryption_info.key_derivation.is_none() {
+            return Err(NotariError::EncryptionFailed(
+                "Can't remove the video's last key slot".to_string(),
+            ));
+        }
 
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("Encrypted video key for recipient: {}", recipient_id),
-                "encryption",
-            );
+        let slot = encrypted_keys.remove(position);
+        if encrypted_keys.is_empty() {
+            encryption_info.encrypted_keys = None;
         }
+        encryption_info.recipient_changes.push(RecipientChange {
+            changed_at: Utc::now(),
+            action: RecipientAction::Removed,
+            recipient_id: slot.recipient_id.clone(),
+            recipient_public_key: slot.recipient_public_key.clone(),
+            key_rotated,
+        });
+        set_stream_algorithm(encryption_info);
+        Ok(slot)
+    }
 
-        // 4. Return encryption info
-        Ok(EncryptionInfo {
-            algorithm: "AES-256-GCM-CHUNKED-PUBKEY".to_string(),
-            key_derivation: None,
-            encrypted_keys: Some(encrypted_keys),
-            nonce: None,
-            tag: None,
-            chunked: Some(ChunkedEncryptionInfo {
-                chunk_size: CHUNK_SIZE as u64,
-                total_chunks,
-                chunks,
-            }),
-        })
+    /// Re-encrypt a chunked video under a new content key and rewrap its key slots
+    ///
+    /// After removing a recipient, this stops a content key they kept from
+    /// opening the video. Every remaining recipient keeps a slot. A password slot
+    /// is rewrapped under `password`, which has to open the current one.
+    pub fn rotate_content_key<P: AsRef<Path>>(
+        input_path: P,
+        output_path: P,
+        key: &ContentKey,
+        encryption_info: &mut EncryptionInfo,
+        password: Option<&str>,
+    ) -> NotariResult<()> {
+        use crate::evidence::encryption_keys::EncryptionKeyManager;
+
+        // Check the credentials before the slow part
+        let password = match (&encryption_info.key_derivation, password) {
+            (None, _) => None,
+            (Some(_), None) => {
+                return Err(NotariError::InvalidPassword(
+                    "The password is needed to rewrap the video's password slot".to_string(),
+                ))
+            }
+            (Some(_), Some(password)) => {
+                use subtle::ConstantTimeEq;
+
+                let password_key = Self::derive_content_key(password, encryption_info)?;
+                if !bool::from(password_key.0[..].ct_eq(&key.0[..])) {
+                    return Err(NotariError::InvalidPassword(
+                        "Password doesn't open this video".to_string(),
+                    ));
+                }
+                Some(password)
+            }
+        };
+        let recipients = encryption_info
+            .encrypted_keys
+            .iter()
+            .flatten()
+            .map(|slot| {
+                let public_key =
+                    EncryptionKeyManager::import_public_key(&slot.recipient_public_key)?;
+                Ok((slot.recipient_id.clone(), public_key))
+            })
+            .collect::<NotariResult<Vec<_>>>()?;
+
+        let (new_key, chunked) =
+            Self::reencrypt_file_with_key(input_path, output_path, key, encryption_info)?;
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Video re-encrypted into {} chunks under a new key",
+                chunked.total_chunks
+            ),
+            "encryption",
+        );
+
+        let encrypted_keys = recipients
+            .iter()
+            .map(|(recipient_id, public_key)| {
+                Self::wrap_content_key_for_recipient(&new_key, recipient_id, public_key)
+            })
+            .collect::<NotariResult<Vec<_>>>()?;
+        if let Some(password) = password {
+            encryption_info.key_derivation = Some(Self::wrap_content_key(&new_key, password)?);
+        }
+        encryption_info.encrypted_keys = Some(encrypted_keys).filter(|keys| !keys.is_empty());
+        encryption_info.chunked = Some(chunked);
+        encryption_info.algorithm = STREAM_ALGORITHM.to_string();
+        set_stream_algorithm(encryption_info);
+        Ok(())
     }
 
     /// Decrypt a video file with public key encryption
@@ -995,132 +1185,269 @@ impl VideoEncryptor {
         private_key: &crypto_box::SecretKey,
         encryption_info: &EncryptionInfo,
     ) -> NotariResult<()> {
-        use base64::{engine::general_purpose, Engine as _};
-
         LOGGER.log(
             LogLevel::Info,
             "Decrypting video with private key",
             "encryption",
         );
 
-        // 1. Get encrypted keys
-        let encrypted_keys = encryption_info.encrypted_keys.as_ref().ok_or_else(|| {
-            NotariError::DecryptionFailed("No encrypted keys found in encryption info".to_string())
-        })?;
-
-        // 2. Find encrypted key for this recipient (try all keys)
-        let my_public_key = private_key.public_key();
-        let my_public_key_b64 = general_purpose::STANDARD.encode(my_public_key.as_bytes());
-
-        let encrypted_key_entry = encrypted_keys
-            .iter()
-            .find(|ek| ek.recipient_public_key == my_public_key_b64)
-            .ok_or_else(|| {
-                NotariError::DecryptionFailed(
-                    "No encrypted key found for this recipient".to_string(),
-                )
-            })?;
-
+        let key = Self::unwrap_content_key(private_key, encryption_info)?;
         LOGGER.log(
             LogLevel::Info,
-            &format!(
-                "Found encrypted key for recipient: {}",
-                encrypted_key_entry.recipient_id
-            ),
+            "Video key decrypted successfully",
             "encryption",
         );
 
-        // 3. Decrypt video key
-        let encrypted_video_key_bytes =
-            general_purpose::STANDARD.decode(&encrypted_key_entry.encrypted_video_key)?;
+        Self::decrypt_file_with_key(input_path, output_path, &key, encryption_info)?;
+        LOGGER.log(LogLevel::Info, "Video decrypted successfully", "encryption");
 
-        // Extract nonce (first 24 bytes) and ciphertext
-        if encrypted_video_key_bytes.len() < 24 {
-            return Err(NotariError::DecryptionFailed(
-                "Invalid encrypted video key: too short".to_string(),
-            ));
-        }
+        Ok(())
+    }
+}
 
-        let key_nonce_bytes: [u8; 24] = encrypted_video_key_bytes[..24]
-            .try_into()
-            .map_err(|_| NotariError::DecryptionFailed("Invalid nonce".to_string()))?;
-        let key_nonce = crypto_box::Nonce::from(key_nonce_bytes);
-        let key_ciphertext = &encrypted_video_key_bytes[24..];
+/// Associated data binding a STREAM chunk to its position
+///
+/// Covers the chunk's index, the chunk count and whether it is the final chunk,
+/// so a chunk moved to another position, or a file with chunks dropped from the
+/// end, fails the GCM tag check.
+fn stream_aad(chunk_index: usize, total_chunks: usize) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(STREAM_AAD_CONTEXT.len() + 17);
+    aad.extend_from_slice(STREAM_AAD_CONTEXT);
+    aad.extend_from_slice(&(chunk_index as u64).to_be_bytes());
+    aad.extend_from_slice(&(total_chunks as u64).to_be_bytes());
+    aad.push(u8::from(chunk_index + 1 == total_chunks));
+    aad
+}
 
-        // Decrypt using recipient's private key and ephemeral public key
-        let ephemeral_public_key_bytes =
-            general_purpose::STANDARD.decode(&encrypted_key_entry.ephemeral_public_key)?;
-        let mut ephemeral_pk_array = [0u8; 32];
-        ephemeral_pk_array.copy_from_slice(&ephemeral_public_key_bytes);
-        let ephemeral_public_key = crypto_box::PublicKey::from(ephemeral_pk_array);
+fn is_stream(encryption_info: &EncryptionInfo) -> bool {
+    encryption_info.algorithm.starts_with(STREAM_ALGORITHM)
+}
 
-        let salsa_box = crypto_box::SalsaBox::new(&ephemeral_public_key, private_key);
-        let video_key_bytes = salsa_box.decrypt(&key_nonce, key_ciphertext).map_err(|e| {
-            NotariError::DecryptionFailed(format!(
-                "Failed to decrypt video key: {}. Wrong private key?",
-                e
+/// Name a STREAM video's algorithm after its key slots, as
+/// `encrypt_file_with_key_slots` does
+fn set_stream_algorithm(encryption_info: &mut EncryptionInfo) {
+    if !is_stream(encryption_info) {
+        return;
+    }
+    encryption_info.algorithm = match encryption_info.encrypted_keys {
+        Some(_) => format!("{}-PUBKEY", STREAM_ALGORITHM),
+        None => STREAM_ALGORITHM.to_string(),
+    };
+}
+
+/// Check that a STREAM chunk table lists every chunk, and has at least one
+///
+/// Legacy chunk tables aren't checked; their chunks aren't bound to anything.
+fn check_chunk_table(
+    encryption_info: &EncryptionInfo,
+    chunked_info: &ChunkedEncryptionInfo,
+) -> NotariResult<()> {
+    if !is_stream(encryption_info) {
+        return Ok(());
+    }
+    if chunked_info.total_chunks == 0 || chunked_info.chunks.len() != chunked_info.total_chunks {
+        return Err(NotariError::DecryptionFailed(format!(
+            "Chunk table lists {} of {} chunks",
+            chunked_info.chunks.len(),
+            chunked_info.total_chunks
+        )));
+    }
+    Ok(())
+}
+
+/// Associated data chunk `chunk_index` was encrypted with
+///
+/// Legacy `AES-256-GCM-CHUNKED` chunks have none. STREAM chunks have to be
+/// listed at their own index.
+fn chunk_aad(
+    encryption_info: &EncryptionInfo,
+    chunked_info: &ChunkedEncryptionInfo,
+    chunk_index: usize,
+) -> NotariResult<Vec<u8>> {
+    if !is_stream(encryption_info) {
+        return Ok(Vec::new());
+    }
+
+    check_chunk_table(encryption_info, chunked_info)?;
+    match chunked_info.chunks.get(chunk_index) {
+        Some(chunk_info) if chunk_info.index == chunk_index => {
+            Ok(stream_aad(chunk_index, chunked_info.total_chunks))
+        }
+        Some(chunk_info) => Err(NotariError::DecryptionFailed(format!(
+            "Chunk {} is out of place at position {}",
+            chunk_info.index, chunk_index
+        ))),
+        None => Err(NotariError::DecryptionFailed(format!(
+            "Chunk index {} out of bounds (total chunks: {})",
+            chunk_index, chunked_info.total_chunks
+        ))),
+    }
+}
+
+/// Encrypt STREAM chunk `chunk_index` of `total_chunks` under a random nonce
+///
+/// Returns the ciphertext and the base64 nonce for its `ChunkInfo`.
+fn seal_chunk(
+    cipher: &Aes256Gcm,
+    chunk_index: usize,
+    total_chunks: usize,
+    plaintext: &[u8],
+) -> NotariResult<(Vec<u8>, String)> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    // Generate unique nonce for this chunk
+    let mut nonce_bytes = [0u8; NONCE_SIZE];
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let aad = stream_aad(chunk_index, total_chunks);
+    let payload = Payload {
+        msg: plaintext,
+        aad: &aad,
+    };
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), payload)
+        .map_err(|e| {
+            NotariError::EncryptionFailed(format!(
+                "Encryption failed for chunk {}: {}",
+                chunk_index, e
             ))
         })?;
 
-        if video_key_bytes.len() != KEY_SIZE {
-            return Err(NotariError::DecryptionFailed(
-                "Invalid video key size after decryption".to_string(),
-            ));
-        }
+    Ok((ciphertext, general_purpose::STANDARD.encode(nonce_bytes)))
+}
 
-        LOGGER.log(
-            LogLevel::Info,
-            "Video key decrypted successfully",
-            "encryption",
-        );
+/// Read and decrypt the chunk listed at `position` of a chunked video
+fn open_chunk(
+    cipher: &Aes256Gcm,
+    input_file: &mut File,
+    encryption_info: &EncryptionInfo,
+    chunked_info: &ChunkedEncryptionInfo,
+    position: usize,
+) -> NotariResult<Vec<u8>> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let chunk_info = &chunked_info.chunks[position];
+
+    // Decode nonce for this chunk
+    let nonce_bytes = general_purpose::STANDARD.decode(&chunk_info.nonce)?;
+    if nonce_bytes.len() != NONCE_SIZE {
+        return Err(NotariError::DecryptionFailed(format!(
+            "Invalid nonce size for chunk {}: expected {}, got {}",
+            chunk_info.index,
+            NONCE_SIZE,
+            nonce_bytes.len()
+        )));
+    }
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let aad = chunk_aad(encryption_info, chunked_info, position)?;
+
+    // Seek to chunk position and read encrypted chunk
+    input_file.seek(std::io::SeekFrom::Start(chunk_info.offset))?;
+    let mut ciphertext = vec![0u8; chunk_info.size as usize];
+    input_file.read_exact(&mut ciphertext)?;
+
+    // Decrypt chunk
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &aad,
+    };
+    let plaintext = cipher.decrypt(nonce, payload).map_err(|_| {
+        NotariError::DecryptionFailed(format!(
+            "Decryption failed for chunk {}: wrong key or corrupted file",
+            chunk_info.index
+        ))
+    })?;
+
+    Ok(plaintext)
+}
 
-        // 4. Decrypt video using video key
-        let video_key = Key::<Aes256Gcm>::from_slice(&video_key_bytes);
-        let cipher = Aes256Gcm::new(video_key);
+/// Key derivation for a new password-encrypted video: Argon2id, random salt
+fn new_key_derivation() -> KeyDerivationInfo {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let mut salt = [0u8; SALT_SIZE];
+    OsRng.fill_bytes(&mut salt);
+    KeyDerivationInfo {
+        algorithm: ARGON2ID_ALGORITHM.to_string(),
+        iterations: ARGON2_TIME_COST,
+        salt: general_purpose::STANDARD.encode(salt),
+        memory_kib: Some(ARGON2_MEMORY_KIB),
+        parallelism: Some(ARGON2_PARALLELISM),
+        wrapped_key: None,
+    }
+}
 
-        let chunked_info = encryption_info
-            .chunked
-            .as_ref()
-            .ok_or_else(|| NotariError::DecryptionFailed("Not a chunked encryption".to_string()))?;
+/// Derive a key from `password` with the algorithm and parameters recorded in
+/// `key_derivation`
+fn derive_password_key(
+    password: &str,
+    key_derivation: &KeyDerivationInfo,
+) -> NotariResult<Zeroizing<[u8; KEY_SIZE]>> {
+    use base64::{engine::general_purpose, Engine as _};
 
-        let mut input_file = File::open(&input_path)?;
-        let mut output_file = File::create(&output_path)?;
+    let salt = general_purpose::STANDARD.decode(&key_derivation.salt)?;
+    let mut key_bytes = Zeroizing::new([0u8; KEY_SIZE]);
 
-        // Decrypt each chunk
-        for chunk_info in &chunked_info.chunks {
-            // Decode nonce
-            let nonce_bytes = general_purpose::STANDARD.decode(&chunk_info.nonce)?;
-            if nonce_bytes.len() != NONCE_SIZE {
+    match key_derivation.algorithm.as_str() {
+        PBKDF2_ALGORITHM => {
+            if key_derivation.iterations > PBKDF2_MAX_ITERATIONS {
                 return Err(NotariError::DecryptionFailed(format!(
-                    "Invalid nonce size for chunk {}: expected {}, got {}",
-                    chunk_info.index,
-                    NONCE_SIZE,
-                    nonce_bytes.len()
+                    "PBKDF2 iteration count of {} exceeds the {} limit",
+                    key_derivation.iterations, PBKDF2_MAX_ITERATIONS
                 )));
             }
-            let nonce = Nonce::from_slice(&nonce_bytes);
-
-            // Read encrypted chunk
-            input_file.seek(std::io::SeekFrom::Start(chunk_info.offset))?;
-            let mut ciphertext = vec![0u8; chunk_info.size as usize];
-            input_file.read_exact(&mut ciphertext)?;
-
-            // Decrypt chunk
-            let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
-                NotariError::DecryptionFailed(format!(
-                    "Decryption failed for chunk {}: corrupted file or wrong key",
-                    chunk_info.index
-                ))
+            pbkdf2_hmac::<Sha256>(
+                password.as_bytes(),
+                &salt,
+                key_derivation.iterations,
+                &mut key_bytes[..],
+            );
+        }
+        ARGON2ID_ALGORITHM => {
+            let memory_kib = key_derivation.memory_kib.unwrap_or(ARGON2_MEMORY_KIB);
+            if memory_kib > ARGON2_MAX_MEMORY_KIB {
+                return Err(NotariError::DecryptionFailed(format!(
+                    "Argon2id memory cost of {} KiB exceeds the {} KiB limit",
+                    memory_kib, ARGON2_MAX_MEMORY_KIB
+                )));
+            }
+            if key_derivation.iterations > ARGON2_MAX_TIME_COST {
+                return Err(NotariError::DecryptionFailed(format!(
+                    "Argon2id time cost of {} exceeds the {} limit",
+                    key_derivation.iterations, ARGON2_MAX_TIME_COST
+                )));
+            }
+            let parallelism = key_derivation.parallelism.unwrap_or(ARGON2_PARALLELISM);
+            if parallelism > ARGON2_MAX_PARALLELISM {
+                return Err(NotariError::DecryptionFailed(format!(
+                    "Argon2id parallelism of {} exceeds the {} limit",
+                    parallelism, ARGON2_MAX_PARALLELISM
+                )));
+            }
+            let params = Params::new(
+                memory_kib,
+                key_derivation.iterations,
+                parallelism,
+                Some(KEY_SIZE),
+            )
+            .map_err(|e| {
+                NotariError::DecryptionFailed(format!("Invalid Argon2id parameters: {}", e))
             })?;
-
-            // Write decrypted chunk
-            output_file.write_all(&plaintext)?;
+            Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(password.as_bytes(), &salt, &mut key_bytes[..])
+                .map_err(|e| {
+                    NotariError::DecryptionFailed(format!("Argon2id key derivation failed: {}", e))
+                })?;
+        }
+        other => {
+            return Err(NotariError::DecryptionFailed(format!(
+                "Unsupported key derivation algorithm: {}",
+                other
+            )))
         }
-
-        LOGGER.log(LogLevel::Info, "Video decrypted successfully", "encryption");
-
-        Ok(())
     }
+
+    Ok(key_bytes)
 }
 
 /// Validate password strength
@@ -1169,8 +1496,10 @@ mod tests {
         assert_eq!(encryption_info.algorithm, "AES-256-GCM");
         assert!(encryption_info.key_derivation.is_some());
         let key_derivation = encryption_info.key_derivation.as_ref().unwrap();
-        assert_eq!(key_derivation.algorithm, "PBKDF2-HMAC-SHA256");
-        assert_eq!(key_derivation.iterations, PBKDF2_ITERATIONS);
+        assert_eq!(key_derivation.algorithm, ARGON2ID_ALGORITHM);
+        assert_eq!(key_derivation.iterations, ARGON2_TIME_COST);
+        assert_eq!(key_derivation.memory_kib, Some(ARGON2_MEMORY_KIB));
+        assert_eq!(key_derivation.parallelism, Some(ARGON2_PARALLELISM));
 
         // Decrypt
         let decrypted_file = NamedTempFile::new().unwrap();
@@ -1282,7 +1611,7 @@ mod tests {
         .unwrap();
 
         // Verify encryption info
-        assert_eq!(encryption_info.algorithm, "AES-256-GCM-CHUNKED");
+        assert_eq!(encryption_info.algorithm, STREAM_ALGORITHM);
         assert!(encryption_info.chunked.is_some());
         let chunked_info = encryption_info.chunked.as_ref().unwrap();
         assert_eq!(chunked_info.chunk_size, CHUNK_SIZE as u64);
@@ -1335,6 +1664,247 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /// Encrypt `data` the way `AES-256-GCM-CHUNKED` files were, with
+    /// independent chunks and no associated data
+    fn encrypt_legacy_chunked(data: &[u8], output: &Path, password: &str) -> EncryptionInfo {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let salt = [7u8; SALT_SIZE];
+        let iterations = 1_000;
+        let mut key_bytes = [0u8; KEY_SIZE];
+        pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, iterations, &mut key_bytes);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key_bytes));
+
+        let mut ciphertext = Vec::new();
+        let mut chunks = Vec::new();
+        for (index, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
+            let mut nonce_bytes = [0u8; NONCE_SIZE];
+            OsRng.fill_bytes(&mut nonce_bytes);
+            let encrypted = cipher
+                .encrypt(Nonce::from_slice(&nonce_bytes), chunk)
+                .unwrap();
+            chunks.push(ChunkInfo {
+                index,
+                offset: ciphertext.len() as u64,
+                size: encrypted.len() as u64,
+                nonce: general_purpose::STANDARD.encode(nonce_bytes),
+            });
+            ciphertext.extend_from_slice(&encrypted);
+        }
+        std::fs::write(output, ciphertext).unwrap();
+
+        EncryptionInfo {
+            algorithm: LEGACY_CHUNKED_ALGORITHM.to_string(),
+            key_derivation: Some(KeyDerivationInfo {
+                algorithm: PBKDF2_ALGORITHM.to_string(),
+                iterations,
+                salt: general_purpose::STANDARD.encode(salt),
+                memory_kib: None,
+                parallelism: None,
+                wrapped_key: None,
+            }),
+            encrypted_keys: None,
+            nonce: None,
+            tag: None,
+            chunked: Some(ChunkedEncryptionInfo {
+                chunk_size: CHUNK_SIZE as u64,
+                total_chunks: chunks.len(),
+                chunks,
+            }),
+            recipient_changes: Vec::new(),
+        }
+    }
+
+    /// A file of `chunks` chunks, each filled with its index
+    fn write_numbered_chunks(chunks: usize) -> (NamedTempFile, Vec<u8>) {
+        let test_data: Vec<u8> = (0..chunks)
+            .flat_map(|index| vec![index as u8; CHUNK_SIZE])
+            .chain(vec![0xff; 500])
+            .collect();
+        let mut input_file = NamedTempFile::new().unwrap();
+        input_file.write_all(&test_data).unwrap();
+        input_file.flush().unwrap();
+        (input_file, test_data)
+    }
+
+    #[test]
+    fn test_stream_rejects_reordered_chunks() {
+        let (input_file, test_data) = write_numbered_chunks(2);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let password = "StreamTest123";
+        let encryption_info = VideoEncryptor::encrypt_file_chunked(
+            input_file.path(),
+            encrypted_file.path(),
+            password,
+        )
+        .unwrap();
+
+        // In place, chunks and byte ranges decrypt
+        let chunk = VideoEncryptor::decrypt_chunk_by_index(
+            encrypted_file.path(),
+            1,
+            password,
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(chunk, test_data[CHUNK_SIZE..CHUNK_SIZE * 2]);
+
+        // Swapping the chunk table entries is caught before decryption
+        let mut swapped = encryption_info.clone();
+        swapped.chunked.as_mut().unwrap().chunks.swap(0, 1);
+        assert!(VideoEncryptor::decrypt_chunk_by_index(
+            encrypted_file.path(),
+            0,
+            password,
+            &swapped
+        )
+        .is_err());
+
+        // Pointing chunk 0 at chunk 1's ciphertext fails its tag
+        let mut moved = encryption_info.clone();
+        let chunks = &mut moved.chunked.as_mut().unwrap().chunks;
+        let (offset, size, nonce) = (chunks[1].offset, chunks[1].size, chunks[1].nonce.clone());
+        chunks[0].offset = offset;
+        chunks[0].size = size;
+        chunks[0].nonce = nonce;
+        assert!(
+            VideoEncryptor::decrypt_chunk_by_index(encrypted_file.path(), 0, password, &moved)
+                .is_err()
+        );
+        assert!(VideoEncryptor::decrypt_byte_range(
+            encrypted_file.path(),
+            0,
+            CHUNK_SIZE as u64 + 10,
+            password,
+            &moved
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_stream_rejects_truncation() {
+        let (input_file, _) = write_numbered_chunks(2);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let password = "StreamTest123";
+        let encryption_info = VideoEncryptor::encrypt_file_chunked(
+            input_file.path(),
+            encrypted_file.path(),
+            password,
+        )
+        .unwrap();
+        let decrypted_file = NamedTempFile::new().unwrap();
+
+        // Dropping the final chunk from the table
+        let mut truncated = encryption_info.clone();
+        truncated.chunked.as_mut().unwrap().chunks.pop();
+        assert!(VideoEncryptor::decrypt_file(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            password,
+            &truncated
+        )
+        .is_err());
+
+        // ...and from the chunk count: chunk 1 wasn't encrypted as the final chunk
+        truncated.chunked.as_mut().unwrap().total_chunks -= 1;
+        assert!(VideoEncryptor::decrypt_chunk_by_index(
+            encrypted_file.path(),
+            1,
+            password,
+            &truncated
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_legacy_chunked_still_decrypts() {
+        let (_, test_data) = write_numbered_chunks(1);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let password = "LegacyTest123";
+        let encryption_info = encrypt_legacy_chunked(&test_data, encrypted_file.path(), password);
+
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            password,
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        let range = VideoEncryptor::decrypt_byte_range(
+            encrypted_file.path(),
+            CHUNK_SIZE as u64 - 2,
+            CHUNK_SIZE as u64 + 1,
+            password,
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(range, test_data[CHUNK_SIZE - 2..CHUNK_SIZE + 2]);
+    }
+
+    #[test]
+    fn test_rekey_pbkdf2_to_argon2id() {
+        let (_, test_data) = write_numbered_chunks(1);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let mut encryption_info =
+            encrypt_legacy_chunked(&test_data, encrypted_file.path(), "LegacyTest123");
+        let ciphertext = std::fs::read(encrypted_file.path()).unwrap();
+
+        // A wrong password derives a key that doesn't open the video
+        let wrong_key =
+            VideoEncryptor::derive_content_key("WrongPass123", &encryption_info).unwrap();
+        assert!(
+            VideoEncryptor::check_content_key(&ciphertext, &wrong_key, &encryption_info).is_err()
+        );
+
+        let key = VideoEncryptor::derive_content_key("LegacyTest123", &encryption_info).unwrap();
+        VideoEncryptor::check_content_key(&ciphertext, &key, &encryption_info).unwrap();
+        let key_derivation = VideoEncryptor::wrap_content_key(&key, "NewPassword456").unwrap();
+        assert_eq!(key_derivation.algorithm, ARGON2ID_ALGORITHM);
+        assert!(key_derivation.wrapped_key.is_some());
+        encryption_info.key_derivation = Some(key_derivation);
+
+        // The video is unchanged and opens with the new password only
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            "NewPassword456",
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+        assert!(VideoEncryptor::derive_content_key("LegacyTest123", &encryption_info).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_key_derivation_rejected() {
+        let mut key_derivation = new_key_derivation();
+        key_derivation.algorithm = "scrypt".to_string();
+        assert!(derive_password_key("TestPassword123", &key_derivation).is_err());
+
+        // Parameters from a manifest can't make us allocate unbounded memory
+        let mut key_derivation = new_key_derivation();
+        key_derivation.memory_kib = Some(ARGON2_MAX_MEMORY_KIB + 1);
+        assert!(derive_password_key("TestPassword123", &key_derivation).is_err());
+
+        // ...or spend unbounded time
+        let mut key_derivation = new_key_derivation();
+        key_derivation.iterations = ARGON2_MAX_TIME_COST + 1;
+        assert!(derive_password_key("TestPassword123", &key_derivation).is_err());
+
+        let mut key_derivation = new_key_derivation();
+        key_derivation.parallelism = Some(ARGON2_MAX_PARALLELISM + 1);
+        assert!(derive_password_key("TestPassword123", &key_derivation).is_err());
+
+        let mut key_derivation = new_key_derivation();
+        key_derivation.algorithm = PBKDF2_ALGORITHM.to_string();
+        key_derivation.iterations = PBKDF2_MAX_ITERATIONS + 1;
+        assert!(derive_password_key("TestPassword123", &key_derivation).is_err());
+    }
+
     #[test]
     fn test_encrypt_empty_file() {
         // Create empty file
@@ -1426,14 +1996,18 @@ mod tests {
         let encryption_info = EncryptionInfo {
             algorithm: "AES-256-GCM".to_string(),
             key_derivation: Some(KeyDerivationInfo {
-                algorithm: "PBKDF2-HMAC-SHA256".to_string(),
-                iterations: PBKDF2_ITERATIONS,
+                algorithm: ARGON2ID_ALGORITHM.to_string(),
+                iterations: ARGON2_TIME_COST,
                 salt: "test_salt_base64".to_string(),
+                memory_kib: Some(ARGON2_MEMORY_KIB),
+                parallelism: Some(ARGON2_PARALLELISM),
+                wrapped_key: None,
             }),
             encrypted_keys: None,
             nonce: Some("test_nonce_base64".to_string()),
             tag: Some("test_tag_base64".to_string()),
             chunked: None,
+            recipient_changes: Vec::new(),
         };
 
         // Serialize and deserialize
@@ -1445,6 +2019,11 @@ mod tests {
             deserialized.key_derivation.as_ref().unwrap().iterations,
             encryption_info.key_derivation.as_ref().unwrap().iterations
         );
+        assert_eq!(
+            deserialized.key_derivation.as_ref().unwrap().memory_kib,
+            Some(ARGON2_MEMORY_KIB)
+        );
+        assert!(!json.contains("wrapped_key"));
         assert_eq!(deserialized.nonce, encryption_info.nonce);
     }
 
@@ -1478,7 +2057,7 @@ mod tests {
         .unwrap();
 
         // Verify encryption info
-        assert_eq!(encryption_info.algorithm, "AES-256-GCM-CHUNKED-PUBKEY");
+        assert_eq!(encryption_info.algorithm, "AES-256-GCM-STREAM-PUBKEY");
         assert!(encryption_info.key_derivation.is_none());
         assert!(encryption_info.encrypted_keys.is_some());
         let encrypted_keys = encryption_info.encrypted_keys.as_ref().unwrap();
@@ -1676,4 +2255,311 @@ mod tests {
         // Should fail - need at least one recipient
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_hybrid_recipients_and_password() {
+        use crate::evidence::EncryptionKeyManager;
+
+        let (input_file, test_data) = write_numbered_chunks(1);
+        let alice_key = EncryptionKeyManager::generate();
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let encryption_info = VideoEncryptor::encrypt_file_with_key_slots(
+            input_file.path(),
+            encrypted_file.path(),
+            vec![("alice@example.com".to_string(), alice_key.public_key())],
+            Some("AuthorPass123"),
+        )
+        .unwrap();
+        assert_eq!(encryption_info.encrypted_keys.as_ref().unwrap().len(), 1);
+        let key_derivation = encryption_info.key_derivation.as_ref().unwrap();
+        assert_eq!(key_derivation.algorithm, ARGON2ID_ALGORITHM);
+        assert!(key_derivation.wrapped_key.is_some());
+
+        // The recipient and the password holder both open the same video
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file_with_private_key(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            alice_key.secret_key(),
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        VideoEncryptor::decrypt_file(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            "AuthorPass123",
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        // A private key without a slot falls back to the password
+        let outsider = EncryptionKeyManager::generate();
+        let key = VideoEncryptor::open_content_key(
+            Some("AuthorPass123"),
+            Some(outsider.secret_key()),
+            &encryption_info,
+        )
+        .unwrap();
+        let chunk = VideoEncryptor::decrypt_chunk_with_key(
+            encrypted_file.path(),
+            0,
+            &key,
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(chunk, test_data[..CHUNK_SIZE]);
+
+        assert!(VideoEncryptor::open_content_key(
+            Some("WrongPass123"),
+            Some(outsider.secret_key()),
+            &encryption_info
+        )
+        .is_err());
+        assert!(VideoEncryptor::open_content_key(None, None, &encryption_info).is_err());
+    }
+
+    #[test]
+    fn test_key_slots_password_only() {
+        let (input_file, test_data) = write_numbered_chunks(0);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let encryption_info = VideoEncryptor::encrypt_file_with_key_slots(
+            input_file.path(),
+            encrypted_file.path(),
+            vec![],
+            Some("AuthorPass123"),
+        )
+        .unwrap();
+        assert_eq!(encryption_info.algorithm, STREAM_ALGORITHM);
+        assert!(encryption_info.encrypted_keys.is_none());
+
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            "AuthorPass123",
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        assert!(VideoEncryptor::encrypt_file_with_key_slots(
+            input_file.path(),
+            encrypted_file.path(),
+            vec![],
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_add_and_remove_recipient() {
+        use crate::evidence::EncryptionKeyManager;
+
+        let (input_file, test_data) = write_numbered_chunks(1);
+        let owner_key = EncryptionKeyManager::generate();
+        let bob_key = EncryptionKeyManager::generate();
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let mut encryption_info = VideoEncryptor::encrypt_file_with_public_keys(
+            input_file.path(),
+            encrypted_file.path(),
+            vec![("owner".to_string(), owner_key.public_key())],
+        )
+        .unwrap();
+        let chunked_before = serde_json::to_string(&encryption_info.chunked).unwrap();
+
+        // The owner's key slot opens the content key, which is wrapped for Bob
+        let key =
+            VideoEncryptor::unwrap_content_key(owner_key.secret_key(), &encryption_info).unwrap();
+        VideoEncryptor::add_recipient(
+            &mut encryption_info,
+            &key,
+            "bob@example.com",
+            &bob_key.public_key(),
+        )
+        .unwrap();
+        assert_eq!(encryption_info.encrypted_keys.as_ref().unwrap().len(), 2);
+        assert!(VideoEncryptor::add_recipient(
+            &mut encryption_info,
+            &key,
+            "bob again",
+            &bob_key.public_key()
+        )
+        .is_err());
+
+        // Nothing but the key slots changed
+        assert_eq!(
+            serde_json::to_string(&encryption_info.chunked).unwrap(),
+            chunked_before
+        );
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file_with_private_key(
+            encrypted_file.path(),
+            decrypted_file.path(),
+            bob_key.secret_key(),
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        let removed = VideoEncryptor::remove_recipient(
+            &mut encryption_info,
+            &bob_key.export_public_key(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(removed.recipient_id, "bob@example.com");
+        assert!(
+            VideoEncryptor::unwrap_content_key(bob_key.secret_key(), &encryption_info).is_err()
+        );
+
+        // The last key slot stays
+        assert!(VideoEncryptor::remove_recipient(
+            &mut encryption_info,
+            &owner_key.export_public_key(),
+            false
+        )
+        .is_err());
+
+        let changes = &encryption_info.recipient_changes;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].action, RecipientAction::Added);
+        assert_eq!(changes[1].action, RecipientAction::Removed);
+        assert_eq!(changes[1].recipient_public_key, bob_key.export_public_key());
+        assert!(!changes[1].key_rotated);
+    }
+
+    #[test]
+    fn test_rotate_content_key_after_removal() {
+        use crate::evidence::EncryptionKeyManager;
+
+        let (input_file, test_data) = write_numbered_chunks(2);
+        let owner_key = EncryptionKeyManager::generate();
+        let bob_key = EncryptionKeyManager::generate();
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let mut encryption_info = VideoEncryptor::encrypt_file_with_key_slots(
+            input_file.path(),
+            encrypted_file.path(),
+            vec![
+                ("owner".to_string(), owner_key.public_key()),
+                ("bob@example.com".to_string(), bob_key.public_key()),
+            ],
+            Some("AuthorPass123"),
+        )
+        .unwrap();
+        let old_info = encryption_info.clone();
+        let old_key = VideoEncryptor::unwrap_content_key(bob_key.secret_key(), &old_info).unwrap();
+
+        let key =
+            VideoEncryptor::unwrap_content_key(owner_key.secret_key(), &encryption_info).unwrap();
+        VideoEncryptor::remove_recipient(&mut encryption_info, &bob_key.export_public_key(), true)
+            .unwrap();
+
+        // Rewrapping the password slot takes the video's password
+        let rotated_file = NamedTempFile::new().unwrap();
+        assert!(VideoEncryptor::rotate_content_key(
+            encrypted_file.path(),
+            rotated_file.path(),
+            &key,
+            &mut encryption_info,
+            None
+        )
+        .is_err());
+        assert!(VideoEncryptor::rotate_content_key(
+            encrypted_file.path(),
+            rotated_file.path(),
+            &key,
+            &mut encryption_info,
+            Some("WrongPass123")
+        )
+        .is_err());
+        // ...and a content key the password opens
+        assert!(matches!(
+            VideoEncryptor::rotate_content_key(
+                encrypted_file.path(),
+                rotated_file.path(),
+                &ContentKey::generate(),
+                &mut encryption_info,
+                Some("AuthorPass123")
+            ),
+            Err(NotariError::InvalidPassword(_))
+        ));
+        VideoEncryptor::rotate_content_key(
+            encrypted_file.path(),
+            rotated_file.path(),
+            &key,
+            &mut encryption_info,
+            Some("AuthorPass123"),
+        )
+        .unwrap();
+        assert_eq!(encryption_info.algorithm, "AES-256-GCM-STREAM-PUBKEY");
+        assert_eq!(encryption_info.recipient_changes.len(), 1);
+        assert!(encryption_info.recipient_changes[0].key_rotated);
+
+        // The owner and the password open the re-encrypted video
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file_with_private_key(
+            rotated_file.path(),
+            decrypted_file.path(),
+            owner_key.secret_key(),
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+        VideoEncryptor::decrypt_file(
+            rotated_file.path(),
+            decrypted_file.path(),
+            "AuthorPass123",
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+
+        // Bob's copy of the old content key no longer opens it
+        assert!(VideoEncryptor::decrypt_chunk_with_key(
+            rotated_file.path(),
+            0,
+            &old_key,
+            &encryption_info
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_reencrypt_legacy_chunked() {
+        let (_, test_data) = write_numbered_chunks(1);
+        let encrypted_file = NamedTempFile::new().unwrap();
+        let password = "LegacyTest123";
+        let mut encryption_info =
+            encrypt_legacy_chunked(&test_data, encrypted_file.path(), password);
+        let key = VideoEncryptor::derive_content_key(password, &encryption_info).unwrap();
+
+        let rotated_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::rotate_content_key(
+            encrypted_file.path(),
+            rotated_file.path(),
+            &key,
+            &mut encryption_info,
+            Some(password),
+        )
+        .unwrap();
+
+        // The rotated video is STREAM, with its key wrapped under Argon2id
+        assert_eq!(encryption_info.algorithm, STREAM_ALGORITHM);
+        let key_derivation = encryption_info.key_derivation.as_ref().unwrap();
+        assert_eq!(key_derivation.algorithm, ARGON2ID_ALGORITHM);
+        assert!(key_derivation.wrapped_key.is_some());
+
+        let decrypted_file = NamedTempFile::new().unwrap();
+        VideoEncryptor::decrypt_file(
+            rotated_file.path(),
+            decrypted_file.path(),
+            password,
+            &encryption_info,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(decrypted_file.path()).unwrap(), test_data);
+    }
 }
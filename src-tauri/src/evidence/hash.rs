@@ -4,6 +4,12 @@ use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
 
+use super::blockchain::merkle::{self, MerkleTree};
+use crate::error::NotariResult;
+
+/// Size of the chunks hashed into `ChunkHashes`, the same as chunked encryption
+pub const HASH_CHUNK_SIZE: u64 = 1024 * 1024;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HashInfo {
     pub algorithm: String,
@@ -44,6 +50,36 @@ impl HashInfo {
         }
     }
 
+    /// Calculate the SHA-256 hash of a file and its chunk hashes in one pass
+    pub fn from_file_chunked<P: AsRef<Path>>(path: P) -> io::Result<(Self, ChunkHashes)> {
+        let mut file = File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut chunks: Vec<[u8; 32]> = Vec::new();
+        let mut file_size = 0;
+        let mut buffer = Vec::with_capacity(HASH_CHUNK_SIZE as usize);
+
+        loop {
+            buffer.clear();
+            let bytes_read = (&mut file).take(HASH_CHUNK_SIZE).read_to_end(&mut buffer)?;
+            // An empty file is a single empty chunk, so every tree has a leaf
+            if bytes_read == 0 && !chunks.is_empty() {
+                break;
+            }
+            hasher.update(&buffer);
+            chunks.push(Sha256::digest(&buffer).into());
+            file_size += bytes_read as u64;
+            if (bytes_read as u64) < HASH_CHUNK_SIZE {
+                break;
+            }
+        }
+
+        let hash = Self {
+            algorithm: "SHA-256".to_string(),
+            value: hex::encode(hasher.finalize()),
+        };
+        Ok((hash, ChunkHashes::from_chunks(&chunks, file_size)))
+    }
+
     /// Verify hash matches expected value
     pub fn verify<P: AsRef<Path>>(&self, path: P) -> io::Result<bool> {
         let computed = Self::from_file(path)?;
@@ -51,6 +87,102 @@ impl HashInfo {
     }
 }
 
+/// SHA-256 hashes of consecutive fixed-size chunks of a file and their Merkle root
+///
+/// The root is a `MerkleTree` over the chunk hashes, so any chunk-aligned part of
+/// the file can be checked on its own and a mismatch pinned to the chunk it's in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkHashes {
+    pub algorithm: String,
+    pub chunk_size: u64,
+    pub file_size: u64,
+    /// Hash of each chunk (hex), in file order; the last chunk may be short
+    pub chunks: Vec<String>,
+    pub merkle_root: String,
+}
+
+impl ChunkHashes {
+    /// Calculate the chunk hashes of a file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        HashInfo::from_file_chunked(path).map(|(_, chunks)| chunks)
+    }
+
+    fn from_chunks(chunks: &[[u8; 32]], file_size: u64) -> Self {
+        let tree = MerkleTree::new(chunks).expect("a file has at least one chunk");
+        Self {
+            algorithm: "SHA-256".to_string(),
+            chunk_size: HASH_CHUNK_SIZE,
+            file_size,
+            chunks: chunks.iter().map(hex::encode).collect(),
+            merkle_root: hex::encode(tree.root()),
+        }
+    }
+
+    /// Byte offset of chunk `index`
+    pub fn offset(&self, index: usize) -> u64 {
+        index as u64 * self.chunk_size
+    }
+
+    /// Recompute the Merkle root over `chunks`
+    pub fn compute_root(chunks: &[String]) -> NotariResult<String> {
+        let leaves = chunks
+            .iter()
+            .map(|chunk| merkle::decode_hash(chunk))
+            .collect::<NotariResult<Vec<_>>>()?;
+        Ok(hex::encode(MerkleTree::new(&leaves)?.root()))
+    }
+
+    /// Whether the chunk hashes lead to `merkle_root`
+    pub fn root_valid(&self) -> bool {
+        Self::compute_root(&self.chunks).is_ok_and(|root| root == self.merkle_root)
+    }
+
+    /// Index of the first chunk where `other` differs from these hashes
+    ///
+    /// A file that was cut short or extended differs at the first chunk one of
+    /// them is missing. `None` if every chunk matches.
+    pub fn first_mismatch(&self, other: &ChunkHashes) -> Option<usize> {
+        if self.chunk_size != other.chunk_size {
+            return Some(0);
+        }
+        self.chunks
+            .iter()
+            .zip(&other.chunks)
+            .position(|(expected, actual)| expected != actual)
+            .or_else(|| {
+                (self.chunks.len() != other.chunks.len())
+                    .then(|| self.chunks.len().min(other.chunks.len()))
+            })
+    }
+
+    /// Locate `excerpt` among these chunks and check it against the Merkle root
+    ///
+    /// The excerpt must start on a chunk boundary of the original file and may
+    /// only end short of one at the end of the file. Its chunk hashes replace
+    /// the original's at that position and the root is recomputed, so a match
+    /// proves the excerpt is an unaltered part of the recording. Returns the
+    /// index of the excerpt's first chunk, or `None` if it isn't part of it.
+    pub fn locate_excerpt(&self, excerpt: &ChunkHashes) -> Option<usize> {
+        if excerpt.chunk_size != self.chunk_size || excerpt.file_size == 0 {
+            return None;
+        }
+
+        let count = excerpt.chunks.len();
+        (0..self.chunks.len())
+            .filter(|&start| self.chunks[start] == excerpt.chunks[0])
+            .find(|&start| {
+                let end = start + count;
+                if end > self.chunks.len() {
+                    return false;
+                }
+
+                let mut leaves = self.chunks.clone();
+                leaves.splice(start..end, excerpt.chunks.iter().cloned());
+                Self::compute_root(&leaves).is_ok_and(|root| root == self.merkle_root)
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +297,87 @@ mod tests {
         assert_eq!(deserialized.value, hash.value);
     }
 
+    fn chunked_file(data: &[u8]) -> tempfile::NamedTempFile {
+        let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+        temp_file.write_all(data).unwrap();
+        temp_file.flush().unwrap();
+        temp_file
+    }
+
+    /// Two and a half chunks, each chunk filled with its own byte
+    fn chunk_data() -> Vec<u8> {
+        let chunk = HASH_CHUNK_SIZE as usize;
+        (0..chunk * 5 / 2).map(|i| (i / chunk) as u8).collect()
+    }
+
+    #[test]
+    fn test_chunk_hashes_from_file() {
+        let data = chunk_data();
+        let temp_file = chunked_file(&data);
+
+        let (hash, chunks) = HashInfo::from_file_chunked(temp_file.path()).unwrap();
+        assert_eq!(
+            hash.value,
+            HashInfo::from_file(temp_file.path()).unwrap().value
+        );
+        assert_eq!(chunks.chunks.len(), 3);
+        assert_eq!(chunks.file_size, data.len() as u64);
+        assert_eq!(
+            chunks.chunks[2],
+            HashInfo::from_bytes(&data[HASH_CHUNK_SIZE as usize * 2..]).value
+        );
+        assert!(chunks.root_valid());
+
+        let empty = ChunkHashes::from_file(chunked_file(b"").path()).unwrap();
+        assert_eq!(empty.chunks.len(), 1);
+        assert!(empty.root_valid());
+    }
+
+    #[test]
+    fn test_chunk_hashes_first_mismatch() {
+        let mut data = chunk_data();
+        let original = ChunkHashes::from_file(chunked_file(&data).path()).unwrap();
+        assert_eq!(original.first_mismatch(&original), None);
+
+        data[HASH_CHUNK_SIZE as usize + 7] ^= 0xff;
+        let modified = ChunkHashes::from_file(chunked_file(&data).path()).unwrap();
+        assert_eq!(original.first_mismatch(&modified), Some(1));
+
+        let truncated =
+            ChunkHashes::from_file(chunked_file(&data[..HASH_CHUNK_SIZE as usize]).path()).unwrap();
+        assert_eq!(original.first_mismatch(&truncated), Some(1));
+
+        let mut tampered = original.clone();
+        tampered.chunks[0] = tampered.chunks[1].clone();
+        assert!(!tampered.root_valid());
+    }
+
+    #[test]
+    fn test_locate_excerpt() {
+        let data = chunk_data();
+        let chunk = HASH_CHUNK_SIZE as usize;
+        let original = ChunkHashes::from_file(chunked_file(&data).path()).unwrap();
+        let excerpt = |range: std::ops::Range<usize>| {
+            ChunkHashes::from_file(chunked_file(&data[range]).path()).unwrap()
+        };
+
+        assert_eq!(original.locate_excerpt(&excerpt(0..chunk)), Some(0));
+        assert_eq!(
+            original.locate_excerpt(&excerpt(chunk..data.len())),
+            Some(1)
+        );
+        assert_eq!(original.locate_excerpt(&original), Some(0));
+
+        // Not on a chunk boundary, or cut short in the middle of the file
+        assert_eq!(original.locate_excerpt(&excerpt(1..chunk + 1)), None);
+        assert_eq!(original.locate_excerpt(&excerpt(0..chunk / 2)), None);
+
+        let mut altered = data[chunk..chunk * 2].to_vec();
+        altered[0] ^= 0xff;
+        let altered = ChunkHashes::from_file(chunked_file(&altered).path()).unwrap();
+        assert_eq!(original.locate_excerpt(&altered), None);
+    }
+
     #[test]
     fn test_hash_from_file_nonexistent() {
         let result = HashInfo::from_file("/nonexistent/file.txt");
@@ -0,0 +1,572 @@
+//! Signed history of signing key rotations
+//!
+//! Replacing the signing key would orphan trust in everything signed before.
+//! Instead, each rotation is recorded as a statement naming the retired key and
+//! its replacement, signed by both. The statements chain from the first key,
+//! the identity verifiers pin, to the key in use. Proof packs carry the chain
+//! up to the key their manifest is signed with as `key_history.json`.
+
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::canonical;
+use super::keychain;
+use super::signature::KeyManager;
+use crate::error::{NotariError, NotariResult};
+use crate::logger::{LogLevel, LOGGER};
+use crate::repository::keychain::key_ids;
+use crate::repository::traits::KeyRepository;
+
+/// Current key history format version
+pub const KEY_HISTORY_VERSION: u32 = 1;
+
+/// Name of the key history, in the app data directory and the proof pack
+pub const KEY_HISTORY_FILE: &str = "key_history.json";
+
+/// Age after which the signing key is due for rotation
+pub const ROTATION_INTERVAL_DAYS: i64 = 365;
+
+/// Bundle identifier the app data directory is named after
+pub(crate) const APP_IDENTIFIER: &str = "com.notari.tray";
+
+/// Replacement of one signing key by the next
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRotation {
+    pub sequence: u64,
+    pub rotated_at: DateTime<Utc>,
+    /// Key being retired (Ed25519, base64)
+    pub previous_key: String,
+    /// Key replacing it (Ed25519, base64)
+    pub public_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Signature by `previous_key` over the signed fields (base64)
+    pub signature: String,
+    /// Signature by `public_key` over the same fields, showing it was held (base64)
+    pub new_key_signature: String,
+}
+
+/// Fields of a rotation covered by both signatures
+#[derive(Serialize)]
+struct SignedFields<'a> {
+    identity_key: &'a str,
+    sequence: u64,
+    rotated_at: &'a DateTime<Utc>,
+    previous_key: &'a str,
+    public_key: &'a str,
+    reason: Option<&'a str>,
+}
+
+impl KeyRotation {
+    /// Canonical JSON of the signed fields, bound to the chain's identity key
+    fn signable_data(&self, identity_key: &str) -> Vec<u8> {
+        canonical::to_vec(&SignedFields {
+            identity_key,
+            sequence: self.sequence,
+            rotated_at: &self.rotated_at,
+            previous_key: &self.previous_key,
+            public_key: &self.public_key,
+            reason: self.reason.as_deref(),
+        })
+        .unwrap()
+    }
+}
+
+/// Chain of rotations from the identity key to the current signing key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyHistory {
+    pub version: u32,
+    /// First signing key, which verifiers pin (Ed25519, base64)
+    pub identity_key: String,
+    /// When the identity key was first recorded
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub rotations: Vec<KeyRotation>,
+}
+
+impl KeyHistory {
+    /// Start a history with `identity` as its first key
+    pub fn new(identity: &KeyManager) -> Self {
+        Self {
+            version: KEY_HISTORY_VERSION,
+            identity_key: encode_public_key(identity),
+            created_at: Utc::now(),
+            rotations: Vec::new(),
+        }
+    }
+
+    /// Location of the key history in the app data directory
+    pub fn default_path() -> NotariResult<PathBuf> {
+        let data_dir = dirs::data_dir().ok_or_else(|| {
+            NotariError::StorageLoadFailed("Could not determine data directory".to_string())
+        })?;
+        Ok(data_dir.join(APP_IDENTIFIER).join(KEY_HISTORY_FILE))
+    }
+
+    /// Read a history from `path`
+    pub fn load(path: &Path) -> NotariResult<Self> {
+        let data = std::fs::read(path)
+            .map_err(|e| NotariError::StorageLoadFailed(format!("{}: {}", path.display(), e)))?;
+        Self::from_json(&data)
+    }
+
+    /// Parse a history, rejecting versions newer than this build understands
+    pub fn from_json(data: &[u8]) -> NotariResult<Self> {
+        let history: Self = serde_json::from_slice(data)?;
+        if history.version > KEY_HISTORY_VERSION {
+            return Err(NotariError::StorageLoadFailed(format!(
+                "Unsupported key history version {}",
+                history.version
+            )));
+        }
+        Ok(history)
+    }
+
+    /// Read the history at `path`, or start one for `current` if there is none
+    ///
+    /// A history that doesn't end at `current` belongs to a key replaced without
+    /// rotating, so a new one is started in its place.
+    pub fn load_or_start(path: &Path, current: &KeyManager) -> NotariResult<Self> {
+        if path.exists() {
+            let history = Self::load(path)?;
+            if history.current_key() == encode_public_key(current) {
+                return Ok(history);
+            }
+            LOGGER.log(
+                LogLevel::Warn,
+                "Key history doesn't end at the current signing key, starting a new one",
+                "key_history",
+            );
+        }
+        Ok(Self::new(current))
+    }
+
+    /// Write the history to `path`, replacing the previous one atomically
+    pub fn save(&self, path: &Path) -> NotariResult<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e))
+            })?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        let data = serde_json::to_vec_pretty(self)?;
+        std::fs::write(&tmp_path, data)
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .map_err(|e| NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Key in use after the last rotation (Ed25519, base64)
+    pub fn current_key(&self) -> &str {
+        self.rotations
+            .last()
+            .map_or(&self.identity_key, |rotation| &rotation.public_key)
+    }
+
+    /// When the current key came into use
+    pub fn current_key_since(&self) -> DateTime<Utc> {
+        self.rotations
+            .last()
+            .map_or(self.created_at, |rotation| rotation.rotated_at)
+    }
+
+    /// Whether the current key is older than `ROTATION_INTERVAL_DAYS` at `now`
+    pub fn rotation_due(&self, now: DateTime<Utc>) -> bool {
+        now - self.current_key_since() >= chrono::Duration::days(ROTATION_INTERVAL_DAYS)
+    }
+
+    /// Record the replacement of the current key `current` by `next`
+    pub fn rotate(
+        &mut self,
+        current: &KeyManager,
+        next: &KeyManager,
+        reason: Option<String>,
+    ) -> NotariResult<&KeyRotation> {
+        let previous_key = encode_public_key(current);
+        if previous_key != self.current_key() {
+            return Err(NotariError::KeyRotationFailed(
+                "Key being retired isn't the current key of the history".to_string(),
+            ));
+        }
+        let public_key = encode_public_key(next);
+        if public_key == previous_key {
+            return Err(NotariError::KeyRotationFailed(
+                "New key is the same as the current key".to_string(),
+            ));
+        }
+
+        let mut rotation = KeyRotation {
+            sequence: self.rotations.len() as u64,
+            rotated_at: Utc::now(),
+            previous_key,
+            public_key,
+            reason,
+            signature: String::new(),
+            new_key_signature: String::new(),
+        };
+        let data = rotation.signable_data(&self.identity_key);
+        rotation.signature = current.sign(&data).signature;
+        rotation.new_key_signature = next.sign(&data).signature;
+
+        self.rotations.push(rotation);
+        Ok(self.rotations.last().unwrap())
+    }
+
+    /// The history up to the rotation that introduced `public_key`
+    ///
+    /// Returns `None` if `public_key` never was a key of this history.
+    pub fn chain_to(&self, public_key: &str) -> Option<Self> {
+        let len = if self.identity_key == public_key {
+            0
+        } else {
+            self.rotations
+                .iter()
+                .position(|rotation| rotation.public_key == public_key)?
+                + 1
+        };
+        Some(Self {
+            rotations: self.rotations[..len].to_vec(),
+            ..self.clone()
+        })
+    }
+
+    /// Check that each rotation is signed by the key it retires and the key it
+    /// introduces, and that rotations follow on from one another
+    pub fn verify(&self) -> Result<(), String> {
+        let mut current = self.identity_key.as_str();
+        let mut since = self.created_at;
+
+        for (index, rotation) in self.rotations.iter().enumerate() {
+            if rotation.sequence != index as u64 {
+                return Err(format!(
+                    "Rotation {} has sequence number {}",
+                    index, rotation.sequence
+                ));
+            }
+            if rotation.previous_key != current {
+                return Err(format!(
+                    "Rotation {} doesn't retire the key before it",
+                    index
+                ));
+            }
+            if rotation.rotated_at < since {
+                return Err(format!(
+                    "Rotation {} is dated before the key it retires",
+                    index
+                ));
+            }
+
+            let data = rotation.signable_data(&self.identity_key);
+            let signed_by = |key: &str, signature: &str| {
+                KeyManager::verify(key, signature, &data).unwrap_or(false)
+            };
+            if !signed_by(&rotation.previous_key, &rotation.signature) {
+                return Err(format!(
+                    "Rotation {} isn't signed by the retired key",
+                    index
+                ));
+            }
+            if !signed_by(&rotation.public_key, &rotation.new_key_signature) {
+                return Err(format!("Rotation {} isn't signed by the new key", index));
+            }
+
+            current = &rotation.public_key;
+            since = rotation.rotated_at;
+        }
+        Ok(())
+    }
+}
+
+/// Base64 Ed25519 public key of `key`, as it appears in signatures
+fn encode_public_key(key: &KeyManager) -> String {
+    general_purpose::STANDARD.encode(key.public_key().as_bytes())
+}
+
+/// Replace the keychain signing key with a new one, recording the rotation in
+/// the history at `path`
+///
+/// The retired key stays in the keychain under its public key, so recordings it
+/// signed can still be re-signed (see [`signing_key_for`]). The history is saved
+/// before the keychain is updated, and restored if that fails, so it never ends
+/// at a key that isn't in use.
+pub fn rotate_signing_key(path: &Path, reason: Option<String>) -> NotariResult<KeyRotation> {
+    rotate_key_in(keychain::repository(), path, reason)
+}
+
+/// The keychain signing key with the base64 public key `public_key`: the key in
+/// use, or one retired by [`rotate_signing_key`]
+pub fn signing_key_for(public_key: &str) -> NotariResult<KeyManager> {
+    find_signing_key(keychain::repository(), public_key)
+}
+
+fn rotate_key_in(
+    keys: &dyn KeyRepository,
+    path: &Path,
+    reason: Option<String>,
+) -> NotariResult<KeyRotation> {
+    let current = KeyManager::from_bytes(&keys.retrieve_key(key_ids::SIGNING_KEY)?)?;
+    let mut history = KeyHistory::load_or_start(path, &current)?;
+    let previous = path.exists().then(|| history.clone());
+
+    let next = KeyManager::generate();
+    let rotation = history.rotate(&current, &next, reason)?.clone();
+    history.save(path)?;
+
+    let retired_id = key_ids::retired_signing_key(&rotation.previous_key);
+    if let Err(e) = keys
+        .store_key(&retired_id, &current.to_bytes())
+        .and_then(|()| keys.store_key(key_ids::SIGNING_KEY, &next.to_bytes()))
+    {
+        let _ = match previous {
+            Some(previous) => previous.save(path),
+            None => std::fs::remove_file(path).map_err(NotariError::from),
+        };
+        return Err(NotariError::KeyRotationFailed(e.to_string()));
+    }
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Signing key rotated (rotation {}): {}...",
+            rotation.sequence,
+            &rotation.public_key[..16.min(rotation.public_key.len())]
+        ),
+        "key_history",
+    );
+    Ok(rotation)
+}
+
+fn find_signing_key(keys: &dyn KeyRepository, public_key: &str) -> NotariResult<KeyManager> {
+    let current = KeyManager::from_bytes(&keys.retrieve_key(key_ids::SIGNING_KEY)?)?;
+    if encode_public_key(&current) == public_key {
+        return Ok(current);
+    }
+
+    let retired = keys
+        .retrieve_key(&key_ids::retired_signing_key(public_key))
+        .map_err(|_| {
+            NotariError::NoSigningKey(format!("no key in the keychain matches {}", public_key))
+        })?;
+    let retired = KeyManager::from_bytes(&retired)?;
+    if encode_public_key(&retired) != public_key {
+        return Err(NotariError::NoSigningKey(format!(
+            "the retired key stored for {} doesn't match it",
+            public_key
+        )));
+    }
+    Ok(retired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    /// In-memory stand-in for the keychain
+    #[derive(Default)]
+    struct MemoryKeys(Mutex<HashMap<String, Vec<u8>>>);
+
+    impl KeyRepository for MemoryKeys {
+        fn store_key(&self, key_id: &str, key_bytes: &[u8]) -> NotariResult<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(key_id.to_string(), key_bytes.to_vec());
+            Ok(())
+        }
+
+        fn retrieve_key(&self, key_id: &str) -> NotariResult<Vec<u8>> {
+            self.0
+                .lock()
+                .unwrap()
+                .get(key_id)
+                .cloned()
+                .ok_or_else(|| NotariError::KeychainRetrieveFailed(key_id.to_string()))
+        }
+
+        fn delete_key(&self, key_id: &str) -> NotariResult<()> {
+            self.0.lock().unwrap().remove(key_id);
+            Ok(())
+        }
+
+        fn has_key(&self, key_id: &str) -> NotariResult<bool> {
+            Ok(self.0.lock().unwrap().contains_key(key_id))
+        }
+
+        fn list_keys(&self) -> NotariResult<Vec<String>> {
+            Ok(self.0.lock().unwrap().keys().cloned().collect())
+        }
+    }
+
+    /// History rotated twice, with every key in order
+    fn rotated_history() -> (KeyHistory, Vec<KeyManager>) {
+        let keys: Vec<KeyManager> = (0..3).map(|_| KeyManager::generate()).collect();
+        let mut history = KeyHistory::new(&keys[0]);
+        history.rotate(&keys[0], &keys[1], None).unwrap();
+        history
+            .rotate(&keys[1], &keys[2], Some("Annual rotation".to_string()))
+            .unwrap();
+        (history, keys)
+    }
+
+    #[test]
+    fn test_rotate_chains_keys() {
+        let (history, keys) = rotated_history();
+
+        assert_eq!(history.identity_key, encode_public_key(&keys[0]));
+        assert_eq!(history.current_key(), encode_public_key(&keys[2]));
+        assert_eq!(
+            history.rotations[1].previous_key,
+            encode_public_key(&keys[1])
+        );
+        assert_eq!(history.verify(), Ok(()));
+        assert!(!history.rotation_due(Utc::now()));
+        assert!(history.rotation_due(Utc::now() + chrono::Duration::days(366)));
+    }
+
+    #[test]
+    fn test_rotate_requires_current_key() {
+        let (mut history, keys) = rotated_history();
+
+        assert!(history
+            .rotate(&keys[1], &KeyManager::generate(), None)
+            .is_err());
+        assert!(history.rotate(&keys[2], &keys[2], None).is_err());
+        assert_eq!(history.rotations.len(), 2);
+    }
+
+    #[test]
+    fn test_chain_to() {
+        let (history, keys) = rotated_history();
+
+        let chain = history.chain_to(&encode_public_key(&keys[1])).unwrap();
+        assert_eq!(chain.rotations.len(), 1);
+        assert_eq!(chain.current_key(), encode_public_key(&keys[1]));
+        assert_eq!(chain.verify(), Ok(()));
+
+        let identity = history.chain_to(&history.identity_key).unwrap();
+        assert!(identity.rotations.is_empty());
+
+        assert!(history
+            .chain_to(&encode_public_key(&KeyManager::generate()))
+            .is_none());
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let (history, keys) = rotated_history();
+
+        let mut altered = history.clone();
+        altered.rotations[1].reason = Some("Compromised".to_string());
+        assert!(altered.verify().is_err());
+
+        // A rotation signed only by the new key can't claim the identity
+        let mut forged = history.clone();
+        let intruder = KeyManager::generate();
+        forged.rotations[1].public_key = encode_public_key(&intruder);
+        let data = forged.rotations[1].signable_data(&forged.identity_key);
+        forged.rotations[1].new_key_signature = intruder.sign(&data).signature;
+        assert!(forged.verify().is_err());
+
+        // Dropping a rotation breaks the chain even though each is signed
+        let mut dropped = history.clone();
+        dropped.rotations.remove(0);
+        assert!(dropped.verify().is_err());
+
+        // The chain is bound to its identity key
+        let mut rebased = history.clone();
+        rebased.identity_key = encode_public_key(&keys[1]);
+        rebased.rotations.remove(0);
+        rebased.rotations[0].sequence = 0;
+        assert!(rebased.verify().is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_or_start() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data").join(KEY_HISTORY_FILE);
+        let (history, keys) = rotated_history();
+        history.save(&path).unwrap();
+
+        let loaded = KeyHistory::load_or_start(&path, &keys[2]).unwrap();
+        assert_eq!(loaded.rotations.len(), 2);
+        assert_eq!(loaded.verify(), Ok(()));
+
+        // A key replaced outside a rotation starts over
+        let other = KeyManager::generate();
+        let restarted = KeyHistory::load_or_start(&path, &other).unwrap();
+        assert!(restarted.rotations.is_empty());
+        assert_eq!(restarted.identity_key, encode_public_key(&other));
+    }
+
+    #[test]
+    fn test_retired_key_still_anchors_older_recordings() {
+        use crate::evidence::{AnchorProof, BlockchainAnchor, EvidenceManifestBuilder, HashInfo};
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(KEY_HISTORY_FILE);
+        let keys = MemoryKeys::default();
+        let original = KeyManager::generate();
+        keys.store_key(key_ids::SIGNING_KEY, &original.to_bytes())
+            .unwrap();
+
+        // A recording signed before the rotation
+        let now = Utc::now();
+        let mut manifest = EvidenceManifestBuilder::new()
+            .session_id(uuid::Uuid::new_v4())
+            .file_path(PathBuf::from("/tmp/test.mov"))
+            .file_hash(HashInfo::from_bytes(b"test data"))
+            .file_size(9)
+            .duration(60.0)
+            .window_title("Test Window")
+            .window_id(123)
+            .app_name("Test App")
+            .app_bundle_id("com.test.app")
+            .resolution("1920x1080")
+            .frame_rate(30)
+            .codec("h264")
+            .system(
+                "macOS",
+                "14.0",
+                "test-device",
+                "test-host",
+                "1.0.0",
+                "notari",
+            )
+            .timestamps_from_dates(now, now)
+            .build()
+            .unwrap();
+        manifest.sign(&original);
+
+        rotate_key_in(&keys, &path, None).unwrap();
+        let current =
+            KeyManager::from_bytes(&keys.retrieve_key(key_ids::SIGNING_KEY).unwrap()).unwrap();
+        assert_ne!(encode_public_key(&current), manifest.signature.public_key);
+
+        // Anchoring re-signs with the key that signed the recording
+        let key_manager = find_signing_key(&keys, &manifest.signature.public_key).unwrap();
+        let hash = manifest.compute_pre_anchor_hash();
+        manifest.add_anchor(BlockchainAnchor {
+            anchored_at: now,
+            anchored_hash: hash.clone(),
+            manifest_hash: hash.clone(),
+            proof: AnchorProof::Mock {
+                hash: hash.clone(),
+                timestamp: now,
+            },
+        });
+        manifest.sign(&key_manager);
+        assert!(manifest.verify_signature().unwrap());
+        assert_eq!(manifest.signature.public_key, encode_public_key(&original));
+        assert_eq!(manifest.compute_pre_anchor_hash(), hash);
+
+        // New recordings resolve to the current key, unknown keys to nothing
+        let found = find_signing_key(&keys, &encode_public_key(&current)).unwrap();
+        assert_eq!(encode_public_key(&found), encode_public_key(&current));
+        let stranger = encode_public_key(&KeyManager::generate());
+        assert!(find_signing_key(&keys, &stranger).is_err());
+    }
+}
@@ -32,6 +32,11 @@ pub fn has_signing_key() -> bool {
     KEYCHAIN_REPO.has_key(key_ids::SIGNING_KEY).unwrap_or(false)
 }
 
+/// The keychain repository itself, for operations spanning several keys
+pub(crate) fn repository() -> &'static KeychainRepository {
+    &KEYCHAIN_REPO
+}
+
 // ============================================================================
 // Encryption Key Operations (X25519)
 // ============================================================================
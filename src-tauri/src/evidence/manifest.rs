@@ -2,7 +2,18 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::blockchain::BlockchainAnchor;
-use super::{HashInfo, SignatureInfo};
+use super::canonical;
+use super::checkpoint::CheckpointSummary;
+use super::{ChunkHashes, HashInfo, SignatureInfo};
+
+/// Current manifest format version
+///
+/// 2.0 signs and anchors the RFC 8785 canonical form of the manifest instead of
+/// serde_json's field-order output.
+pub const MANIFEST_VERSION: &str = "2.0";
+
+/// Last manifest version whose signature covers serde_json field-order output
+pub const LEGACY_MANIFEST_VERSION: &str = "1.0";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvidenceManifest {
@@ -11,11 +22,22 @@ pub struct EvidenceManifest {
     pub metadata: Metadata,
     pub system: SystemInfo,
     pub timestamps: Timestamps,
+
+    /// Signed checkpoints taken while recording, packed as `checkpoints.json`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoints: Option<CheckpointSummary>,
+
     pub signature: SignatureInfo,
 
-    /// Blockchain anchor (optional, added in v1.1)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Single blockchain anchor (v1.1 - 2.0), superseded by `anchors`
+    ///
+    /// Kept so manifests signed with it still verify; `add_anchor` moves it into `anchors`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub blockchain_anchor: Option<BlockchainAnchor>,
+
+    /// Anchors on independent chains or timestamping services
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub anchors: Vec<BlockchainAnchor>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,10 +48,16 @@ pub struct RecordingInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption: Option<EncryptionInfo>,
     pub plaintext_hash: HashInfo,
+    /// Hashes of each chunk of the plaintext video, to locate changes and check excerpts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plaintext_chunks: Option<ChunkHashes>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encrypted_hash: Option<HashInfo>,
     pub file_size_bytes: u64,
     pub duration_seconds: f64,
+    /// Finalized from a session journal after the app exited mid-recording
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub recovered: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +80,9 @@ pub struct EncryptionInfo {
     // For chunk-based encryption
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chunked: Option<ChunkedEncryptionInfo>,
+    /// Recipients added or revoked after the recording was encrypted, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipient_changes: Vec<RecipientChange>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +104,27 @@ pub struct EncryptedKey {
     pub algorithm: String,
 }
 
+/// Recipient key slot added to or removed from an encrypted recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipientChange {
+    pub changed_at: DateTime<Utc>,
+    pub action: RecipientAction,
+    pub recipient_id: String,
+    /// Recipient's public key (X25519, base64 encoded)
+    pub recipient_public_key: String,
+    /// The video was re-encrypted under a new content key, so a removed
+    /// recipient who kept the old one can't read it either
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub key_rotated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientAction {
+    Added,
+    Removed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkedEncryptionInfo {
     pub chunk_size: u64,
@@ -90,9 +142,22 @@ pub struct ChunkInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyDerivationInfo {
+    /// `PBKDF2-HMAC-SHA256` or `Argon2id`
     pub algorithm: String,
+    /// PBKDF2 iterations, or the Argon2id time cost
     pub iterations: u32,
     pub salt: String,
+    /// Argon2id memory cost in KiB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_kib: Option<u32>,
+    /// Argon2id lanes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallelism: Option<u32>,
+    /// Content key encrypted with the derived key (base64 of nonce || AES-256-GCM
+    /// ciphertext), for recordings re-keyed without re-encrypting the video.
+    /// Without it the derived key is the content key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrapped_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -143,12 +208,84 @@ pub struct Timestamps {
     pub started_at: DateTime<Utc>,
     pub stopped_at: DateTime<Utc>,
     pub manifest_created_at: DateTime<Utc>,
+    /// Wall-clock spans between `started_at` and `stopped_at` not covered by the video
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pauses: Vec<PauseInterval>,
+}
+
+/// A span during which capture was paused
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PauseInterval {
+    pub paused_at: DateTime<Utc>,
+    pub resumed_at: DateTime<Utc>,
+}
+
+impl PauseInterval {
+    /// Length of the gap
+    pub fn duration(&self) -> chrono::Duration {
+        self.resumed_at - self.paused_at
+    }
+}
+
+impl Timestamps {
+    /// Total wall-clock time spent paused
+    pub fn paused_duration(&self) -> chrono::Duration {
+        self.pauses
+            .iter()
+            .fold(chrono::Duration::zero(), |total, pause| {
+                total + pause.duration()
+            })
+    }
+
+    /// Wall-clock time actually covered by the video
+    pub fn recorded_duration(&self) -> chrono::Duration {
+        self.stopped_at - self.started_at - self.paused_duration()
+    }
+
+    /// Check that pauses are ordered, non-overlapping and within the recording window
+    pub fn validate_pauses(&self) -> Result<(), String> {
+        let mut previous_end = self.started_at;
+        for (index, pause) in self.pauses.iter().enumerate() {
+            if pause.resumed_at < pause.paused_at {
+                return Err(format!("Pause {} resumes before it starts", index));
+            }
+            if pause.paused_at < previous_end {
+                return Err(format!(
+                    "Pause {} overlaps the recording start or a previous pause",
+                    index
+                ));
+            }
+            if pause.resumed_at > self.stopped_at {
+                return Err(format!("Pause {} extends past the recording stop", index));
+            }
+            previous_end = pause.resumed_at;
+        }
+        Ok(())
+    }
 }
 
 impl EvidenceManifest {
+    /// Whether this manifest predates canonical JSON (version 1.x)
+    ///
+    /// Legacy manifests keep their original byte encoding so existing signatures
+    /// and anchors remain verifiable.
+    pub fn uses_legacy_encoding(&self) -> bool {
+        self.version.starts_with("1.")
+    }
+
+    /// Serialize the manifest to the byte form used for signing and hashing
+    fn encode(&self) -> Vec<u8> {
+        if self.uses_legacy_encoding() {
+            serde_json::to_vec(self).unwrap()
+        } else {
+            canonical::to_vec(self).unwrap()
+        }
+    }
+
     /// Get the data to be signed (everything except the signature itself)
     /// Note: blockchain_anchor IS included in the signature (if present) to provide
     /// offline verification of anchor metadata. The manifest is re-signed after anchoring.
+    /// The signer's certified identity is included too, so it can't be swapped.
     pub fn signable_data(&self) -> Vec<u8> {
         // Create a copy without signature (but keep blockchain_anchor if present)
         let mut manifest_copy = self.clone();
@@ -157,17 +294,23 @@ impl EvidenceManifest {
             public_key: String::new(),
             signature: String::new(),
             signed_data_hash: String::new(),
+            identity: self.signature.identity.clone(),
         };
         // blockchain_anchor is now included in signed data for offline verification
 
-        // Serialize to JSON (deterministic)
-        serde_json::to_vec(&manifest_copy).unwrap()
+        // Canonical JSON (RFC 8785) for 2.0+, serde_json field order for 1.0
+        manifest_copy.encode()
     }
 
     /// Sign the manifest
+    ///
+    /// A signer identity set in `signature.identity` beforehand is kept and signed.
     pub fn sign(&mut self, key_manager: &super::signature::KeyManager) {
         let data = self.signable_data();
-        self.signature = key_manager.sign(&data);
+        self.signature = SignatureInfo {
+            identity: self.signature.identity.take(),
+            ..key_manager.sign(&data)
+        };
     }
 
     /// Verify the manifest signature
@@ -180,25 +323,62 @@ impl EvidenceManifest {
         )
     }
 
-    /// Compute the pre-anchor manifest hash (manifest without blockchain_anchor field)
+    /// Compute the pre-anchor manifest hash (manifest without signature or anchors)
     /// This is used for on-chain verification, as the blockchain stores the hash of the
     /// manifest before the anchor was added.
+    ///
+    /// The signature bytes are left out as well: the manifest is re-signed after
+    /// every anchor, and every anchor has to cover the same content. The signing
+    /// key, algorithm and signer identity stay in, so a manifest re-signed by
+    /// someone else no longer matches its anchors.
     pub fn compute_pre_anchor_hash(&self) -> String {
         use sha2::{Digest, Sha256};
 
-        // Create a copy without blockchain_anchor
+        // Create a copy without signature bytes, blockchain_anchor or anchors
         let mut manifest_copy = self.clone();
+        manifest_copy.signature.signature.clear();
+        manifest_copy.signature.signed_data_hash.clear();
         manifest_copy.blockchain_anchor = None;
+        manifest_copy.anchors.clear();
 
-        // Serialize to JSON
-        let json = serde_json::to_string(&manifest_copy).unwrap();
-
-        // Compute SHA256 hash
+        // Compute SHA256 hash of the canonical encoding
         let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
+        hasher.update(manifest_copy.encode());
         format!("{:x}", hasher.finalize())
     }
 
+    /// All anchors, the legacy `blockchain_anchor` first
+    pub fn all_anchors(&self) -> impl Iterator<Item = &BlockchainAnchor> {
+        self.blockchain_anchor.iter().chain(self.anchors.iter())
+    }
+
+    /// Whether the manifest has been anchored at least once
+    pub fn is_anchored(&self) -> bool {
+        self.all_anchors().next().is_some()
+    }
+
+    /// Whether the manifest is already anchored to `service` (see `AnchorProof::service_id`)
+    pub fn is_anchored_to(&self, service: &str) -> bool {
+        self.all_anchors().any(|anchor| anchor.proof.service_id() == service)
+    }
+
+    /// Move a legacy `blockchain_anchor` to the front of `anchors`
+    ///
+    /// Changes the signed data, so the manifest must be re-signed afterwards.
+    pub fn migrate_legacy_anchor(&mut self) {
+        if let Some(anchor) = self.blockchain_anchor.take() {
+            self.anchors.insert(0, anchor);
+        }
+    }
+
+    /// Add an anchor, migrating any legacy anchor first
+    ///
+    /// The manifest must be re-signed afterwards.
+    pub fn add_anchor(&mut self, anchor: BlockchainAnchor) {
+        self.migrate_legacy_anchor();
+        self.anchors.push(anchor);
+    }
+
     /// Save manifest to JSON file
     pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
         let json = serde_json::to_string_pretty(self)?;
@@ -252,7 +432,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(manifest.version, "1.0");
+        assert_eq!(manifest.version, MANIFEST_VERSION);
         assert_eq!(manifest.recording.session_id, session_id.to_string());
     }
 
@@ -358,4 +538,138 @@ mod tests {
             "Signature should be valid after re-signing with blockchain anchor"
         );
     }
+
+    fn build_test_manifest() -> EvidenceManifest {
+        use crate::evidence::EvidenceManifestBuilder;
+
+        let now = Utc::now();
+        EvidenceManifestBuilder::new()
+            .session_id(Uuid::new_v4())
+            .file_path(PathBuf::from("/tmp/test.mov"))
+            .file_hash(HashInfo {
+                algorithm: "SHA-256".to_string(),
+                value: "abc123".to_string(),
+            })
+            .file_size(1024)
+            .duration(60.5)
+            .window_title("Test Window")
+            .window_id(123)
+            .app_name("Test App")
+            .app_bundle_id("com.test.app")
+            .resolution("1920x1080")
+            .frame_rate(30)
+            .codec("H.264")
+            .system("macOS", "14.0", "test-device", "test-host", "1.0.0", "notari")
+            .timestamps_from_dates(now, now)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signature_survives_reserialization() {
+        let mut manifest = build_test_manifest();
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+
+        // Round-trip through pretty JSON and a generic Value with keys in another order
+        let pretty = serde_json::to_string_pretty(&manifest).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        let reordered = canonical::canonicalize(&value);
+        let reloaded: EvidenceManifest = serde_json::from_str(&reordered).unwrap();
+
+        assert!(reloaded.verify_signature().unwrap());
+        assert_eq!(
+            reloaded.compute_pre_anchor_hash(),
+            manifest.compute_pre_anchor_hash()
+        );
+    }
+
+    #[test]
+    fn test_signable_data_is_canonical() {
+        let manifest = build_test_manifest();
+        let data = manifest.signable_data();
+
+        let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(canonical::canonicalize(&value).into_bytes(), data);
+    }
+
+    #[test]
+    fn test_legacy_manifest_still_verifies() {
+        let mut manifest = build_test_manifest();
+        manifest.version = LEGACY_MANIFEST_VERSION.to_string();
+        assert!(manifest.uses_legacy_encoding());
+
+        // Sign the way 1.0 manifests were signed: serde_json field order
+        let key_manager = KeyManager::generate();
+        let mut unsigned = manifest.clone();
+        unsigned.signature = SignatureInfo {
+            algorithm: String::new(),
+            public_key: String::new(),
+            signature: String::new(),
+            signed_data_hash: String::new(),
+            identity: None,
+        };
+        let legacy_bytes = serde_json::to_vec(&unsigned).unwrap();
+        manifest.signature = key_manager.sign(&legacy_bytes);
+
+        assert_eq!(manifest.signable_data(), legacy_bytes);
+        assert!(manifest.verify_signature().unwrap());
+    }
+
+    fn timestamps_with_pauses(pauses: &[(i64, i64)]) -> Timestamps {
+        let start = Utc::now() - chrono::Duration::seconds(100);
+        let at = |secs: i64| start + chrono::Duration::seconds(secs);
+        Timestamps {
+            started_at: start,
+            stopped_at: at(60),
+            manifest_created_at: at(61),
+            pauses: pauses
+                .iter()
+                .map(|&(paused, resumed)| PauseInterval {
+                    paused_at: at(paused),
+                    resumed_at: at(resumed),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_pause_accounting() {
+        let timestamps = timestamps_with_pauses(&[(10, 15), (30, 40)]);
+        assert!(timestamps.validate_pauses().is_ok());
+        assert_eq!(timestamps.paused_duration().num_seconds(), 15);
+        assert_eq!(timestamps.recorded_duration().num_seconds(), 45);
+    }
+
+    #[test]
+    fn test_invalid_pauses_rejected() {
+        for pauses in [
+            [(10, 5), (20, 25)],  // resumes before pausing
+            [(10, 20), (15, 25)], // overlapping
+            [(30, 40), (10, 20)], // out of order
+            [(10, 20), (50, 70)], // past the stop
+        ] {
+            assert!(
+                timestamps_with_pauses(&pauses).validate_pauses().is_err(),
+                "{:?} should be rejected",
+                pauses
+            );
+        }
+        // Starts before the recording
+        let early = timestamps_with_pauses(&[(-5, 5)]);
+        assert!(early.validate_pauses().is_err());
+    }
+
+    #[test]
+    fn test_pauses_omitted_when_empty() {
+        let manifest = build_test_manifest();
+        let json = serde_json::to_value(&manifest).unwrap();
+        assert!(json["timestamps"].get("pauses").is_none());
+
+        let mut paused = manifest.clone();
+        paused.timestamps = timestamps_with_pauses(&[(10, 15)]);
+        let json = serde_json::to_string(&paused).unwrap();
+        let reloaded: EvidenceManifest = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.timestamps.pauses, paused.timestamps.pauses);
+    }
 }
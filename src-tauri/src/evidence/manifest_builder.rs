@@ -1,8 +1,8 @@
 use super::manifest::{
     CustomMetadata, EncryptionInfo, EvidenceManifest, Metadata, RecordingInfo, SystemInfo,
-    Timestamps, VideoInfo, WindowInfo,
+    Timestamps, VideoInfo, WindowInfo, MANIFEST_VERSION,
 };
-use super::{BlockchainAnchor, HashInfo, SignatureInfo};
+use super::{BlockchainAnchor, CheckpointSummary, ChunkHashes, HashInfo, SignatureInfo};
 use crate::error::{NotariError, NotariResult};
 use chrono::{DateTime, Utc};
 use std::path::PathBuf;
@@ -43,11 +43,13 @@ pub struct EvidenceManifestBuilder {
     session_id: Option<Uuid>,
     file_path: Option<PathBuf>,
     file_hash: Option<HashInfo>,
+    file_chunks: Option<ChunkHashes>,
     file_size: Option<u64>,
     duration: Option<f64>,
     encrypted: bool,
     encryption_info: Option<EncryptionInfo>,
     encrypted_hash: Option<HashInfo>,
+    recovered: bool,
 
     // Window metadata
     window_title: Option<String>,
@@ -70,9 +72,10 @@ pub struct EvidenceManifestBuilder {
 
     // Timestamps
     timestamps: Option<Timestamps>,
+    checkpoints: Option<CheckpointSummary>,
 
     // Optional fields
-    blockchain_anchor: Option<BlockchainAnchor>,
+    anchors: Vec<BlockchainAnchor>,
 }
 
 impl EvidenceManifestBuilder {
@@ -82,11 +85,13 @@ impl EvidenceManifestBuilder {
             session_id: None,
             file_path: None,
             file_hash: None,
+            file_chunks: None,
             file_size: None,
             duration: None,
             encrypted: false,
             encryption_info: None,
             encrypted_hash: None,
+            recovered: false,
             window_title: None,
             window_id: None,
             app_name: None,
@@ -99,7 +104,8 @@ impl EvidenceManifestBuilder {
             custom_tags: None,
             system_info: None,
             timestamps: None,
-            blockchain_anchor: None,
+            checkpoints: None,
+            anchors: Vec::new(),
         }
     }
 
@@ -119,6 +125,12 @@ impl EvidenceManifestBuilder {
         self
     }
 
+    /// Per-chunk hashes of the plaintext video
+    pub fn file_chunks(mut self, chunks: ChunkHashes) -> Self {
+        self.file_chunks = Some(chunks);
+        self
+    }
+
     pub fn file_size(mut self, size: u64) -> Self {
         self.file_size = Some(size);
         self
@@ -145,6 +157,12 @@ impl EvidenceManifestBuilder {
         self
     }
 
+    /// Mark the recording as recovered from an interrupted session
+    pub fn recovered(mut self, recovered: bool) -> Self {
+        self.recovered = recovered;
+        self
+    }
+
     // Window metadata methods
     pub fn window_title(mut self, title: impl Into<String>) -> Self {
         self.window_title = Some(title.into());
@@ -248,13 +266,20 @@ impl EvidenceManifestBuilder {
             started_at,
             stopped_at,
             manifest_created_at: Utc::now(),
+            pauses: Vec::new(),
         });
         self
     }
 
+    /// Commit to the checkpoint log taken while recording
+    pub fn checkpoints(mut self, summary: CheckpointSummary) -> Self {
+        self.checkpoints = Some(summary);
+        self
+    }
+
     // Optional fields
-    pub fn blockchain_anchor(mut self, anchor: BlockchainAnchor) -> Self {
-        self.blockchain_anchor = Some(anchor);
+    pub fn anchor(mut self, anchor: BlockchainAnchor) -> Self {
+        self.anchors.push(anchor);
         self
     }
 
@@ -314,6 +339,9 @@ impl EvidenceManifestBuilder {
         let timestamps = self
             .timestamps
             .ok_or_else(|| NotariError::BuilderError("timestamps is required".to_string()))?;
+        timestamps
+            .validate_pauses()
+            .map_err(NotariError::BuilderError)?;
 
         // Validate encryption consistency
         if self.encrypted && self.encryption_info.is_none() {
@@ -337,16 +365,18 @@ impl EvidenceManifestBuilder {
         };
 
         Ok(EvidenceManifest {
-            version: "1.0".to_string(),
+            version: MANIFEST_VERSION.to_string(),
             recording: RecordingInfo {
                 session_id: session_id.to_string(),
                 file_path: file_path.to_string_lossy().to_string(),
                 encrypted: self.encrypted,
                 encryption: self.encryption_info,
                 plaintext_hash: file_hash,
+                plaintext_chunks: self.file_chunks,
                 encrypted_hash: self.encrypted_hash,
                 file_size_bytes: file_size,
                 duration_seconds: duration,
+                recovered: self.recovered,
             },
             metadata: Metadata {
                 window: WindowInfo {
@@ -364,13 +394,16 @@ impl EvidenceManifestBuilder {
             },
             system,
             timestamps,
+            checkpoints: self.checkpoints,
             signature: SignatureInfo {
                 algorithm: String::new(),
                 public_key: String::new(),
                 signature: String::new(),
                 signed_data_hash: String::new(),
+                identity: None,
             },
-            blockchain_anchor: self.blockchain_anchor,
+            blockchain_anchor: None,
+            anchors: self.anchors,
         })
     }
 }
@@ -402,6 +435,7 @@ mod tests {
             started_at: now,
             stopped_at: now,
             manifest_created_at: now,
+            pauses: Vec::new(),
         }
     }
 
@@ -1,29 +1,44 @@
 pub mod blockchain;
+pub mod canonical;
+pub mod checkpoint;
+pub mod der;
 pub mod encryption;
 pub mod encryption_keys;
 pub mod hash;
+pub mod key_history;
 pub mod keychain;
 pub mod manifest;
 pub mod manifest_builder;
+pub mod pack_index;
 pub mod proof_pack;
 pub mod signature;
+pub mod signer_certificate;
+pub mod trusted_signers;
 pub mod verification;
+pub mod x509;
 
 pub use blockchain::{
     AnchorProof, BlockchainAnchor, BlockchainAnchorer, BlockchainAnchorerFactory, BlockchainConfig,
     BlockchainConfigBuilder, BlockchainEnvironment, ChainConfig, EthereumAnchorer, MockAnchorer,
-    WalletConfig, WalletManager,
+    OpenTimestampsAnchorer, OpenTimestampsConfig, TsaAnchorer, TsaConfig, WalletConfig,
+    WalletManager,
 };
-pub use encryption::{validate_password, VideoEncryptor};
+pub use checkpoint::{Checkpoint, CheckpointLog, CheckpointSummary};
+pub use encryption::{validate_password, ContentKey, VideoEncryptor};
 pub use encryption_keys::EncryptionKeyManager;
-pub use hash::HashInfo;
+pub use hash::{ChunkHashes, HashInfo};
+pub use key_history::{KeyHistory, KeyRotation};
 pub use manifest::{
     CustomMetadata, EncryptedKey, EncryptionInfo, EvidenceManifest, KeyDerivationInfo, Metadata,
-    RecordingInfo, SystemInfo, Timestamps, VideoInfo, WindowInfo,
+    PauseInterval, RecipientAction, RecipientChange, RecordingInfo, SystemInfo, Timestamps,
+    VideoInfo, WindowInfo, LEGACY_MANIFEST_VERSION, MANIFEST_VERSION,
 };
 pub use manifest_builder::EvidenceManifestBuilder;
-pub use signature::{KeyManager, SignatureInfo};
+pub use pack_index::{IndexEntry, ProofPackIndex, LEGACY_PROOF_PACK_VERSION, PROOF_PACK_VERSION};
+pub use signature::{KeyManager, SignatureInfo, SignerIdentity};
+pub use trusted_signers::TrustedSigner;
 pub use verification::{
-    BlockchainAnchorCheck, CheckResult, RecordingInfoSummary, SignatureInfoSummary,
+    BlockchainAnchorCheck, CheckResult, CheckpointCheck, ChunkHashCheck, ExcerptReport,
+    KeyHistoryCheck, ProofPackCheck, RecordingInfoSummary, SignatureInfoSummary,
     VerificationChecks, VerificationInfo, VerificationReport, VerificationStatus, Verifier,
 };
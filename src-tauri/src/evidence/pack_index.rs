@@ -0,0 +1,172 @@
+//! Signed file index for proof pack format 2.0
+//!
+//! `index.json` lists every other entry of the archive with its SHA-256 and size,
+//! and is signed with the same Ed25519 key as the manifest. This covers files the
+//! manifest signature does not (README.txt, metadata.json, public_key.txt).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::canonical;
+use super::signature::{KeyManager, SignatureInfo};
+use crate::error::NotariResult;
+
+/// Archive entry name of the index
+pub const INDEX_FILENAME: &str = "index.json";
+
+/// Current proof pack layout version (signed index)
+pub const PROOF_PACK_VERSION: &str = "2.0";
+
+/// Proof pack layout without an index
+pub const LEGACY_PROOF_PACK_VERSION: &str = "1.0";
+
+/// Hash and size of a single archive entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Entry name inside the archive (e.g. "evidence/recording.mov")
+    pub path: String,
+    /// SHA-256 of the uncompressed contents (hex)
+    pub sha256: String,
+    /// Uncompressed size in bytes
+    pub size: u64,
+}
+
+impl IndexEntry {
+    /// Build an entry for in-memory contents
+    pub fn from_bytes(path: impl Into<String>, data: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        Self {
+            path: path.into(),
+            sha256: format!("{:x}", hasher.finalize()),
+            size: data.len() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofPackIndex {
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    pub files: Vec<IndexEntry>,
+    pub signature: SignatureInfo,
+}
+
+impl ProofPackIndex {
+    /// Create an unsigned index (entries are sorted by path)
+    pub fn new(mut files: Vec<IndexEntry>) -> Self {
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Self {
+            version: PROOF_PACK_VERSION.to_string(),
+            created_at: Utc::now(),
+            files,
+            signature: SignatureInfo {
+                algorithm: String::new(),
+                public_key: String::new(),
+                signature: String::new(),
+                signed_data_hash: String::new(),
+                identity: None,
+            },
+        }
+    }
+
+    /// Get the data to be signed: canonical JSON of the index without its signature
+    pub fn signable_data(&self) -> Vec<u8> {
+        let mut index_copy = self.clone();
+        index_copy.signature = SignatureInfo {
+            algorithm: String::new(),
+            public_key: String::new(),
+            signature: String::new(),
+            signed_data_hash: String::new(),
+            identity: None,
+        };
+        canonical::to_vec(&index_copy).unwrap()
+    }
+
+    /// Sign the index
+    pub fn sign(&mut self, key_manager: &KeyManager) {
+        let data = self.signable_data();
+        self.signature = key_manager.sign(&data);
+    }
+
+    /// Verify the index signature
+    pub fn verify_signature(&self) -> NotariResult<bool> {
+        let data = self.signable_data();
+        KeyManager::verify(&self.signature.public_key, &self.signature.signature, &data)
+    }
+
+    /// Look up the entry for an archive path
+    pub fn entry(&self, path: &str) -> Option<&IndexEntry> {
+        self.files.iter().find(|entry| entry.path == path)
+    }
+}
+
+/// Result of checking a proof pack against its index
+#[derive(Debug, Clone)]
+pub struct IndexVerification {
+    /// The index, or `None` for a 1.0 pack without one
+    pub index: Option<ProofPackIndex>,
+    /// Whether the index signature is valid
+    pub signature_valid: bool,
+    /// Entries present in the archive but not listed in the index
+    pub unlisted_files: Vec<String>,
+    /// Entries whose hash or size differs from the index
+    pub modified_files: Vec<String>,
+    /// Entries listed in the index but absent from the archive
+    pub missing_files: Vec<String>,
+}
+
+impl IndexVerification {
+    /// Whether every file is accounted for and unmodified
+    pub fn files_intact(&self) -> bool {
+        self.unlisted_files.is_empty()
+            && self.modified_files.is_empty()
+            && self.missing_files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_index() -> ProofPackIndex {
+        ProofPackIndex::new(vec![
+            IndexEntry::from_bytes("metadata.json", b"{}"),
+            IndexEntry::from_bytes("evidence/test.mov", b"video"),
+        ])
+    }
+
+    #[test]
+    fn test_entries_sorted_and_hashed() {
+        let index = sample_index();
+        assert_eq!(index.files[0].path, "evidence/test.mov");
+        assert_eq!(index.files[1].path, "metadata.json");
+        assert_eq!(index.files[0].size, 5);
+        assert_eq!(
+            index.entry("metadata.json").unwrap().sha256,
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+        );
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut index = sample_index();
+        index.sign(&KeyManager::generate());
+        assert!(index.verify_signature().unwrap());
+
+        // Survives a JSON round trip
+        let json = serde_json::to_string_pretty(&index).unwrap();
+        let reloaded: ProofPackIndex = serde_json::from_str(&json).unwrap();
+        assert!(reloaded.verify_signature().unwrap());
+    }
+
+    #[test]
+    fn test_tampered_index_fails_verification() {
+        let mut index = sample_index();
+        index.sign(&KeyManager::generate());
+
+        index.files[0].size += 1;
+        assert!(!index.verify_signature().unwrap());
+    }
+}
@@ -1,13 +1,19 @@
+use sha2::{Digest, Sha256};
 use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::io::{self, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 use zip::write::FileOptions;
 use zip::ZipWriter;
 
+use super::checkpoint::{CHECKPOINT_LOG_FILE, SEGMENTS_DIR};
+use super::key_history::KEY_HISTORY_FILE;
 use super::keychain;
 use super::manifest::EvidenceManifest;
+use super::pack_index::{
+    IndexEntry, IndexVerification, ProofPackIndex, INDEX_FILENAME, PROOF_PACK_VERSION,
+};
 use super::signature::KeyManager;
-use crate::error::{NotariError, NotariResult};
+use crate::error::{NotariError, NotariResult, ProofPackRejection};
 
 /// Proof pack metadata
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -31,31 +37,114 @@ pub fn create_proof_pack<P: AsRef<Path>>(
     manifest_path: P,
     output_path: P,
 ) -> NotariResult<PathBuf> {
-    let video_path = video_path.as_ref();
-    let manifest_path = manifest_path.as_ref();
-    let output_path = output_path.as_ref();
-
-    // Load manifest to get metadata
-    let manifest = EvidenceManifest::load(manifest_path)?;
+    create_proof_pack_with_progress(
+        video_path,
+        manifest_path,
+        output_path,
+        None,
+        &[],
+        None,
+        |_, _| {},
+    )
+}
 
-    // Get public key from keychain
-    let public_key = if let Ok(key_bytes) = keychain::retrieve_signing_key() {
-        let key_manager = KeyManager::from_bytes(&key_bytes)?;
-        let public_key_bytes = key_manager.public_key();
-        use base64::{engine::general_purpose, Engine as _};
-        general_purpose::STANDARD.encode(public_key_bytes.as_bytes())
+/// Create a proof pack, reporting `(bytes_written, total_bytes)` as entries are streamed
+///
+/// The video is copied into the archive in fixed-size chunks and stored uncompressed
+/// (recordings are already compressed), so memory use does not grow with file size.
+/// `checkpoint_log` is packed as `checkpoints.json` and `key_history` as
+/// `key_history.json` if given. `segments`, the files the video was joined from,
+/// are packed under `segments/` unless the recording is encrypted.
+pub fn create_proof_pack_with_progress<P, F>(
+    video_path: P,
+    manifest_path: P,
+    output_path: P,
+    checkpoint_log: Option<&Path>,
+    segments: &[PathBuf],
+    key_history: Option<&Path>,
+    on_progress: F,
+) -> NotariResult<PathBuf>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    // Get signing key from keychain (signs the file index)
+    let key_manager = if let Ok(key_bytes) = keychain::retrieve_signing_key() {
+        KeyManager::from_bytes(&key_bytes)?
     } else {
         return Err(NotariError::NoSigningKey(
             "No signing key found in keychain".to_string(),
         ));
     };
 
+    write_proof_pack(
+        video_path.as_ref(),
+        manifest_path.as_ref(),
+        output_path.as_ref(),
+        checkpoint_log,
+        segments,
+        key_history,
+        &key_manager,
+        on_progress,
+    )
+}
+
+/// Write the proof pack archive, signing its file index with `key_manager`
+#[allow(clippy::too_many_arguments)]
+fn write_proof_pack<F: FnMut(u64, u64)>(
+    video_path: &Path,
+    manifest_path: &Path,
+    output_path: &Path,
+    checkpoint_log: Option<&Path>,
+    segments: &[PathBuf],
+    key_history: Option<&Path>,
+    key_manager: &KeyManager,
+    mut on_progress: F,
+) -> NotariResult<PathBuf> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    // Load manifest to get metadata
+    let manifest = EvidenceManifest::load(manifest_path)?;
+    let public_key = general_purpose::STANDARD.encode(key_manager.public_key().as_bytes());
+
+    let video_size = fs::metadata(video_path)?.len();
+    let manifest_size = fs::metadata(manifest_path)?.len();
+    let checkpoint_log_size = match checkpoint_log {
+        Some(path) => fs::metadata(path)?.len(),
+        None => 0,
+    };
+    // The segments aren't encrypted, so they'd give away an encrypted video
+    let segments: &[PathBuf] = if manifest.recording.encrypted {
+        &[]
+    } else {
+        segments
+    };
+    let segment_sizes = segments
+        .iter()
+        .map(|path| Ok(fs::metadata(path)?.len()))
+        .collect::<NotariResult<Vec<u64>>>()?;
+    let key_history_size = match key_history {
+        Some(path) => fs::metadata(path)?.len(),
+        None => 0,
+    };
+    let total_bytes = video_size
+        + manifest_size
+        + checkpoint_log_size
+        + segment_sizes.iter().sum::<u64>()
+        + key_history_size;
+    let mut progress = ProgressTracker::new(total_bytes);
+
+    // Every entry written is recorded here and signed into index.json at the end
+    let mut index_entries = Vec::new();
+
     // Create ZIP file
-    let file = File::create(output_path)?;
+    let file = BufWriter::new(File::create(output_path)?);
     let mut zip = ZipWriter::new(file);
     let options: FileOptions<()> = FileOptions::default()
         .compression_method(zip::CompressionMethod::Deflated)
         .unix_permissions(0o644);
+    // Video data is already compressed; deflating it only costs CPU
+    let video_options = stored_options(video_size);
 
     // Add video file
     let video_filename = video_path
@@ -65,11 +154,14 @@ pub fn create_proof_pack<P: AsRef<Path>>(
             NotariError::ProofPackCreationFailed("Invalid video filename".to_string())
         })?;
 
-    zip.start_file(format!("evidence/{}", video_filename), options)?;
-    let mut video_file = File::open(video_path)?;
-    let mut video_buffer = Vec::new();
-    video_file.read_to_end(&mut video_buffer)?;
-    zip.write_all(&video_buffer)?;
+    let video_entry = format!("evidence/{}", video_filename);
+    zip.start_file(video_entry.as_str(), video_options)?;
+    index_entries.push(copy_hashed(
+        &video_entry,
+        File::open(video_path)?,
+        &mut zip,
+        |n| progress.advance(n, &mut on_progress),
+    )?);
 
     // Add manifest
     let manifest_filename = manifest_path
@@ -79,24 +171,78 @@ pub fn create_proof_pack<P: AsRef<Path>>(
             NotariError::ProofPackCreationFailed("Invalid manifest filename".to_string())
         })?;
 
-    zip.start_file(format!("evidence/{}", manifest_filename), options)?;
-    let mut manifest_file = File::open(manifest_path)?;
-    let mut manifest_buffer = Vec::new();
-    manifest_file.read_to_end(&mut manifest_buffer)?;
-    zip.write_all(&manifest_buffer)?;
+    let manifest_entry = format!("evidence/{}", manifest_filename);
+    zip.start_file(manifest_entry.as_str(), options)?;
+    index_entries.push(copy_hashed(
+        &manifest_entry,
+        File::open(manifest_path)?,
+        &mut zip,
+        |n| progress.advance(n, &mut on_progress),
+    )?);
 
     // Add public key
-    zip.start_file("evidence/public_key.txt", options)?;
-    zip.write_all(public_key.as_bytes())?;
+    write_indexed(
+        &mut zip,
+        &mut index_entries,
+        "evidence/public_key.txt",
+        public_key.as_bytes(),
+        options,
+    )?;
 
     // Add README
     let readme = generate_readme(&manifest, &public_key);
-    zip.start_file("README.txt", options)?;
-    zip.write_all(readme.as_bytes())?;
+    write_indexed(
+        &mut zip,
+        &mut index_entries,
+        "README.txt",
+        readme.as_bytes(),
+        options,
+    )?;
+
+    // Add the checkpoints taken while recording
+    if let Some(checkpoint_log) = checkpoint_log {
+        zip.start_file(CHECKPOINT_LOG_FILE, options)?;
+        index_entries.push(copy_hashed(
+            CHECKPOINT_LOG_FILE,
+            File::open(checkpoint_log)?,
+            &mut zip,
+            |n| progress.advance(n, &mut on_progress),
+        )?);
+    }
+
+    // Add the segments the video was joined from; remuxing changed their bytes,
+    // so these are what the checkpoints can be matched against
+    for (segment, &size) in segments.iter().zip(&segment_sizes) {
+        let segment_filename = segment
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| {
+                NotariError::ProofPackCreationFailed("Invalid segment filename".to_string())
+            })?;
+        let segment_entry = format!("{}/{}", SEGMENTS_DIR, segment_filename);
+        zip.start_file(segment_entry.as_str(), stored_options(size))?;
+        index_entries.push(copy_hashed(
+            &segment_entry,
+            File::open(segment)?,
+            &mut zip,
+            |n| progress.advance(n, &mut on_progress),
+        )?);
+    }
+
+    // Add the rotations linking the signing key to its identity key
+    if let Some(key_history) = key_history {
+        zip.start_file(KEY_HISTORY_FILE, options)?;
+        index_entries.push(copy_hashed(
+            KEY_HISTORY_FILE,
+            File::open(key_history)?,
+            &mut zip,
+            |n| progress.advance(n, &mut on_progress),
+        )?);
+    }
 
     // Add metadata (including custom metadata from manifest)
     let metadata = ProofPackMetadata {
-        version: "1.0".to_string(),
+        version: PROOF_PACK_VERSION.to_string(),
         created_at: chrono::Utc::now().to_rfc3339(),
         notari_version: env!("CARGO_PKG_VERSION").to_string(),
         recording_filename: video_filename.to_string(),
@@ -118,71 +264,809 @@ pub fn create_proof_pack<P: AsRef<Path>>(
             .and_then(|c| c.tags.clone()),
     };
 
-    zip.start_file("metadata.json", options)?;
     let metadata_json = serde_json::to_string_pretty(&metadata)?;
-    zip.write_all(metadata_json.as_bytes())?;
+    write_indexed(
+        &mut zip,
+        &mut index_entries,
+        "metadata.json",
+        metadata_json.as_bytes(),
+        options,
+    )?;
+
+    // Add signed index of everything above
+    write_index(&mut zip, index_entries, key_manager, options)?;
 
     // Finish ZIP
-    zip.finish()?;
+    zip.finish()?.flush()?;
+    progress.finish(&mut on_progress);
 
     Ok(output_path.to_path_buf())
 }
 
+/// Write an in-memory entry and record it for the index
+fn write_indexed<W: Write + io::Seek>(
+    zip: &mut ZipWriter<W>,
+    index_entries: &mut Vec<IndexEntry>,
+    name: &str,
+    data: &[u8],
+    options: FileOptions<()>,
+) -> NotariResult<()> {
+    zip.start_file(name, options)?;
+    zip.write_all(data)?;
+    index_entries.push(IndexEntry::from_bytes(name, data));
+    Ok(())
+}
+
+/// Sign the index over `index_entries` and write it as the last entry
+fn write_index<W: Write + io::Seek>(
+    zip: &mut ZipWriter<W>,
+    index_entries: Vec<IndexEntry>,
+    key_manager: &KeyManager,
+    options: FileOptions<()>,
+) -> NotariResult<()> {
+    let mut index = ProofPackIndex::new(index_entries);
+    index.sign(key_manager);
+
+    zip.start_file(INDEX_FILENAME, options)?;
+    zip.write_all(serde_json::to_string_pretty(&index)?.as_bytes())?;
+    Ok(())
+}
+
+/// Stream `reader` into `writer`, hashing it on the way, and return its index entry
+fn copy_hashed<R: Read, W: Write, F: FnMut(u64)>(
+    name: &str,
+    reader: R,
+    writer: &mut W,
+    mut on_read: F,
+) -> io::Result<IndexEntry> {
+    let mut hasher = Sha256::new();
+    let mut reader = InspectReader::new(reader, |chunk: &[u8]| {
+        hasher.update(chunk);
+        on_read(chunk.len() as u64);
+    });
+    let size = io::copy(&mut reader, writer)?;
+    drop(reader);
+
+    Ok(IndexEntry {
+        path: name.to_string(),
+        sha256: format!("{:x}", hasher.finalize()),
+        size,
+    })
+}
+
+/// Entries at or above this size need ZIP64 headers
+const ZIP64_THRESHOLD: u64 = u32::MAX as u64;
+
+/// Options for an uncompressed entry of `size` bytes
+fn stored_options(size: u64) -> FileOptions<'static, ()> {
+    FileOptions::default()
+        .compression_method(zip::CompressionMethod::Stored)
+        .unix_permissions(0o644)
+        .large_file(size >= ZIP64_THRESHOLD)
+}
+
+/// Turns byte counts into throttled progress callbacks (at most one per percent)
+struct ProgressTracker {
+    processed: u64,
+    total: u64,
+    last_percent: Option<u64>,
+}
+
+impl ProgressTracker {
+    fn new(total: u64) -> Self {
+        Self {
+            processed: 0,
+            total,
+            last_percent: None,
+        }
+    }
+
+    fn advance<F: FnMut(u64, u64)>(&mut self, bytes: u64, on_progress: &mut F) {
+        self.processed += bytes;
+        let percent = if self.total == 0 {
+            100
+        } else {
+            self.processed.min(self.total) * 100 / self.total
+        };
+        if self.last_percent != Some(percent) {
+            self.last_percent = Some(percent);
+            on_progress(self.processed.min(self.total), self.total);
+        }
+    }
+
+    fn finish<F: FnMut(u64, u64)>(&mut self, on_progress: &mut F) {
+        if self.last_percent != Some(100) {
+            self.last_percent = Some(100);
+            on_progress(self.total, self.total);
+        }
+    }
+}
+
+/// `Read` adapter that hands every chunk read through it to a callback
+struct InspectReader<R, F> {
+    inner: R,
+    on_read: F,
+}
+
+impl<R: Read, F: FnMut(&[u8])> InspectReader<R, F> {
+    fn new(inner: R, on_read: F) -> Self {
+        Self { inner, on_read }
+    }
+}
+
+impl<R: Read, F: FnMut(&[u8])> Read for InspectReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        (self.on_read)(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Video file extensions accepted under `evidence/`
+const VIDEO_EXTENSIONS: &[&str] = &[".mov", ".mov.enc"];
+
+/// Size and count limits applied when extracting an untrusted proof pack
+#[derive(Debug, Clone, Copy)]
+pub struct ExtractionLimits {
+    /// Maximum number of entries in the archive, not counting segments
+    pub max_entries: usize,
+    /// Maximum number of segments packed under `segments/`
+    pub max_segments: usize,
+    /// Maximum uncompressed size of the video entry, and of each segment
+    pub max_video_size: u64,
+    /// Maximum uncompressed size of any other entry (manifest, README, ...)
+    pub max_file_size: u64,
+    /// Maximum uncompressed size of all entries combined
+    pub max_total_size: u64,
+}
+
+impl Default for ExtractionLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: 64,
+            // Eleven hours of ten-second segments
+            max_segments: 4096,
+            max_video_size: 64 * 1024 * 1024 * 1024,
+            max_file_size: 16 * 1024 * 1024,
+            // The video, the segments it was joined from, and the rest
+            max_total_size: 2 * 64 * 1024 * 1024 * 1024 + 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// Role of an archive entry in the proof pack layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Directory,
+    Video,
+    Manifest,
+    PublicKey,
+    Readme,
+    Metadata,
+    Index,
+    Checkpoints,
+    Segment,
+    KeyHistory,
+}
+
+/// Entry that passed validation and may be written to disk
+struct ValidatedEntry {
+    index: usize,
+    name: String,
+    relative_path: PathBuf,
+    kind: EntryKind,
+    size: u64,
+}
+
+/// Map an entry name onto the expected proof pack layout (`None` = not allowed)
+fn classify_entry(name: &str) -> Option<EntryKind> {
+    match name {
+        "evidence/" | "segments/" => Some(EntryKind::Directory),
+        "evidence/public_key.txt" => Some(EntryKind::PublicKey),
+        "README.txt" => Some(EntryKind::Readme),
+        "metadata.json" => Some(EntryKind::Metadata),
+        INDEX_FILENAME => Some(EntryKind::Index),
+        CHECKPOINT_LOG_FILE => Some(EntryKind::Checkpoints),
+        KEY_HISTORY_FILE => Some(EntryKind::KeyHistory),
+        _ => {
+            // Files live directly under evidence/ or segments/, no nesting or hidden files
+            let (dir, file_name) = name.split_once('/')?;
+            if file_name.is_empty()
+                || file_name.starts_with('.')
+                || file_name.contains('/')
+                || file_name.contains('\\')
+            {
+                return None;
+            }
+
+            let has_stem = |ext: &str| file_name.len() > ext.len() && file_name.ends_with(ext);
+            if dir == SEGMENTS_DIR {
+                return has_stem(".mov").then_some(EntryKind::Segment);
+            }
+            if dir != "evidence" {
+                return None;
+            }
+            if VIDEO_EXTENSIONS.iter().any(|ext| has_stem(ext)) {
+                Some(EntryKind::Video)
+            } else if has_stem(".json") {
+                Some(EntryKind::Manifest)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Check every entry against the layout whitelist and limits without extracting anything
+fn validate_entries<R: Read + io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    limits: &ExtractionLimits,
+) -> NotariResult<Vec<ValidatedEntry>> {
+    if archive.len() > limits.max_entries + limits.max_segments {
+        return Err(ProofPackRejection::TooManyEntries {
+            count: archive.len(),
+            limit: limits.max_entries + limits.max_segments,
+        }
+        .into());
+    }
+
+    let mut entries: Vec<ValidatedEntry> = Vec::with_capacity(archive.len());
+    let mut total_size: u64 = 0;
+    let mut segment_count = 0;
+
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index).map_err(|e| {
+            NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
+        })?;
+        let name = file.name().to_string();
+
+        if file.is_symlink() {
+            return Err(ProofPackRejection::Symlink(name).into());
+        }
+        let relative_path = file
+            .enclosed_name()
+            .ok_or_else(|| ProofPackRejection::UnsafePath(name.clone()))?;
+        let kind = classify_entry(&name)
+            .ok_or_else(|| ProofPackRejection::UnexpectedEntry(name.clone()))?;
+        if (kind == EntryKind::Directory) != file.is_dir() {
+            return Err(ProofPackRejection::UnexpectedEntry(name).into());
+        }
+        if entries.iter().any(|entry| entry.name == name) {
+            return Err(ProofPackRejection::DuplicateEntry(name).into());
+        }
+        if kind == EntryKind::Segment {
+            segment_count += 1;
+        }
+        let (count, limit) = if kind == EntryKind::Segment {
+            (segment_count, limits.max_segments)
+        } else {
+            (entries.len() + 1 - segment_count, limits.max_entries)
+        };
+        if count > limit {
+            return Err(ProofPackRejection::TooManyEntries { count, limit }.into());
+        }
+        if entries.iter().any(|entry| entry.kind == kind) {
+            match kind {
+                EntryKind::Video => return Err(ProofPackRejection::MultipleVideos(name).into()),
+                EntryKind::Manifest => {
+                    return Err(ProofPackRejection::MultipleManifests(name).into())
+                }
+                _ => {}
+            }
+        }
+
+        let size = file.size();
+        let limit = if matches!(kind, EntryKind::Video | EntryKind::Segment) {
+            limits.max_video_size
+        } else {
+            limits.max_file_size
+        };
+        if size > limit {
+            return Err(ProofPackRejection::EntryTooLarge { name, size, limit }.into());
+        }
+        total_size = total_size.saturating_add(size);
+        if total_size > limits.max_total_size {
+            return Err(ProofPackRejection::ArchiveTooLarge {
+                limit: limits.max_total_size,
+            }
+            .into());
+        }
+
+        entries.push(ValidatedEntry {
+            index,
+            name,
+            relative_path,
+            kind,
+            size,
+        });
+    }
+
+    if !entries.iter().any(|entry| entry.kind == EntryKind::Video) {
+        return Err(ProofPackRejection::MissingVideo.into());
+    }
+    if !entries
+        .iter()
+        .any(|entry| entry.kind == EntryKind::Manifest)
+    {
+        return Err(ProofPackRejection::MissingManifest.into());
+    }
+
+    Ok(entries)
+}
+
 /// Extract a proof pack and return paths to extracted files
+///
+/// Proof packs are untrusted input: the whole archive is validated against the
+/// expected layout and [`ExtractionLimits::default`] before anything is written.
 pub fn extract_proof_pack<P: AsRef<Path>>(
     proof_pack_path: P,
     extract_dir: P,
+) -> NotariResult<(PathBuf, PathBuf)> {
+    extract_proof_pack_with_limits(proof_pack_path, extract_dir, &ExtractionLimits::default())
+}
+
+/// Extract a proof pack with explicit size limits
+pub fn extract_proof_pack_with_limits<P: AsRef<Path>>(
+    proof_pack_path: P,
+    extract_dir: P,
+    limits: &ExtractionLimits,
 ) -> NotariResult<(PathBuf, PathBuf)> {
     let proof_pack_path = proof_pack_path.as_ref();
     let extract_dir = extract_dir.as_ref();
 
-    // Create extraction directory
-    fs::create_dir_all(extract_dir)?;
-
     // Open ZIP file
     let file = File::open(proof_pack_path)?;
     let mut archive = zip::ZipArchive::new(file).map_err(|e| {
         NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
     })?;
 
+    // Reject the archive before touching the filesystem
+    let entries = validate_entries(&mut archive, limits)?;
+
+    // Create extraction directory
+    fs::create_dir_all(extract_dir)?;
+
     let mut video_path: Option<PathBuf> = None;
     let mut manifest_path: Option<PathBuf> = None;
 
-    // Extract all files
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i).map_err(|e| {
-            NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
-        })?;
-        let outpath = extract_dir.join(file.name());
+    for entry in entries {
+        let outpath = extract_dir.join(&entry.relative_path);
 
-        if file.name().ends_with('/') {
+        if entry.kind == EntryKind::Directory {
             fs::create_dir_all(&outpath)?;
-        } else {
-            if let Some(p) = outpath.parent() {
-                fs::create_dir_all(p)?;
+            continue;
+        }
+
+        if let Some(p) = outpath.parent() {
+            fs::create_dir_all(p)?;
+        }
+        let mut file = archive.by_index(entry.index).map_err(|e| {
+            NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
+        })?;
+        let mut outfile = File::create(&outpath)?;
+
+        // The declared size is attacker-controlled; never write past it
+        let written = io::copy(&mut (&mut file).take(entry.size + 1), &mut outfile)?;
+        if written > entry.size {
+            drop(outfile);
+            let _ = fs::remove_file(&outpath);
+            return Err(ProofPackRejection::SizeMismatch {
+                name: entry.name,
+                declared: entry.size,
             }
-            let mut outfile = File::create(&outpath)?;
-            std::io::copy(&mut file, &mut outfile)?;
-
-            // Track video and manifest paths
-            if file.name().starts_with("evidence/") {
-                if file.name().ends_with(".mov") || file.name().ends_with(".mov.enc") {
-                    video_path = Some(outpath.clone());
-                } else if file.name().ends_with(".json") {
-                    manifest_path = Some(outpath.clone());
-                }
+            .into());
+        }
+
+        // Track video and manifest paths
+        match entry.kind {
+            EntryKind::Video => video_path = Some(outpath),
+            EntryKind::Manifest => manifest_path = Some(outpath),
+            _ => {}
+        }
+    }
+
+    let video_path = video_path.ok_or(ProofPackRejection::MissingVideo)?;
+    let manifest_path = manifest_path.ok_or(ProofPackRejection::MissingManifest)?;
+
+    Ok((video_path, manifest_path))
+}
+
+/// Read a small text entry of a validated archive, bounded by its validated size
+fn read_validated_entry<R: Read + io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    entry: &ValidatedEntry,
+) -> NotariResult<String> {
+    let file = archive.by_index(entry.index).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
+    })?;
+
+    let mut contents = String::new();
+    file.take(entry.size + 1).read_to_string(&mut contents)?;
+    if contents.len() as u64 > entry.size {
+        return Err(ProofPackRejection::SizeMismatch {
+            name: entry.name.clone(),
+            declared: entry.size,
+        }
+        .into());
+    }
+    Ok(contents)
+}
+
+/// Validate a proof pack and read a single entry of the given kind, if present
+fn read_proof_pack_entry(
+    proof_pack_path: &Path,
+    kind: EntryKind,
+) -> NotariResult<Option<(String, String)>> {
+    let file = File::open(proof_pack_path)?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
+    })?;
+    let entries = validate_entries(&mut archive, &ExtractionLimits::default())?;
+
+    match entries.iter().find(|entry| entry.kind == kind) {
+        Some(entry) => {
+            let contents = read_validated_entry(&mut archive, entry)?;
+            Ok(Some((entry.name.clone(), contents)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Read the manifest JSON from a proof pack without extracting it
+///
+/// Returns the manifest's entry name and its raw contents. The archive is validated
+/// with the same rules as [`extract_proof_pack`].
+pub fn read_proof_pack_manifest<P: AsRef<Path>>(
+    proof_pack_path: P,
+) -> NotariResult<(String, String)> {
+    read_proof_pack_entry(proof_pack_path.as_ref(), EntryKind::Manifest)?
+        .ok_or_else(|| ProofPackRejection::MissingManifest.into())
+}
+
+/// Read `metadata.json` from a proof pack without extracting it
+pub fn read_proof_pack_metadata<P: AsRef<Path>>(
+    proof_pack_path: P,
+) -> NotariResult<ProofPackMetadata> {
+    let (_, contents) = read_proof_pack_entry(proof_pack_path.as_ref(), EntryKind::Metadata)?
+        .ok_or_else(|| {
+            NotariError::InvalidProofPack("No metadata.json in proof pack".to_string())
+        })?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Read `checkpoints.json` from a proof pack without extracting it
+///
+/// Returns `None` if the recording has no checkpoint log.
+pub fn read_proof_pack_checkpoints<P: AsRef<Path>>(
+    proof_pack_path: P,
+) -> NotariResult<Option<String>> {
+    Ok(
+        read_proof_pack_entry(proof_pack_path.as_ref(), EntryKind::Checkpoints)?
+            .map(|(_, contents)| contents),
+    )
+}
+
+/// Hash the segments packed under `segments/`, in archive order, without
+/// extracting them
+///
+/// Returns the hex SHA-256 of each, or an empty list if the pack has none. The
+/// archive is validated with the same rules as [`extract_proof_pack`].
+pub fn hash_proof_pack_segments<P: AsRef<Path>>(proof_pack_path: P) -> NotariResult<Vec<String>> {
+    let file = File::open(proof_pack_path.as_ref())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
+    })?;
+    let entries = validate_entries(&mut archive, &ExtractionLimits::default())?;
+
+    let mut hashes = Vec::new();
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.kind == EntryKind::Segment)
+    {
+        let file = archive.by_index(entry.index).map_err(|e| {
+            NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
+        })?;
+        let (sha256, size) = hash_reader(file.take(entry.size + 1))?;
+        if size > entry.size {
+            return Err(ProofPackRejection::SizeMismatch {
+                name: entry.name.clone(),
+                declared: entry.size,
             }
+            .into());
         }
+        hashes.push(sha256);
     }
+    Ok(hashes)
+}
+
+/// Read `key_history.json` from a proof pack without extracting it
+///
+/// Returns `None` if the manifest is signed with an identity key.
+pub fn read_proof_pack_key_history<P: AsRef<Path>>(
+    proof_pack_path: P,
+) -> NotariResult<Option<String>> {
+    Ok(
+        read_proof_pack_entry(proof_pack_path.as_ref(), EntryKind::KeyHistory)?
+            .map(|(_, contents)| contents),
+    )
+}
 
-    let video_path = video_path.ok_or_else(|| {
-        NotariError::InvalidProofPack("No video file found in proof pack".to_string())
+/// Read up to `limit` bytes from the start of a proof pack's video without
+/// extracting it
+pub fn read_proof_pack_video_prefix<P: AsRef<Path>>(
+    proof_pack_path: P,
+    limit: u64,
+) -> NotariResult<Vec<u8>> {
+    let file = File::open(proof_pack_path.as_ref())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
     })?;
-    let manifest_path = manifest_path.ok_or_else(|| {
-        NotariError::InvalidProofPack("No manifest file found in proof pack".to_string())
+    let entries = validate_entries(&mut archive, &ExtractionLimits::default())?;
+    let entry = entries
+        .iter()
+        .find(|entry| entry.kind == EntryKind::Video)
+        .ok_or(ProofPackRejection::MissingVideo)?;
+
+    let file = archive.by_index(entry.index).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
     })?;
+    let mut contents = Vec::new();
+    file.take(limit.min(entry.size))
+        .read_to_end(&mut contents)?;
+    Ok(contents)
+}
 
-    Ok((video_path, manifest_path))
+/// Check every entry of a proof pack against its signed `index.json`
+///
+/// Returns an [`IndexVerification`] with `index: None` for 1.0 packs, which have
+/// no index. Entries are hashed straight from the archive, nothing is extracted.
+pub fn verify_proof_pack_index<P: AsRef<Path>>(
+    proof_pack_path: P,
+) -> NotariResult<IndexVerification> {
+    let file = File::open(proof_pack_path.as_ref())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+        NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
+    })?;
+    let entries = validate_entries(&mut archive, &ExtractionLimits::default())?;
+
+    let index = match entries.iter().find(|entry| entry.kind == EntryKind::Index) {
+        Some(entry) => {
+            let contents = read_validated_entry(&mut archive, entry)?;
+            serde_json::from_str::<ProofPackIndex>(&contents).map_err(|e| {
+                NotariError::InvalidProofPack(format!("Invalid {}: {}", INDEX_FILENAME, e))
+            })?
+        }
+        None => {
+            return Ok(IndexVerification {
+                index: None,
+                signature_valid: false,
+                unlisted_files: Vec::new(),
+                modified_files: Vec::new(),
+                missing_files: Vec::new(),
+            })
+        }
+    };
+
+    // A malformed key or signature is a failed check, not an unreadable pack
+    let signature_valid = index.verify_signature().unwrap_or(false);
+
+    let mut unlisted_files = Vec::new();
+    let mut modified_files = Vec::new();
+    for entry in &entries {
+        if matches!(entry.kind, EntryKind::Directory | EntryKind::Index) {
+            continue;
+        }
+        let Some(expected) = index.entry(&entry.name) else {
+            unlisted_files.push(entry.name.clone());
+            continue;
+        };
+
+        let file = archive.by_index(entry.index).map_err(|e| {
+            NotariError::ProofPackExtractionFailed(format!("Failed to read ZIP entry: {}", e))
+        })?;
+        let (sha256, size) = hash_reader(file.take(entry.size + 1))?;
+        if size != expected.size || sha256 != expected.sha256 {
+            modified_files.push(entry.name.clone());
+        }
+    }
+
+    let missing_files = index
+        .files
+        .iter()
+        .filter(|listed| !entries.iter().any(|entry| entry.name == listed.path))
+        .map(|listed| listed.path.clone())
+        .collect();
+
+    Ok(IndexVerification {
+        index: Some(index),
+        signature_valid,
+        unlisted_files,
+        modified_files,
+        missing_files,
+    })
+}
+
+/// Replace the manifest inside an existing proof pack and re-sign its index
+///
+/// `public_key.txt` and `README.txt` are regenerated for `key_manager`, which may
+/// differ from the key the pack was created with after a rotation. All other
+/// entries are copied without recompression. The rewritten pack always
+/// uses the 2.0 layout, so 1.0 packs gain an index (and `metadata.json` its new
+/// version). The original file is only replaced once the new archive is complete.
+pub fn replace_proof_pack_manifest<P: AsRef<Path>>(
+    proof_pack_path: P,
+    manifest: &EvidenceManifest,
+    key_manager: &KeyManager,
+) -> NotariResult<()> {
+    rewrite_proof_pack(proof_pack_path.as_ref(), manifest, None, key_manager)
+}
+
+/// Replace the video and manifest inside an existing proof pack and re-sign its
+/// index
+///
+/// For videos re-encrypted in place, e.g. under a new content key. The video
+/// keeps its entry name; everything else is as for [`replace_proof_pack_manifest`].
+pub fn replace_proof_pack_video<P: AsRef<Path>, Q: AsRef<Path>>(
+    proof_pack_path: P,
+    video_path: Q,
+    manifest: &EvidenceManifest,
+    key_manager: &KeyManager,
+) -> NotariResult<()> {
+    rewrite_proof_pack(
+        proof_pack_path.as_ref(),
+        manifest,
+        Some(video_path.as_ref()),
+        key_manager,
+    )
+}
+
+fn rewrite_proof_pack(
+    proof_pack_path: &Path,
+    manifest: &EvidenceManifest,
+    video_path: Option<&Path>,
+    key_manager: &KeyManager,
+) -> NotariResult<()> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let temp_path = proof_pack_path.with_extension("notari.tmp");
+
+    let result = (|| -> NotariResult<()> {
+        let file = File::open(proof_pack_path)?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+            NotariError::ProofPackExtractionFailed(format!("Failed to open ZIP archive: {}", e))
+        })?;
+        let entries = validate_entries(&mut archive, &ExtractionLimits::default())?;
+        let public_key = general_purpose::STANDARD.encode(key_manager.public_key().as_bytes());
+
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(&temp_path)?));
+        let options: FileOptions<()> = FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .unix_permissions(0o644);
+        let mut index_entries = Vec::new();
+
+        for entry in &entries {
+            if let (EntryKind::Video, Some(video_path)) = (&entry.kind, video_path) {
+                let video_size = fs::metadata(video_path)?.len();
+                zip.start_file(entry.name.as_str(), stored_options(video_size))?;
+                index_entries.push(copy_hashed(
+                    &entry.name,
+                    File::open(video_path)?,
+                    &mut zip,
+                    |_| {},
+                )?);
+                continue;
+            }
+
+            match entry.kind {
+                EntryKind::Directory | EntryKind::Index => continue,
+                EntryKind::Manifest => {
+                    let manifest_json = serde_json::to_string_pretty(manifest)?;
+                    write_indexed(
+                        &mut zip,
+                        &mut index_entries,
+                        &entry.name,
+                        manifest_json.as_bytes(),
+                        options,
+                    )?;
+                }
+                EntryKind::PublicKey => {
+                    write_indexed(
+                        &mut zip,
+                        &mut index_entries,
+                        &entry.name,
+                        public_key.as_bytes(),
+                        options,
+                    )?;
+                }
+                EntryKind::Readme => {
+                    let readme = generate_readme(manifest, &public_key);
+                    write_indexed(
+                        &mut zip,
+                        &mut index_entries,
+                        &entry.name,
+                        readme.as_bytes(),
+                        options,
+                    )?;
+                }
+                EntryKind::Metadata => {
+                    let contents = read_validated_entry(&mut archive, entry)?;
+                    let mut metadata: ProofPackMetadata = serde_json::from_str(&contents)?;
+                    metadata.version = PROOF_PACK_VERSION.to_string();
+                    let metadata_json = serde_json::to_string_pretty(&metadata)?;
+                    write_indexed(
+                        &mut zip,
+                        &mut index_entries,
+                        &entry.name,
+                        metadata_json.as_bytes(),
+                        options,
+                    )?;
+                }
+                _ => {
+                    // Hash the stored contents, then copy the compressed bytes as-is
+                    let file = archive.by_index(entry.index).map_err(|e| {
+                        NotariError::ProofPackExtractionFailed(format!(
+                            "Failed to read ZIP entry: {}",
+                            e
+                        ))
+                    })?;
+                    let (sha256, size) = hash_reader(file.take(entry.size + 1))?;
+                    if size > entry.size {
+                        return Err(ProofPackRejection::SizeMismatch {
+                            name: entry.name.clone(),
+                            declared: entry.size,
+                        }
+                        .into());
+                    }
+
+                    let raw = archive.by_index_raw(entry.index).map_err(|e| {
+                        NotariError::ProofPackExtractionFailed(format!(
+                            "Failed to read ZIP entry: {}",
+                            e
+                        ))
+                    })?;
+                    zip.raw_copy_file(raw)?;
+                    index_entries.push(IndexEntry {
+                        path: entry.name.clone(),
+                        sha256,
+                        size,
+                    });
+                }
+            }
+        }
+
+        write_index(&mut zip, index_entries, key_manager, options)?;
+        zip.finish()?.flush()?;
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            fs::rename(&temp_path, proof_pack_path)?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            Err(e)
+        }
+    }
+}
+
+/// Stream a reader through SHA-256, returning the hex digest and byte count
+fn hash_reader<R: Read>(mut reader: R) -> io::Result<(String, u64)> {
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    let mut size: u64 = 0;
+
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+        size += n as u64;
+    }
+
+    Ok((format!("{:x}", hasher.finalize()), size))
 }
 
 /// Generate README content for proof pack
@@ -205,6 +1089,9 @@ evidence/
   └── public_key.txt                (Public key for signature verification)
 
 metadata.json                       (Proof pack metadata)
+checkpoints.json                    (Checkpoints signed while recording, if taken)
+key_history.json                    (Rotations of the signing key, if rotated)
+index.json                          (Signed SHA-256 index of every file above)
 README.txt                          (This file)
 
 ═══════════════════════════════════════════════════════════════════════════════
@@ -225,7 +1112,8 @@ Encrypted:        {}
 This evidence is protected by:
   • Ed25519 digital signature
   • SHA-256 cryptographic hash
-  • Tamper-evident manifest{}
+  • Tamper-evident manifest
+  • Signed file index covering every file in this pack{}
 
 Public Key (Ed25519):
 {}
@@ -244,6 +1132,7 @@ Public Key (Ed25519):
 The Notari app will:
   ✓ Verify the digital signature
   ✓ Verify the file hash
+  ✓ Check every file against the signed index
   ✓ Display all evidence metadata
   ✓ Allow you to play the video (with decryption if needed)
 
@@ -303,12 +1192,15 @@ Generated by Notari v{} on {}
         } else {
             "No"
         },
-        // Blockchain anchor info
-        if let Some(anchor) = &manifest.blockchain_anchor {
-            format!("\n  • Blockchain anchor: {}", anchor.proof.description())
-        } else {
-            String::new()
-        },
+        // Checkpoint and blockchain anchor info, one line each
+        manifest
+            .checkpoints
+            .iter()
+            .map(|summary| format!("\n  • {} checkpoints signed while recording", summary.count))
+            .chain(manifest.all_anchors().map(|anchor| {
+                format!("\n  • Blockchain anchor: {}", anchor.proof.description())
+            }))
+            .collect::<String>(),
         public_key,
         env!("CARGO_PKG_VERSION"),
         chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
@@ -318,7 +1210,10 @@ Generated by Notari v{} on {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::evidence::{signature::KeyManager, HashInfo};
+    use crate::evidence::x509::Certificate;
+    use crate::evidence::{
+        signature::KeyManager, signer_certificate, CheckpointLog, HashInfo, KeyHistory,
+    };
     use std::io::Write;
     use tempfile::TempDir;
     use uuid::Uuid;
@@ -527,4 +1422,1182 @@ mod tests {
                 || error_msg.contains("No manifest file found")
         );
     }
+
+    /// Peak resident set size of this process, in bytes (Linux only)
+    #[cfg(target_os = "linux")]
+    fn peak_rss_bytes() -> u64 {
+        let status = std::fs::read_to_string("/proc/self/status").unwrap();
+        let line = status
+            .lines()
+            .find(|line| line.starts_with("VmHWM:"))
+            .unwrap();
+        let kb: u64 = line
+            .trim_start_matches("VmHWM:")
+            .trim()
+            .trim_end_matches("kB")
+            .trim()
+            .parse()
+            .unwrap();
+        kb * 1024
+    }
+
+    /// Peak RSS of running `body` in the test `name` of this module on its own
+    /// (Linux only)
+    ///
+    /// Peak RSS covers the whole process, so it is measured in a child that
+    /// re-runs this test binary with `--exact`. In that child `body` runs, the peak
+    /// is handed back and `None` is returned; the parent gets `Some(peak)`.
+    #[cfg(target_os = "linux")]
+    fn peak_rss_of(name: &str, body: impl FnOnce()) -> Option<u64> {
+        const PEAK_RSS_FILE_ENV: &str = "NOTARI_TEST_PEAK_RSS_FILE";
+
+        if let Some(path) = std::env::var_os(PEAK_RSS_FILE_ENV) {
+            body();
+            std::fs::write(path, peak_rss_bytes().to_string()).unwrap();
+            return None;
+        }
+
+        // Test names don't include the crate name
+        let (_, module) = module_path!().split_once("::").unwrap();
+        let test = format!("{}::{}", module, name);
+        let peak_file = tempfile::NamedTempFile::new().unwrap();
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([test.as_str(), "--exact", "--include-ignored"])
+            .env(PEAK_RSS_FILE_ENV, peak_file.path())
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        let peak = std::fs::read_to_string(peak_file.path()).unwrap();
+        Some(peak.parse().unwrap())
+    }
+
+    /// Write a proof pack around a sparse video of `video_size` bytes and return
+    /// the archive path and the progress updates that were reported
+    fn write_sparse_proof_pack(temp_dir: &TempDir, video_size: u64) -> (PathBuf, Vec<(u64, u64)>) {
+        let video_path = temp_dir.path().join("large.mov");
+        let manifest_path = temp_dir.path().join("large.json");
+        let output_path = temp_dir.path().join("large.notari");
+
+        // Sparse file: takes no disk space or memory until read
+        let video_file = std::fs::File::create(&video_path).unwrap();
+        video_file.set_len(video_size).unwrap();
+        drop(video_file);
+
+        create_test_manifest().save(&manifest_path).unwrap();
+
+        let mut updates = Vec::new();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            None,
+            &[],
+            None,
+            &KeyManager::generate(),
+            |processed, total| updates.push((processed, total)),
+        )
+        .unwrap();
+
+        (output_path, updates)
+    }
+
+    #[test]
+    fn test_video_entry_is_stored_uncompressed() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let output_path = temp_dir.path().join("test.notari");
+
+        std::fs::write(&video_path, b"fake video content").unwrap();
+        create_test_manifest().save(&manifest_path).unwrap();
+
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            None,
+            &[],
+            None,
+            &KeyManager::generate(),
+            |_, _| {},
+        )
+        .unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&output_path).unwrap()).unwrap();
+        let video = archive.by_name("evidence/test.mov").unwrap();
+        assert_eq!(video.compression(), zip::CompressionMethod::Stored);
+        drop(video);
+        let manifest = archive.by_name("evidence/test.json").unwrap();
+        assert_eq!(manifest.compression(), zip::CompressionMethod::Deflated);
+    }
+
+    #[test]
+    fn test_proof_pack_progress_reporting() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_, updates) = write_sparse_proof_pack(&temp_dir, 8 * 1024 * 1024);
+
+        assert!(!updates.is_empty());
+        // Throttled to at most one update per percent
+        assert!(updates.len() <= 101);
+        // Monotonic and ending at 100%
+        assert!(updates.windows(2).all(|w| w[0].0 <= w[1].0));
+        let (processed, total) = *updates.last().unwrap();
+        assert_eq!(processed, total);
+        assert!(total > 8 * 1024 * 1024);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_large_proof_pack_memory_is_bounded() {
+        let video_size: u64 = 256 * 1024 * 1024;
+        let Some(peak) = peak_rss_of("test_large_proof_pack_memory_is_bounded", || {
+            let temp_dir = TempDir::new().unwrap();
+            let (output_path, _) = write_sparse_proof_pack(&temp_dir, video_size);
+
+            let extract_dir = temp_dir.path().join("extracted");
+            let (extracted_video, _) = extract_proof_pack(&output_path, &extract_dir).unwrap();
+            assert_eq!(
+                std::fs::metadata(&extracted_video).unwrap().len(),
+                video_size
+            );
+        }) else {
+            return;
+        };
+
+        // Buffering the video anywhere would push the peak past its size
+        assert!(
+            peak < video_size / 2,
+            "peak RSS {} MiB for a {} MiB video",
+            peak / (1024 * 1024),
+            video_size / (1024 * 1024)
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[ignore = "packs and extracts a 4.5 GiB sparse video; run with --ignored"]
+    fn test_multi_gigabyte_proof_pack_uses_zip64_and_bounded_memory() {
+        // Larger than 4 GiB so the video entry needs ZIP64 headers
+        let video_size: u64 = 9 * 512 * 1024 * 1024;
+        let Some(peak) = peak_rss_of(
+            "test_multi_gigabyte_proof_pack_uses_zip64_and_bounded_memory",
+            || {
+                let temp_dir = TempDir::new().unwrap();
+                let (output_path, updates) = write_sparse_proof_pack(&temp_dir, video_size);
+                assert_eq!(updates.last().unwrap().0, updates.last().unwrap().1);
+
+                let extract_dir = temp_dir.path().join("extracted");
+                let (extracted_video, _) = extract_proof_pack(&output_path, &extract_dir).unwrap();
+                assert_eq!(
+                    std::fs::metadata(&extracted_video).unwrap().len(),
+                    video_size
+                );
+            },
+        ) else {
+            return;
+        };
+
+        assert!(
+            peak < 256 * 1024 * 1024,
+            "peak RSS {} MiB for a {} MiB video",
+            peak / (1024 * 1024),
+            video_size / (1024 * 1024)
+        );
+    }
+
+    /// Build a raw ZIP with the given entries (no layout validation on write)
+    fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+        let file = std::fs::File::create(path).unwrap();
+        let mut zip = zip::ZipWriter::new(file);
+        let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+        for (name, data) in entries {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn assert_rejected(result: NotariResult<(PathBuf, PathBuf)>, expected: ProofPackRejection) {
+        match result {
+            Err(NotariError::ProofPackRejected(rejection)) => assert_eq!(rejection, expected),
+            other => panic!("expected rejection {:?}, got {:?}", expected, other),
+        }
+    }
+
+    #[test]
+    fn test_extract_rejects_path_traversal() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("evil.notari");
+        let extract_dir = temp_dir.path().join("extracted");
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("evidence/test.json", b"{}"),
+                ("../escaped.txt", b"pwned"),
+            ],
+        );
+
+        assert_rejected(
+            extract_proof_pack(&pack, &extract_dir),
+            ProofPackRejection::UnsafePath("../escaped.txt".to_string()),
+        );
+        assert!(!temp_dir.path().join("escaped.txt").exists());
+        // Nothing is written when validation fails
+        assert!(!extract_dir.join("evidence/test.mov").exists());
+    }
+
+    #[test]
+    fn test_extract_rejects_absolute_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("evil.notari");
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("/tmp/notari-evil.json", b"{}"),
+            ],
+        );
+
+        assert_rejected(
+            extract_proof_pack(&pack, &temp_dir.path().join("extracted")),
+            ProofPackRejection::UnsafePath("/tmp/notari-evil.json".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_extract_rejects_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("evil.notari");
+
+        let file = std::fs::File::create(&pack).unwrap();
+        let mut zip = zip::ZipWriter::new(file);
+        let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+        zip.add_symlink("evidence/test.mov", "/etc/passwd", options)
+            .unwrap();
+        zip.start_file("evidence/test.json", options).unwrap();
+        zip.write_all(b"{}").unwrap();
+        zip.finish().unwrap();
+
+        assert_rejected(
+            extract_proof_pack(&pack, &temp_dir.path().join("extracted")),
+            ProofPackRejection::Symlink("evidence/test.mov".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_extract_rejects_unexpected_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let extract_dir = temp_dir.path().join("extracted");
+
+        for name in [
+            "evidence/run.sh",
+            "evidence/nested/test.json",
+            "evidence/.hidden.json",
+            "other/test.json",
+        ] {
+            let pack = temp_dir.path().join("unexpected.notari");
+            write_zip(
+                &pack,
+                &[
+                    ("evidence/test.mov", b"video"),
+                    ("evidence/test.json", b"{}"),
+                    (name, b"data"),
+                ],
+            );
+
+            assert_rejected(
+                extract_proof_pack(&pack, &extract_dir),
+                ProofPackRejection::UnexpectedEntry(name.to_string()),
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_rejects_ambiguous_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("ambiguous.notari");
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("evidence/test.json", b"{}"),
+                ("evidence/other.json", b"{}"),
+            ],
+        );
+
+        assert_rejected(
+            extract_proof_pack(&pack, &temp_dir.path().join("extracted")),
+            ProofPackRejection::MultipleManifests("evidence/other.json".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_extract_enforces_size_limits() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("bomb.notari");
+        let extract_dir = temp_dir.path().join("extracted");
+        let zeros = vec![0u8; 64 * 1024];
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("evidence/test.json", &zeros),
+            ],
+        );
+
+        let limits = ExtractionLimits {
+            max_file_size: 1024,
+            ..ExtractionLimits::default()
+        };
+        assert_rejected(
+            extract_proof_pack_with_limits(&pack, &extract_dir, &limits),
+            ProofPackRejection::EntryTooLarge {
+                name: "evidence/test.json".to_string(),
+                size: zeros.len() as u64,
+                limit: 1024,
+            },
+        );
+
+        let limits = ExtractionLimits {
+            max_total_size: 1024,
+            ..ExtractionLimits::default()
+        };
+        assert_rejected(
+            extract_proof_pack_with_limits(&pack, &extract_dir, &limits),
+            ProofPackRejection::ArchiveTooLarge { limit: 1024 },
+        );
+
+        let limits = ExtractionLimits {
+            max_entries: 1,
+            ..ExtractionLimits::default()
+        };
+        assert_rejected(
+            extract_proof_pack_with_limits(&pack, &extract_dir, &limits),
+            ProofPackRejection::TooManyEntries { count: 2, limit: 1 },
+        );
+    }
+
+    /// Read every entry of an archive into memory
+    fn read_entries(path: &Path) -> Vec<(String, Vec<u8>)> {
+        let mut archive = zip::ZipArchive::new(File::open(path).unwrap()).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut data = Vec::new();
+                file.read_to_end(&mut data).unwrap();
+                (file.name().to_string(), data)
+            })
+            .collect()
+    }
+
+    /// Write a small, valid 2.0 proof pack and return its path
+    fn write_small_proof_pack(temp_dir: &TempDir, key_manager: &KeyManager) -> PathBuf {
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let output_path = temp_dir.path().join("test.notari");
+
+        // Matches the hash in the test manifest, signed by the pack's key
+        std::fs::write(&video_path, b"test data").unwrap();
+        let mut manifest = create_test_manifest();
+        manifest.sign(key_manager);
+        manifest.save(&manifest_path).unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            None,
+            &[],
+            None,
+            key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+
+        output_path
+    }
+
+    #[test]
+    fn test_proof_pack_index_covers_every_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let pack = write_small_proof_pack(&temp_dir, &key_manager);
+
+        let result = verify_proof_pack_index(&pack).unwrap();
+        let index = result.index.as_ref().unwrap();
+        assert!(result.signature_valid);
+        assert!(result.files_intact());
+        assert_eq!(index.version, PROOF_PACK_VERSION);
+        assert_eq!(index.files.len(), 5);
+        assert_eq!(
+            index.entry("evidence/test.mov").unwrap().sha256,
+            HashInfo::from_bytes(b"test data").value
+        );
+
+        use base64::{engine::general_purpose, Engine as _};
+        assert_eq!(
+            index.signature.public_key,
+            general_purpose::STANDARD.encode(key_manager.public_key().as_bytes())
+        );
+        assert_eq!(
+            read_proof_pack_metadata(&pack).unwrap().version,
+            PROOF_PACK_VERSION
+        );
+    }
+
+    #[test]
+    fn test_proof_pack_with_checkpoint_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let log_path = temp_dir.path().join(CHECKPOINT_LOG_FILE);
+        let output_path = temp_dir.path().join("test.notari");
+
+        std::fs::write(&video_path, b"test data").unwrap();
+        create_test_manifest().save(&manifest_path).unwrap();
+        std::fs::write(&log_path, br#"{"checkpoints":[]}"#).unwrap();
+        let segment_paths: Vec<PathBuf> = ["segment-00000.mov", "segment-00001.mov"]
+            .iter()
+            .map(|name| temp_dir.path().join(name))
+            .collect();
+        std::fs::write(&segment_paths[0], b"test").unwrap();
+        std::fs::write(&segment_paths[1], b" data").unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            Some(&log_path),
+            &segment_paths,
+            None,
+            &key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_proof_pack_checkpoints(&output_path)
+                .unwrap()
+                .as_deref(),
+            Some(r#"{"checkpoints":[]}"#)
+        );
+        let result = verify_proof_pack_index(&output_path).unwrap();
+        assert!(result.files_intact());
+        let index = result.index.unwrap();
+        assert!(index.entry(CHECKPOINT_LOG_FILE).is_some());
+        assert!(index.entry("segments/segment-00001.mov").is_some());
+        assert_eq!(
+            hash_proof_pack_segments(&output_path).unwrap(),
+            vec![
+                HashInfo::from_bytes(b"test").value,
+                HashInfo::from_bytes(b" data").value,
+            ]
+        );
+
+        let extract_dir = temp_dir.path().join("extracted");
+        extract_proof_pack(&output_path, &extract_dir).unwrap();
+        assert!(extract_dir.join(CHECKPOINT_LOG_FILE).is_file());
+        assert!(extract_dir
+            .join(SEGMENTS_DIR)
+            .join("segment-00000.mov")
+            .is_file());
+
+        // Segments of encrypted recordings aren't packed
+        let mut manifest = create_test_manifest();
+        manifest.recording.encrypted = true;
+        manifest.save(&manifest_path).unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            Some(&log_path),
+            &segment_paths,
+            None,
+            &key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+        assert!(hash_proof_pack_segments(&output_path).unwrap().is_empty());
+
+        // Packs without a log have no entry
+        let pack = write_small_proof_pack(&temp_dir, &key_manager);
+        assert_eq!(read_proof_pack_checkpoints(&pack).unwrap(), None);
+    }
+
+    #[test]
+    fn test_proof_pack_index_detects_modified_and_missing_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = write_small_proof_pack(&temp_dir, &KeyManager::generate());
+
+        let tampered = temp_dir.path().join("tampered.notari");
+        let entries: Vec<(String, Vec<u8>)> = read_entries(&pack)
+            .into_iter()
+            .filter(|(name, _)| name != "README.txt")
+            .map(|(name, data)| {
+                if name == "metadata.json" {
+                    (name, br#"{"version":"2.0","title":"edited"}"#.to_vec())
+                } else {
+                    (name, data)
+                }
+            })
+            .collect();
+        let refs: Vec<(&str, &[u8])> = entries
+            .iter()
+            .map(|(name, data)| (name.as_str(), data.as_slice()))
+            .collect();
+        write_zip(&tampered, &refs);
+
+        let result = verify_proof_pack_index(&tampered).unwrap();
+        assert!(result.signature_valid);
+        assert!(!result.files_intact());
+        assert_eq!(result.modified_files, vec!["metadata.json".to_string()]);
+        assert_eq!(result.missing_files, vec!["README.txt".to_string()]);
+        assert!(result.unlisted_files.is_empty());
+    }
+
+    #[test]
+    fn test_legacy_proof_pack_has_no_index() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("legacy.notari");
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("evidence/test.json", b"{}"),
+            ],
+        );
+
+        let result = verify_proof_pack_index(&pack).unwrap();
+        assert!(result.index.is_none());
+        assert!(!result.signature_valid);
+    }
+
+    #[test]
+    fn test_verifier_checks_proof_pack_index() {
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+
+        let temp_dir = TempDir::new().unwrap();
+        let pack = write_small_proof_pack(&temp_dir, &KeyManager::generate());
+
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.proof_pack.unwrap();
+        assert_eq!(check.format_version, PROOF_PACK_VERSION);
+        assert_eq!(check.index_signature, CheckResult::Pass);
+        assert_eq!(check.files_match, CheckResult::Pass);
+
+        // Unsigned files such as metadata.json are now covered
+        let tampered = temp_dir.path().join("tampered.notari");
+        let entries: Vec<(String, Vec<u8>)> = read_entries(&pack)
+            .into_iter()
+            .map(|(name, data)| {
+                if name == "metadata.json" {
+                    let mut metadata: ProofPackMetadata = serde_json::from_slice(&data).unwrap();
+                    metadata.title = Some("edited".to_string());
+                    (name, serde_json::to_vec(&metadata).unwrap())
+                } else {
+                    (name, data)
+                }
+            })
+            .collect();
+        let refs: Vec<(&str, &[u8])> = entries
+            .iter()
+            .map(|(name, data)| (name.as_str(), data.as_slice()))
+            .collect();
+        write_zip(&tampered, &refs);
+
+        let report = Verifier::verify_proof_pack(&tampered).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.proof_pack.unwrap();
+        assert_eq!(check.files_match, CheckResult::Fail);
+        assert_eq!(check.modified_files, vec!["metadata.json".to_string()]);
+    }
+
+    #[test]
+    fn test_verifier_rejects_index_from_other_signer() {
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+
+        let temp_dir = TempDir::new().unwrap();
+        let pack = write_small_proof_pack(&temp_dir, &KeyManager::generate());
+
+        // Re-sign the index with a different key; the manifest is untouched
+        let mut archive = zip::ZipArchive::new(File::open(&pack).unwrap()).unwrap();
+        let mut index: ProofPackIndex =
+            serde_json::from_reader(archive.by_name(INDEX_FILENAME).unwrap()).unwrap();
+        index.sign(&KeyManager::generate());
+        let index_json = serde_json::to_vec(&index).unwrap();
+
+        let forged = temp_dir.path().join("forged.notari");
+        let entries: Vec<(String, Vec<u8>)> = read_entries(&pack)
+            .into_iter()
+            .map(|(name, data)| {
+                if name == INDEX_FILENAME {
+                    (name, index_json.clone())
+                } else {
+                    (name, data)
+                }
+            })
+            .collect();
+        let refs: Vec<(&str, &[u8])> = entries
+            .iter()
+            .map(|(name, data)| (name.as_str(), data.as_slice()))
+            .collect();
+        write_zip(&forged, &refs);
+
+        let report = Verifier::verify_proof_pack(&forged).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.proof_pack.unwrap();
+        assert_eq!(check.index_signature, CheckResult::Fail);
+        assert_eq!(check.files_match, CheckResult::Pass);
+    }
+
+    /// Pack `log` and the `packed` segments with a manifest signed by `key_manager`
+    /// that commits to `committed` and lists `joined` as the segments in the video
+    fn write_checkpointed_proof_pack(
+        temp_dir: &TempDir,
+        key_manager: &KeyManager,
+        committed: &CheckpointLog,
+        log: &CheckpointLog,
+        joined: &[&[u8]],
+        packed: &[&[u8]],
+    ) -> PathBuf {
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let log_path = temp_dir.path().join(CHECKPOINT_LOG_FILE);
+        let output_path = temp_dir.path().join("test.notari");
+
+        std::fs::write(&video_path, b"test data").unwrap();
+        committed.save(&log_path).unwrap();
+        let mut manifest = create_test_manifest();
+        manifest.recording.session_id = committed.session_id.clone();
+        manifest.timestamps.started_at -= chrono::Duration::minutes(2);
+        let mut summary = CheckpointLog::summarize(&log_path).unwrap();
+        summary.segments = joined
+            .iter()
+            .map(|segment| HashInfo::from_bytes(segment).value)
+            .collect();
+        manifest.checkpoints = Some(summary);
+        manifest.sign(key_manager);
+        manifest.save(&manifest_path).unwrap();
+
+        let segment_paths: Vec<PathBuf> = packed
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let path = temp_dir.path().join(format!("segment-{:05}.mov", index));
+                std::fs::write(&path, segment).unwrap();
+                path
+            })
+            .collect();
+        log.save(&log_path).unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            Some(&log_path),
+            &segment_paths,
+            None,
+            key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+
+        output_path
+    }
+
+    #[test]
+    fn test_verifier_checks_checkpoints() {
+        use crate::evidence::checkpoint::CHECKPOINT_INTERVAL;
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let session_id = Uuid::new_v4().to_string();
+        let mut log = CheckpointLog::new(&session_id, &key_manager, CHECKPOINT_INTERVAL);
+        log.append(&key_manager, vec![HashInfo::from_bytes(b"test").value], 4);
+        log.append(&key_manager, vec![HashInfo::from_bytes(b" data").value], 5);
+        let segments: &[&[u8]] = &[b"test", b" data"];
+
+        let pack =
+            write_checkpointed_proof_pack(&temp_dir, &key_manager, &log, &log, segments, segments);
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.count, 2);
+        assert_eq!(check.log_match, CheckResult::Pass);
+        assert_eq!(check.chain_valid, CheckResult::Pass);
+        assert_eq!(check.times_valid, CheckResult::Pass);
+        assert_eq!(check.timestamps_valid, CheckResult::Skip);
+        assert_eq!(check.segments_valid, CheckResult::Pass);
+        assert_eq!(check.segments_packed, CheckResult::Pass);
+        assert!(check.max_gap_seconds >= 60);
+
+        // A packed segment other than the one listed in the manifest
+        let pack = write_checkpointed_proof_pack(
+            &temp_dir,
+            &key_manager,
+            &log,
+            &log,
+            segments,
+            &[b"test", b" edit"],
+        );
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.segments_valid, CheckResult::Pass);
+        assert_eq!(check.segments_packed, CheckResult::Fail);
+
+        // Segments listed in the manifest that were never checkpointed
+        let pack = write_checkpointed_proof_pack(
+            &temp_dir,
+            &key_manager,
+            &log,
+            &log,
+            &[b"edit", b" data"],
+            &[b"edit", b" data"],
+        );
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.segments_valid, CheckResult::Fail);
+        assert_eq!(check.segments_packed, CheckResult::Pass);
+
+        // A log swapped after the manifest was signed
+        let mut altered = log.clone();
+        altered.checkpoints[1].bytes_written = 50;
+        let pack = write_checkpointed_proof_pack(
+            &temp_dir,
+            &key_manager,
+            &log,
+            &altered,
+            segments,
+            segments,
+        );
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.log_match, CheckResult::Fail);
+        assert_eq!(check.signatures_valid, CheckResult::Fail);
+
+        // Checkpoints dropped before the manifest was signed
+        let mut dropped = log.clone();
+        dropped.checkpoints.remove(0);
+        let pack = write_checkpointed_proof_pack(
+            &temp_dir,
+            &key_manager,
+            &dropped,
+            &dropped,
+            &[b" data"],
+            &[b" data"],
+        );
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.log_match, CheckResult::Pass);
+        assert_eq!(check.signatures_valid, CheckResult::Pass);
+        assert_eq!(check.chain_valid, CheckResult::Fail);
+
+        // Checkpoints signed by someone other than the manifest signer
+        let other_key = KeyManager::generate();
+        let mut foreign = CheckpointLog::new(&session_id, &other_key, CHECKPOINT_INTERVAL);
+        foreign.append(&other_key, Vec::new(), 0);
+        let pack =
+            write_checkpointed_proof_pack(&temp_dir, &key_manager, &foreign, &foreign, &[], &[]);
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.checkpoints.unwrap();
+        assert_eq!(check.log_match, CheckResult::Fail);
+        assert_eq!(check.signatures_valid, CheckResult::Pass);
+    }
+
+    /// Pack a manifest signed by `key_manager` with `history` as its key history
+    fn write_rotated_proof_pack(
+        temp_dir: &TempDir,
+        key_manager: &KeyManager,
+        history: &KeyHistory,
+    ) -> PathBuf {
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let history_path = temp_dir.path().join(KEY_HISTORY_FILE);
+        let output_path = temp_dir.path().join("test.notari");
+
+        std::fs::write(&video_path, b"test data").unwrap();
+        let mut manifest = create_test_manifest();
+        manifest.sign(key_manager);
+        manifest.save(&manifest_path).unwrap();
+        history.save(&history_path).unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            None,
+            &[],
+            Some(&history_path),
+            key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+
+        output_path
+    }
+
+    #[test]
+    fn test_verifier_checks_key_history() {
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+        use base64::{engine::general_purpose, Engine as _};
+
+        let temp_dir = TempDir::new().unwrap();
+        let identity = KeyManager::generate();
+        let key_manager = KeyManager::generate();
+        let mut history = KeyHistory::new(&identity);
+        history
+            .rotate(&identity, &key_manager, Some("Annual rotation".to_string()))
+            .unwrap();
+
+        let pack = write_rotated_proof_pack(&temp_dir, &key_manager, &history);
+        assert!(read_proof_pack_key_history(&pack).unwrap().is_some());
+        let mut report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.key_history.clone().unwrap();
+        assert_eq!(check.chain_valid, CheckResult::Pass);
+        assert_eq!(check.links_signer, CheckResult::Pass);
+        assert_eq!(check.identity_pinned, CheckResult::Skip);
+        assert_eq!(check.identity_key, history.identity_key);
+        assert_eq!(check.rotations, 1);
+
+        Verifier::check_identity(&mut report, &history.identity_key);
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.key_history.unwrap();
+        assert_eq!(check.identity_pinned, CheckResult::Pass);
+
+        // Pinned to another identity
+        let stranger =
+            general_purpose::STANDARD.encode(KeyManager::generate().public_key().as_bytes());
+        let mut report = Verifier::verify_proof_pack(&pack).unwrap();
+        Verifier::check_identity(&mut report, &stranger);
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+
+        // A history that doesn't lead to the signer
+        let mut unrelated = KeyHistory::new(&identity);
+        unrelated
+            .rotate(&identity, &KeyManager::generate(), None)
+            .unwrap();
+        let pack = write_rotated_proof_pack(&temp_dir, &key_manager, &unrelated);
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.key_history.unwrap();
+        assert_eq!(check.chain_valid, CheckResult::Pass);
+        assert_eq!(check.links_signer, CheckResult::Fail);
+
+        // Recordings signed with the identity key itself carry no history
+        let pack = write_small_proof_pack(&temp_dir, &identity);
+        let mut report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert!(report.verification.checks.key_history.is_none());
+        Verifier::check_identity(&mut report, &history.identity_key);
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+    }
+
+    /// Proof pack whose manifest signature carries `chain`, created `created_at`
+    fn write_certified_proof_pack(
+        temp_dir: &TempDir,
+        key_manager: &KeyManager,
+        chain: &[Certificate],
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> PathBuf {
+        let video_path = temp_dir.path().join("test.mov");
+        let manifest_path = temp_dir.path().join("test.json");
+        let output_path = temp_dir.path().join("test.notari");
+
+        std::fs::write(&video_path, b"test data").unwrap();
+        let mut manifest = create_test_manifest();
+        manifest.timestamps.manifest_created_at = created_at;
+        manifest.signature.identity =
+            Some(signer_certificate::identity(chain, key_manager).unwrap());
+        manifest.sign(key_manager);
+        manifest.save(&manifest_path).unwrap();
+        write_proof_pack(
+            &video_path,
+            &manifest_path,
+            &output_path,
+            None,
+            &[],
+            None,
+            key_manager,
+            |_, _| {},
+        )
+        .unwrap();
+
+        output_path
+    }
+
+    #[test]
+    fn test_verifier_checks_certificate_chain() {
+        use crate::evidence::x509::testing::{
+            ed25519_spki, issue, issue_for_spki, CertificateParams, TestKey,
+        };
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let ca_key = TestKey::generate();
+        let mut ca_params = CertificateParams::new("Example Corp CA");
+        ca_params.is_ca = true;
+        let ca = Certificate::from_der(&issue(&ca_params, &ca_key, None)).unwrap();
+        let leaf = Certificate::from_der(&issue_for_spki(
+            &CertificateParams::signer("Alice"),
+            &ed25519_spki(key_manager.public_key().as_bytes()),
+            ("Example Corp CA", &ca_key),
+        ))
+        .unwrap();
+        let chain = [leaf, ca.clone()];
+
+        let pack = write_certified_proof_pack(&temp_dir, &key_manager, &chain, chrono::Utc::now());
+        let mut report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.certificate.clone().unwrap();
+        assert_eq!(check.chain_valid, CheckResult::Pass);
+        assert_eq!(check.valid_at_signing, CheckResult::Pass);
+        assert_eq!(check.trusted, CheckResult::Skip);
+        assert_eq!(check.subject, "CN=Alice");
+
+        Verifier::check_certificate(&pack, &mut report, &[ca]).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.certificate.clone().unwrap();
+        assert_eq!(check.trusted, CheckResult::Pass);
+        assert_eq!(check.trust_anchor.as_deref(), Some("CN=Example Corp CA"));
+        assert_eq!(
+            report.verification.signature_info.trusted_signer.as_deref(),
+            Some("CN=Alice")
+        );
+
+        // Another organisation's CA doesn't vouch for the signer
+        let mut other_params = CertificateParams::new("Other CA");
+        other_params.is_ca = true;
+        let other_ca =
+            Certificate::from_der(&issue(&other_params, &TestKey::generate(), None)).unwrap();
+        let mut report = Verifier::verify_proof_pack(&pack).unwrap();
+        Verifier::check_certificate(&pack, &mut report, &[other_ca]).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.certificate.unwrap();
+        assert_eq!(check.trusted, CheckResult::Fail);
+        assert_eq!(report.verification.signature_info.trusted_signer, None);
+
+        // Signed after the certificate expired
+        let expired = chrono::Utc::now() + chrono::Duration::days(400);
+        let pack = write_certified_proof_pack(&temp_dir, &key_manager, &chain, expired);
+        let report = Verifier::verify_proof_pack(&pack).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = report.verification.checks.certificate.unwrap();
+        assert_eq!(check.valid_at_signing, CheckResult::Fail);
+    }
+
+    #[test]
+    fn test_verifier_warns_on_legacy_proof_pack() {
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+
+        let temp_dir = TempDir::new().unwrap();
+        let pack = write_small_proof_pack(&temp_dir, &KeyManager::generate());
+
+        // Same pack in the 1.0 layout: no index.json
+        let legacy = temp_dir.path().join("legacy.notari");
+        let entries: Vec<(String, Vec<u8>)> = read_entries(&pack)
+            .into_iter()
+            .filter(|(name, _)| name != INDEX_FILENAME)
+            .collect();
+        let refs: Vec<(&str, &[u8])> = entries
+            .iter()
+            .map(|(name, data)| (name.as_str(), data.as_slice()))
+            .collect();
+        write_zip(&legacy, &refs);
+
+        let report = Verifier::verify_proof_pack(&legacy).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Warning);
+        assert_eq!(
+            report.verification.checks.signature_valid,
+            CheckResult::Pass
+        );
+        assert_eq!(report.verification.checks.hash_match, CheckResult::Pass);
+        let check = report.verification.checks.proof_pack.unwrap();
+        assert_eq!(check.format_version, "1.0");
+        assert_eq!(check.index_signature, CheckResult::Skip);
+    }
+
+    #[test]
+    fn test_verifier_accepts_legacy_anchor() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use crate::evidence::blockchain::{AnchorProof, BlockchainAnchor};
+        use crate::evidence::{CheckResult, VerificationStatus, Verifier, LEGACY_MANIFEST_VERSION};
+        use base64::{engine::general_purpose, Engine as _};
+
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let tsa = TestTsa::new();
+        let mut manifest = create_test_manifest();
+        manifest.version = LEGACY_MANIFEST_VERSION.to_string();
+        manifest.sign(&key_manager);
+
+        // Anchor the way 1.0 did: hash the manifest.json text as packed, then add
+        // the anchor and re-sign
+        let manifest_json = serde_json::to_string_pretty(&manifest).unwrap();
+        let raw_hash = hex::encode(Sha256::digest(manifest_json.as_bytes()));
+        let write_pack = |path: &Path, anchored_hash: &str| {
+            let mut manifest = manifest.clone();
+            manifest.blockchain_anchor = Some(BlockchainAnchor {
+                anchored_at: chrono::Utc::now(),
+                anchored_hash: anchored_hash.to_string(),
+                manifest_hash: anchored_hash.to_string(),
+                proof: AnchorProof::Rfc3161 {
+                    tsa_url: "http://127.0.0.1/tsr".to_string(),
+                    tsa_name: "CN=Notari Test TSA".to_string(),
+                    token: general_purpose::STANDARD
+                        .encode(tsa.token(&hex::decode(&raw_hash).unwrap(), None)),
+                    gen_time: chrono::Utc::now(),
+                    serial_number: "04d2".to_string(),
+                },
+            });
+            manifest.sign(&key_manager);
+            let manifest_json = serde_json::to_string_pretty(&manifest).unwrap();
+            write_zip(
+                path,
+                &[
+                    ("evidence/test.mov", b"test data"),
+                    ("evidence/test.json", manifest_json.as_bytes()),
+                ],
+            );
+        };
+
+        let legacy = temp_dir.path().join("legacy.notari");
+        write_pack(&legacy, &raw_hash);
+        let report = Verifier::verify_proof_pack(&legacy).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Warning);
+        let check = &report.verification.checks.anchors[0];
+        assert_eq!(check.hash_match, CheckResult::Pass);
+        assert!(check.timestamp_token.as_ref().unwrap().verified);
+
+        // The stored hash is still checked against the proof
+        let forged = temp_dir.path().join("forged.notari");
+        write_pack(&forged, &hex::encode(Sha256::digest(b"other manifest")));
+        let report = Verifier::verify_proof_pack(&forged).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        assert!(
+            !report.verification.checks.anchors[0]
+                .timestamp_token
+                .as_ref()
+                .unwrap()
+                .verified
+        );
+    }
+
+    #[test]
+    fn test_replace_manifest_resigns_index() {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let pack = write_small_proof_pack(&temp_dir, &key_manager);
+
+        let mut manifest = create_test_manifest();
+        manifest.recording.duration_seconds = 120.0;
+        manifest.sign(&key_manager);
+        replace_proof_pack_manifest(&pack, &manifest, &key_manager).unwrap();
+
+        let (_, manifest_json) = read_proof_pack_manifest(&pack).unwrap();
+        let reloaded: EvidenceManifest = serde_json::from_str(&manifest_json).unwrap();
+        assert_eq!(reloaded.recording.duration_seconds, 120.0);
+
+        let result = verify_proof_pack_index(&pack).unwrap();
+        assert!(result.signature_valid);
+        assert!(result.files_intact());
+        assert!(!temp_dir.path().join("test.notari.tmp").exists());
+
+        // Re-signing with a rotated key regenerates the public key and README
+        let rotated = KeyManager::generate();
+        manifest.sign(&rotated);
+        replace_proof_pack_manifest(&pack, &manifest, &rotated).unwrap();
+        let public_key = general_purpose::STANDARD.encode(rotated.public_key().as_bytes());
+        let entries = read_entries(&pack);
+        let entry = |name: &str| {
+            let (_, data) = entries.iter().find(|(entry, _)| entry == name).unwrap();
+            String::from_utf8(data.clone()).unwrap()
+        };
+        assert_eq!(entry("evidence/public_key.txt"), public_key);
+        assert!(entry("README.txt").contains(&public_key));
+        assert!(verify_proof_pack_index(&pack).unwrap().files_intact());
+
+        // The video is copied without recompression
+        let mut archive = zip::ZipArchive::new(File::open(&pack).unwrap()).unwrap();
+        let video = archive.by_name("evidence/test.mov").unwrap();
+        assert_eq!(video.compression(), zip::CompressionMethod::Stored);
+    }
+
+    #[test]
+    fn test_replace_video() {
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let pack = write_small_proof_pack(&temp_dir, &key_manager);
+        let video_path = temp_dir.path().join("rotated.mov");
+        fs::write(&video_path, b"re-encrypted data").unwrap();
+
+        let mut manifest = create_test_manifest();
+        manifest.sign(&key_manager);
+        replace_proof_pack_video(&pack, &video_path, &manifest, &key_manager).unwrap();
+
+        // Same entry name, new contents, and the index covers them
+        assert_eq!(
+            read_proof_pack_video_prefix(&pack, u64::MAX).unwrap(),
+            b"re-encrypted data"
+        );
+        let result = verify_proof_pack_index(&pack).unwrap();
+        assert!(result.signature_valid);
+        assert!(result.files_intact());
+
+        let mut archive = zip::ZipArchive::new(File::open(&pack).unwrap()).unwrap();
+        let video = archive.by_name("evidence/test.mov").unwrap();
+        assert_eq!(video.compression(), zip::CompressionMethod::Stored);
+    }
+
+    #[test]
+    fn test_read_video_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = write_small_proof_pack(&temp_dir, &KeyManager::generate());
+
+        assert_eq!(read_proof_pack_video_prefix(&pack, 4).unwrap(), b"test");
+        assert_eq!(
+            read_proof_pack_video_prefix(&pack, u64::MAX).unwrap(),
+            b"test data"
+        );
+    }
+
+    #[test]
+    fn test_replace_manifest_upgrades_legacy_pack() {
+        let temp_dir = TempDir::new().unwrap();
+        let pack = temp_dir.path().join("legacy.notari");
+        let metadata = br#"{"version":"1.0","created_at":"2024-01-01T00:00:00Z","notari_version":"0.1.0","recording_filename":"test.mov","is_encrypted":false}"#;
+        write_zip(
+            &pack,
+            &[
+                ("evidence/test.mov", b"video"),
+                ("evidence/test.json", b"{}"),
+                ("metadata.json", metadata),
+            ],
+        );
+
+        let key_manager = KeyManager::generate();
+        let mut manifest = create_test_manifest();
+        manifest.sign(&key_manager);
+        replace_proof_pack_manifest(&pack, &manifest, &key_manager).unwrap();
+
+        let result = verify_proof_pack_index(&pack).unwrap();
+        assert!(result.index.is_some());
+        assert!(result.signature_valid);
+        assert!(result.files_intact());
+        assert_eq!(
+            read_proof_pack_metadata(&pack).unwrap().version,
+            PROOF_PACK_VERSION
+        );
+    }
 }
@@ -9,6 +9,19 @@ pub struct SignatureInfo {
     pub public_key: String,
     pub signature: String,
     pub signed_data_hash: String,
+    /// Certified identity of the holder of `public_key`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<SignerIdentity>,
+}
+
+/// Identity a third party certifies for a signing key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SignerIdentity {
+    /// X.509 certificate chain issued by an organisational CA, leaf first
+    /// (base64 DER). The leaf certifies the Ed25519 signing key.
+    #[serde(rename = "x509")]
+    X509 { certificates: Vec<String> },
 }
 
 pub struct KeyManager {
@@ -61,6 +74,7 @@ impl KeyManager {
             public_key: general_purpose::STANDARD.encode(public_key.as_bytes()),
             signature: general_purpose::STANDARD.encode(signature.to_bytes()),
             signed_data_hash: format!("sha256:{}", hex::encode(data_hash)),
+            identity: None,
         }
     }
 
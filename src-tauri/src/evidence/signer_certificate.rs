@@ -0,0 +1,459 @@
+//! X.509 certificates tying signing keys to organisational identities
+//!
+//! In managed deployments an organisational CA certifies each user's Ed25519
+//! signing key. The chain, leaf first, is embedded in the manifest signature as
+//! `SignerIdentity::X509`, where the signature covers it, and verifiers check it
+//! against the CA certificates they are configured to trust. The chain for the
+//! keychain signing key is kept as PEM in the app data directory.
+
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::key_history::APP_IDENTIFIER;
+use super::signature::{KeyManager, SignerIdentity};
+use super::x509::{self, Certificate};
+use crate::error::{NotariError, NotariResult};
+
+/// Name of the keychain signing key's certificate chain in the app data directory
+pub const SIGNER_CERTIFICATES_FILE: &str = "signer_certificates.pem";
+
+const PEM_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_END: &str = "-----END CERTIFICATE-----";
+
+/// Extended key usage purposes that let a certificate sign manifests
+const SIGNING_PURPOSES: [&str; 3] = [
+    x509::OID_KP_CODE_SIGNING,
+    x509::OID_KP_DOCUMENT_SIGNING,
+    x509::OID_ANY_EXTENDED_KEY_USAGE,
+];
+
+/// Readable summary of a certificate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateSummary {
+    pub subject: String,
+    pub issuer: String,
+    /// SHA-256 fingerprint of the DER encoding (hex)
+    pub fingerprint: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub is_ca: bool,
+}
+
+impl From<&Certificate> for CertificateSummary {
+    fn from(certificate: &Certificate) -> Self {
+        Self {
+            subject: certificate.subject_name(),
+            issuer: certificate.issuer_name(),
+            fingerprint: certificate.fingerprint(),
+            not_before: certificate.not_before,
+            not_after: certificate.not_after,
+            is_ca: certificate.is_ca,
+        }
+    }
+}
+
+/// Parse certificates from PEM, or from concatenated DER
+pub fn parse_certificates(data: &[u8]) -> NotariResult<Vec<Certificate>> {
+    let text = match std::str::from_utf8(data) {
+        Ok(text) if text.contains(PEM_BEGIN) => text,
+        _ => return x509::parse_certificates(data),
+    };
+
+    let mut certificates = Vec::new();
+    for block in text.split(PEM_BEGIN).skip(1) {
+        let (body, _) = block.split_once(PEM_END).ok_or_else(|| {
+            NotariError::CertificateError("unterminated PEM certificate".to_string())
+        })?;
+        let der = general_purpose::STANDARD
+            .decode(body.split_whitespace().collect::<String>())
+            .map_err(|e| NotariError::CertificateError(format!("invalid PEM: {}", e)))?;
+        certificates.push(Certificate::from_der(&der)?);
+    }
+    Ok(certificates)
+}
+
+/// Encode certificates as PEM
+pub fn to_pem(certificates: &[Certificate]) -> String {
+    let mut pem = String::new();
+    for certificate in certificates {
+        let encoded = general_purpose::STANDARD.encode(&certificate.der);
+        pem.push_str(PEM_BEGIN);
+        pem.push('\n');
+        for line in encoded.as_bytes().chunks(64) {
+            pem.push_str(std::str::from_utf8(line).unwrap());
+            pem.push('\n');
+        }
+        pem.push_str(PEM_END);
+        pem.push('\n');
+    }
+    pem
+}
+
+/// Where the keychain signing key's certificate chain is kept
+pub fn default_path() -> NotariResult<PathBuf> {
+    let data_dir = dirs::data_dir().ok_or_else(|| {
+        NotariError::StorageLoadFailed("Could not determine data directory".to_string())
+    })?;
+    Ok(data_dir.join(APP_IDENTIFIER).join(SIGNER_CERTIFICATES_FILE))
+}
+
+/// Read the chain at `path`, if there is one
+pub fn load_chain(path: &Path) -> NotariResult<Option<Vec<Certificate>>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let data = std::fs::read(path)
+        .map_err(|e| NotariError::StorageLoadFailed(format!("{}: {}", path.display(), e)))?;
+    parse_certificates(&data).map(Some)
+}
+
+/// Save `chain` to `path` as the chain to sign with `key` as
+///
+/// Fails unless the chain's leaf certifies `key`.
+pub fn save_chain(path: &Path, chain: &[Certificate], key: &KeyManager) -> NotariResult<()> {
+    identity(chain, key)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e)))?;
+    }
+    let tmp_path = path.with_extension("pem.tmp");
+    std::fs::write(&tmp_path, to_pem(chain))
+        .and_then(|_| std::fs::rename(&tmp_path, path))
+        .map_err(|e| NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e)))
+}
+
+/// Identity to sign with `key` as, checking the leaf of `chain` certifies it
+pub fn identity(chain: &[Certificate], key: &KeyManager) -> NotariResult<SignerIdentity> {
+    let leaf = chain
+        .first()
+        .ok_or_else(|| NotariError::CertificateError("empty certificate chain".to_string()))?;
+    if leaf.ed25519_public_key() != Some(key.public_key().as_bytes().as_slice()) {
+        return Err(NotariError::CertificateError(format!(
+            "{} doesn't certify the signing key",
+            leaf.subject_name()
+        )));
+    }
+
+    Ok(SignerIdentity::X509 {
+        certificates: chain
+            .iter()
+            .map(|certificate| general_purpose::STANDARD.encode(&certificate.der))
+            .collect(),
+    })
+}
+
+/// Certificates of an X.509 signer identity, leaf first
+pub fn identity_certificates(identity: &SignerIdentity) -> NotariResult<Vec<Certificate>> {
+    match identity {
+        SignerIdentity::X509 { certificates } => certificates
+            .iter()
+            .map(|encoded| Certificate::from_der(&general_purpose::STANDARD.decode(encoded)?))
+            .collect(),
+    }
+}
+
+/// Check that the leaf of `chain` certifies `signer_key` (Ed25519) for signing
+/// and that every certificate is issued by the CA after it
+///
+/// The leaf needs the digitalSignature key usage or a code or document signing
+/// extended key usage, and neither extension may rule signing out.
+pub fn verify_chain(chain: &[Certificate], signer_key: &[u8]) -> Result<(), String> {
+    let leaf = chain.first().ok_or("Certificate chain is empty")?;
+    if leaf.ed25519_public_key() != Some(signer_key) {
+        return Err(format!(
+            "{} doesn't certify the manifest signer's key",
+            leaf.subject_name()
+        ));
+    }
+
+    let key_usage = leaf
+        .key_usage
+        .map(|_| leaf.allows_key_usage(x509::KEY_USAGE_DIGITAL_SIGNATURE));
+    let extended_key_usage = (!leaf.extended_key_usage.is_empty()).then(|| {
+        SIGNING_PURPOSES
+            .iter()
+            .any(|purpose| leaf.has_extended_key_usage(purpose))
+    });
+    match (key_usage, extended_key_usage) {
+        (Some(false), _) | (_, Some(false)) => {
+            return Err(format!(
+                "{} isn't allowed to sign (key usage)",
+                leaf.subject_name()
+            ))
+        }
+        (None, None) => {
+            return Err(format!(
+                "{} has no key usage allowing it to sign",
+                leaf.subject_name()
+            ))
+        }
+        _ => {}
+    }
+    verify_issuers(chain)
+}
+
+/// Check that every certificate in `chain` is issued by the CA after it
+///
+/// Every certificate must only have critical extensions this module understands,
+/// and every issuer must be a CA allowed to sign certificates whose path length
+/// constraint admits the intermediate CAs below it.
+pub fn verify_issuers(chain: &[Certificate]) -> Result<(), String> {
+    for certificate in chain {
+        if let Some(oid) = certificate.unknown_critical_extensions.first() {
+            return Err(format!(
+                "{} has an unsupported critical extension {}",
+                certificate.subject_name(),
+                oid
+            ));
+        }
+    }
+    for (intermediates, pair) in chain.windows(2).enumerate() {
+        let (certificate, issuer) = (&pair[0], &pair[1]);
+        check_issuer(issuer, intermediates)?;
+        match certificate.is_issued_by(issuer) {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(format!(
+                    "{} isn't issued by {}",
+                    certificate.subject_name(),
+                    issuer.subject_name()
+                ))
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+    Ok(())
+}
+
+/// Check that `issuer` may issue certificates with `intermediates` CAs between
+/// it and the leaf
+fn check_issuer(issuer: &Certificate, intermediates: usize) -> Result<(), String> {
+    if !issuer.is_ca {
+        return Err(format!("{} isn't a CA", issuer.subject_name()));
+    }
+    if !issuer.allows_key_usage(x509::KEY_USAGE_KEY_CERT_SIGN) {
+        return Err(format!(
+            "{} isn't allowed to sign certificates",
+            issuer.subject_name()
+        ));
+    }
+    match issuer.path_len_constraint {
+        Some(max) if intermediates as u64 > max => Err(format!(
+            "{} allows at most {} intermediate CA(s) below it",
+            issuer.subject_name(),
+            max
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Check that every certificate in `chain` was valid at `time`
+pub fn verify_validity(chain: &[Certificate], time: &DateTime<Utc>) -> Result<(), String> {
+    for certificate in chain {
+        if !certificate.is_valid_at(time) {
+            return Err(format!(
+                "{} wasn't valid at {} (valid {} to {})",
+                certificate.subject_name(),
+                time.to_rfc3339(),
+                certificate.not_before.to_rfc3339(),
+                certificate.not_after.to_rfc3339()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Trust anchor `chain` leads to, if any
+///
+/// The last certificate of the chain has to be one of `trust_anchors`, or be
+/// issued by one that is a CA valid at `time` whose constraints admit the chain.
+pub fn find_trust_anchor<'a>(
+    chain: &[Certificate],
+    trust_anchors: &'a [Certificate],
+    time: &DateTime<Utc>,
+) -> Option<&'a Certificate> {
+    let last = chain.last()?;
+    trust_anchors.iter().find(|anchor| {
+        anchor.der == last.der
+            || (check_issuer(anchor, chain.len() - 1).is_ok()
+                && anchor.is_valid_at(time)
+                && last.is_issued_by(anchor).unwrap_or(false))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evidence::x509::testing::{
+        ed25519_spki, issue, issue_for_spki, CertificateParams, TestKey,
+    };
+
+    /// Organisational CA and a certificate it issued for `key`
+    fn issue_chain(key: &KeyManager) -> (Certificate, Certificate) {
+        let ca_key = TestKey::generate();
+        let mut ca_params = CertificateParams::new("Example Corp CA");
+        ca_params.is_ca = true;
+        let ca = Certificate::from_der(&issue(&ca_params, &ca_key, None)).unwrap();
+
+        let leaf = Certificate::from_der(&issue_for_spki(
+            &CertificateParams::signer("Alice"),
+            &ed25519_spki(key.public_key().as_bytes()),
+            ("Example Corp CA", &ca_key),
+        ))
+        .unwrap();
+        (leaf, ca)
+    }
+
+    #[test]
+    fn test_pem_round_trip() {
+        let key = KeyManager::generate();
+        let (leaf, ca) = issue_chain(&key);
+
+        let pem = to_pem(&[leaf.clone(), ca.clone()]);
+        let parsed = parse_certificates(pem.as_bytes()).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].der, leaf.der);
+        assert_eq!(parsed[1].der, ca.der);
+
+        let der = [leaf.der.clone(), ca.der.clone()].concat();
+        assert_eq!(parse_certificates(&der).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_identity_requires_certified_key() {
+        let key = KeyManager::generate();
+        let (leaf, ca) = issue_chain(&key);
+
+        let identity = identity(&[leaf.clone(), ca.clone()], &key).unwrap();
+        let certificates = identity_certificates(&identity).unwrap();
+        assert_eq!(certificates[0].subject_name(), "CN=Alice");
+
+        assert!(super::identity(&[leaf, ca], &KeyManager::generate()).is_err());
+        assert!(super::identity(&[], &key).is_err());
+    }
+
+    #[test]
+    fn test_chain_validation() {
+        let key = KeyManager::generate();
+        let (leaf, ca) = issue_chain(&key);
+        let chain = [leaf.clone(), ca.clone()];
+        let now = Utc::now();
+        let signer_key = key.public_key().to_bytes();
+
+        assert_eq!(verify_chain(&chain, &signer_key), Ok(()));
+        assert!(verify_chain(&chain, &[0u8; 32]).is_err());
+        assert_eq!(verify_validity(&chain, &now), Ok(()));
+        assert!(verify_validity(&chain, &(now + chrono::Duration::days(400))).is_err());
+
+        // The chain may end at the anchor or just below it
+        assert!(find_trust_anchor(&chain, &[ca.clone()], &now).is_some());
+        assert!(find_trust_anchor(&[leaf.clone()], &[ca.clone()], &now).is_some());
+
+        // A CA with the same name but another key isn't trusted
+        let mut other_params = CertificateParams::new("Example Corp CA");
+        other_params.is_ca = true;
+        let other_ca =
+            Certificate::from_der(&issue(&other_params, &TestKey::generate(), None)).unwrap();
+        assert!(find_trust_anchor(&[leaf.clone()], &[other_ca.clone()], &now).is_none());
+        assert!(verify_chain(&[leaf, other_ca], &signer_key).is_err());
+    }
+
+    #[test]
+    fn test_chain_requires_signing_leaf() {
+        let key = KeyManager::generate();
+        let signer_key = key.public_key().to_bytes();
+        let ca_key = TestKey::generate();
+        let mut ca_params = CertificateParams::new("Example Corp CA");
+        ca_params.is_ca = true;
+        let ca = Certificate::from_der(&issue(&ca_params, &ca_key, None)).unwrap();
+        let leaf_with = |params: &CertificateParams<'_>| {
+            let leaf = Certificate::from_der(&issue_for_spki(
+                params,
+                &ed25519_spki(key.public_key().as_bytes()),
+                ("Example Corp CA", &ca_key),
+            ))
+            .unwrap();
+            verify_chain(&[leaf, ca.clone()], &signer_key)
+        };
+
+        assert_eq!(leaf_with(&CertificateParams::signer("Alice")), Ok(()));
+
+        // Code signing extended key usage without a key usage extension
+        let mut params = CertificateParams::new("Alice");
+        params.extended_key_usage = &[x509::OID_KP_CODE_SIGNING];
+        assert_eq!(leaf_with(&params), Ok(()));
+
+        // Nothing saying the key may sign
+        assert!(leaf_with(&CertificateParams::new("Alice")).is_err());
+
+        // Usages that rule signing out
+        let mut params = CertificateParams::new("Alice");
+        params.key_usage = Some(x509::KEY_USAGE_KEY_CERT_SIGN);
+        assert!(leaf_with(&params).is_err());
+        let mut params = CertificateParams::signer("Alice");
+        params.extended_key_usage = &[x509::OID_KP_TIME_STAMPING];
+        assert!(leaf_with(&params).is_err());
+
+        // An extension the verifier can't enforce
+        let mut params = CertificateParams::signer("Alice");
+        params.critical_extensions = &["1.2.3.4"];
+        let error = leaf_with(&params).unwrap_err();
+        assert!(error.contains("unsupported critical extension 1.2.3.4"));
+    }
+
+    #[test]
+    fn test_chain_enforces_ca_constraints() {
+        let key = KeyManager::generate();
+        let signer_key = key.public_key().to_bytes();
+        let now = Utc::now();
+
+        // Root -> Intermediate -> Alice, with the root's path length constraint varied
+        let chain_under_root = |root_params: &CertificateParams<'_>| {
+            let root_key = TestKey::generate();
+            let root = Certificate::from_der(&issue(root_params, &root_key, None)).unwrap();
+            let intermediate_key = TestKey::generate();
+            let mut intermediate_params = CertificateParams::new("Example Corp Issuing CA");
+            intermediate_params.is_ca = true;
+            let intermediate = Certificate::from_der(&issue(
+                &intermediate_params,
+                &intermediate_key,
+                Some(("Example Corp Root", &root_key)),
+            ))
+            .unwrap();
+            let leaf = Certificate::from_der(&issue_for_spki(
+                &CertificateParams::signer("Alice"),
+                &ed25519_spki(key.public_key().as_bytes()),
+                ("Example Corp Issuing CA", &intermediate_key),
+            ))
+            .unwrap();
+            (vec![leaf, intermediate], root)
+        };
+
+        let mut root_params = CertificateParams::new("Example Corp Root");
+        root_params.is_ca = true;
+        root_params.path_len = Some(1);
+        let (chain, root) = chain_under_root(&root_params);
+        let full_chain = [chain.clone(), vec![root.clone()]].concat();
+        assert_eq!(verify_chain(&full_chain, &signer_key), Ok(()));
+        assert!(find_trust_anchor(&chain, &[root], &now).is_some());
+
+        // pathLen 0: the root may only issue end-entity certificates
+        root_params.path_len = Some(0);
+        let (chain, root) = chain_under_root(&root_params);
+        let full_chain = [chain.clone(), vec![root.clone()]].concat();
+        let error = verify_chain(&full_chain, &signer_key).unwrap_err();
+        assert!(error.contains("at most 0 intermediate CA(s)"));
+        assert!(find_trust_anchor(&chain, &[root], &now).is_none());
+
+        // A CA whose key usage excludes certificate signing
+        root_params.path_len = None;
+        root_params.key_usage = Some(x509::KEY_USAGE_DIGITAL_SIGNATURE);
+        let (chain, root) = chain_under_root(&root_params);
+        let full_chain = [chain.clone(), vec![root.clone()]].concat();
+        assert!(verify_chain(&full_chain, &signer_key).is_err());
+        assert!(find_trust_anchor(&chain, &[root], &now).is_none());
+    }
+}
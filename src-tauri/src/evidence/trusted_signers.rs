@@ -0,0 +1,103 @@
+//! Signing keys the user trusts, pinned under a name
+//!
+//! A valid manifest signature only shows the recording is unchanged since it was
+//! signed with the key embedded in the manifest, and anyone can generate a key.
+//! Pinning the public keys of known signers lets verification tell a recording
+//! signed by "Alice" apart from one signed by an unknown key.
+
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{NotariError, NotariResult};
+
+/// Public key pinned under a name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustedSigner {
+    pub name: String,
+    /// Ed25519 public key (base64). An identity key also vouches for the keys
+    /// rotated in from it.
+    pub public_key: String,
+    pub added_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TrustedSigner {
+    /// Pin `public_key` under `name`
+    ///
+    /// The key is normalised to standard base64, so keys pasted with surrounding
+    /// whitespace still match the ones in manifests.
+    pub fn new(name: &str, public_key: &str, note: Option<String>) -> NotariResult<Self> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(NotariError::InvalidTrustedSigner(
+                "Name must not be empty".to_string(),
+            ));
+        }
+
+        let key_bytes = general_purpose::STANDARD
+            .decode(public_key.trim())
+            .map_err(|e| NotariError::InvalidTrustedSigner(format!("Invalid public key: {}", e)))?;
+        if key_bytes.len() != 32 {
+            return Err(NotariError::InvalidTrustedSigner(format!(
+                "Public key must be 32 bytes, got {}",
+                key_bytes.len()
+            )));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            public_key: general_purpose::STANDARD.encode(key_bytes),
+            added_at: Utc::now(),
+            note: note.filter(|note| !note.trim().is_empty()),
+        })
+    }
+}
+
+/// Find the trusted signer pinning any of `keys`, in order
+///
+/// Pass the manifest signer's key first and its identity key after it, so a
+/// pin on the exact key wins over a pin on the identity.
+pub fn find_trusted<'a>(signers: &'a [TrustedSigner], keys: &[&str]) -> Option<&'a TrustedSigner> {
+    keys.iter()
+        .find_map(|key| signers.iter().find(|signer| signer.public_key == *key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evidence::signature::KeyManager;
+
+    fn encoded_key() -> String {
+        general_purpose::STANDARD.encode(KeyManager::generate().public_key().as_bytes())
+    }
+
+    #[test]
+    fn test_trusted_signer_validates_input() {
+        let key = encoded_key();
+
+        let signer = TrustedSigner::new(" Alice ", &format!(" {}\n", key), None).unwrap();
+        assert_eq!(signer.name, "Alice");
+        assert_eq!(signer.public_key, key);
+
+        assert!(TrustedSigner::new("  ", &key, None).is_err());
+        assert!(TrustedSigner::new("Alice", "not base64!", None).is_err());
+        assert!(TrustedSigner::new("Alice", "AAAA", None).is_err());
+    }
+
+    #[test]
+    fn test_find_trusted_prefers_earlier_keys() {
+        let (signer_key, identity_key) = (encoded_key(), encoded_key());
+        let signers = vec![
+            TrustedSigner::new("Alice", &identity_key, None).unwrap(),
+            TrustedSigner::new("Alice (laptop)", &signer_key, None).unwrap(),
+        ];
+
+        let found = find_trusted(&signers, &[&signer_key, &identity_key]).unwrap();
+        assert_eq!(found.name, "Alice (laptop)");
+        let found = find_trusted(&signers, &[&encoded_key(), &identity_key]).unwrap();
+        assert_eq!(found.name, "Alice");
+        assert!(find_trusted(&signers, &[&encoded_key()]).is_none());
+    }
+}
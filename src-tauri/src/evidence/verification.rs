@@ -1,9 +1,22 @@
+use base64::{engine::general_purpose, Engine as _};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
-use super::{EvidenceManifest, HashInfo};
-use crate::error::NotariResult;
+use super::blockchain::opentimestamps;
+use super::blockchain::rfc3161::TokenVerification;
+use super::blockchain::{
+    AnchorProof, BlockchainAnchor, BlockchainAnchorer, OpenTimestampsAnchorer, TsaAnchorer,
+};
+use super::checkpoint::{CheckpointLog, CheckpointSummary};
+use super::key_history::KeyHistory;
+use super::pack_index::LEGACY_PROOF_PACK_VERSION;
+use super::proof_pack;
+use super::signer_certificate;
+use super::trusted_signers::{self, TrustedSigner};
+use super::x509::Certificate;
+use super::{ChunkHashes, EvidenceManifest, HashInfo, PauseInterval};
+use crate::error::{NotariError, NotariResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationReport {
@@ -33,21 +46,719 @@ pub struct VerificationChecks {
     pub signature_valid: CheckResult,
     pub hash_match: CheckResult,
 
-    /// Blockchain anchor verification (optional)
+    /// Per-chunk hash check (only present when the manifest has chunk hashes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_hashes: Option<ChunkHashCheck>,
+
+    /// One check per blockchain or timestamp anchor, in manifest order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub anchors: Vec<BlockchainAnchorCheck>,
+
+    /// Proof pack file index verification (only present when verifying a .notari file)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_pack: Option<ProofPackCheck>,
+
+    /// In-session checkpoint log verification (only present when the proof pack
+    /// has checkpoints)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoints: Option<CheckpointCheck>,
+
+    /// Link from the signer's key to its identity key (only present when the
+    /// proof pack has a key history or an identity key was pinned)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_history: Option<KeyHistoryCheck>,
+
+    /// Signer's X.509 certificate chain (only present when the manifest
+    /// signature carries one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<CertificateCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofPackCheck {
+    /// Proof pack layout version ("1.0" packs have no index)
+    pub format_version: String,
+    /// Index signature is valid and made by the manifest signer
+    pub index_signature: CheckResult,
+    /// Every archive entry is listed in the index and matches its hash and size
+    pub files_match: CheckResult,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unlisted_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modified_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkHashCheck {
+    /// The chunk hashes in the manifest lead to its signed Merkle root
+    pub root_valid: CheckResult,
+    /// Every chunk of the video matches its hash (skipped for encrypted videos,
+    /// whose chunk hashes cover the plaintext)
+    pub chunks_match: CheckResult,
+    pub chunk_size: u64,
+    pub chunk_count: usize,
+    /// First chunk of the video that doesn't match the manifest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_mismatch: Option<usize>,
+    /// Byte offset of `first_mismatch` in the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_mismatch_offset: Option<u64>,
+}
+
+impl ChunkHashCheck {
+    /// Compare `expected` from the manifest against the video's `computed` chunk hashes
+    fn new(expected: &ChunkHashes, computed: Option<&ChunkHashes>) -> Self {
+        let first_mismatch = computed.and_then(|computed| expected.first_mismatch(computed));
+        Self {
+            root_valid: if expected.root_valid() {
+                CheckResult::Pass
+            } else {
+                CheckResult::Fail
+            },
+            chunks_match: match (computed, first_mismatch) {
+                (None, _) => CheckResult::Skip,
+                (Some(_), None) => CheckResult::Pass,
+                (Some(_), Some(_)) => CheckResult::Fail,
+            },
+            chunk_size: expected.chunk_size,
+            chunk_count: expected.chunks.len(),
+            first_mismatch,
+            first_mismatch_offset: first_mismatch.map(|index| expected.offset(index)),
+        }
+    }
+
+    fn passed(&self) -> bool {
+        self.root_valid != CheckResult::Fail && self.chunks_match != CheckResult::Fail
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointCheck {
+    /// The packed log is the one the manifest commits to, from the same session
+    /// and signed with the manifest signer's key
+    pub log_match: CheckResult,
+    /// Every checkpoint is signed by the log's key
+    pub signatures_valid: CheckResult,
+    /// Sequence numbers, links and running hashes follow on from each other
+    pub chain_valid: CheckResult,
+    /// Checkpoint times increase and fall within the recording
+    pub times_valid: CheckResult,
+    /// RFC 3161 tokens match their checkpoints (skipped if none were time-stamped)
+    pub timestamps_valid: CheckResult,
+    /// The checkpointed segments are the ones the manifest lists as joined into
+    /// the video or dropped
+    pub segments_valid: CheckResult,
+    /// The segments packed with the video are the ones the manifest lists as
+    /// joined into it (skipped for encrypted recordings, which don't carry them)
+    pub segments_packed: CheckResult,
+    pub count: usize,
+    pub timestamped: usize,
+    /// Segments the manifest lists as joined into the video
+    pub joined_segments: usize,
+    /// Checkpointed segments left out of the video because they were missing or
+    /// damaged when it was joined
+    pub dropped_segments: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub blockchain_anchor: Option<BlockchainAnchorCheck>,
+    pub last_at: Option<DateTime<Utc>>,
+    /// Longest stretch of the recording without a checkpoint
+    pub max_gap_seconds: i64,
+    /// First problem found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CheckpointCheck {
+    /// Check the packed `log_json` against the manifest's `summary`, and the
+    /// hashes of the packed segments against the segments it lists (`None` if
+    /// the recording is encrypted)
+    fn new(
+        manifest: &EvidenceManifest,
+        summary: Option<&CheckpointSummary>,
+        log_json: &str,
+        packed_segments: Option<&[String]>,
+    ) -> Self {
+        let log = match serde_json::from_str::<CheckpointLog>(log_json) {
+            Ok(log) => log,
+            Err(e) => return Self::failed(format!("Unreadable checkpoint log: {}", e)),
+        };
+        let mut errors = Vec::new();
+
+        let log_hash = HashInfo::from_bytes(log_json.as_bytes());
+        let log_match = match summary {
+            None => Err("Checkpoint log isn't referenced by the manifest".to_string()),
+            Some(summary) if summary.log_hash.value != log_hash.value => {
+                Err("Checkpoint log doesn't match the manifest".to_string())
+            }
+            Some(_) if log.session_id != manifest.recording.session_id => {
+                Err("Checkpoint log is from another session".to_string())
+            }
+            Some(_) if log.public_key != manifest.signature.public_key => {
+                Err("Checkpoint log isn't signed by the manifest signer".to_string())
+            }
+            Some(_) => Ok(()),
+        };
+        let signatures_valid = if log.signatures_valid() {
+            Ok(())
+        } else {
+            Err("Checkpoint signature is invalid".to_string())
+        };
+        let started_at = manifest.timestamps.started_at;
+        let stopped_at = manifest.timestamps.stopped_at;
+        let chain_valid = log.verify_chain();
+        let times_valid = log.verify_times(started_at, stopped_at);
+        let timestamps_valid = log.verify_timestamps();
+        let segments_valid = summary.map(|summary| log.verify_segments(summary));
+        let dropped_segments = match segments_valid {
+            Some(Ok(dropped)) => dropped,
+            _ => 0,
+        };
+        let segments_valid = segments_valid.map(|check| check.map(|_| ()));
+        let segments_packed = summary.zip(packed_segments).map(|(summary, packed)| {
+            if packed == summary.segments.as_slice() {
+                Ok(())
+            } else {
+                Err("Packed segments aren't the ones joined into the video".to_string())
+            }
+        });
+
+        let mut result = |check: &Result<(), String>| match check {
+            Ok(()) => CheckResult::Pass,
+            Err(e) => {
+                errors.push(e.clone());
+                CheckResult::Fail
+            }
+        };
+        let mut check = Self {
+            log_match: result(&log_match),
+            signatures_valid: result(&signatures_valid),
+            chain_valid: result(&chain_valid),
+            times_valid: result(&times_valid),
+            timestamps_valid: timestamps_valid
+                .as_ref()
+                .map_or(CheckResult::Skip, &mut result),
+            segments_valid: segments_valid
+                .as_ref()
+                .map_or(CheckResult::Skip, &mut result),
+            segments_packed: segments_packed
+                .as_ref()
+                .map_or(CheckResult::Skip, &mut result),
+            count: log.checkpoints.len(),
+            timestamped: log
+                .checkpoints
+                .iter()
+                .filter(|checkpoint| checkpoint.timestamp.is_some())
+                .count(),
+            joined_segments: summary.map_or(0, |summary| summary.segments.len()),
+            dropped_segments,
+            first_at: log
+                .checkpoints
+                .first()
+                .map(|checkpoint| checkpoint.created_at),
+            last_at: log
+                .checkpoints
+                .last()
+                .map(|checkpoint| checkpoint.created_at),
+            max_gap_seconds: log.max_gap(started_at, stopped_at).num_seconds(),
+            error: None,
+        };
+        check.error = errors.into_iter().next();
+        check
+    }
+
+    /// A check that failed before the log could be read
+    fn failed(error: String) -> Self {
+        Self {
+            log_match: CheckResult::Fail,
+            signatures_valid: CheckResult::Skip,
+            chain_valid: CheckResult::Skip,
+            times_valid: CheckResult::Skip,
+            timestamps_valid: CheckResult::Skip,
+            segments_valid: CheckResult::Skip,
+            segments_packed: CheckResult::Skip,
+            count: 0,
+            timestamped: 0,
+            joined_segments: 0,
+            dropped_segments: 0,
+            first_at: None,
+            last_at: None,
+            max_gap_seconds: 0,
+            error: Some(error),
+        }
+    }
+
+    fn passed(&self) -> bool {
+        [
+            &self.log_match,
+            &self.signatures_valid,
+            &self.chain_valid,
+            &self.times_valid,
+            &self.timestamps_valid,
+            &self.segments_valid,
+            &self.segments_packed,
+        ]
+        .iter()
+        .all(|check| **check != CheckResult::Fail)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyHistoryCheck {
+    /// Every rotation is signed by the key it retires and the key it introduces,
+    /// in order (skipped when the signer's key is an identity key)
+    pub chain_valid: CheckResult,
+    /// The chain ends at the key the manifest is signed with
+    pub links_signer: CheckResult,
+    /// Identity key the signer's key was rotated in from
+    pub identity_key: String,
+    pub rotations: usize,
+    /// When the signer's key was rotated in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotated_at: Option<DateTime<Utc>>,
+    /// The identity key is the one pinned by the verifier (skipped if none was)
+    pub identity_pinned: CheckResult,
+    /// First problem found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl KeyHistoryCheck {
+    /// Check the packed `history_json` links `signer_key` to its identity key
+    fn new(signer_key: &str, history_json: &str) -> Self {
+        let history = match KeyHistory::from_json(history_json.as_bytes()) {
+            Ok(history) => history,
+            Err(e) => {
+                return Self {
+                    chain_valid: CheckResult::Fail,
+                    links_signer: CheckResult::Skip,
+                    error: Some(format!("Unreadable key history: {}", e)),
+                    ..Self::identity(signer_key)
+                }
+            }
+        };
+
+        let chain_valid = history.verify();
+        let links_signer = if history.current_key() == signer_key {
+            Ok(())
+        } else {
+            Err("Key history doesn't end at the manifest signer's key".to_string())
+        };
+        let result = |check: &Result<(), String>| match check {
+            Ok(()) => CheckResult::Pass,
+            Err(_) => CheckResult::Fail,
+        };
+
+        Self {
+            chain_valid: result(&chain_valid),
+            links_signer: result(&links_signer),
+            identity_key: history.identity_key.clone(),
+            rotations: history.rotations.len(),
+            rotated_at: history.rotations.last().map(|rotation| rotation.rotated_at),
+            identity_pinned: CheckResult::Skip,
+            error: chain_valid.and(links_signer).err(),
+        }
+    }
+
+    /// A signer without key history, which is its own identity key
+    fn identity(signer_key: &str) -> Self {
+        Self {
+            chain_valid: CheckResult::Skip,
+            links_signer: CheckResult::Skip,
+            identity_key: signer_key.to_string(),
+            rotations: 0,
+            rotated_at: None,
+            identity_pinned: CheckResult::Skip,
+            error: None,
+        }
+    }
+
+    fn passed(&self) -> bool {
+        [&self.chain_valid, &self.links_signer, &self.identity_pinned]
+            .iter()
+            .all(|check| **check != CheckResult::Fail)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateCheck {
+    /// The leaf certificate certifies the manifest signer's key and each
+    /// certificate is issued by the CA after it
+    pub chain_valid: CheckResult,
+    /// Every certificate was valid when the manifest was created
+    pub valid_at_signing: CheckResult,
+    /// The chain leads to a configured trust anchor (skipped if none were given)
+    pub trusted: CheckResult,
+    /// Subject of the leaf certificate
+    pub subject: String,
+    /// Issuer of the leaf certificate
+    pub issuer: String,
+    /// SHA-256 fingerprint of the leaf certificate (hex)
+    pub fingerprint: String,
+    /// Subject of the trust anchor the chain leads to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_anchor: Option<String>,
+    /// First problem found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CertificateCheck {
+    /// Check the certificate chain in `manifest`'s signature against `trust_anchors`
+    ///
+    /// Returns `None` if the signature carries no chain.
+    fn new(manifest: &EvidenceManifest, trust_anchors: &[Certificate]) -> Option<Self> {
+        let identity = manifest.signature.identity.as_ref()?;
+        let chain = match signer_certificate::identity_certificates(identity) {
+            Ok(chain) if !chain.is_empty() => chain,
+            Ok(_) => return Some(Self::failed("Certificate chain is empty".to_string())),
+            Err(e) => return Some(Self::failed(format!("Unreadable certificate chain: {}", e))),
+        };
+
+        let signed_at = &manifest.timestamps.manifest_created_at;
+        let chain_valid = general_purpose::STANDARD
+            .decode(&manifest.signature.public_key)
+            .map_err(|e| format!("Invalid signer key: {}", e))
+            .and_then(|signer_key| signer_certificate::verify_chain(&chain, &signer_key));
+        let valid_at_signing = signer_certificate::verify_validity(&chain, signed_at);
+        let trust_anchor = signer_certificate::find_trust_anchor(&chain, trust_anchors, signed_at);
+        let trusted = match trust_anchor {
+            _ if trust_anchors.is_empty() => CheckResult::Skip,
+            Some(_) => CheckResult::Pass,
+            None => CheckResult::Fail,
+        };
+        let result = |check: &Result<(), String>| match check {
+            Ok(()) => CheckResult::Pass,
+            Err(_) => CheckResult::Fail,
+        };
+
+        let leaf = &chain[0];
+        Some(Self {
+            chain_valid: result(&chain_valid),
+            valid_at_signing: result(&valid_at_signing),
+            trusted: trusted.clone(),
+            subject: leaf.subject_name(),
+            issuer: leaf.issuer_name(),
+            fingerprint: leaf.fingerprint(),
+            trust_anchor: trust_anchor.map(Certificate::subject_name),
+            error: chain_valid.and(valid_at_signing).err().or_else(|| {
+                (trusted == CheckResult::Fail)
+                    .then(|| "Certificate chain doesn't lead to a trusted CA".to_string())
+            }),
+        })
+    }
+
+    fn failed(error: String) -> Self {
+        Self {
+            chain_valid: CheckResult::Fail,
+            valid_at_signing: CheckResult::Skip,
+            trusted: CheckResult::Skip,
+            subject: String::new(),
+            issuer: String::new(),
+            fingerprint: String::new(),
+            trust_anchor: None,
+            error: Some(error),
+        }
+    }
+
+    /// Whether the chain is sound; one that isn't trusted still passes
+    fn passed(&self) -> bool {
+        self.chain_valid != CheckResult::Fail && self.valid_at_signing != CheckResult::Fail
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockchainAnchorCheck {
     pub present: bool,
+    /// Chain or service holding the anchor (see `AnchorProof::service_id`)
+    #[serde(default)]
+    pub service: String,
     pub algorithm: String,
     pub anchored_at: String,
+    /// The anchored hash is the pre-anchor hash recomputed from the manifest
+    pub hash_match: CheckResult,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub explorer_url: Option<String>,
     /// On-chain verification status (only present for deep verification)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_chain_verified: Option<OnChainVerificationResult>,
+    /// Offline RFC 3161 token verification (only present for RFC 3161 anchors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_token: Option<TimestampTokenCheck>,
+    /// Offline batch membership verification (only present for batched anchors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merkle_inclusion: Option<MerkleInclusionCheck>,
+    /// Bitcoin attestation of an OpenTimestamps proof (only present for
+    /// OpenTimestamps anchors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bitcoin_attestation: Option<BitcoinAttestationCheck>,
+}
+
+/// The hash `anchor` has to cover, given the pre-anchor hash recomputed from
+/// `manifest`
+///
+/// Anchors of 1.0 manifests made before the pre-anchor hash was recomputable
+/// cover the raw `manifest.json` text, signature included, which is gone once
+/// the manifest is re-signed with the anchor. For those the stored hash is taken
+/// instead; the proof is still checked against it, and the signature binds the
+/// anchor to the manifest.
+fn expected_anchor_hash<'a>(
+    manifest: &EvidenceManifest,
+    anchor: &'a BlockchainAnchor,
+    pre_anchor_hash: &'a str,
+) -> &'a str {
+    if manifest.uses_legacy_encoding()
+        && anchor.anchored_hash != pre_anchor_hash
+        && anchor.anchored_hash == anchor.manifest_hash
+    {
+        &anchor.anchored_hash
+    } else {
+        pre_anchor_hash
+    }
+}
+
+impl BlockchainAnchorCheck {
+    /// Offline checks of `anchor` (no network access)
+    ///
+    /// `pre_anchor_hash` is recomputed from the manifest (see
+    /// `expected_anchor_hash`), not taken from the anchor, so a proof for some
+    /// other hash doesn't verify.
+    fn offline(anchor: &BlockchainAnchor, pre_anchor_hash: &str) -> Self {
+        let hash_match =
+            if anchor.anchored_hash == pre_anchor_hash && anchor.manifest_hash == pre_anchor_hash {
+                CheckResult::Pass
+            } else {
+                CheckResult::Fail
+            };
+        Self {
+            present: true,
+            service: anchor.proof.service_id(),
+            algorithm: anchor.proof.description(),
+            anchored_at: anchor.anchored_at.to_rfc3339(),
+            hash_match,
+            explorer_url: anchor.proof.explorer_url(),
+            on_chain_verified: None, // Standard verification doesn't check on-chain
+            timestamp_token: TimestampTokenCheck::verify(anchor, pre_anchor_hash, None),
+            merkle_inclusion: MerkleInclusionCheck::verify(anchor, pre_anchor_hash),
+            bitcoin_attestation: BitcoinAttestationCheck::verify(anchor, pre_anchor_hash, None),
+        }
+    }
+
+    /// Whether the anchor covers this manifest and the self-contained parts of
+    /// the proof (RFC 3161 token, batch inclusion proof) are valid
+    fn proofs_valid(&self) -> bool {
+        self.hash_match == CheckResult::Pass
+            && self
+                .timestamp_token
+                .as_ref()
+                .map_or(true, |token| token.verified)
+            && self
+                .merkle_inclusion
+                .as_ref()
+                .map_or(true, |inclusion| inclusion.verified)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimestampTokenCheck {
+    /// Token signature and message imprint are valid for the anchored hash
+    pub verified: bool,
+    /// The TSA certificate chain leads to a configured trust anchor (skipped
+    /// until checked by `Verifier::check_timestamp_authorities`). A token from an
+    /// untrusted TSA has a valid signature but doesn't vouch for the time.
+    pub trusted: CheckResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gen_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tsa_name: Option<String>,
+    /// SHA-256 fingerprint of the TSA certificate, for comparison against a trusted TSA
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tsa_certificate_sha256: Option<String>,
+    /// Subject of the trust anchor the TSA chain leads to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_anchor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TimestampTokenCheck {
+    /// Check the RFC 3161 token of `anchor`, and with `trust_anchors` whether its
+    /// TSA chains to one of them
+    fn verify(
+        anchor: &BlockchainAnchor,
+        pre_anchor_hash: &str,
+        trust_anchors: Option<&[Certificate]>,
+    ) -> Option<Self> {
+        if !matches!(anchor.proof.root_proof(), AnchorProof::Rfc3161 { .. }) {
+            return None;
+        }
+
+        let result = anchor
+            .proof
+            .resolve_root(pre_anchor_hash)
+            .and_then(|(hash, proof)| TsaAnchorer::verify_token(&hash, proof));
+        Some(match result {
+            Ok(token) => {
+                let trust = trust_anchors.map(|anchors| Self::find_trust_anchor(&token, anchors));
+                Self {
+                    verified: true,
+                    trusted: match trust {
+                        None => CheckResult::Skip,
+                        Some(Ok(_)) => CheckResult::Pass,
+                        Some(Err(_)) => CheckResult::Fail,
+                    },
+                    gen_time: Some(token.gen_time),
+                    tsa_name: Some(token.tsa_name),
+                    tsa_certificate_sha256: Some(token.tsa_certificate_sha256),
+                    trust_anchor: trust.clone().and_then(Result::ok),
+                    error: trust.and_then(Result::err),
+                }
+            }
+            Err(e) => Self {
+                verified: false,
+                trusted: CheckResult::Skip,
+                gen_time: None,
+                tsa_name: None,
+                tsa_certificate_sha256: None,
+                trust_anchor: None,
+                error: Some(e.to_string()),
+            },
+        })
+    }
+
+    /// Subject of the trust anchor the token's TSA chain leads to
+    fn find_trust_anchor(
+        token: &TokenVerification,
+        trust_anchors: &[Certificate],
+    ) -> Result<String, String> {
+        let chain = &token.tsa_chain;
+        signer_certificate::verify_issuers(chain)?;
+        signer_certificate::verify_validity(chain, &token.gen_time)?;
+        signer_certificate::find_trust_anchor(chain, trust_anchors, &token.gen_time)
+            .map(Certificate::subject_name)
+            .ok_or_else(|| "Valid signature, untrusted TSA".to_string())
+    }
+
+    /// Whether the token vouches for its time: valid and from a trusted TSA
+    pub fn passed(&self) -> bool {
+        self.verified && self.trusted == CheckResult::Pass
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoinAttestationCheck {
+    /// The proof carries a Bitcoin attestation, not just pending calendar commitments
+    pub complete: bool,
+    /// The attestation matches a supplied Bitcoin block header (skipped without
+    /// one, leaving a complete proof's attestation unchecked)
+    pub header_match: CheckResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Hash of the matching block header (big-endian hex, as shown by explorers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BitcoinAttestationCheck {
+    /// Check the OpenTimestamps proof of `anchor`, against `block_header` (80
+    /// bytes) if given
+    fn verify(
+        anchor: &BlockchainAnchor,
+        pre_anchor_hash: &str,
+        block_header: Option<&[u8]>,
+    ) -> Option<Self> {
+        if !matches!(
+            anchor.proof.root_proof(),
+            AnchorProof::OpenTimestamps { .. }
+        ) {
+            return None;
+        }
+
+        let mut check = Self {
+            complete: false,
+            header_match: CheckResult::Skip,
+            height: None,
+            block_hash: None,
+            block_time: None,
+            error: None,
+        };
+        let (hash, proof) = match anchor.proof.resolve_root(pre_anchor_hash) {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                check.header_match = CheckResult::Fail;
+                check.error = Some(e.to_string());
+                return Some(check);
+            }
+        };
+        check.complete =
+            opentimestamps::decode_proof(proof).is_ok_and(|file| file.timestamp.is_complete());
+
+        let Some(block_header) = block_header else {
+            return Some(check);
+        };
+        match OpenTimestampsAnchorer::verify_with_block_header(&hash, proof, block_header) {
+            Ok(attestation) => {
+                check.header_match = CheckResult::Pass;
+                check.height = Some(attestation.height);
+                check.block_hash = Some(attestation.block_hash);
+                check.block_time = DateTime::from_timestamp(attestation.block_time.into(), 0);
+            }
+            Err(e) => {
+                check.header_match = CheckResult::Fail;
+                check.error = Some(e.to_string());
+            }
+        }
+        Some(check)
+    }
+
+    /// Whether the attestation was confirmed against a block header
+    pub fn passed(&self) -> bool {
+        self.header_match == CheckResult::Pass
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerkleInclusionCheck {
+    /// The manifest hash and inclusion proof lead to the anchored Merkle root
+    pub verified: bool,
+    pub merkle_root: String,
+    pub leaf_index: u64,
+    pub leaf_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl MerkleInclusionCheck {
+    fn verify(anchor: &BlockchainAnchor, pre_anchor_hash: &str) -> Option<Self> {
+        let AnchorProof::MerkleBatch {
+            merkle_root,
+            leaf_index,
+            leaf_count,
+            ..
+        } = &anchor.proof
+        else {
+            return None;
+        };
+
+        let error = anchor
+            .proof
+            .resolve_root(pre_anchor_hash)
+            .err()
+            .map(|e| e.to_string());
+        Some(Self {
+            verified: error.is_none(),
+            merkle_root: merkle_root.clone(),
+            leaf_index: *leaf_index,
+            leaf_count: *leaf_count,
+            error,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,13 +790,90 @@ pub struct RecordingInfoSummary {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    /// Wall-clock spans not covered by the video
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pauses: Vec<PauseInterval>,
+    /// Finalized after the app exited mid-recording
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub recovered: bool,
+}
+
+impl RecordingInfoSummary {
+    fn from_manifest(manifest: &EvidenceManifest) -> Self {
+        let custom = manifest.metadata.custom.as_ref();
+        Self {
+            session_id: manifest.recording.session_id.clone(),
+            created_at: manifest.timestamps.started_at,
+            duration_seconds: manifest.recording.duration_seconds,
+            window_title: manifest.metadata.window.title.clone(),
+            title: custom.and_then(|c| c.title.clone()),
+            description: custom.and_then(|c| c.description.clone()),
+            tags: custom.and_then(|c| c.tags.clone()),
+            pauses: manifest.timestamps.pauses.clone(),
+            recovered: manifest.recording.recovered,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignatureInfoSummary {
     pub algorithm: String,
     pub public_key: String,
+    /// Outcome of the signature check and who the signer is, e.g. "Valid
+    /// signature, unknown signer" or "Valid signature, trusted signer Alice"
     pub verified_by: String,
+    /// Name the signer's key, or the identity key it was rotated in from, is
+    /// trusted under, or the subject of its trusted certificate (see
+    /// `Verifier::check_signer` and `Verifier::check_certificate`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_signer: Option<String>,
+}
+
+impl SignatureInfoSummary {
+    fn new(manifest: &EvidenceManifest, signature_valid: bool) -> Self {
+        Self {
+            algorithm: manifest.signature.algorithm.clone(),
+            public_key: manifest.signature.public_key.clone(),
+            verified_by: if signature_valid {
+                "Valid signature, unknown signer".to_string()
+            } else {
+                "Invalid signature".to_string()
+            },
+            trusted_signer: None,
+        }
+    }
+
+    /// Name the signer if a valid signature was made with a key in `keys` that
+    /// one of `signers` pins
+    fn identify(&mut self, signature_valid: bool, keys: &[&str], signers: &[TrustedSigner]) {
+        if !signature_valid {
+            return;
+        }
+        if let Some(signer) = trusted_signers::find_trusted(signers, keys) {
+            self.verified_by = format!("Valid signature, trusted signer {}", signer.name);
+            self.trusted_signer = Some(signer.name.clone());
+        }
+    }
+}
+
+/// Result of checking an excerpt cut from a recording against its manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcerptReport {
+    pub timestamp: DateTime<Utc>,
+    pub status: VerificationStatus,
+    pub signature_valid: CheckResult,
+    /// The excerpt's chunk hashes lead to the manifest's Merkle root
+    pub excerpt_match: CheckResult,
+    /// Index of the original chunk the excerpt starts at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_chunk: Option<usize>,
+    /// Byte offset of the excerpt in the original video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    pub chunk_count: usize,
+    pub size_bytes: u64,
+    pub recording_info: RecordingInfoSummary,
+    pub signature_info: SignatureInfoSummary,
 }
 
 pub struct Verifier;
@@ -112,7 +900,22 @@ impl Verifier {
         );
 
         // Check 1: Manifest structure
-        let manifest_check = CheckResult::Pass;
+        let structure_valid = match manifest.timestamps.validate_pauses() {
+            Ok(()) => true,
+            Err(e) => {
+                LOGGER.log(
+                    LogLevel::Warn,
+                    &format!("Invalid pause intervals: {}", e),
+                    "verifier",
+                );
+                false
+            }
+        };
+        let manifest_check = if structure_valid {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail
+        };
 
         // Check 2: Verify signature
         let signature_valid = manifest.verify_signature()?;
@@ -122,8 +925,16 @@ impl Verifier {
             CheckResult::Fail
         };
 
-        // Check 3: Verify hash
-        let computed_hash = HashInfo::from_file(&video_path)?;
+        // Check 3: Verify hash, and chunk hashes when the manifest has them. They
+        // cover the plaintext, so an encrypted video can only be checked as a whole.
+        let expected_chunks = manifest.recording.plaintext_chunks.as_ref();
+        let (computed_hash, computed_chunks) =
+            if expected_chunks.is_some() && !manifest.recording.encrypted {
+                let (hash, chunks) = HashInfo::from_file_chunked(&video_path)?;
+                (hash, Some(chunks))
+            } else {
+                (HashInfo::from_file(&video_path)?, None)
+            };
 
         // For encrypted files, compare against encrypted_hash; otherwise use plaintext_hash
         let expected_hash = if manifest.recording.encrypted {
@@ -154,21 +965,51 @@ impl Verifier {
             CheckResult::Fail
         };
 
-        // Check blockchain anchor if present (metadata only, no on-chain verification)
-        let blockchain_anchor_check =
-            manifest
-                .blockchain_anchor
-                .as_ref()
-                .map(|anchor| BlockchainAnchorCheck {
-                    present: true,
-                    algorithm: anchor.proof.description(),
-                    anchored_at: anchor.anchored_at.to_rfc3339(),
-                    explorer_url: anchor.proof.explorer_url(),
-                    on_chain_verified: None, // Standard verification doesn't check on-chain
-                });
+        let chunk_check =
+            expected_chunks.map(|expected| ChunkHashCheck::new(expected, computed_chunks.as_ref()));
+        if let Some(index) = chunk_check.as_ref().and_then(|check| check.first_mismatch) {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Video differs from the manifest from chunk {} on", index),
+                "verifier",
+            );
+        }
+        let chunks_valid = chunk_check.as_ref().map_or(true, ChunkHashCheck::passed);
+
+        // Check every anchor against the recomputed pre-anchor hash (no on-chain
+        // verification)
+        let pre_anchor_hash = manifest.compute_pre_anchor_hash();
+        let anchor_checks: Vec<BlockchainAnchorCheck> = manifest
+            .all_anchors()
+            .map(|anchor| {
+                BlockchainAnchorCheck::offline(
+                    anchor,
+                    expected_anchor_hash(&manifest, anchor, &pre_anchor_hash),
+                )
+            })
+            .collect();
+
+        // RFC 3161 tokens and batch inclusion proofs are self-contained, so a bad one
+        // fails even standard verification
+        let anchor_proofs_valid = anchor_checks
+            .iter()
+            .all(BlockchainAnchorCheck::proofs_valid);
+
+        // A certificate chain has to certify the signer and be valid when the
+        // manifest was created; whether its CA is trusted is up to `check_certificate`
+        let certificate_check = CertificateCheck::new(&manifest, &[]);
+        let certificate_valid = certificate_check
+            .as_ref()
+            .map_or(true, CertificateCheck::passed);
 
         // Determine overall status
-        let status = if signature_valid && hash_match {
+        let status = if structure_valid
+            && signature_valid
+            && hash_match
+            && chunks_valid
+            && anchor_proofs_valid
+            && certificate_valid
+        {
             VerificationStatus::Verified
         } else {
             VerificationStatus::Failed
@@ -182,38 +1023,380 @@ impl Verifier {
                     manifest_structure: manifest_check,
                     signature_valid: signature_check,
                     hash_match: hash_check,
-                    blockchain_anchor: blockchain_anchor_check,
-                },
-                recording_info: RecordingInfoSummary {
-                    session_id: manifest.recording.session_id.clone(),
-                    created_at: manifest.timestamps.started_at,
-                    duration_seconds: manifest.recording.duration_seconds,
-                    window_title: manifest.metadata.window.title.clone(),
-                    title: manifest
-                        .metadata
-                        .custom
-                        .as_ref()
-                        .and_then(|c| c.title.clone()),
-                    description: manifest
-                        .metadata
-                        .custom
-                        .as_ref()
-                        .and_then(|c| c.description.clone()),
-                    tags: manifest
-                        .metadata
-                        .custom
-                        .as_ref()
-                        .and_then(|c| c.tags.clone()),
-                },
-                signature_info: SignatureInfoSummary {
-                    algorithm: manifest.signature.algorithm.clone(),
-                    public_key: manifest.signature.public_key.clone(),
-                    verified_by: format!("notari-verifier v{}", env!("CARGO_PKG_VERSION")),
+                    chunk_hashes: chunk_check,
+                    anchors: anchor_checks,
+                    proof_pack: None,
+                    checkpoints: None,
+                    key_history: None,
+                    certificate: certificate_check,
                 },
+                recording_info: RecordingInfoSummary::from_manifest(&manifest),
+                signature_info: SignatureInfoSummary::new(&manifest, signature_valid),
             },
         })
     }
 
+    /// Check that `excerpt_path` is an unaltered part of the recording in `manifest_path`
+    ///
+    /// The excerpt must be cut from the plaintext video on chunk boundaries (see
+    /// `ChunkHashes::locate_excerpt`); its position is found from the chunk hashes.
+    pub fn verify_excerpt<P: AsRef<Path>>(
+        manifest_path: P,
+        excerpt_path: P,
+    ) -> NotariResult<ExcerptReport> {
+        let manifest = EvidenceManifest::load(&manifest_path)?;
+        Self::verify_excerpt_against(&manifest, excerpt_path.as_ref())
+    }
+
+    /// Check an excerpt against the manifest inside a .notari proof pack
+    pub fn verify_excerpt_in_proof_pack<P: AsRef<Path>>(
+        proof_pack_path: P,
+        excerpt_path: P,
+    ) -> NotariResult<ExcerptReport> {
+        let (_, manifest_json) = proof_pack::read_proof_pack_manifest(proof_pack_path)?;
+        let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)?;
+        Self::verify_excerpt_against(&manifest, excerpt_path.as_ref())
+    }
+
+    fn verify_excerpt_against(
+        manifest: &EvidenceManifest,
+        excerpt_path: &Path,
+    ) -> NotariResult<ExcerptReport> {
+        let expected = manifest
+            .recording
+            .plaintext_chunks
+            .as_ref()
+            .ok_or_else(|| {
+                NotariError::VerificationFailed(
+                    "Manifest has no chunk hashes, so excerpts can't be verified".to_string(),
+                )
+            })?;
+
+        let signature_valid = manifest.verify_signature()?;
+        let excerpt = ChunkHashes::from_file(excerpt_path)?;
+        let first_chunk = expected.locate_excerpt(&excerpt);
+
+        let status = if signature_valid && first_chunk.is_some() {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Failed
+        };
+
+        Ok(ExcerptReport {
+            timestamp: Utc::now(),
+            status,
+            signature_valid: if signature_valid {
+                CheckResult::Pass
+            } else {
+                CheckResult::Fail
+            },
+            excerpt_match: if first_chunk.is_some() {
+                CheckResult::Pass
+            } else {
+                CheckResult::Fail
+            },
+            first_chunk,
+            offset: first_chunk.map(|index| expected.offset(index)),
+            chunk_count: excerpt.chunks.len(),
+            size_bytes: excerpt.file_size,
+            recording_info: RecordingInfoSummary::from_manifest(manifest),
+            signature_info: SignatureInfoSummary::new(manifest, signature_valid),
+        })
+    }
+
+    /// Verify a .notari proof pack: manifest signature, video hash and file index
+    pub fn verify_proof_pack<P: AsRef<Path>>(
+        proof_pack_path: P,
+    ) -> NotariResult<VerificationReport> {
+        let proof_pack_path = proof_pack_path.as_ref();
+        let temp_dir = std::env::temp_dir().join(format!("notari_verify_{}", uuid::Uuid::new_v4()));
+
+        let result = proof_pack::extract_proof_pack(proof_pack_path, temp_dir.as_path())
+            .and_then(|(video_path, manifest_path)| Self::verify(&manifest_path, &video_path))
+            .and_then(|mut report| {
+                Self::check_proof_pack(proof_pack_path, &mut report)?;
+                Ok(report)
+            });
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        result
+    }
+
+    /// Check a proof pack against its signed file index and fold the result into `report`
+    ///
+    /// `report` must come from verifying the manifest inside the same pack. The index
+    /// has to be signed by the manifest signer. 1.0 packs have no index and downgrade
+    /// a verified report to a warning. The pack's checkpoint log and key history, if
+    /// any, are checked as well.
+    pub fn check_proof_pack<P: AsRef<Path>>(
+        proof_pack_path: P,
+        report: &mut VerificationReport,
+    ) -> NotariResult<()> {
+        let proof_pack_path = proof_pack_path.as_ref();
+        let result = proof_pack::verify_proof_pack_index(proof_pack_path)?;
+        let info = &mut report.verification;
+
+        let check = match result.index {
+            Some(ref index) => {
+                let signed_by_manifest_signer =
+                    index.signature.public_key == info.signature_info.public_key;
+                ProofPackCheck {
+                    format_version: index.version.clone(),
+                    index_signature: if result.signature_valid && signed_by_manifest_signer {
+                        CheckResult::Pass
+                    } else {
+                        CheckResult::Fail
+                    },
+                    files_match: if result.files_intact() {
+                        CheckResult::Pass
+                    } else {
+                        CheckResult::Fail
+                    },
+                    unlisted_files: result.unlisted_files,
+                    modified_files: result.modified_files,
+                    missing_files: result.missing_files,
+                }
+            }
+            None => ProofPackCheck {
+                format_version: LEGACY_PROOF_PACK_VERSION.to_string(),
+                index_signature: CheckResult::Skip,
+                files_match: CheckResult::Skip,
+                unlisted_files: Vec::new(),
+                modified_files: Vec::new(),
+                missing_files: Vec::new(),
+            },
+        };
+
+        if check.index_signature == CheckResult::Fail || check.files_match == CheckResult::Fail {
+            info.status = VerificationStatus::Failed;
+        } else if check.index_signature == CheckResult::Skip
+            && info.status == VerificationStatus::Verified
+        {
+            info.status = VerificationStatus::Warning;
+        }
+        info.checks.proof_pack = Some(check);
+
+        Self::check_checkpoints(proof_pack_path, report)?;
+        Self::check_key_history(proof_pack_path, report)
+    }
+
+    /// Check the checkpoint log in a proof pack and fold the result into `report`
+    ///
+    /// The log has to be the one the manifest commits to, signed by the manifest
+    /// signer, with consistent running hashes and increasing times within the
+    /// recording, and cover the segments the manifest lists as joined into the
+    /// video. Unless the recording is encrypted, those segments have to be packed
+    /// as well. Checkpointed segments left out of the video downgrade a verified
+    /// report to a warning. Packs without checkpoints are left as they are.
+    fn check_checkpoints(
+        proof_pack_path: &Path,
+        report: &mut VerificationReport,
+    ) -> NotariResult<()> {
+        let (_, manifest_json) = proof_pack::read_proof_pack_manifest(proof_pack_path)?;
+        let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)?;
+        let summary = manifest.checkpoints.as_ref();
+
+        let check = match proof_pack::read_proof_pack_checkpoints(proof_pack_path)? {
+            Some(log_json) => {
+                let packed_segments = if manifest.recording.encrypted {
+                    None
+                } else {
+                    Some(proof_pack::hash_proof_pack_segments(proof_pack_path)?)
+                };
+                CheckpointCheck::new(&manifest, summary, &log_json, packed_segments.as_deref())
+            }
+            None if summary.is_some() => {
+                CheckpointCheck::failed("Checkpoint log is missing from the proof pack".to_string())
+            }
+            None => return Ok(()),
+        };
+
+        let info = &mut report.verification;
+        if !check.passed() {
+            info.status = VerificationStatus::Failed;
+        } else if check.dropped_segments > 0 && info.status == VerificationStatus::Verified {
+            info.status = VerificationStatus::Warning;
+        }
+        info.checks.checkpoints = Some(check);
+        Ok(())
+    }
+
+    /// Check the key history in a proof pack and fold the result into `report`
+    ///
+    /// The history has to be a valid chain of rotations ending at the manifest
+    /// signer's key. Packs signed with an identity key have none.
+    fn check_key_history(
+        proof_pack_path: &Path,
+        report: &mut VerificationReport,
+    ) -> NotariResult<()> {
+        let Some(history_json) = proof_pack::read_proof_pack_key_history(proof_pack_path)? else {
+            return Ok(());
+        };
+
+        let info = &mut report.verification;
+        let check = KeyHistoryCheck::new(&info.signature_info.public_key, &history_json);
+        if !check.passed() {
+            info.status = VerificationStatus::Failed;
+        }
+        info.checks.key_history = Some(check);
+        Ok(())
+    }
+
+    /// Check that the recording in `report` was signed by `identity_key`, or by a
+    /// key rotated in from it, and fold the result into `report`
+    ///
+    /// `report` must come from `verify_proof_pack` or `check_proof_pack`, which
+    /// read the key history. A signer that isn't linked to `identity_key` fails
+    /// verification.
+    pub fn check_identity(report: &mut VerificationReport, identity_key: &str) {
+        let info = &mut report.verification;
+        let check = info
+            .checks
+            .key_history
+            .get_or_insert_with(|| KeyHistoryCheck::identity(&info.signature_info.public_key));
+
+        if check.passed() && check.identity_key == identity_key {
+            check.identity_pinned = CheckResult::Pass;
+        } else {
+            check.identity_pinned = CheckResult::Fail;
+            check.error.get_or_insert_with(|| {
+                "Signer's key isn't linked to the pinned identity key".to_string()
+            });
+            info.status = VerificationStatus::Failed;
+        }
+    }
+
+    /// Check the signer's certificate chain in a proof pack against the CA
+    /// certificates in `trust_anchors` and fold the result into `report`
+    ///
+    /// A chain leading to one of them names the signer by its certificate subject.
+    /// One that doesn't leaves the signer unknown, without failing verification;
+    /// `verify` already fails chains that don't certify the signer's key or
+    /// weren't valid when the manifest was created. Packs whose manifest carries
+    /// no chain are left as they are.
+    pub fn check_certificate<P: AsRef<Path>>(
+        proof_pack_path: P,
+        report: &mut VerificationReport,
+        trust_anchors: &[Certificate],
+    ) -> NotariResult<()> {
+        let (_, manifest_json) = proof_pack::read_proof_pack_manifest(proof_pack_path)?;
+        let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)?;
+        let Some(check) = CertificateCheck::new(&manifest, trust_anchors) else {
+            return Ok(());
+        };
+
+        let info = &mut report.verification;
+        if !check.passed() {
+            info.status = VerificationStatus::Failed;
+        } else if check.trusted == CheckResult::Pass
+            && info.checks.signature_valid == CheckResult::Pass
+        {
+            info.signature_info.verified_by =
+                format!("Valid signature, certified signer {}", check.subject);
+            info.signature_info.trusted_signer = Some(check.subject.clone());
+        }
+        info.checks.certificate = Some(check);
+        Ok(())
+    }
+
+    /// Check the TSA certificate chains of the RFC 3161 anchors in a proof pack
+    /// against the CA certificates in `trust_anchors` and fold the results into
+    /// `report`
+    ///
+    /// `report` must come from verifying the manifest inside the same pack. A
+    /// token whose TSA doesn't chain to one of them keeps its valid signature but
+    /// doesn't count as a passing anchor; with no trust anchors, no TSA is
+    /// trusted. Verification doesn't fail either way.
+    pub fn check_timestamp_authorities<P: AsRef<Path>>(
+        proof_pack_path: P,
+        report: &mut VerificationReport,
+        trust_anchors: &[Certificate],
+    ) -> NotariResult<()> {
+        Self::recheck_anchors(proof_pack_path, report, |anchor, hash, check| {
+            check.timestamp_token = TimestampTokenCheck::verify(anchor, hash, Some(trust_anchors));
+        })
+    }
+
+    /// Check the Bitcoin attestations of the OpenTimestamps anchors in a proof
+    /// pack against `block_header` (80 bytes) and fold the results into `report`
+    ///
+    /// `report` must come from verifying the manifest inside the same pack. The
+    /// header has to come from a source the reviewer trusts; without one, a
+    /// complete proof is reported as complete with its attestation unchecked. A
+    /// proof that doesn't match the header doesn't count as a passing anchor, but
+    /// verification doesn't fail.
+    pub fn check_block_header<P: AsRef<Path>>(
+        proof_pack_path: P,
+        report: &mut VerificationReport,
+        block_header: &[u8],
+    ) -> NotariResult<()> {
+        Self::recheck_anchors(proof_pack_path, report, |anchor, hash, check| {
+            check.bitcoin_attestation =
+                BitcoinAttestationCheck::verify(anchor, hash, Some(block_header));
+        })
+    }
+
+    /// Call `recheck` with each anchor of the manifest in a proof pack, the hash
+    /// it has to cover and the anchor's check in `report`
+    fn recheck_anchors<P, F>(
+        proof_pack_path: P,
+        report: &mut VerificationReport,
+        mut recheck: F,
+    ) -> NotariResult<()>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&BlockchainAnchor, &str, &mut BlockchainAnchorCheck),
+    {
+        let (_, manifest_json) = proof_pack::read_proof_pack_manifest(proof_pack_path)?;
+        let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)?;
+        let pre_anchor_hash = manifest.compute_pre_anchor_hash();
+        for (anchor, check) in manifest
+            .all_anchors()
+            .zip(report.verification.checks.anchors.iter_mut())
+        {
+            recheck(
+                anchor,
+                expected_anchor_hash(&manifest, anchor, &pre_anchor_hash),
+                check,
+            );
+        }
+        Ok(())
+    }
+
+    /// Name the signer of the recording in `report` if its key is trusted
+    ///
+    /// The signer's key is looked up in `signers`, then the identity key it was
+    /// rotated in from when `report` has a valid key history. An untrusted signer
+    /// doesn't fail verification; the report just calls it unknown.
+    pub fn check_signer(report: &mut VerificationReport, signers: &[TrustedSigner]) {
+        let info = &mut report.verification;
+        let signature_valid = info.checks.signature_valid == CheckResult::Pass;
+        let signer_key = info.signature_info.public_key.clone();
+        let identity_key = info
+            .checks
+            .key_history
+            .as_ref()
+            .filter(|check| check.passed())
+            .map(|check| check.identity_key.as_str());
+
+        let keys: Vec<&str> = std::iter::once(signer_key.as_str())
+            .chain(identity_key)
+            .collect();
+        info.signature_info
+            .identify(signature_valid, &keys, signers);
+    }
+
+    /// Name the signer of the recording an excerpt was checked against if its
+    /// key is trusted
+    pub fn check_excerpt_signer(report: &mut ExcerptReport, signers: &[TrustedSigner]) {
+        let signature_valid = report.signature_valid == CheckResult::Pass;
+        let signer_key = report.signature_info.public_key.clone();
+        report
+            .signature_info
+            .identify(signature_valid, &[&signer_key], signers);
+    }
+
     /// Quick verification (just signature, no hash)
     pub fn verify_signature_only<P: AsRef<Path>>(manifest_path: P) -> NotariResult<bool> {
         let manifest = EvidenceManifest::load(&manifest_path)?;
@@ -221,74 +1404,108 @@ impl Verifier {
     }
 
     /// Deep verification with on-chain blockchain verification
-    /// This performs standard verification plus queries the blockchain to confirm the anchor
-    pub async fn verify_deep<P: AsRef<Path>>(
+    /// This performs standard verification plus queries the chain or service behind each
+    /// anchor to confirm it. `anchorer_for` returns an anchorer able to verify a given
+    /// proof, e.g. `BlockchainAnchorerFactory::create_verifier`.
+    ///
+    /// The calendars can only show an OpenTimestamps proof is complete, so such an
+    /// anchor only counts as verified once its Bitcoin attestation matches
+    /// `block_header` (80 bytes, from a source the reviewer trusts).
+    pub async fn verify_deep<P, F>(
         manifest_path: P,
         video_path: P,
-        anchorer: &dyn crate::evidence::blockchain::BlockchainAnchorer,
-    ) -> NotariResult<VerificationReport> {
+        block_header: Option<&[u8]>,
+        anchorer_for: F,
+    ) -> NotariResult<VerificationReport>
+    where
+        P: AsRef<Path>,
+        F: Fn(&AnchorProof) -> NotariResult<Box<dyn BlockchainAnchorer>>,
+    {
         use crate::logger::{LogLevel, LOGGER};
 
         // First perform standard verification
         let mut report = Self::verify(&manifest_path, &video_path)?;
+        if report.verification.checks.anchors.is_empty() {
+            return Ok(report);
+        }
 
-        // If there's a blockchain anchor, verify it on-chain
-        if let Some(ref mut anchor_check) = report.verification.checks.blockchain_anchor {
-            let manifest = EvidenceManifest::load(&manifest_path)?;
-
-            if let Some(ref blockchain_anchor) = manifest.blockchain_anchor {
-                LOGGER.log(
-                    LogLevel::Info,
-                    "Performing on-chain verification of blockchain anchor",
-                    "verifier",
-                );
+        // Verify each anchor independently, for the hash recomputed from the manifest
+        let manifest = EvidenceManifest::load(&manifest_path)?;
+        let pre_anchor_hash = manifest.compute_pre_anchor_hash();
+        for (blockchain_anchor, anchor_check) in manifest
+            .all_anchors()
+            .zip(report.verification.checks.anchors.iter_mut())
+        {
+            let anchored_hash =
+                expected_anchor_hash(&manifest, blockchain_anchor, &pre_anchor_hash);
+            LOGGER.log(
+                LogLevel::Info,
+                &format!(
+                    "Performing on-chain verification of {} anchor",
+                    blockchain_anchor.proof.chain_name()
+                ),
+                "verifier",
+            );
 
-                // Use the manifest hash that was actually anchored (stored in blockchain_anchor)
-                let anchored_hash = &blockchain_anchor.manifest_hash;
+            LOGGER.log(
+                LogLevel::Info,
+                &format!("Pre-anchor manifest hash: {}", anchored_hash),
+                "verifier",
+            );
 
-                LOGGER.log(
-                    LogLevel::Info,
-                    &format!("Anchored manifest hash: {}", anchored_hash),
-                    "verifier",
-                );
+            // Verify on-chain (batched anchors are checked via their Merkle root)
+            let mut verification_result = match async {
+                let (root, proof) = blockchain_anchor.proof.resolve_root(anchored_hash)?;
+                let anchorer = anchorer_for(proof)?;
+                anchorer.verify(&root, proof).await
+            }
+            .await
+            {
+                Ok(verified) => {
+                    LOGGER.log(
+                        LogLevel::Info,
+                        &format!("On-chain verification result: {}", verified),
+                        "verifier",
+                    );
 
-                // Verify on-chain
-                let verification_result = match anchorer
-                    .verify(anchored_hash, &blockchain_anchor.proof)
-                    .await
-                {
-                    Ok(verified) => {
-                        LOGGER.log(
-                            LogLevel::Info,
-                            &format!("On-chain verification result: {}", verified),
-                            "verifier",
-                        );
-
-                        OnChainVerificationResult {
-                            verified,
-                            chain_name: blockchain_anchor.proof.chain_name(),
-                            contract_address: blockchain_anchor.proof.contract_address(),
-                            error: None,
-                        }
+                    OnChainVerificationResult {
+                        verified,
+                        chain_name: blockchain_anchor.proof.chain_name(),
+                        contract_address: blockchain_anchor.proof.contract_address(),
+                        error: None,
                     }
-                    Err(e) => {
-                        LOGGER.log(
-                            LogLevel::Error,
-                            &format!("On-chain verification error: {}", e),
-                            "verifier",
-                        );
-
-                        OnChainVerificationResult {
-                            verified: false,
-                            chain_name: blockchain_anchor.proof.chain_name(),
-                            contract_address: blockchain_anchor.proof.contract_address(),
-                            error: Some(e.to_string()),
-                        }
+                }
+                Err(e) => {
+                    LOGGER.log(
+                        LogLevel::Error,
+                        &format!("On-chain verification error: {}", e),
+                        "verifier",
+                    );
+
+                    OnChainVerificationResult {
+                        verified: false,
+                        chain_name: blockchain_anchor.proof.chain_name(),
+                        contract_address: blockchain_anchor.proof.contract_address(),
+                        error: Some(e.to_string()),
                     }
-                };
+                }
+            };
 
-                anchor_check.on_chain_verified = Some(verification_result);
+            if let Some(ref mut attestation) = anchor_check.bitcoin_attestation {
+                if block_header.is_some() {
+                    *attestation = BitcoinAttestationCheck::verify(
+                        blockchain_anchor,
+                        anchored_hash,
+                        block_header,
+                    )
+                    .unwrap();
+                }
+                // Completed from the calendars, but not checked against a block
+                attestation.complete |= verification_result.verified;
+                verification_result.verified = attestation.passed();
             }
+
+            anchor_check.on_chain_verified = Some(verification_result);
         }
 
         Ok(report)
@@ -299,6 +1516,7 @@ impl Verifier {
 mod tests {
     use super::*;
     use crate::evidence::signature::KeyManager;
+    use sha2::{Digest, Sha256};
     use std::io::Write;
     use uuid::Uuid;
 
@@ -331,7 +1549,14 @@ mod tests {
             .resolution("1920x1080")
             .frame_rate(30)
             .codec("H.264")
-            .system("macOS", "14.0", "test-device", "test-host", "1.0.0", "notari")
+            .system(
+                "macOS",
+                "14.0",
+                "test-device",
+                "test-host",
+                "1.0.0",
+                "notari",
+            )
             .timestamps_from_dates(now, now)
             .build()
             .unwrap();
@@ -382,7 +1607,14 @@ mod tests {
             .resolution("1920x1080")
             .frame_rate(30)
             .codec("H.264")
-            .system("macOS", "14.0", "test-device", "test-host", "1.0.0", "notari")
+            .system(
+                "macOS",
+                "14.0",
+                "test-device",
+                "test-host",
+                "1.0.0",
+                "notari",
+            )
             .timestamps_from_dates(now, now)
             .build()
             .unwrap();
@@ -404,4 +1636,621 @@ mod tests {
             _ => panic!("Verification should have failed"),
         }
     }
+
+    /// Manifest for the video at `video_path`, not yet signed
+    fn unsigned_manifest(video_path: &Path) -> EvidenceManifest {
+        use crate::evidence::EvidenceManifestBuilder;
+
+        let now = Utc::now();
+        EvidenceManifestBuilder::new()
+            .session_id(Uuid::new_v4())
+            .file_path(video_path.to_path_buf())
+            .file_hash(HashInfo::from_file(video_path).unwrap())
+            .file_size(18)
+            .duration(60.0)
+            .window_title("Test Window")
+            .window_id(123)
+            .app_name("Test App")
+            .app_bundle_id("com.test.app")
+            .resolution("1920x1080")
+            .frame_rate(30)
+            .codec("H.264")
+            .system(
+                "macOS",
+                "14.0",
+                "test-device",
+                "test-host",
+                "1.0.0",
+                "notari",
+            )
+            .timestamps_from_dates(now, now)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verification_checks_pauses() {
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let video_path = video_file.path().to_path_buf();
+        let key_manager = KeyManager::generate();
+
+        let mut manifest = unsigned_manifest(&video_path);
+        let started = manifest.timestamps.started_at - chrono::Duration::seconds(60);
+        manifest.timestamps.started_at = started;
+        let at = |secs: i64| started + chrono::Duration::seconds(secs);
+        let verify = |manifest: &mut EvidenceManifest, pauses: Vec<PauseInterval>| {
+            manifest.timestamps.pauses = pauses;
+            manifest.sign(&key_manager);
+            let manifest_file = tempfile::NamedTempFile::new().unwrap();
+            manifest.save(manifest_file.path()).unwrap();
+            Verifier::verify(manifest_file.path(), &video_path).unwrap()
+        };
+
+        let pause = PauseInterval {
+            paused_at: at(10),
+            resumed_at: at(20),
+        };
+        let report = verify(&mut manifest, vec![pause.clone()]);
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        assert_eq!(report.verification.recording_info.pauses, vec![pause]);
+
+        // Overlapping pauses are signed but structurally invalid
+        let overlapping = vec![
+            PauseInterval {
+                paused_at: at(10),
+                resumed_at: at(30),
+            },
+            PauseInterval {
+                paused_at: at(20),
+                resumed_at: at(40),
+            },
+        ];
+        let report = verify(&mut manifest, overlapping);
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        assert_eq!(
+            report.verification.checks.manifest_structure,
+            CheckResult::Fail
+        );
+        assert_eq!(
+            report.verification.checks.signature_valid,
+            CheckResult::Pass
+        );
+    }
+
+    #[test]
+    fn test_check_signer_names_trusted_signers() {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let (video_file, _) = chunked_video();
+        let manifest_file = chunked_manifest(video_file.path());
+        let mut report = Verifier::verify(manifest_file.path(), video_file.path()).unwrap();
+        let signer_key = report.verification.signature_info.public_key.clone();
+        let identity_key = general_purpose::STANDARD.encode([7u8; 32]);
+        assert_eq!(
+            report.verification.signature_info.verified_by,
+            "Valid signature, unknown signer"
+        );
+
+        let alice = TrustedSigner::new("Alice", &identity_key, None).unwrap();
+        Verifier::check_signer(&mut report, &[alice.clone()]);
+        assert_eq!(report.verification.signature_info.trusted_signer, None);
+
+        // The identity key counts once a key history links the signer to it
+        report.verification.checks.key_history = Some(KeyHistoryCheck {
+            identity_key: identity_key.clone(),
+            ..KeyHistoryCheck::identity(&signer_key)
+        });
+        Verifier::check_signer(&mut report, &[alice]);
+        assert_eq!(
+            report.verification.signature_info.verified_by,
+            "Valid signature, trusted signer Alice"
+        );
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+
+        // A trusted key doesn't vouch for an invalid signature
+        report.verification.checks.signature_valid = CheckResult::Fail;
+        report.verification.signature_info = SignatureInfoSummary {
+            verified_by: "Invalid signature".to_string(),
+            trusted_signer: None,
+            ..report.verification.signature_info
+        };
+        let bob = TrustedSigner::new("Bob", &signer_key, None).unwrap();
+        Verifier::check_signer(&mut report, &[bob]);
+        assert_eq!(
+            report.verification.signature_info.verified_by,
+            "Invalid signature"
+        );
+    }
+
+    /// Two and a half chunks of video, each chunk filled with its own byte
+    fn chunked_video() -> (tempfile::NamedTempFile, Vec<u8>) {
+        let chunk = crate::evidence::hash::HASH_CHUNK_SIZE as usize;
+        let data: Vec<u8> = (0..chunk * 5 / 2).map(|i| (i / chunk) as u8).collect();
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(&data).unwrap();
+        (video_file, data)
+    }
+
+    /// Signed manifest with chunk hashes for `video_path`, saved to a temp file
+    fn chunked_manifest(video_path: &Path) -> tempfile::NamedTempFile {
+        let mut manifest = unsigned_manifest(video_path);
+        manifest.recording.plaintext_chunks = Some(ChunkHashes::from_file(video_path).unwrap());
+        manifest.sign(&KeyManager::generate());
+        let manifest_file = tempfile::NamedTempFile::new().unwrap();
+        manifest.save(manifest_file.path()).unwrap();
+        manifest_file
+    }
+
+    #[test]
+    fn test_verification_reports_first_mismatching_chunk() {
+        let (video_file, mut data) = chunked_video();
+        let video_path = video_file.path().to_path_buf();
+        let manifest_file = chunked_manifest(&video_path);
+
+        let report = Verifier::verify(manifest_file.path(), &video_path).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = report.verification.checks.chunk_hashes.unwrap();
+        assert_eq!(check.chunks_match, CheckResult::Pass);
+        assert_eq!(check.root_valid, CheckResult::Pass);
+        assert_eq!(check.chunk_count, 3);
+        assert_eq!(check.first_mismatch, None);
+
+        let chunk = crate::evidence::hash::HASH_CHUNK_SIZE as usize;
+        data[chunk * 2 + 100] ^= 0xff;
+        std::fs::write(&video_path, &data).unwrap();
+
+        let report = Verifier::verify(manifest_file.path(), &video_path).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        assert_eq!(report.verification.checks.hash_match, CheckResult::Fail);
+        let check = report.verification.checks.chunk_hashes.unwrap();
+        assert_eq!(check.chunks_match, CheckResult::Fail);
+        assert_eq!(check.first_mismatch, Some(2));
+        assert_eq!(check.first_mismatch_offset, Some(chunk as u64 * 2));
+    }
+
+    #[test]
+    fn test_verify_excerpt() {
+        let (video_file, data) = chunked_video();
+        let manifest_file = chunked_manifest(video_file.path());
+        let chunk = crate::evidence::hash::HASH_CHUNK_SIZE as usize;
+        let verify = |excerpt: &[u8]| {
+            let mut excerpt_file = tempfile::NamedTempFile::new().unwrap();
+            excerpt_file.write_all(excerpt).unwrap();
+            Verifier::verify_excerpt(manifest_file.path(), excerpt_file.path()).unwrap()
+        };
+
+        // The tail of the recording, from the second chunk on
+        let report = verify(&data[chunk..]);
+        assert_eq!(report.status, VerificationStatus::Verified);
+        assert_eq!(report.first_chunk, Some(1));
+        assert_eq!(report.offset, Some(chunk as u64));
+        assert_eq!(report.chunk_count, 2);
+
+        let mut altered = data[..chunk * 2].to_vec();
+        altered[chunk + 1] ^= 0xff;
+        let report = verify(&altered);
+        assert_eq!(report.status, VerificationStatus::Failed);
+        assert_eq!(report.excerpt_match, CheckResult::Fail);
+        assert_eq!(report.signature_valid, CheckResult::Pass);
+
+        // Manifests from before chunk hashes can't verify excerpts
+        let plain_manifest = tempfile::NamedTempFile::new().unwrap();
+        let mut manifest = unsigned_manifest(video_file.path());
+        manifest.sign(&KeyManager::generate());
+        manifest.save(plain_manifest.path()).unwrap();
+        assert!(Verifier::verify_excerpt(plain_manifest.path(), video_file.path()).is_err());
+    }
+
+    #[test]
+    fn test_verification_checks_rfc3161_token() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use base64::{engine::general_purpose, Engine as _};
+
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let video_path = video_file.path().to_path_buf();
+        let now = Utc::now();
+
+        let mut manifest = unsigned_manifest(&video_path);
+
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+        let anchored_hash = manifest.compute_pre_anchor_hash();
+
+        let tsa = TestTsa::new();
+        let anchor = |hash: &str| BlockchainAnchor {
+            anchored_at: now,
+            anchored_hash: hash.to_string(),
+            manifest_hash: hash.to_string(),
+            proof: AnchorProof::Rfc3161 {
+                tsa_url: "http://127.0.0.1/tsr".to_string(),
+                tsa_name: "CN=Notari Test TSA".to_string(),
+                token: general_purpose::STANDARD
+                    .encode(tsa.token(&hex::decode(hash).unwrap(), None)),
+                gen_time: now,
+                serial_number: "04d2".to_string(),
+            },
+        };
+        let verify = |manifest: &mut EvidenceManifest, hash: &str| {
+            manifest.anchors = vec![anchor(hash)];
+            manifest.sign(&key_manager);
+            let manifest_file = tempfile::NamedTempFile::new().unwrap();
+            manifest.save(manifest_file.path()).unwrap();
+            Verifier::verify(manifest_file.path(), &video_path).unwrap()
+        };
+
+        let report = verify(&mut manifest, &anchored_hash);
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = &report.verification.checks.anchors[0];
+        assert_eq!(check.hash_match, CheckResult::Pass);
+        let token_check = check.timestamp_token.clone().unwrap();
+        assert!(token_check.verified);
+        assert_eq!(token_check.tsa_name.as_deref(), Some("CN=Notari Test TSA"));
+        assert_eq!(
+            token_check.tsa_certificate_sha256,
+            Some(hex::encode(Sha256::digest(&tsa.certificate)))
+        );
+
+        // A valid token over a different hash, pasted into the re-signed manifest
+        // together with that hash, must not verify
+        let other_hash = hex::encode(Sha256::digest(b"other manifest"));
+        let report = verify(&mut manifest, &other_hash);
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let check = &report.verification.checks.anchors[0];
+        assert_eq!(check.hash_match, CheckResult::Fail);
+        let token_check = check.timestamp_token.clone().unwrap();
+        assert!(!token_check.verified);
+        assert!(token_check.error.is_some());
+    }
+
+    #[test]
+    fn test_timestamp_token_from_untrusted_tsa() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use crate::evidence::x509::testing::{issue, CertificateParams, TestKey};
+        use base64::{engine::general_purpose, Engine as _};
+
+        let hash = hex::encode(Sha256::digest(b"manifest"));
+        let tsa = TestTsa::new();
+        let anchor = BlockchainAnchor {
+            anchored_at: Utc::now(),
+            anchored_hash: hash.clone(),
+            manifest_hash: hash.clone(),
+            proof: AnchorProof::Rfc3161 {
+                tsa_url: "http://127.0.0.1/tsr".to_string(),
+                tsa_name: "CN=Notari Test TSA".to_string(),
+                token: general_purpose::STANDARD
+                    .encode(tsa.token(&hex::decode(&hash).unwrap(), None)),
+                gen_time: Utc::now(),
+                serial_number: "04d2".to_string(),
+            },
+        };
+
+        // Standard verification checks the signature only
+        let check = TimestampTokenCheck::verify(&anchor, &hash, None).unwrap();
+        assert!(check.verified);
+        assert_eq!(check.trusted, CheckResult::Skip);
+        assert!(!check.passed());
+
+        // A self-signed TSA is untrusted unless its own certificate is a trust anchor
+        let mut ca_params = CertificateParams::new("Example Corp CA");
+        ca_params.is_ca = true;
+        let other_ca =
+            Certificate::from_der(&issue(&ca_params, &TestKey::generate(), None)).unwrap();
+        for trust_anchors in [vec![], vec![other_ca]] {
+            let check = TimestampTokenCheck::verify(&anchor, &hash, Some(&trust_anchors)).unwrap();
+            assert!(check.verified);
+            assert_eq!(check.trusted, CheckResult::Fail);
+            assert_eq!(
+                check.error.as_deref(),
+                Some("Valid signature, untrusted TSA")
+            );
+            assert!(check.trust_anchor.is_none());
+            assert!(!check.passed());
+        }
+
+        let tsa_certificate = Certificate::from_der(&tsa.certificate).unwrap();
+        let check = TimestampTokenCheck::verify(&anchor, &hash, Some(&[tsa_certificate])).unwrap();
+        assert_eq!(check.trusted, CheckResult::Pass);
+        assert_eq!(check.trust_anchor.as_deref(), Some("CN=Notari Test TSA"));
+        assert!(check.error.is_none());
+        assert!(check.passed());
+    }
+
+    #[test]
+    fn test_bitcoin_attestation_needs_block_header() {
+        use crate::evidence::blockchain::ots::{Attestation, DetachedTimestamp, Timestamp};
+        use base64::{engine::general_purpose, Engine as _};
+
+        let digest = Sha256::digest(b"manifest").to_vec();
+        let hash = hex::encode(&digest);
+        let ots_anchor = |attestation| {
+            let mut root = Timestamp::new(digest.clone());
+            root.attestations.push(attestation);
+            let file = DetachedTimestamp::new(root).unwrap();
+            BlockchainAnchor {
+                anchored_at: Utc::now(),
+                anchored_hash: hash.clone(),
+                manifest_hash: hash.clone(),
+                proof: AnchorProof::OpenTimestamps {
+                    ots_proof: general_purpose::STANDARD.encode(file.serialize()),
+                    bitcoin_block: None,
+                },
+            }
+        };
+        let pending = ots_anchor(Attestation::Pending {
+            uri: "https://alice.btc.calendar.opentimestamps.org".to_string(),
+        });
+        let complete = ots_anchor(Attestation::Bitcoin { height: 850_000 });
+
+        // Header of a block whose merkle root is the digest itself
+        let mut header = vec![0u8; 80];
+        header[36..68].copy_from_slice(&digest);
+        header[68..72].copy_from_slice(&1_717_000_000u32.to_le_bytes());
+
+        let check = BitcoinAttestationCheck::verify(&pending, &hash, None).unwrap();
+        assert!(!check.complete);
+        assert_eq!(check.header_match, CheckResult::Skip);
+
+        // Complete, but not verified until checked against a block header
+        let check = BitcoinAttestationCheck::verify(&complete, &hash, None).unwrap();
+        assert!(check.complete);
+        assert_eq!(check.header_match, CheckResult::Skip);
+        assert!(!check.passed());
+
+        let check = BitcoinAttestationCheck::verify(&complete, &hash, Some(&header)).unwrap();
+        assert!(check.passed());
+        assert_eq!(check.height, Some(850_000));
+        assert_eq!(check.block_time.unwrap().timestamp(), 1_717_000_000);
+        assert!(check.block_hash.is_some());
+
+        let mut other_block = header.clone();
+        other_block[36] ^= 1;
+        let check = BitcoinAttestationCheck::verify(&complete, &hash, Some(&other_block)).unwrap();
+        assert_eq!(check.header_match, CheckResult::Fail);
+        assert!(check.error.is_some());
+        assert!(!check.passed());
+
+        // Not an OpenTimestamps anchor
+        let mut tsa = complete.clone();
+        tsa.proof = AnchorProof::Rfc3161 {
+            tsa_url: "http://127.0.0.1/tsr".to_string(),
+            tsa_name: "CN=Notari Test TSA".to_string(),
+            token: String::new(),
+            gen_time: Utc::now(),
+            serial_number: "04d2".to_string(),
+        };
+        assert!(BitcoinAttestationCheck::verify(&tsa, &hash, None).is_none());
+    }
+
+    #[test]
+    fn test_verification_checks_merkle_batch_inclusion() {
+        use crate::evidence::blockchain::MerkleTree;
+
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let video_path = video_file.path().to_path_buf();
+
+        let mut manifest = unsigned_manifest(&video_path);
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+        let anchored_hash = manifest.compute_pre_anchor_hash();
+
+        // Batch of three, with this manifest in the middle
+        let leaves = [
+            Sha256::digest(b"first").into(),
+            merkle_leaf(&anchored_hash),
+            Sha256::digest(b"third").into(),
+        ];
+        let tree = MerkleTree::new(&leaves).unwrap();
+        let mut path = tree.proof(1).unwrap();
+        let batch_anchor = |path: Vec<_>| BlockchainAnchor {
+            anchored_at: Utc::now(),
+            anchored_hash: anchored_hash.clone(),
+            manifest_hash: anchored_hash.clone(),
+            proof: AnchorProof::MerkleBatch {
+                merkle_root: hex::encode(tree.root()),
+                leaf_index: 1,
+                leaf_count: 3,
+                path,
+                root_proof: Box::new(AnchorProof::Mock {
+                    hash: hex::encode(tree.root()),
+                    timestamp: Utc::now(),
+                }),
+            },
+        };
+        let verify = |manifest: &mut EvidenceManifest, anchor: BlockchainAnchor| {
+            manifest.anchors = vec![anchor];
+            manifest.sign(&key_manager);
+            let manifest_file = tempfile::NamedTempFile::new().unwrap();
+            manifest.save(manifest_file.path()).unwrap();
+            Verifier::verify(manifest_file.path(), &video_path).unwrap()
+        };
+
+        let report = verify(&mut manifest, batch_anchor(path.clone()));
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let check = &report.verification.checks.anchors[0];
+        assert_eq!(check.algorithm, "Mock (Development) - batch of 3");
+        let inclusion = check.merkle_inclusion.clone().unwrap();
+        assert!(inclusion.verified);
+        assert_eq!(inclusion.leaf_count, 3);
+
+        // A proof that does not lead to the anchored root fails verification
+        path[0].sibling = hex::encode(Sha256::digest(b"forged"));
+        let report = verify(&mut manifest, batch_anchor(path));
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+        let inclusion = report.verification.checks.anchors[0]
+            .merkle_inclusion
+            .clone()
+            .unwrap();
+        assert!(!inclusion.verified);
+    }
+
+    #[test]
+    fn test_every_anchor_covers_the_same_hash() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use base64::{engine::general_purpose, Engine as _};
+
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let mut manifest = unsigned_manifest(video_file.path());
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+
+        // Anchor twice the way anchor_recording does: hash, add the anchor, re-sign
+        let tsa = TestTsa::new();
+        for tsa_url in ["http://127.0.0.1/first", "http://127.0.0.1/second"] {
+            let hash = manifest.compute_pre_anchor_hash();
+            let token = tsa.token(&hex::decode(&hash).unwrap(), None);
+            manifest.add_anchor(BlockchainAnchor {
+                anchored_at: Utc::now(),
+                anchored_hash: hash.clone(),
+                manifest_hash: hash,
+                proof: AnchorProof::Rfc3161 {
+                    tsa_url: tsa_url.to_string(),
+                    tsa_name: "CN=Notari Test TSA".to_string(),
+                    token: general_purpose::STANDARD.encode(&token),
+                    gen_time: Utc::now(),
+                    serial_number: "04d2".to_string(),
+                },
+            });
+            manifest.sign(&key_manager);
+        }
+
+        let hash = manifest.compute_pre_anchor_hash();
+        assert_eq!(manifest.anchors.len(), 2);
+        for anchor in &manifest.anchors {
+            assert_eq!(anchor.anchored_hash, hash);
+            assert!(TsaAnchorer::verify_token(&hash, &anchor.proof).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_anchor_fails_after_resigning_with_another_key() {
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let video_path = video_file.path().to_path_buf();
+
+        let mut manifest = unsigned_manifest(&video_path);
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+        let hash = manifest.compute_pre_anchor_hash();
+        manifest.add_anchor(BlockchainAnchor {
+            anchored_at: Utc::now(),
+            anchored_hash: hash.clone(),
+            manifest_hash: hash.clone(),
+            proof: AnchorProof::Mock {
+                hash,
+                timestamp: Utc::now(),
+            },
+        });
+        let verify = |manifest: &EvidenceManifest| {
+            let manifest_file = tempfile::NamedTempFile::new().unwrap();
+            manifest.save(manifest_file.path()).unwrap();
+            Verifier::verify(manifest_file.path(), &video_path).unwrap()
+        };
+
+        manifest.sign(&key_manager);
+        let report = verify(&manifest);
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+
+        // Someone else's signature is valid on its own, but the anchors vouch for
+        // the original signer
+        manifest.sign(&KeyManager::generate());
+        let report = verify(&manifest);
+        assert_eq!(
+            report.verification.checks.signature_valid,
+            CheckResult::Pass
+        );
+        assert_eq!(
+            report.verification.checks.anchors[0].hash_match,
+            CheckResult::Fail
+        );
+        assert_eq!(report.verification.status, VerificationStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_verification_reports_each_anchor() {
+        use crate::evidence::blockchain::rfc3161::testing::TestTsa;
+        use base64::{engine::general_purpose, Engine as _};
+
+        let mut video_file = tempfile::NamedTempFile::new().unwrap();
+        video_file.write_all(b"test video content").unwrap();
+        let video_path = video_file.path().to_path_buf();
+
+        let mut manifest = unsigned_manifest(&video_path);
+        let key_manager = KeyManager::generate();
+        manifest.sign(&key_manager);
+        let anchored_hash = manifest.compute_pre_anchor_hash();
+        let anchor = |proof: AnchorProof| BlockchainAnchor {
+            anchored_at: Utc::now(),
+            anchored_hash: anchored_hash.clone(),
+            manifest_hash: anchored_hash.clone(),
+            proof,
+        };
+
+        // A manifest anchored before multi-anchor support, then to a TSA as well
+        manifest.blockchain_anchor = Some(anchor(AnchorProof::Mock {
+            hash: anchored_hash.clone(),
+            timestamp: Utc::now(),
+        }));
+        manifest.sign(&key_manager);
+
+        let tsa = TestTsa::new();
+        let token = tsa.token(&hex::decode(&anchored_hash).unwrap(), None);
+        manifest.add_anchor(anchor(AnchorProof::Rfc3161 {
+            tsa_url: "http://127.0.0.1/tsr".to_string(),
+            tsa_name: "CN=Notari Test TSA".to_string(),
+            token: general_purpose::STANDARD.encode(&token),
+            gen_time: Utc::now(),
+            serial_number: "04d2".to_string(),
+        }));
+        assert!(manifest.blockchain_anchor.is_none());
+        assert!(manifest.is_anchored_to("mock"));
+        manifest.sign(&key_manager);
+
+        let manifest_file = tempfile::NamedTempFile::new().unwrap();
+        manifest.save(manifest_file.path()).unwrap();
+        let report = Verifier::verify(manifest_file.path(), &video_path).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let services: Vec<_> = report
+            .verification
+            .checks
+            .anchors
+            .iter()
+            .map(|check| check.service.as_str())
+            .collect();
+        assert_eq!(services, ["mock", "rfc3161:http://127.0.0.1/tsr"]);
+
+        // Deep verification asks a separate anchorer for each proof; a failure on one
+        // service does not hide the result of the other
+        let report =
+            Verifier::verify_deep(
+                manifest_file.path(),
+                &video_path,
+                None,
+                |proof| match proof {
+                    AnchorProof::Rfc3161 { .. } => Ok(Box::new(TsaAnchorer::new(
+                        &crate::evidence::blockchain::TsaConfig {
+                            url: "http://127.0.0.1/tsr".to_string(),
+                        },
+                    )?)
+                        as Box<dyn BlockchainAnchorer>),
+                    _ => Err(crate::error::NotariError::BlockchainNotConfigured(
+                        "offline".to_string(),
+                    )),
+                },
+            )
+            .await
+            .unwrap();
+        let checks = &report.verification.checks.anchors;
+        let mock = checks[0].on_chain_verified.as_ref().unwrap();
+        assert!(!mock.verified);
+        assert!(mock.error.is_some());
+        assert!(checks[1].on_chain_verified.as_ref().unwrap().verified);
+    }
+
+    fn merkle_leaf(hash: &str) -> [u8; 32] {
+        hex::decode(hash).unwrap().try_into().unwrap()
+    }
 }
@@ -0,0 +1,657 @@
+//! X.509 certificate parsing and signature verification
+//!
+//! Enough of RFC 5280 to identify a signer, check validity periods, key usage,
+//! path length constraints and issuer signatures. Signatures are verified with `ring` (RSA PKCS#1 v1.5, ECDSA
+//! P-256/P-384, Ed25519).
+
+use chrono::{DateTime, Utc};
+use ring::signature::{self, UnparsedPublicKey, VerificationAlgorithm};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use super::der::{self, Element, Reader, TAG_SEQUENCE, TAG_SET};
+use crate::error::{NotariError, NotariResult};
+
+pub const OID_SHA256: &str = "2.16.840.1.101.3.4.2.1";
+pub const OID_SHA384: &str = "2.16.840.1.101.3.4.2.2";
+pub const OID_SHA512: &str = "2.16.840.1.101.3.4.2.3";
+
+pub const OID_RSA_ENCRYPTION: &str = "1.2.840.113549.1.1.1";
+pub const OID_SHA256_WITH_RSA: &str = "1.2.840.113549.1.1.11";
+pub const OID_SHA384_WITH_RSA: &str = "1.2.840.113549.1.1.12";
+pub const OID_SHA512_WITH_RSA: &str = "1.2.840.113549.1.1.13";
+pub const OID_EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
+pub const OID_ECDSA_WITH_SHA256: &str = "1.2.840.10045.4.3.2";
+pub const OID_ECDSA_WITH_SHA384: &str = "1.2.840.10045.4.3.3";
+pub const OID_CURVE_P256: &str = "1.2.840.10045.3.1.7";
+pub const OID_CURVE_P384: &str = "1.3.132.0.34";
+pub const OID_ED25519: &str = "1.3.101.112";
+
+pub const OID_BASIC_CONSTRAINTS: &str = "2.5.29.19";
+pub const OID_KEY_USAGE: &str = "2.5.29.15";
+pub const OID_EXT_KEY_USAGE: &str = "2.5.29.37";
+pub const OID_SUBJECT_KEY_ID: &str = "2.5.29.14";
+pub const OID_ANY_EXTENDED_KEY_USAGE: &str = "2.5.29.37.0";
+pub const OID_KP_CODE_SIGNING: &str = "1.3.6.1.5.5.7.3.3";
+pub const OID_KP_TIME_STAMPING: &str = "1.3.6.1.5.5.7.3.8";
+pub const OID_KP_DOCUMENT_SIGNING: &str = "1.3.6.1.5.5.7.3.36";
+
+/// Key usage bits, as returned by `Certificate::key_usage` (bit 0 is the most
+/// significant)
+pub const KEY_USAGE_DIGITAL_SIGNATURE: u16 = 0x8000;
+pub const KEY_USAGE_KEY_CERT_SIGN: u16 = 0x8000 >> 5;
+
+fn invalid(msg: impl std::fmt::Display) -> NotariError {
+    NotariError::CertificateError(msg.to_string())
+}
+
+/// Hash data with the digest algorithm identified by `oid`
+pub fn digest(oid: &str, data: &[u8]) -> NotariResult<Vec<u8>> {
+    match oid {
+        OID_SHA256 => Ok(Sha256::digest(data).to_vec()),
+        OID_SHA384 => Ok(Sha384::digest(data).to_vec()),
+        OID_SHA512 => Ok(Sha512::digest(data).to_vec()),
+        other => Err(invalid(format!("unsupported digest algorithm {}", other))),
+    }
+}
+
+/// Parsed X.509 certificate
+#[derive(Debug, Clone)]
+pub struct Certificate {
+    /// Complete DER encoding
+    pub der: Vec<u8>,
+    tbs: Vec<u8>,
+    /// Serial number (big-endian magnitude)
+    pub serial: Vec<u8>,
+    /// DER-encoded issuer Name
+    pub issuer: Vec<u8>,
+    /// DER-encoded subject Name
+    pub subject: Vec<u8>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    key_algorithm: String,
+    key_curve: Option<String>,
+    public_key: Vec<u8>,
+    signature_algorithm: String,
+    signature: Vec<u8>,
+    /// Extended key usage purposes (OIDs); empty if the extension is absent
+    pub extended_key_usage: Vec<String>,
+    /// Key usage bits (`KEY_USAGE_*`); `None` if the extension is absent
+    pub key_usage: Option<u16>,
+    /// Basic constraints cA flag
+    pub is_ca: bool,
+    /// Basic constraints pathLenConstraint: how many intermediate CAs may follow
+    pub path_len_constraint: Option<u64>,
+    pub subject_key_id: Option<Vec<u8>>,
+    /// Critical extensions this parser doesn't understand (OIDs); RFC 5280 says
+    /// the certificate must not be accepted while any are present
+    pub unknown_critical_extensions: Vec<String>,
+}
+
+impl Certificate {
+    /// Parse a DER-encoded certificate
+    pub fn from_der(data: &[u8]) -> NotariResult<Self> {
+        let certificate = der::parse(data)?.expect(TAG_SEQUENCE)?;
+        let mut reader = certificate.reader();
+        let tbs = reader.read_tag(TAG_SEQUENCE)?;
+        let (signature_algorithm, _) = der::algorithm_identifier(reader.read()?)?;
+        let signature = reader.read()?.as_bits()?.to_vec();
+        reader.finish()?;
+
+        let mut fields = tbs.reader();
+        fields.read_optional(der::context(0))?; // version
+        let serial = fields.read()?.integer_bytes()?.to_vec();
+        let (inner_algorithm, _) = der::algorithm_identifier(fields.read()?)?;
+        if inner_algorithm != signature_algorithm {
+            return Err(invalid("signature algorithm mismatch"));
+        }
+        let issuer = fields.read_tag(TAG_SEQUENCE)?.raw.to_vec();
+
+        let mut validity = fields.read_tag(TAG_SEQUENCE)?.reader();
+        let not_before = validity.read()?.as_time()?;
+        let not_after = validity.read()?.as_time()?;
+        validity.finish()?;
+
+        let subject = fields.read_tag(TAG_SEQUENCE)?.raw.to_vec();
+
+        let mut spki = fields.read_tag(TAG_SEQUENCE)?.reader();
+        let (key_algorithm, key_curve) = der::algorithm_identifier(spki.read()?)?;
+        let public_key = spki.read()?.as_bits()?.to_vec();
+        spki.finish()?;
+
+        let mut extended_key_usage = Vec::new();
+        let mut key_usage = None;
+        let mut is_ca = false;
+        let mut path_len_constraint = None;
+        let mut subject_key_id = None;
+        let mut unknown_critical_extensions = Vec::new();
+        while !fields.is_empty() {
+            let field = fields.read()?;
+            if field.tag != der::context(3) {
+                continue; // issuer/subject unique IDs
+            }
+
+            let mut extensions = der::parse(field.value)?.expect(TAG_SEQUENCE)?.reader();
+            while !extensions.is_empty() {
+                let mut extension = extensions.read_tag(TAG_SEQUENCE)?.reader();
+                let id = extension.read()?.as_oid()?;
+                let critical = match extension.read_optional(der::TAG_BOOLEAN)? {
+                    Some(critical) => critical.as_bool()?,
+                    None => false,
+                };
+                let value = extension.read()?.as_octets()?;
+                extension.finish()?;
+
+                match id.as_str() {
+                    OID_EXT_KEY_USAGE => {
+                        let mut purposes = der::parse(value)?.expect(TAG_SEQUENCE)?.reader();
+                        while !purposes.is_empty() {
+                            extended_key_usage.push(purposes.read()?.as_oid()?);
+                        }
+                    }
+                    OID_BASIC_CONSTRAINTS => {
+                        let mut constraints = der::parse(value)?.expect(TAG_SEQUENCE)?.reader();
+                        if let Some(ca) = constraints.read_optional(der::TAG_BOOLEAN)? {
+                            is_ca = ca.as_bool()?;
+                        }
+                        if let Some(path_len) = constraints.read_optional(der::TAG_INTEGER)? {
+                            path_len_constraint = Some(path_len.as_u64()?);
+                        }
+                        constraints.finish()?;
+                    }
+                    OID_KEY_USAGE => {
+                        key_usage = Some(key_usage_bits(der::parse(value)?)?);
+                    }
+                    OID_SUBJECT_KEY_ID => {
+                        subject_key_id = Some(der::parse(value)?.as_octets()?.to_vec());
+                    }
+                    _ if critical => unknown_critical_extensions.push(id),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Self {
+            der: data.to_vec(),
+            tbs: tbs.raw.to_vec(),
+            serial,
+            issuer,
+            subject,
+            not_before,
+            not_after,
+            key_algorithm,
+            key_curve,
+            public_key,
+            signature_algorithm,
+            signature,
+            extended_key_usage,
+            key_usage,
+            is_ca,
+            path_len_constraint,
+            subject_key_id,
+            unknown_critical_extensions,
+        })
+    }
+
+    /// Readable subject name (e.g. "CN=Example TSA, O=Example")
+    pub fn subject_name(&self) -> String {
+        name_to_string(&self.subject)
+    }
+
+    /// Readable issuer name
+    pub fn issuer_name(&self) -> String {
+        name_to_string(&self.issuer)
+    }
+
+    /// SHA-256 fingerprint of the DER encoding (hex)
+    pub fn fingerprint(&self) -> String {
+        hex::encode(Sha256::digest(&self.der))
+    }
+
+    /// Whether `time` lies within the validity period
+    pub fn is_valid_at(&self, time: &DateTime<Utc>) -> bool {
+        self.not_before <= *time && *time <= self.not_after
+    }
+
+    /// Ed25519 public key the certificate certifies, if its key is one
+    pub fn ed25519_public_key(&self) -> Option<&[u8]> {
+        (self.key_algorithm == OID_ED25519).then_some(self.public_key.as_slice())
+    }
+
+    /// Whether the certificate allows the given extended key usage purpose
+    pub fn has_extended_key_usage(&self, purpose: &str) -> bool {
+        self.extended_key_usage.iter().any(|p| p == purpose)
+    }
+
+    /// Whether the key usage extension allows `usage` (a `KEY_USAGE_*` bit);
+    /// without the extension every usage is allowed
+    pub fn allows_key_usage(&self, usage: u16) -> bool {
+        self.key_usage.map_or(true, |bits| bits & usage != 0)
+    }
+
+    /// Verify a signature made with this certificate's key
+    ///
+    /// `digest_algorithm` is only consulted when `signature_algorithm` does not
+    /// name a hash itself (CMS signers may use plain `rsaEncryption`).
+    ///
+    /// Returns `Ok(false)` for a wrong signature and an error for unsupported
+    /// algorithm combinations.
+    pub fn verify_signature(
+        &self,
+        signature_algorithm: &str,
+        digest_algorithm: Option<&str>,
+        message: &[u8],
+        signature: &[u8],
+    ) -> NotariResult<bool> {
+        let algorithm = self.verification_algorithm(signature_algorithm, digest_algorithm)?;
+        Ok(UnparsedPublicKey::new(algorithm, &self.public_key)
+            .verify(message, signature)
+            .is_ok())
+    }
+
+    /// Check that this certificate was signed by `issuer`
+    pub fn is_issued_by(&self, issuer: &Certificate) -> NotariResult<bool> {
+        if self.issuer != issuer.subject {
+            return Ok(false);
+        }
+        issuer.verify_signature(&self.signature_algorithm, None, &self.tbs, &self.signature)
+    }
+
+    fn verification_algorithm(
+        &self,
+        signature_algorithm: &str,
+        digest_algorithm: Option<&str>,
+    ) -> NotariResult<&'static dyn VerificationAlgorithm> {
+        let unsupported = || {
+            invalid(format!(
+                "unsupported signature algorithm {} for {} key",
+                signature_algorithm, self.key_algorithm
+            ))
+        };
+
+        let algorithm: &'static dyn VerificationAlgorithm =
+            match (self.key_algorithm.as_str(), signature_algorithm) {
+                (OID_RSA_ENCRYPTION, OID_SHA256_WITH_RSA) => &signature::RSA_PKCS1_2048_8192_SHA256,
+                (OID_RSA_ENCRYPTION, OID_SHA384_WITH_RSA) => &signature::RSA_PKCS1_2048_8192_SHA384,
+                (OID_RSA_ENCRYPTION, OID_SHA512_WITH_RSA) => &signature::RSA_PKCS1_2048_8192_SHA512,
+                (OID_RSA_ENCRYPTION, OID_RSA_ENCRYPTION) => match digest_algorithm {
+                    Some(OID_SHA256) => &signature::RSA_PKCS1_2048_8192_SHA256,
+                    Some(OID_SHA384) => &signature::RSA_PKCS1_2048_8192_SHA384,
+                    Some(OID_SHA512) => &signature::RSA_PKCS1_2048_8192_SHA512,
+                    _ => return Err(unsupported()),
+                },
+                (OID_EC_PUBLIC_KEY, OID_ECDSA_WITH_SHA256) => match self.key_curve.as_deref() {
+                    Some(OID_CURVE_P256) => &signature::ECDSA_P256_SHA256_ASN1,
+                    Some(OID_CURVE_P384) => &signature::ECDSA_P384_SHA256_ASN1,
+                    _ => return Err(unsupported()),
+                },
+                (OID_EC_PUBLIC_KEY, OID_ECDSA_WITH_SHA384) => match self.key_curve.as_deref() {
+                    Some(OID_CURVE_P256) => &signature::ECDSA_P256_SHA384_ASN1,
+                    Some(OID_CURVE_P384) => &signature::ECDSA_P384_SHA384_ASN1,
+                    _ => return Err(unsupported()),
+                },
+                (OID_ED25519, OID_ED25519) => &signature::ED25519,
+                _ => return Err(unsupported()),
+            };
+        Ok(algorithm)
+    }
+}
+
+/// First 16 bits of a KeyUsage BIT STRING, bit 0 most significant
+fn key_usage_bits(element: Element<'_>) -> NotariResult<u16> {
+    match element.expect(der::TAG_BIT_STRING)?.value {
+        [unused, bits @ ..] if *unused < 8 => {
+            let byte = |i: usize| bits.get(i).copied().unwrap_or(0);
+            Ok(u16::from_be_bytes([byte(0), byte(1)]))
+        }
+        _ => Err(invalid("invalid key usage")),
+    }
+}
+
+/// Parse every certificate in a concatenation of DER certificates
+pub fn parse_certificates(data: &[u8]) -> NotariResult<Vec<Certificate>> {
+    let mut reader = Reader::new(data);
+    let mut certificates = Vec::new();
+    while !reader.is_empty() {
+        certificates.push(Certificate::from_der(reader.read()?.raw)?);
+    }
+    Ok(certificates)
+}
+
+/// Readable form of a DER-encoded Name (RFC 4514 order is not attempted)
+pub fn name_to_string(name: &[u8]) -> String {
+    fn attributes(name: &[u8]) -> NotariResult<Vec<String>> {
+        let mut parts = Vec::new();
+        let mut rdns = der::parse(name)?.expect(TAG_SEQUENCE)?.reader();
+        while !rdns.is_empty() {
+            let mut rdn = rdns.read_tag(TAG_SET)?.reader();
+            while !rdn.is_empty() {
+                let mut attribute = rdn.read_tag(TAG_SEQUENCE)?.reader();
+                let oid = attribute.read()?.as_oid()?;
+                let value = attribute.read()?;
+                let label = match oid.as_str() {
+                    "2.5.4.3" => "CN",
+                    "2.5.4.6" => "C",
+                    "2.5.4.7" => "L",
+                    "2.5.4.8" => "ST",
+                    "2.5.4.10" => "O",
+                    "2.5.4.11" => "OU",
+                    other => other,
+                };
+                let text = value
+                    .as_string()
+                    .unwrap_or_else(|_| format!("#{}", hex::encode(value.raw)));
+                parts.push(format!("{}={}", label, text));
+            }
+        }
+        Ok(parts)
+    }
+
+    match attributes(name) {
+        Ok(parts) if !parts.is_empty() => parts.join(", "),
+        Ok(_) => "(empty name)".to_string(),
+        Err(_) => "(invalid name)".to_string(),
+    }
+}
+
+/// Read a `[0] IMPLICIT SET OF Certificate`-style field that may be absent
+pub fn read_optional_certificates(
+    reader: &mut Reader<'_>,
+    tag: u8,
+) -> NotariResult<Vec<Certificate>> {
+    match reader.read_optional(tag)? {
+        Some(Element { value, .. }) => parse_certificates(value),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    //! Locally generated ECDSA P-256 certificates for tests
+
+    use super::*;
+    use chrono::Duration;
+    use ring::rand::SystemRandom;
+    use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
+
+    pub struct TestKey {
+        key_pair: EcdsaKeyPair,
+    }
+
+    impl TestKey {
+        pub fn generate() -> Self {
+            let rng = SystemRandom::new();
+            let pkcs8 =
+                EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+            let key_pair =
+                EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                    .unwrap();
+            Self { key_pair }
+        }
+
+        /// ECDSA-with-SHA256 signature (DER)
+        pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+            self.key_pair
+                .sign(&SystemRandom::new(), message)
+                .unwrap()
+                .as_ref()
+                .to_vec()
+        }
+
+        fn spki(&self) -> Vec<u8> {
+            der::sequence(&[
+                der::algorithm(OID_EC_PUBLIC_KEY, Some(OID_CURVE_P256)),
+                der::bit_string(self.key_pair.public_key().as_ref()),
+            ])
+        }
+    }
+
+    /// SubjectPublicKeyInfo for an Ed25519 public key
+    pub fn ed25519_spki(public_key: &[u8]) -> Vec<u8> {
+        der::sequence(&[
+            der::algorithm(OID_ED25519, None),
+            der::bit_string(public_key),
+        ])
+    }
+
+    /// Name with a single common name
+    pub fn name(common_name: &str) -> Vec<u8> {
+        der::sequence(&[der::set(&[der::sequence(&[
+            der::oid("2.5.4.3"),
+            der::utf8_string(common_name),
+        ])])])
+    }
+
+    /// Options for `issue`
+    pub struct CertificateParams<'a> {
+        pub subject: &'a str,
+        pub serial: u64,
+        pub not_before: DateTime<Utc>,
+        pub not_after: DateTime<Utc>,
+        pub extended_key_usage: &'a [&'a str],
+        /// `KEY_USAGE_*` bits; no key usage extension if `None`
+        pub key_usage: Option<u16>,
+        pub is_ca: bool,
+        pub path_len: Option<u64>,
+        /// Extensions with these OIDs, marked critical
+        pub critical_extensions: &'a [&'a str],
+    }
+
+    impl<'a> CertificateParams<'a> {
+        /// Valid from an hour ago for a year
+        pub fn new(subject: &'a str) -> Self {
+            Self {
+                subject,
+                serial: 1,
+                not_before: Utc::now() - Duration::hours(1),
+                not_after: Utc::now() + Duration::days(365),
+                extended_key_usage: &[],
+                key_usage: None,
+                is_ca: false,
+                path_len: None,
+                critical_extensions: &[],
+            }
+        }
+
+        /// Like `new`, with the key usage of a manifest signer's certificate
+        pub fn signer(subject: &'a str) -> Self {
+            Self {
+                key_usage: Some(KEY_USAGE_DIGITAL_SIGNATURE),
+                ..Self::new(subject)
+            }
+        }
+    }
+
+    /// Issue a certificate for `key`, signed by `issuer` (self-signed if `None`)
+    pub fn issue(
+        params: &CertificateParams<'_>,
+        key: &TestKey,
+        issuer: Option<(&str, &TestKey)>,
+    ) -> Vec<u8> {
+        issue_for_spki(params, &key.spki(), issuer.unwrap_or((params.subject, key)))
+    }
+
+    /// Issue a certificate for the public key in `spki`, signed by `issuer`
+    pub fn issue_for_spki(
+        params: &CertificateParams<'_>,
+        spki: &[u8],
+        (issuer_name, issuer_key): (&str, &TestKey),
+    ) -> Vec<u8> {
+        let mut extensions = Vec::new();
+        if !params.extended_key_usage.is_empty() {
+            let purposes: Vec<Vec<u8>> = params
+                .extended_key_usage
+                .iter()
+                .map(|p| der::oid(p))
+                .collect();
+            extensions.push(der::sequence(&[
+                der::oid(OID_EXT_KEY_USAGE),
+                der::boolean(true),
+                der::octet_string(&der::sequence(&purposes)),
+            ]));
+        }
+        if let Some(key_usage) = params.key_usage {
+            // DER drops trailing zero bits
+            let bytes = key_usage.to_be_bytes();
+            let bytes = &bytes[..if bytes[1] == 0 { 1 } else { 2 }];
+            let unused = bytes.last().unwrap().trailing_zeros().min(7) as u8;
+            extensions.push(der::sequence(&[
+                der::oid(OID_KEY_USAGE),
+                der::boolean(true),
+                der::octet_string(&der::encode(
+                    der::TAG_BIT_STRING,
+                    &[&[unused][..], bytes].concat(),
+                )),
+            ]));
+        }
+        if params.is_ca {
+            let mut constraints = vec![der::boolean(true)];
+            if let Some(path_len) = params.path_len {
+                constraints.push(der::integer_u64(path_len));
+            }
+            extensions.push(der::sequence(&[
+                der::oid(OID_BASIC_CONSTRAINTS),
+                der::boolean(true),
+                der::octet_string(&der::sequence(&constraints)),
+            ]));
+        }
+        for oid in params.critical_extensions {
+            extensions.push(der::sequence(&[
+                der::oid(oid),
+                der::boolean(true),
+                der::octet_string(&der::null()),
+            ]));
+        }
+
+        let mut tbs = vec![
+            der::explicit(0, &der::integer_u64(2)),
+            der::integer_u64(params.serial),
+            der::algorithm(OID_ECDSA_WITH_SHA256, None),
+            name(issuer_name),
+            der::sequence(&[
+                der::generalized_time(&params.not_before),
+                der::generalized_time(&params.not_after),
+            ]),
+            name(params.subject),
+            spki.to_vec(),
+        ];
+        if !extensions.is_empty() {
+            tbs.push(der::explicit(3, &der::sequence(&extensions)));
+        }
+        let tbs = der::sequence(&tbs);
+
+        der::sequence(&[
+            tbs.clone(),
+            der::algorithm(OID_ECDSA_WITH_SHA256, None),
+            der::bit_string(&issuer_key.sign(&tbs)),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::*;
+    use super::*;
+
+    #[test]
+    fn test_parse_self_signed_certificate() {
+        let key = TestKey::generate();
+        let mut params = CertificateParams::new("Test TSA");
+        params.serial = 4242;
+        params.extended_key_usage = &[OID_KP_TIME_STAMPING];
+        let certificate = Certificate::from_der(&issue(&params, &key, None)).unwrap();
+
+        assert_eq!(certificate.subject_name(), "CN=Test TSA");
+        assert_eq!(certificate.issuer_name(), "CN=Test TSA");
+        assert_eq!(certificate.serial, vec![0x10, 0x92]);
+        assert!(certificate.has_extended_key_usage(OID_KP_TIME_STAMPING));
+        assert!(!certificate.is_ca);
+        assert!(certificate.is_valid_at(&Utc::now()));
+        assert!(!certificate.is_valid_at(&(Utc::now() + chrono::Duration::days(400))));
+        assert_eq!(certificate.fingerprint().len(), 64);
+        assert!(certificate.is_issued_by(&certificate).unwrap());
+    }
+
+    #[test]
+    fn test_parse_key_usage_and_constraints() {
+        let key = TestKey::generate();
+        let mut params = CertificateParams::new("Test CA");
+        params.is_ca = true;
+        params.path_len = Some(0);
+        params.key_usage = Some(KEY_USAGE_KEY_CERT_SIGN);
+        params.critical_extensions = &["2.5.29.30"];
+        let ca = Certificate::from_der(&issue(&params, &key, None)).unwrap();
+        assert_eq!(ca.path_len_constraint, Some(0));
+        assert_eq!(ca.key_usage, Some(KEY_USAGE_KEY_CERT_SIGN));
+        assert!(ca.allows_key_usage(KEY_USAGE_KEY_CERT_SIGN));
+        assert!(!ca.allows_key_usage(KEY_USAGE_DIGITAL_SIGNATURE));
+        assert_eq!(
+            ca.unknown_critical_extensions,
+            vec!["2.5.29.30".to_string()]
+        );
+
+        let signer =
+            Certificate::from_der(&issue(&CertificateParams::signer("Signer"), &key, None))
+                .unwrap();
+        assert_eq!(signer.key_usage, Some(KEY_USAGE_DIGITAL_SIGNATURE));
+        assert_eq!(signer.path_len_constraint, None);
+        assert!(signer.unknown_critical_extensions.is_empty());
+
+        // No extension: nothing is restricted
+        let plain =
+            Certificate::from_der(&issue(&CertificateParams::new("Plain"), &key, None)).unwrap();
+        assert_eq!(plain.key_usage, None);
+        assert!(plain.allows_key_usage(KEY_USAGE_KEY_CERT_SIGN));
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let key = TestKey::generate();
+        let certificate =
+            Certificate::from_der(&issue(&CertificateParams::new("Signer"), &key, None)).unwrap();
+
+        let signature = key.sign(b"message");
+        assert!(certificate
+            .verify_signature(OID_ECDSA_WITH_SHA256, None, b"message", &signature)
+            .unwrap());
+        assert!(!certificate
+            .verify_signature(OID_ECDSA_WITH_SHA256, None, b"other", &signature)
+            .unwrap());
+        assert!(certificate
+            .verify_signature(OID_SHA256_WITH_RSA, None, b"message", &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_issued_by_ca() {
+        let ca_key = TestKey::generate();
+        let mut ca_params = CertificateParams::new("Test CA");
+        ca_params.is_ca = true;
+        let ca = Certificate::from_der(&issue(&ca_params, &ca_key, None)).unwrap();
+        assert!(ca.is_ca);
+
+        let leaf_key = TestKey::generate();
+        let leaf = Certificate::from_der(&issue(
+            &CertificateParams::new("Leaf"),
+            &leaf_key,
+            Some(("Test CA", &ca_key)),
+        ))
+        .unwrap();
+        assert!(leaf.is_issued_by(&ca).unwrap());
+
+        // Same issuer name, different key
+        let impostor =
+            Certificate::from_der(&issue(&ca_params, &TestKey::generate(), None)).unwrap();
+        assert!(!leaf.is_issued_by(&impostor).unwrap());
+        assert!(!ca.is_issued_by(&leaf).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_malformed_certificate() {
+        let key = TestKey::generate();
+        let mut der = issue(&CertificateParams::new("Signer"), &key, None);
+        assert!(Certificate::from_der(&der[..der.len() - 1]).is_err());
+        der.push(0);
+        assert!(Certificate::from_der(&der).is_err());
+        assert!(Certificate::from_der(b"not a certificate").is_err());
+    }
+}
@@ -44,6 +44,7 @@ pub fn run() {
             recording_commands::select_save_directory,
             recording_commands::check_recording_health,
             recording_commands::cleanup_orphaned_recordings,
+            recording_commands::recover_orphaned_recordings,
             recording_commands::pause_recording,
             recording_commands::resume_recording,
             recording_commands::get_active_recording_session,
@@ -60,11 +61,23 @@ pub fn run() {
             recording_commands::log_get_min_level,
             recording_commands::verify_recording,
             recording_commands::verify_recording_deep,
+            recording_commands::verify_excerpt,
             recording_commands::get_evidence_manifest,
             recording_commands::export_public_key,
             recording_commands::has_signing_key,
             recording_commands::generate_signing_key,
             recording_commands::get_signing_key_info,
+            recording_commands::rotate_signing_key,
+            recording_commands::get_key_history,
+            recording_commands::list_trusted_signers,
+            recording_commands::add_trusted_signer,
+            recording_commands::remove_trusted_signer,
+            recording_commands::list_trust_anchors,
+            recording_commands::add_trust_anchor,
+            recording_commands::remove_trust_anchor,
+            recording_commands::get_signer_certificate,
+            recording_commands::set_signer_certificate,
+            recording_commands::clear_signer_certificate,
             recording_commands::generate_encryption_key,
             recording_commands::export_encryption_public_key,
             recording_commands::has_encryption_key,
@@ -74,6 +87,9 @@ pub fn run() {
             recording_commands::decrypt_video,
             recording_commands::encrypt_video_with_public_keys,
             recording_commands::decrypt_video_with_private_key,
+            recording_commands::upgrade_recording_encryption,
+            recording_commands::add_recording_recipient,
+            recording_commands::remove_recording_recipient,
             recording_commands::validate_encryption_password,
             recording_commands::read_file,
             recording_commands::read_manifest_from_notari,
@@ -105,6 +121,10 @@ pub fn run() {
             blockchain_commands::estimate_anchor_cost,
             blockchain_commands::test_connection,
             blockchain_commands::anchor_recording,
+            blockchain_commands::upgrade_anchor,
+            blockchain_commands::queue_anchor,
+            blockchain_commands::get_anchor_queue,
+            blockchain_commands::flush_anchor_queue,
         ])
         .setup(|app| {
             if cfg!(debug_assertions) {
@@ -140,6 +160,21 @@ pub fn run() {
                 }
             }
 
+            // Reload recordings queued for batched anchoring before the last restart
+            use repository::AnchorQueueRepository;
+            if let Ok(pending) = repo_manager.anchors().load_queue() {
+                if !pending.is_empty() {
+                    if let Ok(mut queue) = blockchain_state.queue.lock() {
+                        app_log!(
+                            logger::LogLevel::Info,
+                            "Loaded {} queued anchor(s) from storage",
+                            pending.len()
+                        );
+                        queue.restore(pending);
+                    }
+                }
+            }
+
             // Load mock anchors from storage
             evidence::blockchain::MockAnchorer::load_from_storage();
 
@@ -166,6 +201,31 @@ pub fn run() {
                 }
             }
 
+            // Record the signing key in the key history so its age is tracked
+            let key_history = evidence::KeyHistory::default_path().and_then(|path| {
+                let key_manager = KeyManager::from_bytes(&keychain::retrieve_signing_key()?)?;
+                let history = evidence::KeyHistory::load_or_start(&path, &key_manager)?;
+                history.save(&path)?;
+                Ok(history)
+            });
+            match key_history {
+                Ok(history) if history.rotation_due(chrono::Utc::now()) => {
+                    app_log!(
+                        logger::LogLevel::Warn,
+                        "Signing key in use since {} is due for rotation",
+                        history.current_key_since().format("%Y-%m-%d")
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    app_log!(
+                        logger::LogLevel::Warn,
+                        "Failed to update key history: {}",
+                        e
+                    );
+                }
+            }
+
             // Ensure encryption key exists (generate if needed)
             use evidence::EncryptionKeyManager;
             if !keychain::has_encryption_key() {
@@ -221,6 +281,29 @@ pub fn run() {
                 }
             });
 
+            // Finalize recordings interrupted by a previous crash in the background
+            let app_handle_recovery = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = app_handle_recovery.state::<recording_commands::WindowManagerState>();
+                match recording_commands::recover_orphaned_recordings(state).await {
+                    Ok(proof_packs) if !proof_packs.is_empty() => {
+                        app_log!(
+                            logger::LogLevel::Info,
+                            "Recovered {} interrupted recording(s)",
+                            proof_packs.len()
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        app_log!(
+                            logger::LogLevel::Warn,
+                            "Failed to recover interrupted recordings: {}",
+                            e
+                        );
+                    }
+                }
+            });
+
             // Start periodic health check for recordings using async task
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
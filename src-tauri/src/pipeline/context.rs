@@ -2,6 +2,32 @@ use crate::error::{NotariError, NotariResult};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Callback receiving `(processed, total)` progress units from the running stage
+///
+/// Installed by the executor when events are enabled; stages report through
+/// [`PipelineContext::report_progress`] without needing an `AppHandle`.
+#[derive(Clone)]
+pub struct ProgressReporter(Arc<dyn Fn(u64, u64) + Send + Sync>);
+
+impl ProgressReporter {
+    /// Wrap a progress callback
+    pub fn new(callback: impl Fn(u64, u64) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    /// Forward a progress update to the callback
+    pub fn report(&self, processed: u64, total: u64) {
+        (self.0)(processed, total)
+    }
+}
+
+impl std::fmt::Debug for ProgressReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressReporter")
+    }
+}
 
 /// Pipeline context that holds data passed between stages
 ///
@@ -33,6 +59,9 @@ pub struct PipelineContext {
 
     /// Metadata about the pipeline execution
     metadata: HashMap<String, String>,
+
+    /// Progress sink for the currently executing stage
+    progress_reporter: Option<ProgressReporter>,
 }
 
 impl PipelineContext {
@@ -43,6 +72,7 @@ impl PipelineContext {
             data: HashMap::new(),
             temp_files: Vec::new(),
             metadata: HashMap::new(),
+            progress_reporter: None,
         }
     }
 
@@ -175,6 +205,28 @@ impl PipelineContext {
         &self.metadata
     }
 
+    /// Install the progress sink for the stage about to run
+    pub fn set_progress_reporter(&mut self, reporter: ProgressReporter) {
+        self.progress_reporter = Some(reporter);
+    }
+
+    /// Remove the progress sink once the stage has finished
+    pub fn clear_progress_reporter(&mut self) {
+        self.progress_reporter = None;
+    }
+
+    /// Get the progress sink for the current stage, if any
+    pub fn progress_reporter(&self) -> Option<ProgressReporter> {
+        self.progress_reporter.clone()
+    }
+
+    /// Report `(processed, total)` progress for the current stage (no-op without a sink)
+    pub fn report_progress(&self, processed: u64, total: u64) {
+        if let Some(ref reporter) = self.progress_reporter {
+            reporter.report(processed, total);
+        }
+    }
+
     /// Get all data keys
     pub fn keys(&self) -> Vec<&String> {
         self.data.keys().collect()
@@ -280,6 +332,28 @@ mod tests {
         assert!(!context.has("key1"));
     }
 
+    #[test]
+    fn test_progress_reporter() {
+        use std::sync::Mutex;
+
+        let mut context = PipelineContext::new("session-123");
+        // No reporter installed: reporting is a no-op
+        context.report_progress(1, 2);
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink = updates.clone();
+        context.set_progress_reporter(ProgressReporter::new(move |processed, total| {
+            sink.lock().unwrap().push((processed, total));
+        }));
+
+        context.report_progress(50, 100);
+        context.report_progress(100, 100);
+        context.clear_progress_reporter();
+        context.report_progress(100, 100);
+
+        assert_eq!(*updates.lock().unwrap(), vec![(50, 100), (100, 100)]);
+    }
+
     #[test]
     fn test_clear_data() {
         let mut context = PipelineContext::new("session-123");
@@ -1,4 +1,4 @@
-use super::context::PipelineContext;
+use super::context::{PipelineContext, ProgressReporter};
 use super::core::{PipelineResult, PipelineStage, StageResult};
 use crate::error::NotariResult;
 use crate::events::EventEmitter;
@@ -208,9 +208,34 @@ impl Pipeline {
                 ));
             }
 
+            // Forward stage progress to the frontend while the stage runs
+            if let Some(app) = app {
+                if let Ok(session_id) = uuid::Uuid::parse_str(context.session_id()) {
+                    let app = app.clone();
+                    let pipeline_name = self.name.clone();
+                    let stage_name_owned = stage_name.to_string();
+                    let total_stages = self.stages.len();
+                    context.set_progress_reporter(ProgressReporter::new(
+                        move |processed, total| {
+                            let _ = EventEmitter::pipeline_stage_progress(
+                                &app,
+                                session_id,
+                                &pipeline_name,
+                                &stage_name_owned,
+                                index,
+                                total_stages,
+                                processed,
+                                total,
+                            );
+                        },
+                    ));
+                }
+            }
+
             // Execute stage
             let execute_result = stage.execute(context);
             let duration = stage_start.elapsed();
+            context.clear_progress_reporter();
 
             match execute_result {
                 Ok(_) => {
@@ -45,6 +45,6 @@ pub mod executor;
 pub mod stages;
 
 // Re-export main types
-pub use context::PipelineContext;
+pub use context::{PipelineContext, ProgressReporter};
 pub use core::{PipelineResult, PipelineStage, StageResult};
 pub use executor::{Pipeline, PipelineBuilder};
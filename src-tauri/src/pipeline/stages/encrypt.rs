@@ -7,13 +7,14 @@ use std::time::Duration;
 /// Stage that encrypts the video file with AES-256-GCM
 ///
 /// This stage is optional and will be skipped if no encryption is requested.
-/// Supports both password-based and public key encryption.
+/// Supports password-based and public key encryption, or both at once ("hybrid":
+/// one content key wrapped for each recipient and for the password).
 ///
 /// # Context Requirements
 /// - Input: `video_path` (PathBuf) - Path to video file
-/// - Input: `password` (String, optional) - Encryption password (for password-based encryption)
-/// - Input: `encryption_method` (String, optional) - "password" or "public-key"
-/// - Input: `recipients` (Array of JSON objects, optional) - Recipients for public key encryption
+/// - Input: `password` (String, optional) - Encryption password (for password-based or hybrid encryption)
+/// - Input: `encryption_method` (String, optional) - "password", "public-key" or "hybrid"
+/// - Input: `recipients` (Array of JSON objects, optional) - Recipients for public key or hybrid encryption
 ///   Each recipient: {"id": "alice@example.com", "publicKey": "base64..."}
 ///
 /// # Context Outputs
@@ -56,6 +57,39 @@ impl Default for EncryptStage {
     }
 }
 
+/// Parse the `recipients` array into recipient IDs and public keys
+fn recipient_keys(context: &PipelineContext) -> NotariResult<Vec<(String, crypto_box::PublicKey)>> {
+    use crate::evidence::EncryptionKeyManager;
+
+    // Get recipients from context
+    let recipients_json = context.get_required("recipients")?;
+    let recipients_array = recipients_json.as_array().ok_or_else(|| {
+        crate::error::NotariError::PipelineError("Recipients must be an array".to_string())
+    })?;
+
+    // Parse recipients
+    let mut recipient_keys = Vec::new();
+    for recipient in recipients_array {
+        let id = recipient["id"]
+            .as_str()
+            .ok_or_else(|| {
+                crate::error::NotariError::PipelineError("Recipient missing 'id' field".to_string())
+            })?
+            .to_string();
+
+        let public_key_b64 = recipient["publicKey"].as_str().ok_or_else(|| {
+            crate::error::NotariError::PipelineError(
+                "Recipient missing 'publicKey' field".to_string(),
+            )
+        })?;
+
+        let public_key = EncryptionKeyManager::import_public_key(public_key_b64)?;
+        recipient_keys.push((id, public_key));
+    }
+
+    Ok(recipient_keys)
+}
+
 impl PipelineStage for EncryptStage {
     fn execute(&self, context: &mut PipelineContext) -> NotariResult<()> {
         let video_path = context.get_path("video_path")?;
@@ -80,57 +114,38 @@ impl PipelineStage for EncryptStage {
         let encrypted_path = video_path.with_extension("mov.enc");
 
         // Encrypt based on method
-        let encryption_info = if encryption_method == "public-key" {
-            // Public key encryption
-            use crate::evidence::EncryptionKeyManager;
-
-            // Get recipients from context
-            let recipients_json = context.get_required("recipients")?;
-            let recipients_array = recipients_json.as_array().ok_or_else(|| {
-                crate::error::NotariError::PipelineError("Recipients must be an array".to_string())
-            })?;
-
-            // Parse recipients
-            let mut recipient_keys = Vec::new();
-            for recipient in recipients_array {
-                let id = recipient["id"]
-                    .as_str()
-                    .ok_or_else(|| {
-                        crate::error::NotariError::PipelineError(
-                            "Recipient missing 'id' field".to_string(),
-                        )
-                    })?
-                    .to_string();
-
-                let public_key_b64 = recipient["publicKey"].as_str().ok_or_else(|| {
-                    crate::error::NotariError::PipelineError(
-                        "Recipient missing 'publicKey' field".to_string(),
-                    )
-                })?;
-
-                let public_key = EncryptionKeyManager::import_public_key(public_key_b64)?;
-                recipient_keys.push((id, public_key));
+        let encryption_info = match encryption_method.as_str() {
+            "public-key" | "hybrid" => {
+                let recipient_keys = recipient_keys(context)?;
+                let password = if encryption_method == "hybrid" {
+                    Some(context.get_string("password")?)
+                } else {
+                    None
+                };
+
+                LOGGER.log(
+                    LogLevel::Info,
+                    &format!(
+                        "Encrypting for {} recipients, password slot: {} (session: {})",
+                        recipient_keys.len(),
+                        password.is_some(),
+                        context.session_id()
+                    ),
+                    "pipeline::encrypt",
+                );
+
+                VideoEncryptor::encrypt_file_with_key_slots(
+                    &video_path,
+                    &encrypted_path,
+                    recipient_keys,
+                    password.as_deref(),
+                )?
+            }
+            _ => {
+                // Password-based encryption
+                let password = context.get_string("password")?;
+                VideoEncryptor::encrypt_file_chunked(&video_path, &encrypted_path, &password)?
             }
-
-            LOGGER.log(
-                LogLevel::Info,
-                &format!(
-                    "Encrypting for {} recipients (session: {})",
-                    recipient_keys.len(),
-                    context.session_id()
-                ),
-                "pipeline::encrypt",
-            );
-
-            VideoEncryptor::encrypt_file_with_public_keys(
-                &video_path,
-                &encrypted_path,
-                recipient_keys,
-            )?
-        } else {
-            // Password-based encryption
-            let password = context.get_string("password")?;
-            VideoEncryptor::encrypt_file_chunked(&video_path, &encrypted_path, &password)?
         };
 
         LOGGER.log(
@@ -228,7 +243,7 @@ impl PipelineStage for EncryptStage {
             .get_string("encryption_method")
             .unwrap_or_else(|_| "password".to_string());
 
-        if encryption_method == "public-key" {
+        if encryption_method == "public-key" || encryption_method == "hybrid" {
             // Validate recipients are provided
             if !context.has("recipients") {
                 return Err(crate::error::NotariError::PipelineError(
@@ -246,7 +261,9 @@ impl PipelineStage for EncryptStage {
                     "At least one recipient required for public key encryption".to_string(),
                 ));
             }
-        } else {
+        }
+
+        if encryption_method != "public-key" {
             // Validate password is not empty
             let password = context.get_string("password")?;
             if password.trim().is_empty() {
@@ -363,6 +380,40 @@ mod tests {
         assert_eq!(updated_video_path, encrypted_path);
     }
 
+    #[test]
+    fn test_encrypt_stage_hybrid() {
+        use crate::evidence::{EncryptionInfo, EncryptionKeyManager};
+
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test.mov");
+        let mut file = File::create(&video_path).unwrap();
+        file.write_all(b"test video content").unwrap();
+
+        let recipient = EncryptionKeyManager::generate();
+        let mut context = PipelineContext::new("session-123");
+        context.set_path("video_path", video_path);
+        context.set_string("encryption_method", "hybrid");
+        let recipients = serde_json::json!([{
+            "id": "alice@example.com",
+            "publicKey": recipient.export_public_key(),
+        }]);
+        context.set("recipients", recipients);
+
+        // Hybrid needs the password as well as the recipients
+        let stage = EncryptStage::new();
+        assert!(stage.pre_execute(&context).is_err());
+        context.set_string("password", "SecurePass123");
+        stage.pre_execute(&context).unwrap();
+        stage.execute(&mut context).unwrap();
+
+        let encryption_info: EncryptionInfo =
+            serde_json::from_value(context.get_required("encryption_info").unwrap().clone())
+                .unwrap();
+        let key_derivation = encryption_info.key_derivation.unwrap();
+        assert_eq!(encryption_info.encrypted_keys.unwrap().len(), 1);
+        assert!(key_derivation.wrapped_key.is_some());
+    }
+
     #[test]
     fn test_encrypt_stage_empty_password() {
         let temp_dir = TempDir::new().unwrap();
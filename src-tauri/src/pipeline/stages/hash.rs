@@ -11,6 +11,8 @@ use std::time::Duration;
 ///
 /// # Context Outputs
 /// - `plaintext_hash` (HashInfo serialized as JSON) - SHA-256 hash of video
+/// - `plaintext_chunks` (ChunkHashes serialized as JSON) - Hashes of each 1 MB chunk
+///   of the video and their Merkle root
 ///
 /// # Example
 /// ```no_run
@@ -60,14 +62,16 @@ impl PipelineStage for HashStage {
             "pipeline::hash",
         );
 
-        // Calculate hash
-        let hash = HashInfo::from_file(&video_path)?;
+        // Calculate the whole-file hash and chunk hashes in one pass
+        let (hash, chunks) = HashInfo::from_file_chunked(&video_path)?;
 
         LOGGER.log(
             LogLevel::Info,
             &format!(
-                "Video hash calculated: {} (session: {})",
+                "Video hash calculated: {} ({} chunks, root {}) (session: {})",
                 hash.value,
+                chunks.chunks.len(),
+                chunks.merkle_root,
                 context.session_id()
             ),
             "pipeline::hash",
@@ -79,6 +83,14 @@ impl PipelineStage for HashStage {
         })?;
         context.set("plaintext_hash", hash_json);
 
+        let chunks_json = serde_json::to_value(&chunks).map_err(|e| {
+            crate::error::NotariError::PipelineError(format!(
+                "Failed to serialize chunk hashes: {}",
+                e
+            ))
+        })?;
+        context.set("plaintext_chunks", chunks_json);
+
         Ok(())
     }
 
@@ -107,6 +119,7 @@ impl PipelineStage for HashStage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evidence::ChunkHashes;
     use std::fs::File;
     use std::io::Write;
     use std::path::PathBuf;
@@ -136,6 +149,11 @@ mod tests {
         let hash: HashInfo = serde_json::from_value(hash_json.clone()).unwrap();
         assert_eq!(hash.algorithm, "SHA-256");
         assert!(!hash.value.is_empty());
+
+        let chunks: ChunkHashes =
+            serde_json::from_value(context.get("plaintext_chunks").unwrap().clone()).unwrap();
+        assert_eq!(chunks.chunks, vec![hash.value]);
+        assert!(chunks.root_valid());
     }
 
     #[test]
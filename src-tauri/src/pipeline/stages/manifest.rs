@@ -1,5 +1,8 @@
 use crate::error::NotariResult;
-use crate::evidence::{EvidenceManifestBuilder, HashInfo, SystemInfo, Timestamps};
+use crate::evidence::{
+    CheckpointLog, ChunkHashes, EvidenceManifestBuilder, HashInfo, PauseInterval, SystemInfo,
+    Timestamps,
+};
 
 #[cfg(test)]
 use crate::evidence::EvidenceManifest;
@@ -16,6 +19,7 @@ use uuid::Uuid;
 /// - Input: `session_id` (String) - Recording session ID
 /// - Input: `video_path` (PathBuf) - Path to video file (encrypted or plaintext)
 /// - Input: `plaintext_hash` (HashInfo as JSON) - Hash of plaintext video
+/// - Input: `plaintext_chunks` (ChunkHashes as JSON, optional) - Chunk hashes of plaintext video
 /// - Input: `start_time` (String, ISO 8601) - Recording start time
 /// - Input: `file_size` (Number) - Video file size in bytes
 /// - Input: `duration` (Number) - Recording duration in seconds
@@ -25,6 +29,11 @@ use uuid::Uuid;
 /// - Input: `custom_tags` (Array of strings, optional) - Custom recording tags
 /// - Input: `encryption_info` (EncryptionInfo as JSON, optional) - Encryption metadata
 /// - Input: `encrypted_hash` (HashInfo as JSON, optional) - Hash of encrypted video
+/// - Input: `pauses` (Array of PauseInterval as JSON, optional) - Gaps in the capture
+/// - Input: `recovered` (Boolean, optional) - Recording was recovered after a crash
+/// - Input: `checkpoint_log_path` (PathBuf, optional) - Checkpoints signed while recording
+/// - Input: `joined_segments` (Array of strings, optional) - Hashes of the segments in the video
+/// - Input: `dropped_segments` (Array of strings, optional) - Hashes of the segments left out
 ///
 /// # Context Outputs
 /// - `manifest` (EvidenceManifest serialized as JSON) - Generated manifest
@@ -76,6 +85,64 @@ impl ManifestStage {
 
         Self { system_info }
     }
+
+    /// Create a new manifest stage with auto-detected system information
+    #[cfg(target_os = "linux")]
+    pub fn new_auto() -> Self {
+        // Get distribution name and version (e.g. "Ubuntu 24.04.1 LTS")
+        let os_version = std::fs::read_to_string("/etc/os-release")
+            .ok()
+            .and_then(|release| {
+                release.lines().find_map(|line| {
+                    line.strip_prefix("PRETTY_NAME=")
+                        .map(|value| value.trim_matches('"').to_string())
+                })
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        // Get hostname
+        let hostname = std::fs::read_to_string("/proc/sys/kernel/hostname")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        // Get device ID (systemd machine ID, hostname as fallback)
+        let device_id = std::fs::read_to_string("/etc/machine-id")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| hostname.clone());
+
+        let system_info = SystemInfo {
+            os: "Linux".to_string(),
+            os_version,
+            device_id,
+            hostname,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            recorder: "notari".to_string(),
+        };
+
+        Self { system_info }
+    }
+
+    /// Create a new manifest stage with auto-detected system information
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    pub fn new_auto() -> Self {
+        let hostname = std::env::var("COMPUTERNAME")
+            .or_else(|_| std::env::var("HOSTNAME"))
+            .unwrap_or_else(|_| "Unknown".to_string());
+
+        let system_info = SystemInfo {
+            os: std::env::consts::OS.to_string(),
+            os_version: "Unknown".to_string(),
+            device_id: hostname.clone(),
+            hostname,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            recorder: "notari".to_string(),
+        };
+
+        Self { system_info }
+    }
 }
 
 impl PipelineStage for ManifestStage {
@@ -109,6 +176,19 @@ impl PipelineStage for ManifestStage {
                 ))
             })?;
 
+        // Get chunk hashes (optional)
+        let plaintext_chunks: Option<ChunkHashes> = if context.has("plaintext_chunks") {
+            let chunks_json = context.get_required("plaintext_chunks")?;
+            Some(serde_json::from_value(chunks_json.clone()).map_err(|e| {
+                crate::error::NotariError::PipelineError(format!(
+                    "Failed to deserialize chunk hashes: {}",
+                    e
+                ))
+            })?)
+        } else {
+            None
+        };
+
         // Parse start time
         let start_time_str = context.get_string("start_time")?;
         let start_time = chrono::DateTime::parse_from_rfc3339(&start_time_str)
@@ -151,12 +231,26 @@ impl PipelineStage for ManifestStage {
             0
         };
 
+        // Get pause intervals (optional)
+        let pauses: Vec<PauseInterval> = if context.has("pauses") {
+            let pauses_json = context.get_required("pauses")?;
+            serde_json::from_value(pauses_json.clone()).map_err(|e| {
+                crate::error::NotariError::PipelineError(format!(
+                    "Failed to deserialize pauses: {}",
+                    e
+                ))
+            })?
+        } else {
+            Vec::new()
+        };
+
         // Build timestamps
         let now = Utc::now();
         let timestamps = Timestamps {
             started_at: start_time,
             stopped_at: now,
             manifest_created_at: now,
+            pauses,
         };
 
         // Build manifest using builder
@@ -194,6 +288,34 @@ impl PipelineStage for ManifestStage {
             builder = builder.tags(tags);
         }
 
+        if let Some(chunks) = plaintext_chunks {
+            builder = builder.file_chunks(chunks);
+        }
+
+        if context.has("recovered") {
+            builder = builder.recovered(context.get_bool("recovered")?);
+        }
+
+        // Commit to the checkpoint log that will be packed alongside the manifest
+        if context.has("checkpoint_log_path") {
+            let log_path = context.get_path("checkpoint_log_path")?;
+            let mut summary = CheckpointLog::summarize(&log_path)?;
+            let segment_hashes = |key: &str| -> NotariResult<Vec<String>> {
+                if !context.has(key) {
+                    return Ok(Vec::new());
+                }
+                serde_json::from_value(context.get_required(key)?.clone()).map_err(|e| {
+                    crate::error::NotariError::PipelineError(format!(
+                        "Failed to deserialize {}: {}",
+                        key, e
+                    ))
+                })
+            };
+            summary.segments = segment_hashes("joined_segments")?;
+            summary.dropped_segments = segment_hashes("dropped_segments")?;
+            builder = builder.checkpoints(summary);
+        }
+
         // Add encryption info if provided
         if context.has("encryption_info") {
             let encryption_json = context.get_required("encryption_info")?;
@@ -334,6 +456,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_manifest_stage_with_pauses() {
+        let mut context = PipelineContext::new("550e8400-e29b-41d4-a716-446655440000");
+        context.set_path("video_path", PathBuf::from("/tmp/test.mov"));
+        context.set_number("file_size", 1024000.0);
+        context.set_number("duration", 50.0);
+        context.set_string("start_time", "2024-01-01T12:00:00Z");
+        context.set(
+            "plaintext_hash",
+            serde_json::to_value(HashInfo::from_bytes(b"test data")).unwrap(),
+        );
+        context.set(
+            "pauses",
+            serde_json::json!([{
+                "paused_at": "2024-01-01T12:00:20Z",
+                "resumed_at": "2024-01-01T12:00:30Z"
+            }]),
+        );
+
+        let stage = ManifestStage::new(create_test_system_info());
+        stage.execute(&mut context).unwrap();
+
+        let manifest: EvidenceManifest =
+            serde_json::from_value(context.get("manifest").unwrap().clone()).unwrap();
+        assert_eq!(manifest.timestamps.pauses.len(), 1);
+        assert_eq!(manifest.timestamps.paused_duration().num_seconds(), 10);
+    }
+
     #[test]
     fn test_manifest_stage_missing_required_field() {
         let context = PipelineContext::new("550e8400-e29b-41d4-a716-446655440000");
@@ -378,6 +528,9 @@ mod tests {
                 algorithm: "PBKDF2-HMAC-SHA256".to_string(),
                 iterations: 600000,
                 salt: "test_salt".to_string(),
+                memory_kib: None,
+                parallelism: None,
+                wrapped_key: None,
             }),
             encrypted_keys: None,
             nonce: None,
@@ -392,6 +545,7 @@ mod tests {
                     nonce: "test_nonce".to_string(),
                 }],
             }),
+            recipient_changes: Vec::new(),
         };
         let encryption_json = serde_json::to_value(&encryption_info).unwrap();
         context.set("encryption_info", encryption_json);
@@ -2,6 +2,7 @@ use crate::error::NotariResult;
 use crate::evidence::proof_pack;
 use crate::logger::{LogLevel, LOGGER};
 use crate::pipeline::{PipelineContext, PipelineStage};
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// Stage that packages video and manifest into a .notari proof pack
@@ -9,10 +10,14 @@ use std::time::Duration;
 /// # Context Requirements
 /// - Input: `video_path` (PathBuf) - Path to video file (encrypted or plaintext)
 /// - Input: `manifest_path` (PathBuf) - Path to manifest file
+/// - Input: `checkpoint_log_path` (PathBuf, optional) - Checkpoints signed while recording
+/// - Input: `segment_paths` (Array of paths, optional) - Segments the video was joined from
+/// - Input: `key_history_path` (PathBuf, optional) - Rotations leading to the signing key
 ///
 /// # Context Outputs
 /// - `proof_pack_path` (PathBuf) - Path to created .notari proof pack
-/// - Marks video and manifest as temp files for cleanup
+/// - Marks video, manifest and key history as temp files for cleanup
+/// - Reports byte progress through the context's progress reporter
 ///
 pub struct PackageStage;
 
@@ -33,6 +38,26 @@ impl PipelineStage for PackageStage {
     fn execute(&self, context: &mut PipelineContext) -> NotariResult<()> {
         let video_path = context.get_path("video_path")?;
         let manifest_path = context.get_path("manifest_path")?;
+        let checkpoint_log_path = if context.has("checkpoint_log_path") {
+            Some(context.get_path("checkpoint_log_path")?)
+        } else {
+            None
+        };
+        let segment_paths: Vec<PathBuf> = if context.has("segment_paths") {
+            serde_json::from_value(context.get_required("segment_paths")?.clone()).map_err(|e| {
+                crate::error::NotariError::PipelineError(format!(
+                    "Failed to deserialize segment_paths: {}",
+                    e
+                ))
+            })?
+        } else {
+            Vec::new()
+        };
+        let key_history_path = if context.has("key_history_path") {
+            Some(context.get_path("key_history_path")?)
+        } else {
+            None
+        };
 
         // Generate proof pack path
         let proof_pack_path = video_path.with_extension("notari");
@@ -47,8 +72,21 @@ impl PipelineStage for PackageStage {
             "pipeline::package",
         );
 
-        // Create proof pack
-        proof_pack::create_proof_pack(&video_path, &manifest_path, &proof_pack_path)?;
+        // Create proof pack, streaming byte progress to the pipeline events
+        let reporter = context.progress_reporter();
+        proof_pack::create_proof_pack_with_progress(
+            &video_path,
+            &manifest_path,
+            &proof_pack_path,
+            checkpoint_log_path.as_deref(),
+            &segment_paths,
+            key_history_path.as_deref(),
+            |processed, total| {
+                if let Some(ref reporter) = reporter {
+                    reporter.report(processed, total);
+                }
+            },
+        )?;
 
         LOGGER.log(
             LogLevel::Info,
@@ -63,9 +101,12 @@ impl PipelineStage for PackageStage {
         // Store proof pack path
         context.set_path("proof_pack_path", proof_pack_path);
 
-        // Mark video and manifest as temp files for cleanup
+        // Mark video, manifest and key history as temp files for cleanup
         context.add_temp_file(video_path);
         context.add_temp_file(manifest_path);
+        if let Some(key_history_path) = key_history_path {
+            context.add_temp_file(key_history_path);
+        }
 
         Ok(())
     }
@@ -1,5 +1,9 @@
 use crate::error::NotariResult;
-use crate::evidence::{keychain, EvidenceManifest, KeyManager};
+use crate::evidence::key_history::KEY_HISTORY_FILE;
+use crate::evidence::x509::Certificate;
+use crate::evidence::{
+    keychain, signer_certificate, EvidenceManifest, KeyHistory, KeyManager, SignerIdentity,
+};
 use crate::logger::{LogLevel, LOGGER};
 use crate::pipeline::{PipelineContext, PipelineStage};
 use std::time::Duration;
@@ -7,6 +11,18 @@ use std::time::Duration;
 /// Stage that signs the evidence manifest with Ed25519
 ///
 /// This stage generates a signing key if one doesn't exist, then signs the manifest.
+/// A stage created with [`SignStage::with_key`] signs with that key instead and
+/// never touches the keychain.
+///
+/// If the signing key was rotated in, the rotations leading to it are saved next
+/// to the manifest for the proof pack. The keychain key's history is read from
+/// [`KeyHistory::default_path`]; a stage with its own key uses the history given
+/// to [`SignStage::key_history`], if any.
+///
+/// If the signing key is certified by an X.509 certificate, the chain is embedded
+/// in the signature. The keychain key's chain is read from
+/// [`signer_certificate::default_path`]; a stage with its own key uses the chain
+/// given to [`SignStage::certificate_chain`], if any.
 ///
 /// # Context Requirements
 /// - Input: `manifest` (EvidenceManifest as JSON) - Manifest to sign
@@ -15,34 +31,104 @@ use std::time::Duration;
 /// # Context Outputs
 /// - Updates `manifest` with signature
 /// - Saves signed manifest to `manifest_path`
+/// - `key_history_path` (PathBuf) - Rotations leading to the signing key, if any
 ///
-pub struct SignStage;
+pub struct SignStage {
+    key: Option<KeyManager>,
+    key_history: Option<KeyHistory>,
+    certificate_chain: Option<Vec<Certificate>>,
+}
 
 impl SignStage {
-    /// Create a new sign stage
+    /// Create a new sign stage using the keychain signing key
     pub fn new() -> Self {
-        Self
+        Self {
+            key: None,
+            key_history: None,
+            certificate_chain: None,
+        }
     }
-}
 
-impl Default for SignStage {
-    fn default() -> Self {
-        Self::new()
+    /// Create a sign stage that signs with `key`
+    pub fn with_key(key: KeyManager) -> Self {
+        Self {
+            key: Some(key),
+            key_history: None,
+            certificate_chain: None,
+        }
     }
-}
 
-impl PipelineStage for SignStage {
-    fn execute(&self, context: &mut PipelineContext) -> NotariResult<()> {
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "Signing evidence manifest (session: {})",
-                context.session_id()
-            ),
-            "pipeline::sign",
-        );
+    /// Use `history` instead of the keychain key's history
+    pub fn key_history(mut self, history: KeyHistory) -> Self {
+        self.key_history = Some(history);
+        self
+    }
 
-        // Check if signing key exists, generate if not
+    /// Use `chain` (leaf first) instead of the keychain key's certificate chain
+    pub fn certificate_chain(mut self, chain: Vec<Certificate>) -> Self {
+        self.certificate_chain = Some(chain);
+        self
+    }
+
+    /// X.509 identity certifying `key`, or `None` if it has no certificate
+    ///
+    /// A chain that doesn't certify the key, e.g. one left from before a key
+    /// rotation, is logged and ignored.
+    fn signer_identity(&self, key: &KeyManager) -> Option<SignerIdentity> {
+        let chain = match (&self.certificate_chain, &self.key) {
+            (Some(chain), _) => Ok(chain.clone()),
+            (None, Some(_)) => return None,
+            (None, None) => signer_certificate::default_path()
+                .and_then(|path| signer_certificate::load_chain(&path))
+                .map(|chain| chain.unwrap_or_default()),
+        };
+        let identity = chain.and_then(|chain| {
+            if chain.is_empty() {
+                Ok(None)
+            } else {
+                signer_certificate::identity(&chain, key).map(Some)
+            }
+        });
+
+        identity.unwrap_or_else(|e| {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Signing without certificate: {}", e),
+                "pipeline::sign",
+            );
+            None
+        })
+    }
+
+    /// Rotations leading to `public_key`, or `None` if it is an identity key or
+    /// isn't in the history
+    fn rotations_to(&self, public_key: &str) -> Option<KeyHistory> {
+        let history = match (&self.key_history, &self.key) {
+            (Some(history), _) => history.clone(),
+            (None, Some(_)) => return None,
+            (None, None) => {
+                let loaded = KeyHistory::default_path()
+                    .and_then(|path| path.exists().then(|| KeyHistory::load(&path)).transpose());
+                match loaded {
+                    Ok(history) => history?,
+                    Err(e) => {
+                        LOGGER.log(
+                            LogLevel::Warn,
+                            &format!("Failed to load key history: {}", e),
+                            "pipeline::sign",
+                        );
+                        return None;
+                    }
+                }
+            }
+        };
+        history
+            .chain_to(public_key)
+            .filter(|chain| !chain.rotations.is_empty())
+    }
+
+    /// Load the keychain signing key, generating one on first use
+    pub fn keychain_key() -> NotariResult<KeyManager> {
         if !keychain::has_signing_key() {
             LOGGER.log(
                 LogLevel::Info,
@@ -64,9 +150,36 @@ impl PipelineStage for SignStage {
             );
         }
 
-        // Load signing key
         let key_bytes = keychain::retrieve_signing_key()?;
-        let key_manager = KeyManager::from_bytes(&key_bytes)?;
+        KeyManager::from_bytes(&key_bytes)
+    }
+}
+
+impl Default for SignStage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PipelineStage for SignStage {
+    fn execute(&self, context: &mut PipelineContext) -> NotariResult<()> {
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Signing evidence manifest (session: {})",
+                context.session_id()
+            ),
+            "pipeline::sign",
+        );
+
+        let keychain_key;
+        let key_manager = match self.key {
+            Some(ref key) => key,
+            None => {
+                keychain_key = Self::keychain_key()?;
+                &keychain_key
+            }
+        };
 
         // Log public key for verification
         use base64::{engine::general_purpose, Engine as _};
@@ -91,8 +204,9 @@ impl PipelineStage for SignStage {
                 ))
             })?;
 
-        // Sign manifest
-        manifest.sign(&key_manager);
+        // Sign manifest, with the certificate chain naming the signer if there is one
+        manifest.signature.identity = self.signer_identity(key_manager);
+        manifest.sign(key_manager);
 
         LOGGER.log(
             LogLevel::Info,
@@ -107,6 +221,26 @@ impl PipelineStage for SignStage {
         let manifest_path = context.get_path("manifest_path")?;
         manifest.save(&manifest_path)?;
 
+        // Save the rotations linking the signing key to its identity key
+        if let Some(chain) = self.rotations_to(&public_key_b64) {
+            let key_history_path = manifest_path.with_file_name(format!(
+                "{}.{}",
+                context.session_id(),
+                KEY_HISTORY_FILE
+            ));
+            chain.save(&key_history_path)?;
+            LOGGER.log(
+                LogLevel::Info,
+                &format!(
+                    "Signing key is {} rotation(s) from identity key {}...",
+                    chain.rotations.len(),
+                    &chain.identity_key[..16.min(chain.identity_key.len())]
+                ),
+                "pipeline::sign",
+            );
+            context.set_path("key_history_path", key_history_path);
+        }
+
         LOGGER.log(
             LogLevel::Info,
             &format!(
@@ -266,6 +400,117 @@ mod tests {
         let _ = crate::evidence::keychain::delete_signing_key();
     }
 
+    #[test]
+    fn test_sign_stage_with_key() {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.json");
+
+        let mut context = PipelineContext::new("session-123");
+        context.set(
+            "manifest",
+            serde_json::to_value(create_test_manifest()).unwrap(),
+        );
+        context.set_path("manifest_path", manifest_path.clone());
+
+        let key_manager = KeyManager::generate();
+        let public_key = general_purpose::STANDARD.encode(key_manager.public_key().as_bytes());
+        let stage = SignStage::with_key(key_manager);
+        stage.execute(&mut context).unwrap();
+        stage.post_execute(&context).unwrap();
+
+        let signed_manifest = EvidenceManifest::load(&manifest_path).unwrap();
+        assert_eq!(signed_manifest.signature.public_key, public_key);
+    }
+
+    #[test]
+    fn test_sign_stage_saves_key_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.json");
+
+        let identity = KeyManager::generate();
+        let rotated = KeyManager::generate();
+        let mut history = KeyHistory::new(&identity);
+        history.rotate(&identity, &rotated, None).unwrap();
+        history
+            .rotate(&rotated, &KeyManager::generate(), None)
+            .unwrap();
+
+        let mut context = PipelineContext::new("session-123");
+        context.set(
+            "manifest",
+            serde_json::to_value(create_test_manifest()).unwrap(),
+        );
+        context.set_path("manifest_path", manifest_path);
+
+        // Only the rotation leading to the signing key is kept
+        let stage = SignStage::with_key(rotated).key_history(history.clone());
+        stage.execute(&mut context).unwrap();
+        let saved = KeyHistory::load(&context.get_path("key_history_path").unwrap()).unwrap();
+        assert_eq!(saved.identity_key, history.identity_key);
+        assert_eq!(saved.rotations.len(), 1);
+        assert_eq!(saved.verify(), Ok(()));
+
+        let mut context = PipelineContext::new("session-456");
+        context.set(
+            "manifest",
+            serde_json::to_value(create_test_manifest()).unwrap(),
+        );
+        context.set_path("manifest_path", temp_dir.path().join("identity.json"));
+        SignStage::with_key(identity)
+            .key_history(history)
+            .execute(&mut context)
+            .unwrap();
+        assert!(!context.has("key_history_path"));
+    }
+
+    #[test]
+    fn test_sign_stage_embeds_certificate_chain() {
+        use crate::evidence::x509::testing::{
+            ed25519_spki, issue, issue_for_spki, CertificateParams, TestKey,
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let key_manager = KeyManager::generate();
+        let ca_key = TestKey::generate();
+        let mut ca_params = CertificateParams::new("Example Corp CA");
+        ca_params.is_ca = true;
+        let ca = Certificate::from_der(&issue(&ca_params, &ca_key, None)).unwrap();
+        let leaf = Certificate::from_der(&issue_for_spki(
+            &CertificateParams::signer("Alice"),
+            &ed25519_spki(key_manager.public_key().as_bytes()),
+            ("Example Corp CA", &ca_key),
+        ))
+        .unwrap();
+
+        let sign = |key: KeyManager, name: &str| {
+            let mut context = PipelineContext::new("session-123");
+            context.set(
+                "manifest",
+                serde_json::to_value(create_test_manifest()).unwrap(),
+            );
+            context.set_path("manifest_path", temp_dir.path().join(name));
+            SignStage::with_key(key)
+                .certificate_chain(vec![leaf.clone(), ca.clone()])
+                .execute(&mut context)
+                .unwrap();
+            EvidenceManifest::load(&context.get_path("manifest_path").unwrap()).unwrap()
+        };
+
+        let manifest = sign(key_manager, "certified.json");
+        assert!(manifest.verify_signature().unwrap());
+        let Some(SignerIdentity::X509 { certificates }) = manifest.signature.identity else {
+            panic!("Manifest signature should carry the certificate chain");
+        };
+        assert_eq!(certificates.len(), 2);
+
+        // A chain for another key is left out
+        let manifest = sign(KeyManager::generate(), "uncertified.json");
+        assert!(manifest.verify_signature().unwrap());
+        assert!(manifest.signature.identity.is_none());
+    }
+
     #[test]
     fn test_sign_stage_missing_manifest() {
         let mut context = PipelineContext::new("session-123");
@@ -1,5 +1,4 @@
 use crate::events::EventEmitter;
-use crate::evidence::proof_pack::ProofPackMetadata;
 use crate::logger::{LogEntry, LogLevel, LOGGER};
 use crate::recording_manager::{
     create_recording_manager, ActiveRecordingWithStatus, RecordingInfo, RecordingManager,
@@ -157,6 +156,7 @@ pub async fn start_window_recording(
     recording_description: Option<String>,
     recording_tags: Option<Vec<String>>,
     state: State<'_, WindowManagerState>,
+    blockchain_state: State<'_, crate::blockchain_commands::BlockchainState>,
     app: AppHandle,
 ) -> Result<ActiveRecordingWithStatus, String> {
     let method = encryption_method.as_deref().unwrap_or("password");
@@ -184,15 +184,32 @@ pub async fn start_window_recording(
         if encryption_recipients.is_none() || encryption_recipients.as_ref().unwrap().is_empty() {
             return Err("At least one recipient is required for public key encryption".to_string());
         }
+    } else if method == "hybrid" {
+        if encryption_recipients.is_none() || encryption_recipients.as_ref().unwrap().is_empty() {
+            return Err("At least one recipient is required for hybrid encryption".to_string());
+        }
+        match encryption_password {
+            Some(ref pwd) => crate::evidence::validate_password(pwd)?,
+            None => return Err("A password is required for hybrid encryption".to_string()),
+        }
     }
 
-    // Get window information before starting recording
+    // Get window information before starting recording. It only feeds the manifest
+    // metadata, so a failed lookup (e.g. headless CI with the synthetic recorder)
+    // is left for the recording manager to judge
     let window_info = {
         let manager = state.manager.lock().map_err(|e| e.to_string())?;
-        manager
-            .get_windows()?
-            .into_iter()
-            .find(|w| w.id == window_id)
+        match manager.get_windows() {
+            Ok(windows) => windows.into_iter().find(|w| w.id == window_id),
+            Err(e) => {
+                LOGGER.log(
+                    LogLevel::Warn,
+                    &format!("Failed to look up window {}: {}", window_id, e),
+                    "recording_commands",
+                );
+                None
+            }
+        }
     };
 
     let mut session = state.recording_manager.start_recording(
@@ -236,6 +253,18 @@ pub async fn start_window_recording(
         }
     }
 
+    // Journal the session for crash recovery now that its settings are final
+    if let Err(e) = crate::recording_manager::lifecycle::open_journal(
+        &session.session_id,
+        state.recording_state.clone(),
+    ) {
+        LOGGER.log(
+            LogLevel::Warn,
+            &format!("Failed to open recording journal: {}", e),
+            "recording_commands",
+        );
+    }
+
     LOGGER.log(
         LogLevel::Info,
         &format!("Recording started successfully: {}", session.session_id),
@@ -243,42 +272,57 @@ pub async fn start_window_recording(
     );
 
     // Transition state machine: Idle → Preparing → Recording
-    #[cfg(target_os = "macos")]
     {
-        use crate::recording_manager::macos::MacOSRecordingManager;
-        if let Some(manager) = state
-            .recording_manager
-            .as_any()
-            .downcast_ref::<MacOSRecordingManager>()
-        {
-            // Transition to Preparing
-            if let Err(e) = manager.transition_to_preparing(
-                &session.session_id,
-                state.recording_state.clone(),
-                &app,
-            ) {
-                LOGGER.log(
-                    LogLevel::Warn,
-                    &format!("Failed to transition to Preparing: {}", e),
-                    "recording_commands",
-                );
-            }
+        use crate::recording_manager::lifecycle;
+
+        // Transition to Preparing
+        if let Err(e) = lifecycle::transition_to_preparing(
+            &session.session_id,
+            state.recording_state.clone(),
+            &app,
+        ) {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to transition to Preparing: {}", e),
+                "recording_commands",
+            );
+        }
 
-            // Transition to Recording
-            if let Err(e) = manager.transition_to_recording(
-                &session.session_id,
-                state.recording_state.clone(),
-                &app,
-            ) {
-                LOGGER.log(
-                    LogLevel::Warn,
-                    &format!("Failed to transition to Recording: {}", e),
-                    "recording_commands",
-                );
-            }
+        // Transition to Recording
+        if let Err(e) = lifecycle::transition_to_recording(
+            &session.session_id,
+            state.recording_state.clone(),
+            &app,
+        ) {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to transition to Recording: {}", e),
+                "recording_commands",
+            );
         }
     }
 
+    // Sign checkpoints while recording, with the key that will sign the manifest
+    let signing_key = match state.recording_manager.signing_key() {
+        Some(key) => Ok(key),
+        None => crate::pipeline::stages::SignStage::keychain_key(),
+    };
+    if let Err(e) = signing_key.and_then(|key| {
+        crate::recording_manager::checkpoints::start(
+            &session.session_id,
+            state.recording_state.clone(),
+            key,
+            crate::evidence::checkpoint::CHECKPOINT_INTERVAL,
+            checkpoint_timestamper(&blockchain_state),
+        )
+    }) {
+        LOGGER.log(
+            LogLevel::Warn,
+            &format!("Failed to start recording checkpoints: {}", e),
+            "recording_commands",
+        );
+    }
+
     // Get status from state machine
     let status = {
         let state_guard = state.recording_state.lock().map_err(|e| e.to_string())?;
@@ -292,6 +336,17 @@ pub async fn start_window_recording(
     Ok(session.with_status(status))
 }
 
+/// Time-stamp authority for recording checkpoints, when anchoring goes to one
+fn checkpoint_timestamper(
+    blockchain_state: &crate::blockchain_commands::BlockchainState,
+) -> Option<crate::evidence::TsaAnchorer> {
+    let config = blockchain_state.config.lock().ok()?.clone()?;
+    if !config.enabled || config.environment != crate::evidence::BlockchainEnvironment::Rfc3161 {
+        return None;
+    }
+    crate::evidence::TsaAnchorer::new(&config.tsa).ok()
+}
+
 /// Stop recording session
 #[tauri::command]
 pub async fn stop_recording(
@@ -309,23 +364,16 @@ pub async fn stop_recording(
     let _session_uuid = uuid::Uuid::parse_str(&session_id).map_err(|e| e.to_string())?;
 
     // Transition state machine: Recording → Stopping
-    #[cfg(target_os = "macos")]
     {
-        use crate::recording_manager::macos::MacOSRecordingManager;
-        if let Some(manager) = state
-            .recording_manager
-            .as_any()
-            .downcast_ref::<MacOSRecordingManager>()
+        use crate::recording_manager::lifecycle;
+        if let Err(e) =
+            lifecycle::transition_to_stopping(&session_id, state.recording_state.clone(), &app)
         {
-            if let Err(e) =
-                manager.transition_to_stopping(&session_id, state.recording_state.clone(), &app)
-            {
-                LOGGER.log(
-                    LogLevel::Warn,
-                    &format!("Failed to transition to Stopping: {}", e),
-                    "recording_commands",
-                );
-            }
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to transition to Stopping: {}", e),
+                "recording_commands",
+            );
         }
     }
 
@@ -340,39 +388,51 @@ pub async fn stop_recording(
         "recording_commands",
     );
 
+    // Join the recorded segments into the output file
+    if let Err(e) = crate::recording_manager::lifecycle::finish_segments(
+        state.recording_manager.as_ref(),
+        &session_id,
+        state.recording_state.clone(),
+    ) {
+        LOGGER.log(
+            LogLevel::Error,
+            &format!("Failed to join recording segments: {}", e),
+            "recording_commands",
+        );
+    }
+
     // Process the recording using the pipeline (hashing, encryption, manifest, packaging)
     // This is done in a separate step so the frontend can show progress
-    #[cfg(target_os = "macos")]
     {
-        use crate::recording_manager::macos::MacOSRecordingManager;
-        if let Some(manager) = state
-            .recording_manager
-            .as_any()
-            .downcast_ref::<MacOSRecordingManager>()
-        {
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("Starting post-processing for recording: {}", session_id),
-                "recording_commands",
-            );
+        use crate::recording_manager::lifecycle;
 
-            match manager.process_recording(&session_id, state.recording_state.clone(), &app) {
-                Ok(_) => {
-                    LOGGER.log(
-                        LogLevel::Info,
-                        &format!("Post-processing completed successfully: {}", session_id),
-                        "recording_commands",
-                    );
-                }
-                Err(e) => {
-                    LOGGER.log(
-                        LogLevel::Error,
-                        &format!("Post-processing failed: {}", e),
-                        "recording_commands",
-                    );
-                    // Error is already logged and state machine transitioned to Failed
-                    // in process_recording(), so we just log here for command context
-                }
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Starting post-processing for recording: {}", session_id),
+            "recording_commands",
+        );
+
+        match lifecycle::process_recording(
+            &session_id,
+            state.recording_state.clone(),
+            state.recording_manager.signing_key(),
+            &app,
+        ) {
+            Ok(_) => {
+                LOGGER.log(
+                    LogLevel::Info,
+                    &format!("Post-processing completed successfully: {}", session_id),
+                    "recording_commands",
+                );
+            }
+            Err(e) => {
+                LOGGER.log(
+                    LogLevel::Error,
+                    &format!("Post-processing failed: {}", e),
+                    "recording_commands",
+                );
+                // Error is already logged and state machine transitioned to Failed
+                // in process_recording(), so we just log here for command context
             }
         }
     }
@@ -560,7 +620,10 @@ pub async fn select_save_directory(
 pub async fn check_recording_health(state: State<'_, WindowManagerState>) -> Result<(), String> {
     state
         .recording_manager
-        .check_recording_health(state.recording_state.clone())
+        .check_recording_health(state.recording_state.clone())?;
+
+    // Keep the crash recovery journal current
+    crate::recording_manager::lifecycle::sync_journal(state.recording_state.clone())
         .map_err(Into::into)
 }
 
@@ -580,6 +643,35 @@ pub async fn cleanup_orphaned_recordings(
         .map_err(Into::into)
 }
 
+/// Finalize recordings interrupted by a crash into proof packs
+///
+/// Scans the default and configured save directories for session journals and
+/// returns the paths of the proof packs created.
+#[tauri::command]
+pub async fn recover_orphaned_recordings(
+    state: State<'_, WindowManagerState>,
+) -> Result<Vec<String>, String> {
+    let (dirs, active_session_id) = {
+        let state_guard = state.recording_state.lock().map_err(|e| e.to_string())?;
+        let mut dirs = vec![state.recording_manager.get_default_save_directory()?];
+        dirs.extend(state_guard.preferences.save_directory.clone());
+        let active_session_id = state_guard
+            .get_active_session()
+            .map(|session| session.session_id);
+        (dirs, active_session_id)
+    };
+
+    let proof_packs = crate::recording_manager::recovery::recover_orphaned_recordings(
+        state.recording_manager.as_ref(),
+        &dirs,
+        active_session_id.as_deref(),
+    );
+    Ok(proof_packs
+        .into_iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect())
+}
+
 /// Pause recording session
 #[tauri::command]
 pub async fn pause_recording(
@@ -593,16 +685,24 @@ pub async fn pause_recording(
         "recording_commands",
     );
 
-    // Parse session_id to UUID for event emission
-    let session_uuid = uuid::Uuid::parse_str(&session_id).map_err(|e| e.to_string())?;
-
     state
         .recording_manager
         .pause_recording(&session_id, state.recording_state.clone())
         .map_err(|e: crate::error::NotariError| e.to_string())?;
 
-    // Emit recording state changed event
-    let _ = EventEmitter::recording_state_changed(&app, session_uuid, "Paused");
+    // Transition state machine (emits the state change event)
+    {
+        use crate::recording_manager::lifecycle;
+        if let Err(e) =
+            lifecycle::transition_to_paused(&session_id, state.recording_state.clone(), &app)
+        {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to transition to Paused: {}", e),
+                "recording_commands",
+            );
+        }
+    }
 
     Ok(())
 }
@@ -620,16 +720,24 @@ pub async fn resume_recording(
         "recording_commands",
     );
 
-    // Parse session_id to UUID for event emission
-    let session_uuid = uuid::Uuid::parse_str(&session_id).map_err(|e| e.to_string())?;
-
     state
         .recording_manager
         .resume_recording(&session_id, state.recording_state.clone())
         .map_err(|e: crate::error::NotariError| e.to_string())?;
 
-    // Emit recording state changed event
-    let _ = EventEmitter::recording_state_changed(&app, session_uuid, "Recording");
+    // Transition state machine (emits the state change event)
+    {
+        use crate::recording_manager::lifecycle;
+        if let Err(e) =
+            lifecycle::transition_to_resumed(&session_id, state.recording_state.clone(), &app)
+        {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to transition to Recording: {}", e),
+                "recording_commands",
+            );
+        }
+    }
 
     Ok(())
 }
@@ -648,9 +756,8 @@ pub async fn emit_recording_progress_event(
             let session_uuid =
                 uuid::Uuid::parse_str(&active.session.session_id).map_err(|e| e.to_string())?;
 
-            // Calculate duration
-            let start_time = active.session.start_time;
-            let duration_seconds = (chrono::Utc::now() - start_time).num_seconds() as u64;
+            // Calculate duration (excluding pauses)
+            let duration_seconds = active.recorded_duration().num_seconds().max(0) as u64;
 
             // Get file size
             let file_size_bytes = if active.session.output_path.exists() {
@@ -765,6 +872,9 @@ pub async fn shutdown_recording_system(state: State<'_, WindowManagerState>) ->
         let _ = state
             .recording_manager
             .stop_recording(&id, state.recording_state.clone());
+
+        // Leave the session journaled for recovery on the next start
+        let _ = crate::recording_manager::lifecycle::close_journal(state.recording_state.clone());
     }
 
     // TODO: Persist recording preferences to Tauri store
@@ -870,76 +980,77 @@ pub async fn verify_recording(
     manifest_path: String,
     video_path: String,
 ) -> Result<crate::evidence::VerificationReport, String> {
-    // Extract from .notari file
-    let (resolved_video, resolved_manifest, temp_dir) =
-        resolve_recording_paths(&video_path, &manifest_path)?;
-
-    let result = crate::evidence::Verifier::verify(&resolved_manifest, &resolved_video)
-        .map_err(|e| format!("Verification failed: {}", e));
-
-    // Cleanup temp files
-    let _ = std::fs::remove_dir_all(&temp_dir);
-
-    result
+    // `video_path` is the .notari file; the manifest is read from inside it
+    let _ = manifest_path;
+
+    crate::evidence::Verifier::verify_proof_pack(&video_path)
+        .and_then(|mut report| {
+            let trust_anchors = load_trust_anchors();
+            crate::evidence::Verifier::check_certificate(&video_path, &mut report, &trust_anchors)?;
+            crate::evidence::Verifier::check_timestamp_authorities(
+                &video_path,
+                &mut report,
+                &trust_anchors,
+            )?;
+            crate::evidence::Verifier::check_signer(&mut report, &load_trusted_signers());
+            Ok(report)
+        })
+        .map_err(|e| format!("Verification failed: {}", e))
 }
 
 /// Deep verification with on-chain blockchain verification
+///
+/// `block_header` is the hex of the 80-byte Bitcoin block header attested by an
+/// OpenTimestamps anchor; without it such anchors are reported as complete with
+/// their attestation unchecked.
 #[tauri::command]
 pub async fn verify_recording_deep(
     state: tauri::State<'_, crate::blockchain_commands::BlockchainState>,
     manifest_path: String,
     video_path: String,
+    block_header: Option<String>,
 ) -> Result<crate::evidence::VerificationReport, String> {
-    use crate::evidence::blockchain::{
-        BlockchainAnchorer, BlockchainEnvironment, EthereumAnchorer, MockAnchorer, WalletManager,
-    };
+    use crate::evidence::blockchain::BlockchainAnchorerFactory;
+    use crate::error::NotariError;
+
+    let block_header = block_header
+        .map(|header| parse_block_header(&header))
+        .transpose()?;
 
     // Extract from .notari file
     let (resolved_video, resolved_manifest, temp_dir) =
         resolve_recording_paths(&video_path, &manifest_path)?;
 
-    // Get blockchain config
-    let (environment, chain_config, wallet_config) = {
+    // Get blockchain config; anchors on other chains or services are verified with
+    // their own endpoints, so an unconfigured app can still verify most of them
+    let config = {
         let config_lock = state.config.lock().map_err(|e| e.to_string())?;
-        let config = config_lock.as_ref().ok_or("Blockchain not configured")?;
-
-        (
-            config.environment.clone(),
-            config.chain.clone(),
-            config.wallet.clone(),
-        )
-    };
-
-    // Create anchorer for verification
-    let anchorer: Box<dyn BlockchainAnchorer> = match environment {
-        BlockchainEnvironment::Mock => Box::new(MockAnchorer::new()),
-        _ => {
-            let wallet = wallet_config.ok_or("No wallet configured for deep verification")?;
-            let private_key =
-                WalletManager::get_private_key(chain_config.chain_id, &wallet.address)
-                    .map_err(|e| e.to_string())?;
-
-            Box::new(
-                EthereumAnchorer::new(
-                    &chain_config.rpc_url,
-                    &private_key,
-                    &chain_config.contract_address,
-                    chain_config.chain_id,
-                    &chain_config.name,
-                    &chain_config.explorer_url,
-                )
-                .map_err(|e| e.to_string())?,
-            )
-        }
+        config_lock.clone().unwrap_or_default()
     };
 
-    // Perform deep verification
+    // Perform deep verification, with a verifier for each anchor
     let result = crate::evidence::Verifier::verify_deep(
         &resolved_manifest,
         &resolved_video,
-        anchorer.as_ref(),
+        block_header.as_deref(),
+        |proof| {
+            BlockchainAnchorerFactory::create_verifier(proof, &config)
+                .map_err(|e| NotariError::BlockchainNotConfigured(e.to_string()))
+        },
     )
     .await
+    .and_then(|mut report| {
+        crate::evidence::Verifier::check_proof_pack(&video_path, &mut report)?;
+        let trust_anchors = load_trust_anchors();
+        crate::evidence::Verifier::check_certificate(&video_path, &mut report, &trust_anchors)?;
+        crate::evidence::Verifier::check_timestamp_authorities(
+            &video_path,
+            &mut report,
+            &trust_anchors,
+        )?;
+        crate::evidence::Verifier::check_signer(&mut report, &load_trusted_signers());
+        Ok(report)
+    })
     .map_err(|e| format!("Deep verification failed: {}", e));
 
     // Cleanup temp files
@@ -948,6 +1059,31 @@ pub async fn verify_recording_deep(
     result
 }
 
+/// Decode a raw Bitcoin block header, hex as printed by `bitcoin-cli getblockheader <hash> false`
+fn parse_block_header(header: &str) -> Result<Vec<u8>, String> {
+    let bytes =
+        hex::decode(header.trim()).map_err(|e| format!("Invalid block header hex: {}", e))?;
+    if bytes.len() != 80 {
+        return Err(format!("Block header must be 80 bytes, got {}", bytes.len()));
+    }
+    Ok(bytes)
+}
+
+/// Check that `excerpt_path` is an unaltered, chunk-aligned part of the video in a
+/// .notari proof pack
+#[tauri::command]
+pub async fn verify_excerpt(
+    proof_pack_path: String,
+    excerpt_path: String,
+) -> Result<crate::evidence::ExcerptReport, String> {
+    crate::evidence::Verifier::verify_excerpt_in_proof_pack(&proof_pack_path, &excerpt_path)
+        .map(|mut report| {
+            crate::evidence::Verifier::check_excerpt_signer(&mut report, &load_trusted_signers());
+            report
+        })
+        .map_err(|e| format!("Excerpt verification failed: {}", e))
+}
+
 /// Get evidence manifest for a recording (extracts from .notari if needed)
 #[tauri::command]
 pub async fn get_evidence_manifest(
@@ -1008,11 +1144,37 @@ pub async fn generate_signing_key() -> Result<String, String> {
     Ok(general_purpose::STANDARD.encode(public_key.as_bytes()))
 }
 
+/// Rotate the signing key, keeping recordings signed with the old one linked to it
+///
+/// The new key is added to the key history with a statement signed by the old
+/// one, and proof packs signed with it carry the chain back to the identity key.
+#[tauri::command]
+pub async fn rotate_signing_key(reason: Option<String>) -> Result<String, String> {
+    use crate::evidence::{key_history, KeyHistory};
+
+    let path = KeyHistory::default_path().map_err(|e| e.to_string())?;
+    let rotation = key_history::rotate_signing_key(&path, reason).map_err(|e| e.to_string())?;
+    Ok(rotation.public_key)
+}
+
+/// Get the signing key's rotation history, if it has one
+#[tauri::command]
+pub async fn get_key_history() -> Result<Option<crate::evidence::KeyHistory>, String> {
+    use crate::evidence::KeyHistory;
+
+    let path = KeyHistory::default_path().map_err(|e| e.to_string())?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    KeyHistory::load(&path).map(Some).map_err(|e| e.to_string())
+}
+
 /// Get diagnostic info about signing key
 #[tauri::command]
 pub async fn get_signing_key_info() -> Result<serde_json::Value, String> {
     use crate::evidence::keychain;
     use crate::evidence::signature::KeyManager;
+    use crate::evidence::KeyHistory;
     use base64::{engine::general_purpose, Engine as _};
 
     let has_key = keychain::has_signing_key();
@@ -1028,10 +1190,22 @@ pub async fn get_signing_key_info() -> Result<serde_json::Value, String> {
     match keychain::retrieve_signing_key() {
         Ok(key_bytes) => match KeyManager::from_bytes(&key_bytes) {
             Ok(key_manager) => {
-                let public_key = key_manager.public_key();
+                let public_key =
+                    general_purpose::STANDARD.encode(key_manager.public_key().as_bytes());
+                // Only a history ending at this key describes it
+                let history = KeyHistory::default_path()
+                    .and_then(|path| KeyHistory::load(&path))
+                    .ok()
+                    .filter(|history| history.current_key() == public_key);
                 Ok(serde_json::json!({
                     "has_key": true,
-                    "public_key": general_purpose::STANDARD.encode(public_key.as_bytes()),
+                    "public_key": public_key,
+                    "identity_key": history.as_ref().map(|h| h.identity_key.clone()),
+                    "rotations": history.as_ref().map_or(0, |h| h.rotations.len()),
+                    "key_since": history.as_ref().map(|h| h.current_key_since()),
+                    "rotation_due": history
+                        .as_ref()
+                        .is_some_and(|h| h.rotation_due(chrono::Utc::now())),
                     "error": null
                 }))
             }
@@ -1049,6 +1223,218 @@ pub async fn get_signing_key_info() -> Result<serde_json::Value, String> {
     }
 }
 
+// ============================================================================
+// Trusted Signer Commands
+// ============================================================================
+
+/// Load the trusted signers for verification
+///
+/// A store that can't be read is logged and treated as empty, so recordings
+/// still verify, with their signers reported as unknown.
+fn load_trusted_signers() -> Vec<crate::evidence::TrustedSigner> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .load_signers()
+        .unwrap_or_else(|e| {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to load trusted signers: {}", e),
+                "recording_commands",
+            );
+            Vec::new()
+        })
+}
+
+/// List the trusted signers
+#[tauri::command]
+pub async fn list_trusted_signers() -> Result<Vec<crate::evidence::TrustedSigner>, String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .load_signers()
+        .map_err(|e| e.to_string())
+}
+
+/// Trust a public key under a name, replacing any signer with the same key
+#[tauri::command]
+pub async fn add_trusted_signer(
+    name: String,
+    public_key: String,
+    note: Option<String>,
+) -> Result<crate::evidence::TrustedSigner, String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let signer =
+        crate::evidence::TrustedSigner::new(&name, &public_key, note).map_err(|e| e.to_string())?;
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .save_signer(&signer)
+        .map_err(|e| e.to_string())?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Trusted signer added: {}", signer.name),
+        "recording_commands",
+    );
+    Ok(signer)
+}
+
+/// Stop trusting a public key
+#[tauri::command]
+pub async fn remove_trusted_signer(public_key: String) -> Result<(), String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .delete_signer(&public_key)
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Signer Certificate Commands (X.509)
+// ============================================================================
+
+/// Load the CA certificates trusted for verification
+///
+/// Like the trusted signers, a store that can't be read is logged and treated
+/// as empty.
+fn load_trust_anchors() -> Vec<crate::evidence::x509::Certificate> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .load_trust_anchors()
+        .unwrap_or_else(|e| {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to load trusted CA certificates: {}", e),
+                "recording_commands",
+            );
+            Vec::new()
+        })
+}
+
+/// Read the certificates in a PEM or DER file
+fn read_certificates(path: &str) -> Result<Vec<crate::evidence::x509::Certificate>, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let certificates = crate::evidence::signer_certificate::parse_certificates(&data)
+        .map_err(|e| e.to_string())?;
+    if certificates.is_empty() {
+        return Err(format!("No certificates found in {}", path));
+    }
+    Ok(certificates)
+}
+
+/// List the trusted CA certificates
+#[tauri::command]
+pub async fn list_trust_anchors(
+) -> Result<Vec<crate::evidence::signer_certificate::CertificateSummary>, String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    let anchors = repo_manager
+        .trusted_signers()
+        .load_trust_anchors()
+        .map_err(|e| e.to_string())?;
+    Ok(anchors.iter().map(Into::into).collect())
+}
+
+/// Trust the CA certificates in a PEM or DER file
+#[tauri::command]
+pub async fn add_trust_anchor(
+    path: String,
+) -> Result<Vec<crate::evidence::signer_certificate::CertificateSummary>, String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let certificates = read_certificates(&path)?;
+    if let Some(certificate) = certificates.iter().find(|certificate| !certificate.is_ca) {
+        return Err(format!(
+            "{} isn't a CA certificate",
+            certificate.subject_name()
+        ));
+    }
+
+    let repo_manager = crate::repository::get_repository_manager();
+    for certificate in &certificates {
+        repo_manager
+            .trusted_signers()
+            .save_trust_anchor(certificate)
+            .map_err(|e| e.to_string())?;
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Trusted CA added: {}", certificate.subject_name()),
+            "recording_commands",
+        );
+    }
+    Ok(certificates.iter().map(Into::into).collect())
+}
+
+/// Stop trusting a CA certificate
+#[tauri::command]
+pub async fn remove_trust_anchor(fingerprint: String) -> Result<(), String> {
+    use crate::repository::TrustedSignerRepository;
+
+    let repo_manager = crate::repository::get_repository_manager();
+    repo_manager
+        .trusted_signers()
+        .delete_trust_anchor(&fingerprint)
+        .map_err(|e| e.to_string())
+}
+
+/// Get the certificate chain recordings are signed with, leaf first
+#[tauri::command]
+pub async fn get_signer_certificate(
+) -> Result<Option<Vec<crate::evidence::signer_certificate::CertificateSummary>>, String> {
+    use crate::evidence::signer_certificate;
+
+    let path = signer_certificate::default_path().map_err(|e| e.to_string())?;
+    let chain = signer_certificate::load_chain(&path).map_err(|e| e.to_string())?;
+    Ok(chain.map(|chain| chain.iter().map(Into::into).collect()))
+}
+
+/// Sign recordings with the certificate chain in a PEM or DER file, leaf first
+///
+/// The leaf has to certify the signing key in the keychain.
+#[tauri::command]
+pub async fn set_signer_certificate(
+    path: String,
+) -> Result<Vec<crate::evidence::signer_certificate::CertificateSummary>, String> {
+    use crate::evidence::keychain;
+    use crate::evidence::signature::KeyManager;
+    use crate::evidence::signer_certificate;
+
+    let chain = read_certificates(&path)?;
+    let key_bytes = keychain::retrieve_signing_key().map_err(|e| e.to_string())?;
+    let key_manager = KeyManager::from_bytes(&key_bytes).map_err(|e| e.to_string())?;
+    let chain_path = signer_certificate::default_path().map_err(|e| e.to_string())?;
+    signer_certificate::save_chain(&chain_path, &chain, &key_manager).map_err(|e| e.to_string())?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Signer certificate set: {}", chain[0].subject_name()),
+        "recording_commands",
+    );
+    Ok(chain.iter().map(Into::into).collect())
+}
+
+/// Stop signing recordings with a certificate chain
+#[tauri::command]
+pub async fn clear_signer_certificate() -> Result<(), String> {
+    let path = crate::evidence::signer_certificate::default_path().map_err(|e| e.to_string())?;
+    if path.exists() {
+        std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Encryption Key Commands (X25519)
 // ============================================================================
@@ -1214,6 +1600,314 @@ pub async fn decrypt_video_with_private_key(
     .map_err(|e| format!("Decryption failed: {}", e))
 }
 
+/// Upgrade a password-encrypted .notari file to Argon2id key derivation
+///
+/// The video isn't re-encrypted: its content key is wrapped under an Argon2id
+/// key derived from the same password, and the manifest re-signed. Anchored
+/// recordings are refused, since their anchors cover the encryption info.
+#[tauri::command]
+pub async fn upgrade_recording_encryption(
+    notari_path: String,
+    password: String,
+) -> Result<(), String> {
+    use crate::evidence::encryption::ARGON2ID_ALGORITHM;
+    use crate::evidence::{EvidenceManifest, VideoEncryptor};
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Upgrading key derivation for: {}", notari_path),
+        "recording_commands",
+    );
+
+    let (_, manifest_json) = crate::evidence::proof_pack::read_proof_pack_manifest(&notari_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let mut manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    if manifest.is_anchored() {
+        return Err(
+            "Recording is anchored; changing its encryption info would invalidate the anchor"
+                .to_string(),
+        );
+    }
+
+    let encryption_info = manifest
+        .recording
+        .encryption
+        .as_mut()
+        .ok_or("Recording is not encrypted")?;
+    let key_derivation = encryption_info
+        .key_derivation
+        .as_ref()
+        .ok_or("Recording is not password-encrypted")?;
+    if key_derivation.algorithm == ARGON2ID_ALGORITHM {
+        return Err("Recording already uses Argon2id".to_string());
+    }
+
+    // Check the password against the video before wrapping the key it derives
+    let content_key = VideoEncryptor::derive_content_key(&password, encryption_info)
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+    let ciphertext = read_first_video_chunk(&notari_path, encryption_info)?;
+    VideoEncryptor::check_content_key(&ciphertext, &content_key, encryption_info)
+        .map_err(|_| "Incorrect password".to_string())?;
+
+    encryption_info.key_derivation = Some(
+        VideoEncryptor::wrap_content_key(&content_key, &password)
+            .map_err(|e| format!("Failed to wrap content key: {}", e))?,
+    );
+
+    // Re-sign the manifest and save it back to the .notari file (re-signs the index)
+    let key_manager = crate::blockchain_commands::load_manifest_signing_key(&manifest)?;
+    manifest.sign(&key_manager);
+    crate::evidence::proof_pack::replace_proof_pack_manifest(&notari_path, &manifest, &key_manager)
+        .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        "Key derivation upgraded to Argon2id",
+        "recording_commands",
+    );
+
+    Ok(())
+}
+
+/// Read the start of a .notari file's encrypted video, up to the end of its
+/// first chunk (all of it for file-level encryption)
+fn read_first_video_chunk(
+    notari_path: &str,
+    encryption_info: &crate::evidence::EncryptionInfo,
+) -> Result<Vec<u8>, String> {
+    let prefix_len = match &encryption_info.chunked {
+        Some(chunked) => chunked
+            .chunks
+            .first()
+            .map_or(0, |chunk| chunk.offset.saturating_add(chunk.size)),
+        None => u64::MAX,
+    };
+    crate::evidence::proof_pack::read_proof_pack_video_prefix(notari_path, prefix_len)
+        .map_err(|e| format!("Failed to read video: {}", e))
+}
+
+/// Read the manifest of an encrypted .notari file whose key slots may change,
+/// and open its content key
+///
+/// The key is unwrapped with this device's encryption key if it has a slot,
+/// otherwise with `password`, and checked against the video. Anchored
+/// recordings are refused, since their anchors cover the encryption info.
+fn open_recording_content_key(
+    notari_path: &str,
+    password: Option<&str>,
+) -> Result<
+    (
+        crate::evidence::EvidenceManifest,
+        crate::evidence::ContentKey,
+    ),
+    String,
+> {
+    use crate::evidence::{keychain, EncryptionKeyManager, EvidenceManifest, VideoEncryptor};
+
+    let (_, manifest_json) = crate::evidence::proof_pack::read_proof_pack_manifest(notari_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let manifest: EvidenceManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    if manifest.is_anchored() {
+        return Err(
+            "Recording is anchored; changing its encryption info would invalidate the anchor"
+                .to_string(),
+        );
+    }
+    let encryption_info = manifest
+        .recording
+        .encryption
+        .as_ref()
+        .ok_or("Recording is not encrypted")?;
+
+    let owner_key = if keychain::has_encryption_key() {
+        let key_bytes = keychain::retrieve_encryption_key()
+            .map_err(|e| format!("Failed to retrieve encryption key: {}", e))?;
+        Some(
+            EncryptionKeyManager::from_bytes(&key_bytes)
+                .map_err(|e| format!("Failed to load encryption key: {}", e))?,
+        )
+    } else {
+        None
+    };
+    let password = password.filter(|password| !password.is_empty());
+    if owner_key.is_none() && password.is_none() {
+        return Err(
+            "No encryption key found. Generate one, or enter the recording's password.".to_string(),
+        );
+    }
+
+    let content_key = VideoEncryptor::open_content_key(
+        password,
+        owner_key.as_ref().map(EncryptionKeyManager::secret_key),
+        encryption_info,
+    )
+    .map_err(|e| format!("Failed to open the recording's key: {}", e))?;
+    let ciphertext = read_first_video_chunk(notari_path, encryption_info)?;
+    VideoEncryptor::check_content_key(&ciphertext, &content_key, encryption_info)
+        .map_err(|e| format!("Failed to open the recording's key: {}", e))?;
+
+    Ok((manifest, content_key))
+}
+
+/// Give another recipient a key slot on an encrypted .notari file
+///
+/// Only the manifest's key slots change; the video isn't re-encrypted. The
+/// addition is recorded in the manifest, which is re-signed.
+#[tauri::command]
+pub async fn add_recording_recipient(
+    notari_path: String,
+    recipient_id: String,
+    public_key: String,
+    password: Option<String>,
+) -> Result<(), String> {
+    use crate::evidence::{EncryptionKeyManager, VideoEncryptor};
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Adding recipient {} to: {}", recipient_id, notari_path),
+        "recording_commands",
+    );
+
+    let recipient_id = recipient_id.trim();
+    if recipient_id.is_empty() {
+        return Err("Recipient name must not be empty".to_string());
+    }
+    let recipient_key = EncryptionKeyManager::import_public_key(public_key.trim())
+        .map_err(|e| format!("Invalid public key: {}", e))?;
+
+    let (mut manifest, content_key) =
+        open_recording_content_key(&notari_path, password.as_deref())?;
+    let encryption_info = manifest
+        .recording
+        .encryption
+        .as_mut()
+        .ok_or("Recording is not encrypted")?;
+    VideoEncryptor::add_recipient(encryption_info, &content_key, recipient_id, &recipient_key)
+        .map_err(|e| format!("Failed to add recipient: {}", e))?;
+
+    // Re-sign the manifest and save it back to the .notari file (re-signs the index)
+    let key_manager = crate::blockchain_commands::load_manifest_signing_key(&manifest)?;
+    manifest.sign(&key_manager);
+    crate::evidence::proof_pack::replace_proof_pack_manifest(&notari_path, &manifest, &key_manager)
+        .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Recipient {} added", recipient_id),
+        "recording_commands",
+    );
+
+    Ok(())
+}
+
+/// Remove a recipient's key slot from an encrypted .notari file
+///
+/// Without `rotate_key` only the manifest changes, and a recipient who kept a
+/// copy of the video or its key can still open it. With `rotate_key` the video
+/// is re-encrypted under a new content key and every remaining slot rewrapped.
+/// A recording with a password slot can only be rotated with its password,
+/// even when the owner's encryption key opens it, since the slot is rewrapped
+/// under the new key. The removal is recorded in the manifest, which is re-signed.
+#[tauri::command]
+pub async fn remove_recording_recipient(
+    notari_path: String,
+    recipient_public_key: String,
+    rotate_key: bool,
+    password: Option<String>,
+) -> Result<(), String> {
+    use crate::evidence::{HashInfo, VideoEncryptor};
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Removing recipient from: {} (rotate key: {})",
+            notari_path, rotate_key
+        ),
+        "recording_commands",
+    );
+
+    let (mut manifest, content_key) =
+        open_recording_content_key(&notari_path, password.as_deref())?;
+    let encryption_info = manifest
+        .recording
+        .encryption
+        .as_mut()
+        .ok_or("Recording is not encrypted")?;
+    let password = password.filter(|password| !password.is_empty());
+    if rotate_key && encryption_info.key_derivation.is_some() && password.is_none() {
+        return Err(
+            "Recording has a password slot; enter its password to rotate the key".to_string(),
+        );
+    }
+    let removed =
+        VideoEncryptor::remove_recipient(encryption_info, &recipient_public_key, rotate_key)
+            .map_err(|e| format!("Failed to remove recipient: {}", e))?;
+
+    if !rotate_key {
+        let key_manager = crate::blockchain_commands::load_manifest_signing_key(&manifest)?;
+        manifest.sign(&key_manager);
+        crate::evidence::proof_pack::replace_proof_pack_manifest(
+            &notari_path,
+            &manifest,
+            &key_manager,
+        )
+        .map_err(|e| format!("Failed to update .notari file: {}", e))?;
+    } else {
+        // Re-encrypt the video under a new key, then replace it and the manifest
+        let (video_path, _, temp_dir) = resolve_recording_paths(&notari_path, &notari_path)?;
+        let rotated_path = std::path::Path::new(&temp_dir).join("rotated_video");
+
+        let result = (|| -> Result<(), String> {
+            let encryption_info = manifest
+                .recording
+                .encryption
+                .as_mut()
+                .ok_or("Recording is not encrypted")?;
+            VideoEncryptor::rotate_content_key(
+                std::path::Path::new(&video_path),
+                rotated_path.as_path(),
+                &content_key,
+                encryption_info,
+                password.as_deref(),
+            )
+            .map_err(|e| format!("Failed to re-encrypt video: {}", e))?;
+            manifest.recording.encrypted_hash = Some(
+                HashInfo::from_file(&rotated_path)
+                    .map_err(|e| format!("Failed to hash video: {}", e))?,
+            );
+
+            let key_manager = crate::blockchain_commands::load_manifest_signing_key(&manifest)?;
+            manifest.sign(&key_manager);
+            crate::evidence::proof_pack::replace_proof_pack_video(
+                &notari_path,
+                &rotated_path,
+                &manifest,
+                &key_manager,
+            )
+            .map_err(|e| format!("Failed to update .notari file: {}", e))
+        })();
+
+        // Cleanup temp files
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        result?;
+    }
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Recipient {} removed (key rotated: {})",
+            removed.recipient_id, rotate_key
+        ),
+        "recording_commands",
+    );
+
+    Ok(())
+}
+
 /// Validate encryption password strength
 #[tauri::command]
 pub async fn validate_encryption_password(password: String) -> Result<(), String> {
@@ -1231,45 +1925,23 @@ pub async fn read_file(path: String) -> Result<String, String> {
 /// Read manifest from a .notari proof pack file
 #[tauri::command]
 pub async fn read_manifest_from_notari(notari_path: String) -> Result<String, String> {
-    use std::fs::File;
-    use std::io::Read;
-    use zip::ZipArchive;
-
     LOGGER.log(
         LogLevel::Info,
         &format!("Reading manifest from .notari file: {}", notari_path),
         "recording_commands",
     );
 
-    let file =
-        File::open(&notari_path).map_err(|e| format!("Failed to open .notari file: {}", e))?;
-
-    let mut archive =
-        ZipArchive::new(file).map_err(|e| format!("Failed to read .notari archive: {}", e))?;
-
-    // Find the manifest file (evidence/*.json)
-    for i in 0..archive.len() {
-        let mut file = archive
-            .by_index(i)
-            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
-
-        let name = file.name().to_string();
-        if name.starts_with("evidence/") && name.ends_with(".json") {
-            let mut contents = String::new();
-            file.read_to_string(&mut contents)
-                .map_err(|e| format!("Failed to read manifest: {}", e))?;
-
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("Found manifest in .notari: {}", name),
-                "recording_commands",
-            );
+    // Validates the archive layout and bounds the manifest size before reading
+    let (name, contents) = crate::evidence::proof_pack::read_proof_pack_manifest(&notari_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
 
-            return Ok(contents);
-        }
-    }
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Found manifest in .notari: {}", name),
+        "recording_commands",
+    );
 
-    Err("No manifest found in .notari file".to_string())
+    Ok(contents)
 }
 
 /// Delete a file
@@ -1303,7 +1975,6 @@ pub async fn update_recording_metadata(
     tags: Option<Vec<String>>,
 ) -> Result<(), String> {
     use std::fs;
-    use std::io::Write;
 
     LOGGER.log(
         LogLevel::Info,
@@ -1312,10 +1983,8 @@ pub async fn update_recording_metadata(
     );
 
     // Extract the .notari file to temp
-    let temp_dir = std::env::temp_dir().join(format!("notari_update_{}", uuid::Uuid::new_v4()));
-    fs::create_dir_all(&temp_dir).map_err(|e| format!("Failed to create temp directory: {}", e))?;
-
-    let (video_path, manifest_path, _) = resolve_recording_paths(&notari_path, &notari_path)?;
+    let (video_path, manifest_path, temp_dir) =
+        resolve_recording_paths(&notari_path, &notari_path)?;
 
     // Load manifest
     let mut manifest = crate::evidence::EvidenceManifest::load(&manifest_path)
@@ -1343,52 +2012,25 @@ pub async fn update_recording_metadata(
         .save(&manifest_path)
         .map_err(|e| format!("Failed to save manifest: {}", e))?;
 
-    // Recreate the .notari file with updated manifest
-    let file = fs::File::create(&notari_path)
-        .map_err(|e| format!("Failed to create .notari file: {}", e))?;
-    let mut zip = zip::ZipWriter::new(file);
-    let options: zip::write::FileOptions<()> = zip::write::FileOptions::default()
-        .compression_method(zip::CompressionMethod::Deflated)
-        .unix_permissions(0o644);
-
-    // Add video file
-    let video_data =
-        fs::read(&video_path).map_err(|e| format!("Failed to read video file: {}", e))?;
-    zip.start_file("recording.mov", options)
-        .map_err(|e| format!("Failed to add video to zip: {}", e))?;
-    zip.write_all(&video_data)
-        .map_err(|e| format!("Failed to write video to zip: {}", e))?;
-
-    // Add manifest file
-    let manifest_data =
-        fs::read(&manifest_path).map_err(|e| format!("Failed to read manifest file: {}", e))?;
-    zip.start_file("manifest.json", options)
-        .map_err(|e| format!("Failed to add manifest to zip: {}", e))?;
-    zip.write_all(&manifest_data)
-        .map_err(|e| format!("Failed to write manifest to zip: {}", e))?;
-
-    // Add metadata.json
-    let metadata_json = serde_json::json!({
-        "version": "1.0",
-        "created_at": chrono::Utc::now().to_rfc3339(),
-        "notari_version": env!("CARGO_PKG_VERSION"),
-        "recording_filename": "recording.mov",
-        "is_encrypted": manifest.recording.encrypted,
-    });
-    zip.start_file("metadata.json", options)
-        .map_err(|e| format!("Failed to add metadata to zip: {}", e))?;
-    zip.write_all(
-        serde_json::to_string_pretty(&metadata_json)
-            .unwrap()
-            .as_bytes(),
+    // Rebuild the .notari file with the standard layout, then swap it in place
+    let updated_path = format!("{}.tmp", notari_path);
+    let result = crate::evidence::proof_pack::create_proof_pack(
+        video_path.as_str(),
+        manifest_path.as_str(),
+        updated_path.as_str(),
     )
-    .map_err(|e| format!("Failed to write metadata to zip: {}", e))?;
-
-    zip.finish()
-        .map_err(|e| format!("Failed to finalize zip: {}", e))?;
+    .map_err(|e| format!("Failed to rebuild proof pack: {}", e))
+    .and_then(|_| {
+        fs::rename(&updated_path, &notari_path)
+            .map_err(|e| format!("Failed to replace .notari file: {}", e))
+    });
 
-    // Cleanup temp directory
+    // Cleanup temp files
     let _ = fs::remove_dir_all(&temp_dir);
+    if result.is_err() {
+        let _ = fs::remove_file(&updated_path);
+    }
+    result?;
 
     LOGGER.log(
         LogLevel::Info,
@@ -1466,7 +2108,8 @@ pub struct RecordingEntry {
     pub title: Option<String>,
     pub description: Option<String>,
     pub tags: Option<Vec<String>>,
-    pub blockchain_anchor: Option<BlockchainAnchorInfo>,
+    /// Every anchor on the recording's manifest, oldest first
+    pub anchors: Vec<BlockchainAnchorInfo>,
 }
 
 /// Blockchain anchor information for recording entry
@@ -1476,6 +2119,62 @@ pub struct BlockchainAnchorInfo {
     pub chain_name: String,
     pub tx_hash: Option<String>,
     pub explorer_url: Option<String>,
+    /// Chain or service identifier (`AnchorProof::service_id`)
+    pub service_id: String,
+}
+
+impl BlockchainAnchorInfo {
+    fn from_anchor(anchor: &crate::evidence::BlockchainAnchor) -> Self {
+        use crate::evidence::AnchorProof;
+
+        let anchored_at = anchor.anchored_at.to_rfc3339();
+        let service_id = anchor.proof.service_id();
+        match &anchor.proof {
+            AnchorProof::Ethereum {
+                chain_name,
+                tx_hash,
+                explorer_url,
+                ..
+            } => BlockchainAnchorInfo {
+                anchored_at,
+                chain_name: chain_name.clone(),
+                tx_hash: Some(tx_hash.clone()),
+                explorer_url: Some(explorer_url.clone()),
+                service_id,
+            },
+            AnchorProof::Mock { .. } => BlockchainAnchorInfo {
+                anchored_at,
+                chain_name: "Mock".to_string(),
+                tx_hash: None,
+                explorer_url: None,
+                service_id,
+            },
+            AnchorProof::OpenTimestamps { .. } => BlockchainAnchorInfo {
+                anchored_at,
+                chain_name: "Bitcoin (OpenTimestamps)".to_string(),
+                tx_hash: None,
+                explorer_url: anchor.proof.explorer_url(),
+                service_id,
+            },
+            AnchorProof::Rfc3161 { tsa_name, .. } => BlockchainAnchorInfo {
+                anchored_at,
+                chain_name: format!("RFC 3161 ({})", tsa_name),
+                tx_hash: None,
+                explorer_url: None,
+                service_id,
+            },
+            AnchorProof::MerkleBatch { root_proof, .. } => BlockchainAnchorInfo {
+                anchored_at,
+                chain_name: format!("{} (batch)", root_proof.chain_name()),
+                tx_hash: match root_proof.as_ref() {
+                    AnchorProof::Ethereum { tx_hash, .. } => Some(tx_hash.clone()),
+                    _ => None,
+                },
+                explorer_url: anchor.proof.explorer_url(),
+                service_id,
+            },
+        }
+    }
 }
 
 /// Helper: Extract video and manifest from .notari file
@@ -1489,11 +2188,21 @@ fn resolve_recording_paths(
     std::fs::create_dir_all(&temp_dir)
         .map_err(|e| format!("Failed to create temp directory: {}", e))?;
 
-    let (extracted_video, extracted_manifest) = crate::evidence::proof_pack::extract_proof_pack(
-        notari_path,
-        temp_dir.to_str().ok_or("Invalid temp directory path")?,
-    )
-    .map_err(|e| format!("Failed to extract proof pack: {}", e))?;
+    let extracted = temp_dir
+        .to_str()
+        .ok_or_else(|| "Invalid temp directory path".to_string())
+        .and_then(|dir| {
+            crate::evidence::proof_pack::extract_proof_pack(notari_path, dir)
+                .map_err(|e| format!("Failed to extract proof pack: {}", e))
+        });
+    let (extracted_video, extracted_manifest) = match extracted {
+        Ok(paths) => paths,
+        Err(e) => {
+            // Callers only clean up the temp directory on success
+            let _ = std::fs::remove_dir_all(&temp_dir);
+            return Err(e);
+        }
+    };
 
     Ok((
         extracted_video.to_string_lossy().to_string(),
@@ -1508,7 +2217,6 @@ pub async fn list_recordings(
     state: State<'_, WindowManagerState>,
 ) -> Result<Vec<RecordingEntry>, String> {
     use std::fs;
-    use std::io::Read;
 
     // Get save directory
     let save_dir = {
@@ -1548,119 +2256,37 @@ pub async fn list_recordings(
                     .to_string();
 
                 // Extract metadata (including custom metadata and blockchain anchor) from proof pack
-                let (is_encrypted, title, description, tags, blockchain_anchor) = if let Ok(file) =
-                    fs::File::open(&path)
-                {
-                    if let Ok(mut archive) = zip::ZipArchive::new(file) {
-                        // Get metadata
-                        let (is_encrypted, title, description, tags) = if let Ok(
-                            mut metadata_file,
-                        ) =
-                            archive.by_name("metadata.json")
-                        {
-                            let mut contents = String::new();
-                            if metadata_file.read_to_string(&mut contents).is_ok() {
-                                if let Ok(metadata) =
-                                    serde_json::from_str::<ProofPackMetadata>(&contents)
-                                {
-                                    println!("DEBUG: Found metadata - encrypted: {}, title: {:?}, desc: {:?}, tags: {:?}",
-                                        metadata.is_encrypted, metadata.title, metadata.description, metadata.tags);
-                                    (
-                                        metadata.is_encrypted,
-                                        metadata.title,
-                                        metadata.description,
-                                        metadata.tags,
-                                    )
-                                } else {
-                                    println!("DEBUG: Failed to parse metadata.json");
-                                    (false, None, None, None)
-                                }
-                            } else {
-                                println!("DEBUG: Failed to read metadata.json contents");
-                                (false, None, None, None)
-                            }
-                        } else {
-                            println!("DEBUG: metadata.json not found in archive");
+                let (is_encrypted, title, description, tags) =
+                    match crate::evidence::proof_pack::read_proof_pack_metadata(&path) {
+                        Ok(metadata) => (
+                            metadata.is_encrypted,
+                            metadata.title,
+                            metadata.description,
+                            metadata.tags,
+                        ),
+                        Err(e) => {
+                            LOGGER.log(
+                                LogLevel::Warn,
+                                &format!("Failed to read metadata from {}: {}", filename, e),
+                                "recording_commands",
+                            );
                             (false, None, None, None)
-                        };
-
-                        // Get blockchain anchor from manifest
-                        // Find the manifest file dynamically (it's in evidence/ directory with .json extension)
-                        let mut manifest_filename = None;
-                        for i in 0..archive.len() {
-                            if let Ok(file) = archive.by_index(i) {
-                                let name = file.name();
-                                if name.starts_with("evidence/") && name.ends_with(".json") {
-                                    manifest_filename = Some(name.to_string());
-                                    break;
-                                }
-                            }
                         }
+                    };
 
-                        let blockchain_anchor = if let Some(manifest_name) = manifest_filename {
-                            if let Ok(mut manifest_file) = archive.by_name(&manifest_name) {
-                                let mut contents = String::new();
-                                if manifest_file.read_to_string(&mut contents).is_ok() {
-                                    if let Ok(manifest) =
-                                        serde_json::from_str::<crate::evidence::EvidenceManifest>(
-                                            &contents,
-                                        )
-                                    {
-                                        manifest.blockchain_anchor.map(|anchor| {
-                                            use crate::evidence::AnchorProof;
-                                            match anchor.proof {
-                                                AnchorProof::Ethereum {
-                                                    chain_name,
-                                                    tx_hash,
-                                                    explorer_url,
-                                                    ..
-                                                } => BlockchainAnchorInfo {
-                                                    anchored_at: anchor.anchored_at.to_rfc3339(),
-                                                    chain_name,
-                                                    tx_hash: Some(tx_hash),
-                                                    explorer_url: Some(explorer_url),
-                                                },
-                                                AnchorProof::Mock { .. } => BlockchainAnchorInfo {
-                                                    anchored_at: anchor.anchored_at.to_rfc3339(),
-                                                    chain_name: "Mock".to_string(),
-                                                    tx_hash: None,
-                                                    explorer_url: None,
-                                                },
-                                                AnchorProof::OpenTimestamps { .. } => {
-                                                    BlockchainAnchorInfo {
-                                                        anchored_at: anchor
-                                                            .anchored_at
-                                                            .to_rfc3339(),
-                                                        chain_name: "Bitcoin (OpenTimestamps)"
-                                                            .to_string(),
-                                                        tx_hash: None,
-                                                        explorer_url: None,
-                                                    }
-                                                }
-                                            }
-                                        })
-                                    } else {
-                                        None
-                                    }
-                                } else {
-                                    None
-                                }
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        };
-
-                        (is_encrypted, title, description, tags, blockchain_anchor)
-                    } else {
-                        println!("DEBUG: Failed to open zip archive");
-                        (false, None, None, None, None)
-                    }
-                } else {
-                    println!("DEBUG: Failed to open .notari file");
-                    (false, None, None, None, None)
-                };
+                // Get blockchain anchor from manifest
+                let anchors = crate::evidence::proof_pack::read_proof_pack_manifest(&path)
+                    .ok()
+                    .and_then(|(_, contents)| {
+                        serde_json::from_str::<crate::evidence::EvidenceManifest>(&contents).ok()
+                    })
+                    .map(|manifest| {
+                        manifest
+                            .all_anchors()
+                            .map(BlockchainAnchorInfo::from_anchor)
+                            .collect()
+                    })
+                    .unwrap_or_default();
 
                 // For .notari files, the video_path is the .notari file itself
                 // The manifest is embedded, so has_manifest is always true
@@ -1701,7 +2327,7 @@ pub async fn list_recordings(
                     title,
                     description,
                     tags,
-                    blockchain_anchor,
+                    anchors,
                 });
             }
         }
@@ -1778,25 +2404,40 @@ pub async fn decrypt_and_play_video(
     // Decrypt the video based on encryption method
     let temp_path_str = temp_path.to_string_lossy().to_string();
 
-    if encryption_info.encrypted_keys.is_some() {
-        // Public key encryption - use private key
+    if encryption_info.chunked.is_some() {
+        // Try the keychain key on recipient key slots, then the password
         use crate::evidence::keychain;
         use crate::evidence::EncryptionKeyManager;
 
-        let key_bytes = keychain::retrieve_encryption_key()
-            .map_err(|e| format!("Failed to retrieve encryption key: {}", e))?;
-        let key_manager = EncryptionKeyManager::from_bytes(&key_bytes)
-            .map_err(|e| format!("Failed to load encryption key: {}", e))?;
+        let key_manager =
+            if encryption_info.encrypted_keys.is_some() && keychain::has_encryption_key() {
+                let key_bytes = keychain::retrieve_encryption_key()
+                    .map_err(|e| format!("Failed to retrieve encryption key: {}", e))?;
+                Some(
+                    EncryptionKeyManager::from_bytes(&key_bytes)
+                        .map_err(|e| format!("Failed to load encryption key: {}", e))?,
+                )
+            } else {
+                None
+            };
 
-        VideoEncryptor::decrypt_file_with_private_key(
+        let key = VideoEncryptor::open_content_key(
+            Some(password.as_str()).filter(|password| !password.is_empty()),
+            key_manager
+                .as_ref()
+                .map(|key_manager| key_manager.secret_key()),
+            &encryption_info,
+        )
+        .map_err(|e| format!("Decryption failed: {}", e))?;
+        VideoEncryptor::decrypt_file_with_key(
             &resolved_video,
             &temp_path_str,
-            key_manager.secret_key(),
+            &key,
             &encryption_info,
         )
         .map_err(|e| format!("Decryption failed: {}", e))?;
     } else {
-        // Password-based encryption
+        // Legacy file-level encryption is password-only
         VideoEncryptor::decrypt_file(&resolved_video, &temp_path_str, &password, &encryption_info)
             .map_err(|e| format!("Decryption failed: {}", e))?;
     }
@@ -1879,30 +2520,27 @@ pub async fn start_video_playback(
     let encryption_info = manifest.recording.encryption;
     let is_encrypted = encryption_info.is_some();
 
-    // Determine encryption method
+    // Determine which credentials to try: the password if one was entered and the
+    // video has a password slot, the keychain key if it has recipient key slots
     let (use_password, use_private_key) = if let Some(ref enc_info) = encryption_info {
         let has_password_encryption = enc_info.key_derivation.is_some();
         let has_public_key_encryption = enc_info.encrypted_keys.is_some();
 
-        if has_public_key_encryption {
-            // Public key encryption - retrieve private key from keychain
-            LOGGER.log(
-                LogLevel::Info,
-                "Video encrypted with public key encryption, retrieving private key",
-                "recording_commands",
-            );
-            (false, true)
-        } else if has_password_encryption {
-            // Password-based encryption
-            LOGGER.log(
-                LogLevel::Info,
-                "Video encrypted with password-based encryption",
-                "recording_commands",
-            );
-            (true, false)
-        } else {
+        if !has_password_encryption && !has_public_key_encryption {
             return Err("Invalid encryption info: no key derivation or encrypted keys".to_string());
         }
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Video key slots - password: {}, public key: {}",
+                has_password_encryption, has_public_key_encryption
+            ),
+            "recording_commands",
+        );
+        (
+            has_password_encryption && !password.is_empty(),
+            has_public_key_encryption && crate::evidence::keychain::has_encryption_key(),
+        )
     } else {
         (false, false)
     };
@@ -1980,15 +2618,31 @@ pub async fn start_video_playback(
         None
     };
 
+    // Recover the content key once, with whichever credential opens a key slot;
+    // range requests reuse it
+    let content_key = match &encryption_info {
+        Some(enc_info) => Some(
+            crate::evidence::VideoEncryptor::open_content_key(
+                Some(password.as_str()).filter(|_| use_password),
+                private_key.as_ref(),
+                enc_info,
+            )
+            .map_err(|e| format!("Failed to decrypt video key: {}", e))?,
+        ),
+        None => None,
+    };
+
     // Create stream
     let stream_id = uuid::Uuid::new_v4().to_string();
     let stream = crate::video_server::VideoStream {
         video_path: video_path.into(),
-        password: if use_password { Some(password) } else { None },
-        private_key,
+        content_key,
         encryption_info,
         file_size: plaintext_size, // Use plaintext size for streaming
         temp_dir: temp_dir.into(),
+        chunk_cache: crate::video_server::ChunkCache::new(
+            crate::video_server::CHUNK_CACHE_CAPACITY,
+        ),
     };
 
     // Store stream
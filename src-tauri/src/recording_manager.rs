@@ -1,16 +1,32 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Child;
 use std::sync::{Arc, Mutex};
 
-use crate::error::NotariResult;
+use crate::error::{NotariError, NotariResult};
 use crate::state_machine::{RecordingPreferencesSnapshot, RecordingSessionState};
 
 // Builder module for fluent API construction
 pub mod builder;
 pub use builder::ActiveRecordingBuilder;
 
+// State transitions and post-processing shared by all platforms
+pub mod lifecycle;
+
+// Segment journal and recovery of interrupted sessions
+pub mod journal;
+pub mod recovery;
+pub use journal::SessionJournal;
+
+// Signed checkpoints taken while recording
+pub mod checkpoints;
+pub use checkpoints::SessionCheckpoints;
+
+// Generated recordings for tests and CI
+pub mod synthetic;
+pub use synthetic::{SyntheticConfig, SyntheticRecordingManager};
+
 /// Recording preferences that can be configured by the user
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecordingPreferences {
@@ -84,7 +100,7 @@ pub struct ActiveRecording {
     // Encryption settings
     #[serde(skip_serializing)]
     pub encryption_password: Option<String>,
-    pub encryption_method: Option<String>, // "password" or "public-key"
+    pub encryption_method: Option<String>, // "password", "public-key" or "hybrid"
     pub encryption_recipients: Option<Vec<EncryptionRecipient>>,
     // Custom metadata fields
     pub recording_title: Option<String>,
@@ -132,6 +148,7 @@ pub enum RecordingStatus {
     Idle,
     Preparing,
     Recording,
+    Paused,
     Stopping,
     Processing,
     Completed,
@@ -155,6 +172,10 @@ pub struct InternalRecordingState {
     pub last_health_check: DateTime<Utc>,
     /// Type-safe state machine for recording lifecycle
     pub state_machine: RecordingSessionState,
+    /// On-disk journal of the segments written so far
+    pub journal: Option<SessionJournal>,
+    /// Signed checkpoints of the journaled segments
+    pub checkpoints: Option<SessionCheckpoints>,
 }
 
 impl InternalRecordingState {
@@ -164,6 +185,7 @@ impl InternalRecordingState {
             "Idle" => RecordingStatus::Idle,
             "Preparing" => RecordingStatus::Preparing,
             "Recording" => RecordingStatus::Recording,
+            "Paused" => RecordingStatus::Paused,
             "Stopping" => RecordingStatus::Stopping,
             "Processing" => RecordingStatus::Processing,
             "Completed" => RecordingStatus::Completed,
@@ -171,6 +193,18 @@ impl InternalRecordingState {
             _ => RecordingStatus::Idle,
         }
     }
+
+    /// Time spent capturing so far, excluding completed pauses
+    pub fn recorded_duration(&self) -> chrono::Duration {
+        let paused = self
+            .state_machine
+            .pauses()
+            .iter()
+            .fold(chrono::Duration::zero(), |total, pause| {
+                total + pause.duration()
+            });
+        Utc::now() - self.session.start_time - paused
+    }
 }
 
 /// Global recording state manager
@@ -254,6 +288,24 @@ pub trait RecordingManager: Send + Sync {
 
     /// Validate that a directory is writable for recordings
     fn validate_save_directory(&self, path: &PathBuf) -> NotariResult<bool>;
+
+    /// Key to sign this manager's recordings with; `None` uses the keychain signing key
+    fn signing_key(&self) -> Option<crate::evidence::KeyManager> {
+        None
+    }
+
+    /// Join recorded segments, in order, into a single video at `output_path`
+    ///
+    /// The default only handles a single segment, which is copied as is.
+    fn join_segments(&self, segments: &[PathBuf], output_path: &Path) -> NotariResult<()> {
+        match segments {
+            [segment] => {
+                std::fs::copy(segment, output_path)?;
+                Ok(())
+            }
+            _ => Err(NotariError::PlatformNotSupported),
+        }
+    }
 }
 
 /// Utility functions for recording management
@@ -289,7 +341,27 @@ mod linux;
 pub use linux::LinuxRecordingManager as PlatformRecordingManager;
 
 /// Create a platform-specific recording manager instance
+///
+/// Setting `NOTARI_RECORDER=synthetic` selects the [`SyntheticRecordingManager`]
+/// instead, configured from the `NOTARI_SYNTHETIC_*` variables.
 pub fn create_recording_manager() -> Box<dyn RecordingManager> {
+    if std::env::var(synthetic::RECORDER_ENV).is_ok_and(|recorder| recorder == "synthetic") {
+        let config = SyntheticConfig::from_env().unwrap_or_else(|e| {
+            crate::logger::LOGGER.log(
+                crate::logger::LogLevel::Error,
+                &format!("Invalid synthetic recorder settings, using defaults: {}", e),
+                "recording_manager",
+            );
+            SyntheticConfig::default()
+        });
+        crate::logger::LOGGER.log(
+            crate::logger::LogLevel::Info,
+            &format!("Using synthetic recording manager: {:?}", config),
+            "recording_manager",
+        );
+        return Box::new(SyntheticRecordingManager::new(config));
+    }
+
     Box::new(PlatformRecordingManager::new())
 }
 
@@ -362,6 +434,7 @@ mod tests {
             RecordingStatus::Idle,
             RecordingStatus::Preparing,
             RecordingStatus::Recording,
+            RecordingStatus::Paused,
             RecordingStatus::Stopping,
             RecordingStatus::Processing,
             RecordingStatus::Completed,
@@ -0,0 +1,239 @@
+//! Signed checkpoints taken while a session is recording
+//!
+//! A background thread checkpoints the journaled segments every interval and,
+//! when a time-stamp authority is configured, has each checkpoint time-stamped.
+//! The log is kept in the segment directory next to the journal, so it is packed
+//! with the recording, or with the recovered recording after a crash.
+
+use super::{SessionJournal, SharedRecordingState};
+use crate::error::{NotariError, NotariResult};
+use crate::evidence::checkpoint::{CheckpointLog, CHECKPOINT_LOG_FILE};
+use crate::evidence::{AnchorProof, BlockchainAnchorer, KeyManager, TsaAnchorer};
+use crate::logger::{LogLevel, LOGGER};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Checkpoint log of the active session and the key signing it
+pub struct SessionCheckpoints {
+    log: CheckpointLog,
+    key: KeyManager,
+    path: PathBuf,
+    closed: bool,
+}
+
+impl SessionCheckpoints {
+    /// Start an empty log in the segment directory of `journal`
+    pub fn create(
+        journal: &SessionJournal,
+        key: KeyManager,
+        interval: Duration,
+    ) -> NotariResult<Self> {
+        let checkpoints = Self {
+            log: CheckpointLog::new(&journal.session_id, &key, interval),
+            key,
+            path: journal.dir().join(CHECKPOINT_LOG_FILE),
+            closed: false,
+        };
+        checkpoints.log.save(&checkpoints.path)?;
+        Ok(checkpoints)
+    }
+
+    /// Whether the last checkpoint has been taken
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Sign a checkpoint of the journaled segments not covered yet and save the log
+    ///
+    /// Returns the sequence number and digest of the new checkpoint.
+    pub fn checkpoint(&mut self, journal: &SessionJournal) -> NotariResult<(u64, String)> {
+        let covered = self.log.segment_count() as usize;
+        let segments = journal.segments.get(covered..).unwrap_or_default();
+        let hashes = segments
+            .iter()
+            .map(|segment| segment.hash.value.clone())
+            .collect();
+        let bytes = segments.iter().map(|segment| segment.size).sum();
+
+        let sequence = self.log.append(&self.key, hashes, bytes).sequence;
+        let digest = self.log.checkpoints[sequence as usize].digest(&self.log.session_id);
+        self.log.save(&self.path)?;
+        Ok((sequence, digest))
+    }
+
+    /// Take the last checkpoint once every segment is journaled
+    ///
+    /// Nothing is added to the log afterwards, since the manifest commits to it.
+    pub fn close(&mut self, journal: &SessionJournal) -> NotariResult<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.checkpoint(journal)?;
+        self.closed = true;
+        Ok(())
+    }
+
+    /// Attach the RFC 3161 token for checkpoint `sequence` and save the log
+    pub fn attach_timestamp(&mut self, sequence: u64, proof: AnchorProof) -> NotariResult<()> {
+        self.log.attach_timestamp(sequence, proof)?;
+        self.log.save(&self.path)
+    }
+}
+
+/// Start checkpointing the active session every `interval`
+///
+/// Call after `lifecycle::open_journal`. Checkpoints are signed with `key` and
+/// time-stamped by `timestamper` if given; a checkpoint whose token can't be
+/// obtained is kept without one. The thread ends once the session is replaced
+/// or its log is closed by `lifecycle::finish_segments`.
+pub fn start(
+    session_id: &str,
+    state: SharedRecordingState,
+    key: KeyManager,
+    interval: Duration,
+    timestamper: Option<TsaAnchorer>,
+) -> NotariResult<()> {
+    {
+        let mut state_guard = state.lock()?;
+        let recording = state_guard
+            .active_recording
+            .as_mut()
+            .ok_or(NotariError::NoActiveRecording)?;
+        if recording.session.session_id != session_id {
+            return Err(NotariError::SessionNotFound(session_id.to_string()));
+        }
+        let journal = recording.journal.as_ref().ok_or_else(|| {
+            NotariError::GenericError(format!("No journal for session {}", session_id))
+        })?;
+        recording.checkpoints = Some(SessionCheckpoints::create(journal, key, interval)?);
+    }
+
+    let session_id = session_id.to_string();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(interval);
+
+        let (sequence, digest) = match take_checkpoint(&session_id, &state) {
+            Ok(Some(checkpoint)) => checkpoint,
+            Ok(None) => break,
+            Err(e) => {
+                LOGGER.log(
+                    LogLevel::Warn,
+                    &format!("Failed to checkpoint session {}: {}", session_id, e),
+                    "recording_manager",
+                );
+                continue;
+            }
+        };
+
+        if let Some(ref timestamper) = timestamper {
+            let result = tauri::async_runtime::block_on(timestamper.anchor(&digest))
+                .and_then(|proof| attach_timestamp(&session_id, &state, sequence, proof));
+            if let Err(e) = result {
+                LOGGER.log(
+                    LogLevel::Warn,
+                    &format!("Failed to time-stamp checkpoint {}: {}", sequence, e),
+                    "recording_manager",
+                );
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Checkpoint the session unless it has ended, syncing its journal first
+///
+/// Returns `None` once the session is no longer active or its log is closed.
+fn take_checkpoint(
+    session_id: &str,
+    state: &SharedRecordingState,
+) -> NotariResult<Option<(u64, String)>> {
+    let mut state_guard = state.lock()?;
+    let Some(ref mut recording) = state_guard.active_recording else {
+        return Ok(None);
+    };
+    if recording.session.session_id != session_id {
+        return Ok(None);
+    }
+    let pauses = recording.state_machine.pauses().to_vec();
+    let (Some(journal), Some(checkpoints)) =
+        (recording.journal.as_mut(), recording.checkpoints.as_mut())
+    else {
+        return Ok(None);
+    };
+    if checkpoints.is_closed() {
+        return Ok(None);
+    }
+
+    journal.pauses = pauses;
+    journal.sync(false)?;
+    checkpoints.checkpoint(journal).map(Some)
+}
+
+/// Attach a token to a checkpoint of the session, unless its log was closed
+/// while the token was requested
+fn attach_timestamp(
+    session_id: &str,
+    state: &SharedRecordingState,
+    sequence: u64,
+    proof: AnchorProof,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    let Some(ref mut recording) = state_guard.active_recording else {
+        return Ok(());
+    };
+    if recording.session.session_id != session_id {
+        return Ok(());
+    }
+    match recording.checkpoints {
+        Some(ref mut checkpoints) if !checkpoints.is_closed() => {
+            checkpoints.attach_timestamp(sequence, proof)
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording_manager::{ActiveRecordingBuilder, RecordingPreferences};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_checkpoints_cover_new_segments() {
+        let dir = TempDir::new().unwrap();
+        let session = ActiveRecordingBuilder::new("window_1")
+            .output_path(dir.path().join("recording.mov"))
+            .preferences(RecordingPreferences::default())
+            .build()
+            .unwrap();
+        let mut journal = SessionJournal::create(&session).unwrap();
+        let mut checkpoints =
+            SessionCheckpoints::create(&journal, KeyManager::generate(), Duration::from_secs(1))
+                .unwrap();
+
+        for (index, data) in [&b"first"[..], b"second", b"third"].iter().enumerate() {
+            std::fs::write(
+                journal
+                    .dir()
+                    .join(crate::recording_manager::journal::segment_file_name(
+                        index as u32,
+                    )),
+                data,
+            )
+            .unwrap();
+        }
+        journal.sync(false).unwrap();
+        checkpoints.checkpoint(&journal).unwrap();
+        journal.sync(true).unwrap();
+        checkpoints.close(&journal).unwrap();
+        checkpoints.close(&journal).unwrap();
+
+        let log = CheckpointLog::load(&journal.dir().join(CHECKPOINT_LOG_FILE)).unwrap();
+        assert_eq!(log.checkpoints.len(), 2);
+        assert_eq!(log.checkpoints[0].segments.len(), 2);
+        assert_eq!(log.checkpoints[1].segments.len(), 1);
+        assert_eq!(log.checkpoints[1].bytes_written, 16);
+        assert!(log.signatures_valid());
+        assert_eq!(log.verify_chain(), Ok(()));
+    }
+}
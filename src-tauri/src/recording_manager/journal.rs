@@ -0,0 +1,361 @@
+//! On-disk session journal for crash recovery
+//!
+//! Recorders write rolling segments into a directory next to the final output
+//! (`<output>.segments/segment-00000.mov`, ...). The journal in that directory
+//! records the session details and the hash of every finished segment, so a
+//! session interrupted by a crash can be joined and processed on the next start.
+//! The directory is removed once the proof pack has been created.
+
+use super::{ActiveRecording, EncryptionRecipient, WindowMetadata};
+use crate::error::{NotariError, NotariResult};
+use crate::evidence::{HashInfo, PauseInterval};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Current journal format version
+pub const JOURNAL_VERSION: u32 = 1;
+
+/// Name of the journal file inside the segment directory
+pub const JOURNAL_FILE: &str = "journal.json";
+
+/// Target length of each segment; recorders cut at the next keyframe after it
+pub const SEGMENT_DURATION: Duration = Duration::from_secs(10);
+
+/// printf-style segment file name pattern, as passed to the recorders
+pub const SEGMENT_PATTERN: &str = "segment-%05d.mov";
+
+/// Extension of the segment directory, replacing the output's extension
+const SEGMENT_DIR_EXTENSION: &str = "segments";
+
+/// File name of segment `index`
+pub fn segment_file_name(index: u32) -> String {
+    format!("segment-{:05}.mov", index)
+}
+
+/// Segment directory for a recording written to `output_path`
+pub fn segment_dir(output_path: &Path) -> PathBuf {
+    output_path.with_extension(SEGMENT_DIR_EXTENSION)
+}
+
+fn is_segment_file(name: &str) -> bool {
+    name.starts_with("segment-") && name.ends_with(".mov")
+}
+
+/// A finished segment recorded in the journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentRecord {
+    pub index: u32,
+    pub file_name: String,
+    pub size: u64,
+    pub hash: HashInfo,
+    /// When the recorder finished writing the segment
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Session journal stored as `journal.json` in the segment directory
+///
+/// The encryption password is never written, only whether there was one;
+/// password-encrypted sessions can't be recovered without asking for it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionJournal {
+    pub version: u32,
+    pub session_id: String,
+    pub window_id: String,
+    pub window_metadata: Option<WindowMetadata>,
+    pub output_path: PathBuf,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub password_protected: bool,
+    pub encryption_method: Option<String>,
+    pub encryption_recipients: Option<Vec<EncryptionRecipient>>,
+    pub recording_title: Option<String>,
+    pub recording_description: Option<String>,
+    pub recording_tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub pauses: Vec<PauseInterval>,
+    #[serde(default)]
+    pub segments: Vec<SegmentRecord>,
+    /// Segments left out when the rest were joined: the first one missing or
+    /// damaged on disk, and every one after it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dropped_segments: Vec<SegmentRecord>,
+}
+
+impl SessionJournal {
+    /// Create the segment directory and write an empty journal for `session`
+    pub fn create(session: &ActiveRecording) -> NotariResult<Self> {
+        let journal = Self {
+            version: JOURNAL_VERSION,
+            session_id: session.session_id.clone(),
+            window_id: session.window_id.clone(),
+            window_metadata: session.window_metadata.clone(),
+            output_path: session.output_path.clone(),
+            started_at: session.start_time,
+            updated_at: Utc::now(),
+            password_protected: session.encryption_password.is_some(),
+            encryption_method: session.encryption_method.clone(),
+            encryption_recipients: session.encryption_recipients.clone(),
+            recording_title: session.recording_title.clone(),
+            recording_description: session.recording_description.clone(),
+            recording_tags: session.recording_tags.clone(),
+            pauses: Vec::new(),
+            segments: Vec::new(),
+            dropped_segments: Vec::new(),
+        };
+
+        std::fs::create_dir_all(journal.dir())
+            .map_err(|e| NotariError::DirectoryCreationFailed(e.to_string()))?;
+        journal.save()?;
+        Ok(journal)
+    }
+
+    /// Read the journal in segment directory `dir`
+    pub fn load(dir: &Path) -> NotariResult<Self> {
+        let path = dir.join(JOURNAL_FILE);
+        let data = std::fs::read(&path)
+            .map_err(|e| NotariError::StorageLoadFailed(format!("{}: {}", path.display(), e)))?;
+        let journal: Self = serde_json::from_slice(&data)?;
+        if journal.version > JOURNAL_VERSION {
+            return Err(NotariError::StorageLoadFailed(format!(
+                "Unsupported journal version {} in {}",
+                journal.version,
+                path.display()
+            )));
+        }
+        Ok(journal)
+    }
+
+    /// Write the journal, replacing the previous one atomically
+    pub fn save(&self) -> NotariResult<()> {
+        let path = self.dir().join(JOURNAL_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let data = serde_json::to_vec_pretty(self)?;
+        std::fs::write(&tmp_path, data)
+            .and_then(|_| std::fs::rename(&tmp_path, &path))
+            .map_err(|e| NotariError::StorageSaveFailed(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Segment directory holding this journal
+    pub fn dir(&self) -> PathBuf {
+        segment_dir(&self.output_path)
+    }
+
+    /// Record the hashes of segments finished since the last sync and save
+    ///
+    /// The newest segment is still being written while recording, so it is only
+    /// included when `complete` is set after the recorder has exited. Returns the
+    /// number of segments added.
+    pub fn sync(&mut self, complete: bool) -> NotariResult<usize> {
+        let mut files = segment_files(&self.dir())?;
+        if !complete {
+            files.pop();
+        }
+
+        let mut added = 0;
+        for path in files.iter().skip(self.segments.len()) {
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let metadata = std::fs::metadata(path)?;
+            let hash = HashInfo::from_file(path)
+                .map_err(|e| NotariError::HashingFailed(format!("{}: {}", file_name, e)))?;
+            let completed_at = metadata
+                .modified()
+                .map(DateTime::<Utc>::from)
+                .unwrap_or_else(|_| Utc::now());
+
+            self.segments.push(SegmentRecord {
+                index: self.segments.len() as u32,
+                file_name,
+                size: metadata.len(),
+                hash,
+                completed_at,
+            });
+            added += 1;
+        }
+
+        self.updated_at = Utc::now();
+        self.save()?;
+        Ok(added)
+    }
+
+    /// Paths of the journaled segments that are still intact on disk
+    ///
+    /// Stops at the first segment that is missing or no longer matches its
+    /// recorded hash, since later segments can't be joined across the gap.
+    pub fn intact_segments(&self) -> Vec<PathBuf> {
+        let dir = self.dir();
+        self.segments
+            .iter()
+            .map(|segment| (dir.join(&segment.file_name), segment))
+            .take_while(|(path, segment)| {
+                HashInfo::from_file(path).is_ok_and(|hash| hash.value == segment.hash.value)
+            })
+            .map(|(path, _)| path)
+            .collect()
+    }
+
+    /// Move the segments from the first one that isn't intact on disk to
+    /// `dropped_segments` and save
+    ///
+    /// Returns the paths of the segments left, ready to be joined.
+    pub fn drop_damaged_segments(&mut self) -> NotariResult<Vec<PathBuf>> {
+        let intact = self.intact_segments();
+        let dropped = self.segments.split_off(intact.len());
+        self.dropped_segments.extend(dropped);
+        self.save()?;
+        Ok(intact)
+    }
+
+    /// Time covered by the journaled segments, excluding pauses
+    pub fn recorded_duration(&self) -> chrono::Duration {
+        let ended_at = self
+            .segments
+            .last()
+            .map(|segment| segment.completed_at)
+            .unwrap_or(self.started_at);
+        let paused = self
+            .pauses
+            .iter()
+            .filter(|pause| pause.resumed_at <= ended_at)
+            .fold(chrono::Duration::zero(), |total, pause| {
+                total + pause.duration()
+            });
+        (ended_at - self.started_at - paused).max(chrono::Duration::zero())
+    }
+
+    /// Delete the segment directory and everything in it
+    pub fn remove(&self) -> NotariResult<()> {
+        std::fs::remove_dir_all(self.dir())?;
+        Ok(())
+    }
+}
+
+/// Paths of the segment files in segment directory `dir`, in recording order
+pub fn segment_files(dir: &Path) -> NotariResult<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(is_segment_file)
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Segment directories with a journal in `dir`
+pub fn find_journals(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == SEGMENT_DIR_EXTENSION)
+                && path.join(JOURNAL_FILE).is_file()
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording_manager::{ActiveRecordingBuilder, RecordingPreferences};
+    use tempfile::TempDir;
+
+    fn session(dir: &TempDir) -> ActiveRecording {
+        ActiveRecordingBuilder::new("window_1")
+            .output_path(dir.path().join("recording.mov"))
+            .preferences(RecordingPreferences::default())
+            .encryption_password("secret-password")
+            .title("Journaled")
+            .build()
+            .unwrap()
+    }
+
+    fn write_segment(journal: &SessionJournal, index: u32, data: &[u8]) {
+        std::fs::write(journal.dir().join(segment_file_name(index)), data).unwrap();
+    }
+
+    #[test]
+    fn test_create_and_load() {
+        let dir = TempDir::new().unwrap();
+        let session = session(&dir);
+        let journal = SessionJournal::create(&session).unwrap();
+
+        assert_eq!(journal.dir(), dir.path().join("recording.segments"));
+        assert_eq!(find_journals(dir.path()), vec![journal.dir()]);
+
+        let loaded = SessionJournal::load(&journal.dir()).unwrap();
+        assert_eq!(loaded.session_id, session.session_id);
+        assert_eq!(loaded.recording_title.as_deref(), Some("Journaled"));
+        assert!(loaded.password_protected);
+
+        // The password never reaches the disk
+        let raw = std::fs::read_to_string(journal.dir().join(JOURNAL_FILE)).unwrap();
+        assert!(!raw.contains("secret-password"));
+    }
+
+    #[test]
+    fn test_sync_skips_open_segment() {
+        let dir = TempDir::new().unwrap();
+        let mut journal = SessionJournal::create(&session(&dir)).unwrap();
+        write_segment(&journal, 0, b"first");
+        write_segment(&journal, 1, b"second");
+
+        // Segment 1 may still be growing
+        assert_eq!(journal.sync(false).unwrap(), 1);
+        assert_eq!(journal.sync(false).unwrap(), 0);
+        assert_eq!(journal.segments[0].file_name, "segment-00000.mov");
+        assert_eq!(journal.segments[0].size, 5);
+
+        assert_eq!(journal.sync(true).unwrap(), 1);
+        let loaded = SessionJournal::load(&journal.dir()).unwrap();
+        assert_eq!(loaded.segments.len(), 2);
+        assert_eq!(loaded.segments[1].index, 1);
+    }
+
+    #[test]
+    fn test_intact_segments_stop_at_damage() {
+        let dir = TempDir::new().unwrap();
+        let mut journal = SessionJournal::create(&session(&dir)).unwrap();
+        for index in 0..3 {
+            write_segment(&journal, index, format!("segment {}", index).as_bytes());
+        }
+        journal.sync(true).unwrap();
+        assert_eq!(journal.intact_segments().len(), 3);
+
+        write_segment(&journal, 1, b"tampered");
+        assert_eq!(
+            journal.intact_segments(),
+            vec![journal.dir().join(segment_file_name(0))]
+        );
+
+        // Segments after the damaged one are dropped with it
+        assert_eq!(journal.drop_damaged_segments().unwrap().len(), 1);
+        let loaded = SessionJournal::load(&journal.dir()).unwrap();
+        assert_eq!(loaded.segments.len(), 1);
+        let dropped: Vec<u32> = loaded
+            .dropped_segments
+            .iter()
+            .map(|segment| segment.index)
+            .collect();
+        assert_eq!(dropped, [1, 2]);
+
+        journal.remove().unwrap();
+        assert!(find_journals(dir.path()).is_empty());
+    }
+}
@@ -0,0 +1,634 @@
+//! Platform-independent recording lifecycle
+//!
+//! State machine transitions and the post-recording pipeline shared by every
+//! `RecordingManager` implementation. The platform managers only capture video;
+//! the command layer drives these steps around them.
+
+use super::{journal, ActiveRecording, RecordingManager, SessionJournal, SharedRecordingState};
+use crate::error::{NotariError, NotariResult};
+use crate::evidence::checkpoint::CHECKPOINT_LOG_FILE;
+use crate::evidence::{KeyManager, PauseInterval};
+use crate::logger::{LogLevel, LOGGER};
+use crate::pipeline::stages::*;
+use crate::pipeline::{Pipeline, PipelineContext};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// Build the post-recording pipeline
+///
+/// Manifests are signed with `signing_key` if given, otherwise with the keychain
+/// signing key.
+pub fn post_recording_pipeline(signing_key: Option<KeyManager>) -> Pipeline {
+    let sign_stage = match signing_key {
+        Some(key) => SignStage::with_key(key),
+        None => SignStage::new(),
+    };
+
+    Pipeline::builder("post-recording")
+        .add_stage(HashStage::new())
+        .add_stage(EncryptStage::new())
+        .add_stage(ManifestStage::new_auto())
+        .add_stage(sign_stage)
+        .add_stage(PackageStage::new())
+        .add_stage(CleanupStage::new())
+        .build()
+}
+
+/// Create the post-recording pipeline context for the active session
+///
+/// Sets `video_path`, `file_size`, `duration` and `start_time`, plus encryption
+/// settings, window metadata, custom metadata, `pauses`, and `checkpoint_log_path`
+/// with `joined_segments`, `dropped_segments` and `segment_paths` when the session
+/// has them.
+/// `duration` excludes time spent paused.
+pub fn pipeline_context(
+    session_id: &str,
+    state: SharedRecordingState,
+) -> NotariResult<PipelineContext> {
+    // Get recording info first (before transitioning to Processing)
+    let (session, duration, pauses) = {
+        let state_guard = state.lock()?;
+        if let Some(ref recording) = state_guard.active_recording {
+            if recording.session.session_id != session_id {
+                return Err(NotariError::SessionNotFound(session_id.to_string()));
+            }
+
+            (
+                recording.session.clone(),
+                recording.recorded_duration(),
+                recording.state_machine.pauses().to_vec(),
+            )
+        } else {
+            return Err(NotariError::NoActiveRecording);
+        }
+    };
+
+    session_context(&session, duration, &pauses)
+}
+
+/// Create the post-recording pipeline context for `session`
+///
+/// `duration` is the time captured, excluding `pauses`.
+pub fn session_context(
+    session: &ActiveRecording,
+    duration: chrono::Duration,
+    pauses: &[PauseInterval],
+) -> NotariResult<PipelineContext> {
+    let session_id = session.session_id.as_str();
+    let video_path = session.output_path.clone();
+    let password = session.encryption_password.clone();
+    let encryption_method = session.encryption_method.clone();
+    let encryption_recipients = session.encryption_recipients.clone();
+    let start_time = session.start_time;
+    let file_size = std::fs::metadata(&video_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    let duration = duration.num_seconds().max(0) as f64;
+    let window_metadata = session.window_metadata.clone();
+    let custom_title = session.recording_title.clone();
+    let custom_description = session.recording_description.clone();
+    let custom_tags = session.recording_tags.clone();
+
+    let mut context = PipelineContext::new(session_id);
+    context.set_path("video_path", video_path);
+    context.set_number("file_size", file_size as f64);
+    context.set_number("duration", duration);
+    context.set_string("start_time", start_time.to_rfc3339());
+
+    // Add encryption settings if provided
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Setting encryption context - password: {}, method: {:?}, recipients: {}",
+            password.is_some(),
+            encryption_method,
+            encryption_recipients.as_ref().map(|r| r.len()).unwrap_or(0)
+        ),
+        "recording_manager",
+    );
+
+    if let Some(ref pwd) = password {
+        context.set_string("password", pwd);
+        LOGGER.log(
+            LogLevel::Info,
+            "Set password in context",
+            "recording_manager",
+        );
+    }
+    if let Some(ref method) = encryption_method {
+        context.set_string("encryption_method", method);
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Set encryption_method in context: {}", method),
+            "recording_manager",
+        );
+    }
+    if let Some(ref recipients) = encryption_recipients {
+        // Convert recipients to JSON array
+        let recipients_json: Vec<serde_json::Value> = recipients
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "id": r.id,
+                    "publicKey": r.public_key
+                })
+            })
+            .collect();
+        let recipients_count = recipients_json.len();
+        let recipients_value = serde_json::Value::Array(recipients_json);
+        context.set("recipients", recipients_value);
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Set {} recipients in context", recipients_count),
+            "recording_manager",
+        );
+    }
+
+    // Add window metadata if available
+    if let Some(ref metadata) = window_metadata {
+        let metadata_json = serde_json::to_value(metadata).map_err(|e| {
+            NotariError::PipelineError(format!("Failed to serialize window metadata: {}", e))
+        })?;
+        context.set("window_metadata", metadata_json);
+    }
+
+    // Add custom metadata if provided
+    if let Some(title) = custom_title {
+        context.set_string("custom_title", title);
+    }
+    if let Some(description) = custom_description {
+        context.set_string("custom_description", description);
+    }
+    if let Some(tags) = custom_tags {
+        let tags_json = serde_json::to_value(&tags)
+            .map_err(|e| NotariError::PipelineError(format!("Failed to serialize tags: {}", e)))?;
+        context.set("custom_tags", tags_json);
+    }
+
+    // Add pause intervals so the manifest accounts for gaps in the video
+    if !pauses.is_empty() {
+        let pauses_json = serde_json::to_value(pauses).map_err(|e| {
+            NotariError::PipelineError(format!("Failed to serialize pauses: {}", e))
+        })?;
+        context.set("pauses", pauses_json);
+    }
+
+    // Add the checkpoint log so it is committed to and packed, along with the
+    // segments joined into the video and dropped for the verifier to match against it.
+    // The joined segments are packed too, since the video's bytes don't match them
+    let segment_dir = journal::segment_dir(&session.output_path);
+    let checkpoint_log_path = segment_dir.join(CHECKPOINT_LOG_FILE);
+    if checkpoint_log_path.is_file() {
+        context.set_path("checkpoint_log_path", checkpoint_log_path);
+
+        let journal = SessionJournal::load(&segment_dir)?;
+        let hashes = |segments: &[journal::SegmentRecord]| -> Vec<String> {
+            segments
+                .iter()
+                .map(|segment| segment.hash.value.clone())
+                .collect()
+        };
+        context.set(
+            "joined_segments",
+            serde_json::json!(hashes(&journal.segments)),
+        );
+        context.set(
+            "dropped_segments",
+            serde_json::json!(hashes(&journal.dropped_segments)),
+        );
+        let segment_paths: Vec<PathBuf> = journal
+            .segments
+            .iter()
+            .map(|segment| segment_dir.join(&segment.file_name))
+            .collect();
+        context.set("segment_paths", serde_json::json!(segment_paths));
+    }
+
+    Ok(context)
+}
+
+/// Process a completed recording using the pipeline pattern
+///
+/// This should be called after `RecordingManager::stop_recording()` to perform:
+/// 1. Hash calculation
+/// 2. Encryption (if password provided)
+/// 3. Manifest generation
+/// 4. Signing (with `signing_key`, or the keychain key if `None`)
+/// 5. Packaging into .notari proof pack
+/// 6. Cleanup of temporary files
+///
+/// Events are emitted to the frontend for progress tracking.
+pub fn process_recording(
+    session_id: &str,
+    state: SharedRecordingState,
+    signing_key: Option<KeyManager>,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Starting post-processing for recording: {}", session_id),
+        "recording_manager",
+    );
+
+    let mut context = pipeline_context(session_id, state.clone())?;
+    let video_path = context.get_path("video_path")?;
+    let file_size = context.get_number("file_size")? as u64;
+    let duration = context.get_number("duration")?;
+    let encrypted = context.has("password");
+
+    // Verify video file exists
+    if !video_path.exists() {
+        let error_msg = format!("Video file not found: {:?}", video_path);
+        LOGGER.log(LogLevel::Error, &error_msg, "recording_manager");
+        transition_to_failed(session_id, state.clone(), error_msg.clone(), app)?;
+        return Err(NotariError::FileNotFound(
+            video_path.to_string_lossy().to_string(),
+        ));
+    }
+
+    LOGGER.log(
+        LogLevel::Debug,
+        &format!(
+            "Video file exists: {:?} (size: {} bytes)",
+            video_path, file_size
+        ),
+        "recording_manager",
+    );
+
+    let pipeline = post_recording_pipeline(signing_key);
+
+    // Transition state machine: Stopping → Processing (with actual file size and duration)
+    transition_to_processing(session_id, state.clone(), file_size, duration, app)?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!("Transitioned to Processing state and executing pipeline for session: {} (file_size: {}, duration: {})", session_id, file_size, duration),
+        "recording_manager",
+    );
+
+    // Execute pipeline with events
+    let result = pipeline.execute_with_events(&mut context, app)?;
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Pipeline execution completed for session: {} (success: {})",
+            session_id, result.success
+        ),
+        "recording_manager",
+    );
+
+    if result.success {
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Post-processing completed successfully for recording: {} (duration: {:.2}s)",
+                session_id,
+                result.total_duration.as_secs_f64()
+            ),
+            "recording_manager",
+        );
+
+        // Get proof pack path and hash from context
+        let proof_pack_path = context
+            .get_path("proof_pack_path")
+            .unwrap_or_else(|_| video_path.with_extension("notari"));
+        let plaintext_hash = context
+            .get_string("plaintext_hash")
+            .unwrap_or_else(|_| "unknown".to_string());
+
+        // Transition state machine: Processing → Completed
+        transition_to_completed(
+            session_id,
+            state.clone(),
+            proof_pack_path,
+            plaintext_hash,
+            encrypted,
+            app,
+        )?;
+
+        // The proof pack supersedes the segments kept for crash recovery
+        remove_segments(&video_path);
+
+        Ok(())
+    } else {
+        let error_msg = result.error.unwrap_or_else(|| "Unknown error".to_string());
+        LOGGER.log(
+            LogLevel::Error,
+            &format!(
+                "Post-processing failed for recording {}: {}",
+                session_id, error_msg
+            ),
+            "recording_manager",
+        );
+
+        // Transition state machine: Processing → Failed
+        transition_to_failed(session_id, state.clone(), error_msg.clone(), app)?;
+
+        Err(NotariError::PipelineError(error_msg))
+    }
+}
+
+/// Start the crash recovery journal for the active session
+///
+/// Call once the session's encryption settings and metadata are final, since the
+/// journal records them for recovery.
+pub fn open_journal(session_id: &str, state: SharedRecordingState) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    let recording = state_guard
+        .active_recording
+        .as_mut()
+        .ok_or(NotariError::NoActiveRecording)?;
+    if recording.session.session_id != session_id {
+        return Err(NotariError::SessionNotFound(session_id.to_string()));
+    }
+
+    recording.journal = Some(SessionJournal::create(&recording.session)?);
+    Ok(())
+}
+
+/// Record segments finished since the last sync, and the pauses so far, in the
+/// active session's journal
+pub fn sync_journal(state: SharedRecordingState) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    let Some(ref mut recording) = state_guard.active_recording else {
+        return Ok(());
+    };
+    if !recording.state_machine.is_active() {
+        return Ok(());
+    }
+    let pauses = recording.state_machine.pauses().to_vec();
+    if let Some(ref mut journal) = recording.journal {
+        journal.pauses = pauses;
+        journal.sync(false)?;
+    }
+    Ok(())
+}
+
+/// Record every remaining segment in the active session's journal
+///
+/// Call once the recorder has exited when the session won't be processed now,
+/// such as on app shutdown, so recovery on the next start keeps the last segment
+/// and checkpoint.
+pub fn close_journal(state: SharedRecordingState) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    let Some(ref mut recording) = state_guard.active_recording else {
+        return Ok(());
+    };
+    let pauses = recording.state_machine.pauses().to_vec();
+    if let Some(ref mut journal) = recording.journal {
+        journal.pauses = pauses;
+        journal.sync(true)?;
+        if let Some(ref mut checkpoints) = recording.checkpoints {
+            checkpoints.close(journal)?;
+        }
+    }
+    Ok(())
+}
+
+/// Join the active session's segments into its output file
+///
+/// Call after `RecordingManager::stop_recording()` once the recorder has exited.
+/// The session's last checkpoint is taken here. Segments that no longer match the
+/// journal are left out, along with everything after them, and recorded as
+/// dropped in the journal so the manifest lists them. Does nothing if the
+/// recorder wrote no segments.
+pub fn finish_segments(
+    manager: &dyn RecordingManager,
+    session_id: &str,
+    state: SharedRecordingState,
+) -> NotariResult<()> {
+    let (output_path, segments) = {
+        let mut state_guard = state.lock()?;
+        let recording = state_guard
+            .active_recording
+            .as_mut()
+            .ok_or(NotariError::NoActiveRecording)?;
+        if recording.session.session_id != session_id {
+            return Err(NotariError::SessionNotFound(session_id.to_string()));
+        }
+
+        let output_path = recording.session.output_path.clone();
+        let segment_dir = journal::segment_dir(&output_path);
+        let segments = match recording.journal {
+            Some(ref mut journal) => {
+                journal.pauses = recording.state_machine.pauses().to_vec();
+                journal.sync(true)?;
+                if let Some(ref mut checkpoints) = recording.checkpoints {
+                    if let Err(e) = checkpoints.close(journal) {
+                        LOGGER.log(
+                            LogLevel::Warn,
+                            &format!("Failed to take the last checkpoint: {}", e),
+                            "recording_manager",
+                        );
+                    }
+                }
+                let intact = journal.drop_damaged_segments()?;
+                if !journal.dropped_segments.is_empty() {
+                    LOGGER.log(
+                        LogLevel::Warn,
+                        &format!(
+                            "Only {} of {} segments match the journal for session {}",
+                            intact.len(),
+                            intact.len() + journal.dropped_segments.len(),
+                            session_id
+                        ),
+                        "recording_manager",
+                    );
+                }
+                intact
+            }
+            None if segment_dir.is_dir() => journal::segment_files(&segment_dir)?,
+            None => Vec::new(),
+        };
+        (output_path, segments)
+    };
+
+    if segments.is_empty() {
+        return Ok(());
+    }
+
+    LOGGER.log(
+        LogLevel::Info,
+        &format!(
+            "Joining {} segments into {}",
+            segments.len(),
+            output_path.display()
+        ),
+        "recording_manager",
+    );
+    manager.join_segments(&segments, &output_path)
+}
+
+/// Delete the segment directory of a recording written to `output_path`, if any
+fn remove_segments(output_path: &Path) {
+    let segment_dir = journal::segment_dir(output_path);
+    if !segment_dir.is_dir() {
+        return;
+    }
+    if let Err(e) = std::fs::remove_dir_all(&segment_dir) {
+        LOGGER.log(
+            LogLevel::Warn,
+            &format!("Failed to remove segments {}: {}", segment_dir.display(), e),
+            "recording_manager",
+        );
+    }
+}
+
+/// Transition to Preparing state
+pub fn transition_to_preparing(
+    session_id: &str,
+    state: SharedRecordingState,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .prepare(app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Recording state
+pub fn transition_to_recording(
+    session_id: &str,
+    state: SharedRecordingState,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            let output_path = recording.session.output_path.clone();
+            let process_id = recording.process.as_ref().map(|p| p.id()).unwrap_or(0);
+            let encryption_password = recording.session.encryption_password.clone();
+
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .start(output_path, process_id, encryption_password, app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Paused state
+pub fn transition_to_paused(
+    session_id: &str,
+    state: SharedRecordingState,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .pause(app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition from Paused back to Recording state
+pub fn transition_to_resumed(
+    session_id: &str,
+    state: SharedRecordingState,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .resume(app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Stopping state
+pub fn transition_to_stopping(
+    session_id: &str,
+    state: SharedRecordingState,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .stop(app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Processing state
+pub fn transition_to_processing(
+    session_id: &str,
+    state: SharedRecordingState,
+    file_size: u64,
+    duration: f64,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .process(file_size, duration, app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Completed state
+pub fn transition_to_completed(
+    session_id: &str,
+    state: SharedRecordingState,
+    proof_pack_path: PathBuf,
+    plaintext_hash: String,
+    encrypted: bool,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording
+                .state_machine
+                .clone()
+                .complete(proof_pack_path, plaintext_hash, encrypted, app)
+                .map_err(NotariError::StateTransitionFailed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Transition to Failed state
+pub fn transition_to_failed(
+    session_id: &str,
+    state: SharedRecordingState,
+    error: String,
+    app: &AppHandle,
+) -> NotariResult<()> {
+    let mut state_guard = state.lock()?;
+    if let Some(ref mut recording) = state_guard.active_recording {
+        if recording.session.session_id == session_id {
+            recording.state_machine = recording.state_machine.clone().fail(error.clone(), app);
+        }
+    }
+    Ok(())
+}
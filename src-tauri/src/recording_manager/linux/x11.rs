@@ -0,0 +1,330 @@
+//! X11 window capture
+//!
+//! The window is redirected off-screen with XComposite, so its contents can be
+//! read even while other windows overlap it. Each frame is fetched with
+//! `GetImage` from the window's composite pixmap and written as raw BGRX to the
+//! encoder's stdin at a fixed frame rate.
+
+use crate::error::{NotariError, NotariResult};
+use std::io::Write;
+use std::process::ChildStdin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::composite::{ConnectionExt as _, Redirect};
+use x11rb::protocol::xproto::{ConnectionExt as _, ImageFormat, MapState, Pixmap, Window};
+use x11rb::protocol::ErrorKind;
+use x11rb::rust_connection::RustConnection;
+
+/// Bytes per pixel of the raw frames (BGRX)
+const BYTES_PER_PIXEL: usize = 4;
+
+/// A window prepared for capture
+pub struct X11Source {
+    conn: RustConnection,
+    window: Window,
+    pixmap: Pixmap,
+    width: u32,
+    height: u32,
+}
+
+impl X11Source {
+    /// Connect to the X server and redirect `window` for capture
+    pub fn open(window: Window) -> NotariResult<Self> {
+        let (conn, _) = x11rb::connect(None).map_err(|e| {
+            NotariError::RecordingStartFailed(format!("Failed to connect to X11: {}", e))
+        })?;
+
+        conn.composite_query_version(0, 4)
+            .map_err(|e| composite_error(&e))?
+            .reply()
+            .map_err(|e| composite_error(&e))?;
+
+        let geometry = conn
+            .get_geometry(window)
+            .map_err(|e| window_error(window, &e))?
+            .reply()
+            .map_err(|e| window_error(window, &e))?;
+
+        conn.composite_redirect_window(window, Redirect::AUTOMATIC)
+            .map_err(|e| composite_error(&e))?
+            .check()
+            .map_err(|e| composite_error(&e))?;
+
+        let pixmap = conn.generate_id().map_err(|e| {
+            NotariError::RecordingStartFailed(format!("Failed to allocate pixmap: {}", e))
+        })?;
+
+        // Most encoders require even dimensions for 4:2:0 chroma subsampling
+        let (width, height) = even_frame_size(geometry.width.into(), geometry.height.into());
+
+        Ok(Self {
+            conn,
+            window,
+            pixmap,
+            width,
+            height,
+        })
+    }
+
+    /// Size of the frames written to the encoder
+    pub fn frame_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Start capturing on a background thread, writing frames to `encoder`
+    pub fn start(self, encoder: ChildStdin, fps: u32) -> X11Capture {
+        let stop = Arc::new(AtomicBool::new(false));
+        let paused = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let stop = stop.clone();
+            let paused = paused.clone();
+            std::thread::spawn(move || self.run(encoder, fps, &stop, &paused))
+        };
+
+        X11Capture {
+            stop,
+            paused,
+            thread,
+        }
+    }
+
+    /// Capture loop; returns when stopped, or with an error if the window goes away
+    /// or the encoder stops reading
+    fn run(
+        self,
+        mut encoder: ChildStdin,
+        fps: u32,
+        stop: &AtomicBool,
+        paused: &AtomicBool,
+    ) -> Result<(), String> {
+        let interval = Duration::from_secs(1) / fps.max(1);
+        let mut canvas = vec![0u8; self.width as usize * self.height as usize * BYTES_PER_PIXEL];
+        let mut next_frame = Instant::now();
+
+        while !stop.load(Ordering::Relaxed) {
+            if paused.load(Ordering::Relaxed) {
+                // Paused time is simply not encoded; restart the clock on resume
+                std::thread::sleep(interval);
+                next_frame = Instant::now();
+                continue;
+            }
+
+            // A minimized or unmapped window keeps its last frame
+            self.grab(&mut canvas)?;
+            write_frame(&mut encoder, &canvas)?;
+            next_frame += interval;
+
+            let now = Instant::now();
+            if next_frame > now {
+                std::thread::sleep(next_frame - now);
+            } else {
+                // Fell behind (slow grab); repeat the frame so the video keeps real time
+                while next_frame + interval <= now {
+                    write_frame(&mut encoder, &canvas)?;
+                    next_frame += interval;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy the window contents into `canvas`
+    ///
+    /// Returns `Ok(false)` if the window is not currently viewable.
+    fn grab(&self, canvas: &mut [u8]) -> Result<bool, String> {
+        let window = self.window;
+        let gone = |e: ReplyError| match e {
+            ReplyError::X11Error(ref error)
+                if matches!(error.error_kind, ErrorKind::Window | ErrorKind::Drawable) =>
+            {
+                format!("Window {} was closed", window)
+            }
+            e => format!("Failed to capture window {}: {}", window, e),
+        };
+
+        let attributes = self
+            .conn
+            .get_window_attributes(window)
+            .map_err(|e| gone(e.into()))?
+            .reply()
+            .map_err(gone)?;
+        if attributes.map_state != MapState::VIEWABLE {
+            return Ok(false);
+        }
+        let geometry = self
+            .conn
+            .get_geometry(window)
+            .map_err(|e| gone(e.into()))?
+            .reply()
+            .map_err(gone)?;
+
+        // The composite pixmap includes the border; a new one is named for each frame
+        // because it is replaced whenever the window is resized
+        self.conn
+            .composite_name_window_pixmap(window, self.pixmap)
+            .map_err(|e| gone(e.into()))?;
+        let image = self
+            .conn
+            .get_image(
+                ImageFormat::Z_PIXMAP,
+                self.pixmap,
+                geometry.border_width as i16,
+                geometry.border_width as i16,
+                geometry.width,
+                geometry.height,
+                !0,
+            )
+            .map_err(|e| gone(e.into()))?
+            .reply();
+        let _ = self.conn.free_pixmap(self.pixmap);
+
+        let image = match image {
+            Ok(image) => image,
+            // Unmapped between the attribute query and the grab
+            Err(ReplyError::X11Error(ref error))
+                if matches!(error.error_kind, ErrorKind::Match) =>
+            {
+                return Ok(false)
+            }
+            Err(e) => return Err(gone(e)),
+        };
+
+        let (width, height) = (geometry.width as usize, geometry.height as usize);
+        if image.data.len() != width * height * BYTES_PER_PIXEL {
+            return Err(format!(
+                "Unsupported window pixel format (depth {})",
+                image.depth
+            ));
+        }
+
+        blit(
+            canvas,
+            self.width as usize,
+            self.height as usize,
+            &image.data,
+            width,
+            height,
+        );
+        Ok(true)
+    }
+}
+
+impl Drop for X11Source {
+    fn drop(&mut self) {
+        let _ = self
+            .conn
+            .composite_unredirect_window(self.window, Redirect::AUTOMATIC);
+        let _ = self.conn.flush();
+    }
+}
+
+/// Handle to a running capture thread
+pub struct X11Capture {
+    stop: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    thread: JoinHandle<Result<(), String>>,
+}
+
+impl X11Capture {
+    /// Stop or resume writing frames; paused time is left out of the video
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// Whether the capture thread has exited on its own
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Stop capturing and wait for the thread; closing the encoder's stdin lets it
+    /// finalize the file
+    pub fn finish(self) -> Result<(), String> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.thread
+            .join()
+            .unwrap_or_else(|_| Err("Capture thread panicked".to_string()))
+    }
+}
+
+fn write_frame(encoder: &mut ChildStdin, frame: &[u8]) -> Result<(), String> {
+    encoder
+        .write_all(frame)
+        .map_err(|e| format!("Encoder stopped accepting frames: {}", e))
+}
+
+fn composite_error(e: &dyn std::fmt::Display) -> NotariError {
+    NotariError::RecordingStartFailed(format!("XComposite is not available: {}", e))
+}
+
+fn window_error(window: Window, e: &dyn std::fmt::Display) -> NotariError {
+    NotariError::InvalidWindowId(format!("x11_{} ({})", window, e))
+}
+
+/// Round a window size down to even dimensions of at least 2x2
+fn even_frame_size(width: u32, height: u32) -> (u32, u32) {
+    ((width & !1).max(2), (height & !1).max(2))
+}
+
+/// Copy `image` into the top-left corner of `canvas`
+///
+/// Both are tightly packed BGRX. If the window was resized the image is cropped
+/// and any uncovered area is black.
+fn blit(
+    canvas: &mut [u8],
+    canvas_width: usize,
+    canvas_height: usize,
+    image: &[u8],
+    width: usize,
+    height: usize,
+) {
+    if (width, height) != (canvas_width, canvas_height) {
+        canvas.fill(0);
+    }
+
+    let row_bytes = width.min(canvas_width) * BYTES_PER_PIXEL;
+    for y in 0..height.min(canvas_height) {
+        let src = y * width * BYTES_PER_PIXEL;
+        let dst = y * canvas_width * BYTES_PER_PIXEL;
+        canvas[dst..dst + row_bytes].copy_from_slice(&image[src..src + row_bytes]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_even_frame_size() {
+        assert_eq!(even_frame_size(1920, 1080), (1920, 1080));
+        assert_eq!(even_frame_size(801, 601), (800, 600));
+        assert_eq!(even_frame_size(1, 0), (2, 2));
+    }
+
+    #[test]
+    fn test_blit_crops_and_pads() {
+        // 2x2 canvas, 3x1 image: first row takes two pixels, second row is black
+        let mut canvas = vec![0xffu8; 2 * 2 * BYTES_PER_PIXEL];
+        let image: Vec<u8> = (0..3 * BYTES_PER_PIXEL as u8).collect();
+
+        blit(&mut canvas, 2, 2, &image, 3, 1);
+
+        assert_eq!(&canvas[..8], &image[..8]);
+        assert!(canvas[8..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_blit_same_size_copies_everything() {
+        let mut canvas = vec![0u8; 2 * 2 * BYTES_PER_PIXEL];
+        let image: Vec<u8> = (0..canvas.len() as u8).collect();
+
+        blit(&mut canvas, 2, 2, &image, 2, 2);
+
+        assert_eq!(canvas, image);
+    }
+}
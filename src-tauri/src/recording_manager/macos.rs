@@ -1,6 +1,6 @@
 use super::{
-    ActiveRecording, InternalRecordingState, RecordingInfo, RecordingManager, RecordingPreferences,
-    SharedRecordingState,
+    journal, ActiveRecording, InternalRecordingState, RecordingInfo, RecordingManager,
+    RecordingPreferences, SharedRecordingState,
 };
 use crate::error::{NotariError, NotariResult};
 use crate::evidence::keychain;
@@ -9,16 +9,17 @@ use crate::evidence::{
     WindowInfo as EvidenceWindowInfo,
 };
 use crate::logger::{LogLevel, LOGGER};
-use crate::pipeline::stages::*;
-use crate::pipeline::{Pipeline, PipelineContext};
-use std::io::BufRead;
-use std::path::PathBuf;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
-use tauri::AppHandle;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use uuid::Uuid;
 
+/// How long the sidecar may take to finish the last segment after stdin closes
+const SIDECAR_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct MacOSRecordingManager;
 
 impl MacOSRecordingManager {
@@ -134,6 +135,19 @@ impl MacOSRecordingManager {
         }
     }
 
+    /// Wait for the sidecar to exit on its own; false if it is still running
+    fn wait_for_exit(&self, process: &mut Child) -> bool {
+        let deadline = Instant::now() + SIDECAR_SHUTDOWN_TIMEOUT;
+        while Instant::now() < deadline {
+            match process.try_wait() {
+                Ok(Some(_)) => return true,
+                Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+                Err(_) => return false,
+            }
+        }
+        false
+    }
+
     /// Terminate recording process gracefully
     fn terminate_process(&self, process: &mut Child) -> NotariResult<()> {
         LOGGER.log(LogLevel::Info, "Terminating recording process", "backend");
@@ -160,6 +174,48 @@ impl MacOSRecordingManager {
         Ok(())
     }
 
+    /// Send a control command (`pause` or `resume`) to the SCK sidecar
+    ///
+    /// The sidecar reads one command per line from stdin; EOF stops the recording.
+    fn send_sidecar_command(
+        &self,
+        session_id: &str,
+        state: SharedRecordingState,
+        command: &str,
+    ) -> NotariResult<()> {
+        let mut state_guard = state.lock()?;
+        let recording = state_guard
+            .active_recording
+            .as_mut()
+            .ok_or(NotariError::NoActiveRecording)?;
+        if recording.session.session_id != session_id {
+            return Err(NotariError::SessionNotFound(session_id.to_string()));
+        }
+
+        let stdin = recording
+            .process
+            .as_mut()
+            .and_then(|child| child.stdin.as_mut())
+            .ok_or(NotariError::ProcessNotRunning)?;
+        writeln!(stdin, "{}", command)
+            .and_then(|_| stdin.flush())
+            .map_err(|e| {
+                let error_msg = format!("Failed to send '{}' to sidecar: {}", command, e);
+                LOGGER.log(LogLevel::Error, &error_msg, "backend");
+                NotariError::SidecarError(error_msg)
+            })?;
+
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Sent '{}' to sidecar for recording: {}",
+                command, session_id
+            ),
+            "recording_manager",
+        );
+        Ok(())
+    }
+
     /// Get file size of recording output
     fn get_file_size(&self, path: &PathBuf) -> Option<u64> {
         std::fs::metadata(path).ok().map(|metadata| metadata.len())
@@ -237,21 +293,25 @@ impl RecordingManager for MacOSRecordingManager {
 
         let session = builder.build()?;
 
-        // Try Swift sidecar (ScreenCaptureKit) first
+        // Try Swift sidecar (ScreenCaptureKit) first; it writes rolling segments
         let sidecar_path = self.resolve_sidecar_path();
+        let segment_dir = journal::segment_dir(&output_path);
+        std::fs::create_dir_all(&segment_dir)
+            .map_err(|e| NotariError::DirectoryCreationFailed(e.to_string()))?;
         LOGGER.log(
             LogLevel::Info,
             &format!(
                 "Spawning SCK sidecar: {:?} {} {}",
                 sidecar_path,
                 cg_window_id,
-                output_path.to_string_lossy()
+                segment_dir.to_string_lossy()
             ),
             "backend",
         );
         let mut cmd = Command::new(&sidecar_path);
         cmd.arg(format!("{}", cg_window_id))
-            .arg(output_path.to_string_lossy().to_string())
+            .arg(segment_dir.to_string_lossy().to_string())
+            .arg(journal::SEGMENT_DURATION.as_secs().to_string())
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
@@ -302,6 +362,8 @@ impl RecordingManager for MacOSRecordingManager {
                     process: Some(child),
                     last_health_check: Utc::now(),
                     state_machine,
+                    journal: None,
+                    checkpoints: None,
                 });
                 LOGGER.log(
                     LogLevel::Info,
@@ -343,29 +405,30 @@ impl RecordingManager for MacOSRecordingManager {
 
             // Stop backend
             if let Some(mut child) = recording.process.take() {
-                // If it is the SCK sidecar, close stdin to signal graceful stop, then kill if needed
-                if let Some(stdin) = child.stdin.take() {
-                    drop(stdin); // EOF to sidecar => stop
-                                 // give it a moment to finalize
-                    std::thread::sleep(std::time::Duration::from_millis(300));
-                }
+                // If it is the SCK sidecar, close stdin to signal graceful stop and let it
+                // finish the last segment, then kill if needed
+                let exited = match child.stdin.take() {
+                    Some(stdin) => {
+                        drop(stdin); // EOF to sidecar => stop
+                        self.wait_for_exit(&mut child)
+                    }
+                    None => false,
+                };
                 // Ensure termination for both sidecar and screencapture fallback
-                self.terminate_process(&mut child)?;
+                if !exited {
+                    self.terminate_process(&mut child)?;
+                }
                 // child dropped here; process cleared to avoid repeated health logs
             }
 
-            // Log final file information
-            if let Some(file_size) = self.get_file_size(&recording.session.output_path) {
-                LOGGER.log(
-                    LogLevel::Info,
-                    &format!(
-                        "Recording completed. File: {}, Size: {} bytes",
-                        recording.session.output_path.display(),
-                        file_size
-                    ),
-                    "recording_manager",
-                );
-            }
+            LOGGER.log(
+                LogLevel::Info,
+                &format!(
+                    "Recording completed. Segments: {}",
+                    journal::segment_dir(&recording.session.output_path).display()
+                ),
+                "recording_manager",
+            );
 
             // Note: Post-processing (hashing, encryption, manifest, packaging) is now handled
             // by the process_recording() method which uses the pipeline pattern
@@ -386,16 +449,12 @@ impl RecordingManager for MacOSRecordingManager {
         Ok(())
     }
 
-    fn pause_recording(&self, _session_id: &str, _state: SharedRecordingState) -> NotariResult<()> {
-        Err(NotariError::PlatformNotSupported)
+    fn pause_recording(&self, session_id: &str, state: SharedRecordingState) -> NotariResult<()> {
+        self.send_sidecar_command(session_id, state, "pause")
     }
 
-    fn resume_recording(
-        &self,
-        _session_id: &str,
-        _state: SharedRecordingState,
-    ) -> NotariResult<()> {
-        Err(NotariError::PlatformNotSupported)
+    fn resume_recording(&self, session_id: &str, state: SharedRecordingState) -> NotariResult<()> {
+        self.send_sidecar_command(session_id, state, "resume")
     }
 
     fn get_recording_info(
@@ -485,6 +544,25 @@ impl RecordingManager for MacOSRecordingManager {
         Ok(())
     }
 
+    fn join_segments(&self, segments: &[PathBuf], output_path: &Path) -> NotariResult<()> {
+        let output = Command::new(self.resolve_sidecar_path())
+            .arg("--concat")
+            .arg(output_path)
+            .args(segments)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| {
+                NotariError::SidecarError(format!("Failed to spawn SCK sidecar: {}", e))
+            })?;
+        if !output.status.success() {
+            return Err(NotariError::RecordingStopFailed(format!(
+                "Failed to join segments: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(())
+    }
+
     fn get_default_save_directory(&self) -> NotariResult<PathBuf> {
         // Use ~/Movies/Notari as default
         let home_dir = dirs::home_dir().ok_or_else(|| {
@@ -516,380 +594,8 @@ impl RecordingManager for MacOSRecordingManager {
     }
 }
 
-// Public methods for post-processing
-impl MacOSRecordingManager {
-    /// Process a completed recording using the pipeline pattern
-    ///
-    /// This method should be called after stop_recording() to perform:
-    /// 1. Hash calculation
-    /// 2. Encryption (if password provided)
-    /// 3. Manifest generation
-    /// 4. Signing
-    /// 5. Packaging into .notari proof pack
-    /// 6. Cleanup of temporary files
-    ///
-    /// Events are emitted to the frontend for progress tracking.
-    pub fn process_recording(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        app: &AppHandle,
-    ) -> NotariResult<()> {
-        LOGGER.log(
-            LogLevel::Info,
-            &format!("Starting post-processing for recording: {}", session_id),
-            "recording_manager",
-        );
-
-        // Get recording info first (before transitioning to Processing)
-        let (
-            video_path,
-            password,
-            encryption_method,
-            encryption_recipients,
-            start_time,
-            file_size,
-            duration,
-            window_metadata,
-            custom_title,
-            custom_description,
-            custom_tags,
-        ) = {
-            let state_guard = state.lock()?;
-            if let Some(ref recording) = state_guard.active_recording {
-                if recording.session.session_id != session_id {
-                    return Err(NotariError::SessionNotFound(session_id.to_string()));
-                }
-
-                let file_size = self
-                    .get_file_size(&recording.session.output_path)
-                    .unwrap_or(0);
-                let duration = self.calculate_duration(recording.session.start_time) as f64;
-
-                (
-                    recording.session.output_path.clone(),
-                    recording.session.encryption_password.clone(),
-                    recording.session.encryption_method.clone(),
-                    recording.session.encryption_recipients.clone(),
-                    recording.session.start_time,
-                    file_size,
-                    duration,
-                    recording.session.window_metadata.clone(),
-                    recording.session.recording_title.clone(),
-                    recording.session.recording_description.clone(),
-                    recording.session.recording_tags.clone(),
-                )
-            } else {
-                return Err(NotariError::NoActiveRecording);
-            }
-        };
-
-        // Verify video file exists
-        if !video_path.exists() {
-            let error_msg = format!("Video file not found: {:?}", video_path);
-            LOGGER.log(LogLevel::Error, &error_msg, "recording_manager");
-            self.transition_to_failed(session_id, state.clone(), error_msg.clone(), app)?;
-            return Err(NotariError::FileNotFound(
-                video_path.to_string_lossy().to_string(),
-            ));
-        }
-
-        LOGGER.log(
-            LogLevel::Debug,
-            &format!(
-                "Video file exists: {:?} (size: {} bytes)",
-                video_path, file_size
-            ),
-            "recording_manager",
-        );
-
-        // Build pipeline
-        let pipeline = Pipeline::builder("post-recording")
-            .add_stage(HashStage::new())
-            .add_stage(EncryptStage::new())
-            .add_stage(ManifestStage::new_auto())
-            .add_stage(SignStage::new())
-            .add_stage(PackageStage::new())
-            .add_stage(CleanupStage::new())
-            .build();
-
-        // Create context
-        let mut context = PipelineContext::new(session_id);
-        context.set_path("video_path", video_path.clone());
-        context.set_number("file_size", file_size as f64);
-        context.set_number("duration", duration);
-        context.set_string("start_time", start_time.to_rfc3339());
-
-        // Add encryption settings if provided
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "Setting encryption context - password: {}, method: {:?}, recipients: {}",
-                password.is_some(),
-                encryption_method,
-                encryption_recipients.as_ref().map(|r| r.len()).unwrap_or(0)
-            ),
-            "recording_manager",
-        );
-
-        if let Some(ref pwd) = password {
-            context.set_string("password", pwd);
-            LOGGER.log(
-                LogLevel::Info,
-                "Set password in context",
-                "recording_manager",
-            );
-        }
-        if let Some(ref method) = encryption_method {
-            context.set_string("encryption_method", method);
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("Set encryption_method in context: {}", method),
-                "recording_manager",
-            );
-        }
-        if let Some(ref recipients) = encryption_recipients {
-            // Convert recipients to JSON array
-            let recipients_json: Vec<serde_json::Value> = recipients
-                .iter()
-                .map(|r| {
-                    serde_json::json!({
-                        "id": r.id,
-                        "publicKey": r.public_key
-                    })
-                })
-                .collect();
-            let recipients_count = recipients_json.len();
-            let recipients_value = serde_json::Value::Array(recipients_json);
-            context.set("recipients", recipients_value);
-            LOGGER.log(
-                LogLevel::Info,
-                &format!("Set {} recipients in context", recipients_count),
-                "recording_manager",
-            );
-        }
-
-        // Add window metadata if available
-        if let Some(ref metadata) = window_metadata {
-            let metadata_json = serde_json::to_value(metadata).map_err(|e| {
-                NotariError::PipelineError(format!("Failed to serialize window metadata: {}", e))
-            })?;
-            context.set("window_metadata", metadata_json);
-        }
-
-        // Add custom metadata if provided
-        if let Some(title) = custom_title {
-            context.set_string("custom_title", title);
-        }
-        if let Some(description) = custom_description {
-            context.set_string("custom_description", description);
-        }
-        if let Some(tags) = custom_tags {
-            let tags_json = serde_json::to_value(&tags).map_err(|e| {
-                NotariError::PipelineError(format!("Failed to serialize tags: {}", e))
-            })?;
-            context.set("custom_tags", tags_json);
-        }
-
-        // Transition state machine: Stopping → Processing (with actual file size and duration)
-        self.transition_to_processing(session_id, state.clone(), file_size, duration, app)?;
-
-        LOGGER.log(
-            LogLevel::Info,
-            &format!("Transitioned to Processing state and executing pipeline for session: {} (file_size: {}, duration: {})", session_id, file_size, duration),
-            "recording_manager",
-        );
-
-        // Execute pipeline with events
-        let result = pipeline.execute_with_events(&mut context, app)?;
-
-        LOGGER.log(
-            LogLevel::Info,
-            &format!(
-                "Pipeline execution completed for session: {} (success: {})",
-                session_id, result.success
-            ),
-            "recording_manager",
-        );
-
-        if result.success {
-            LOGGER.log(
-                LogLevel::Info,
-                &format!(
-                    "Post-processing completed successfully for recording: {} (duration: {:.2}s)",
-                    session_id,
-                    result.total_duration.as_secs_f64()
-                ),
-                "recording_manager",
-            );
-
-            // Get proof pack path and hash from context
-            let proof_pack_path = context
-                .get_path("proof_pack_path")
-                .unwrap_or_else(|_| video_path.with_extension("notari"));
-            let plaintext_hash = context
-                .get_string("plaintext_hash")
-                .unwrap_or_else(|_| "unknown".to_string());
-            let encrypted = password.is_some();
-
-            // Transition state machine: Processing → Completed
-            self.transition_to_completed(
-                session_id,
-                state.clone(),
-                proof_pack_path,
-                plaintext_hash,
-                encrypted,
-                app,
-            )?;
-
-            Ok(())
-        } else {
-            let error_msg = result.error.unwrap_or_else(|| "Unknown error".to_string());
-            LOGGER.log(
-                LogLevel::Error,
-                &format!(
-                    "Post-processing failed for recording {}: {}",
-                    session_id, error_msg
-                ),
-                "recording_manager",
-            );
-
-            // Transition state machine: Processing → Failed
-            self.transition_to_failed(session_id, state.clone(), error_msg.clone(), app)?;
-
-            Err(NotariError::PipelineError(error_msg))
-        }
-    }
-}
-
 // Helper methods for evidence generation and encryption
 impl MacOSRecordingManager {
-    /// Transition state machine through recording lifecycle with events
-    pub fn transition_to_preparing(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                recording.state_machine = recording
-                    .state_machine
-                    .clone()
-                    .prepare(app)
-                    .map_err(|e| NotariError::StateTransitionFailed(e))?;
-            }
-        }
-        Ok(())
-    }
-
-    /// Transition to Recording state
-    pub fn transition_to_recording(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                let output_path = recording.session.output_path.clone();
-                let process_id = recording.process.as_ref().map(|p| p.id()).unwrap_or(0);
-                let encryption_password = recording.session.encryption_password.clone();
-
-                recording.state_machine = recording
-                    .state_machine
-                    .clone()
-                    .start(output_path, process_id, encryption_password, app)
-                    .map_err(|e| NotariError::StateTransitionFailed(e))?;
-            }
-        }
-        Ok(())
-    }
-
-    /// Transition to Stopping state
-    pub fn transition_to_stopping(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                recording.state_machine = recording
-                    .state_machine
-                    .clone()
-                    .stop(app)
-                    .map_err(|e| NotariError::StateTransitionFailed(e))?;
-            }
-        }
-        Ok(())
-    }
-
-    /// Transition to Processing state
-    pub fn transition_to_processing(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        file_size: u64,
-        duration: f64,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                recording.state_machine = recording
-                    .state_machine
-                    .clone()
-                    .process(file_size, duration, app)
-                    .map_err(|e| NotariError::StateTransitionFailed(e))?;
-            }
-        }
-        Ok(())
-    }
-
-    /// Transition to Completed state
-    pub fn transition_to_completed(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        proof_pack_path: PathBuf,
-        plaintext_hash: String,
-        encrypted: bool,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                recording.state_machine = recording
-                    .state_machine
-                    .clone()
-                    .complete(proof_pack_path, plaintext_hash, encrypted, app)
-                    .map_err(|e| NotariError::StateTransitionFailed(e))?;
-            }
-        }
-        Ok(())
-    }
-
-    /// Transition to Failed state
-    pub fn transition_to_failed(
-        &self,
-        session_id: &str,
-        state: SharedRecordingState,
-        error: String,
-        app: &tauri::AppHandle,
-    ) -> NotariResult<()> {
-        let mut state_guard = state.lock()?;
-        if let Some(ref mut recording) = state_guard.active_recording {
-            if recording.session.session_id == session_id {
-                recording.state_machine = recording.state_machine.clone().fail(error.clone(), app);
-            }
-        }
-        Ok(())
-    }
-
     /// Encrypt a recorded video file
     #[allow(dead_code)]
     fn encrypt_recording(
@@ -1051,6 +757,7 @@ impl MacOSRecordingManager {
             started_at: session.start_time,
             stopped_at: now,
             manifest_created_at: now,
+            pauses: Vec::new(),
         };
 
         // Create manifest using builder
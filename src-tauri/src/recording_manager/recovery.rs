@@ -0,0 +1,243 @@
+//! Recovery of sessions interrupted mid-recording
+//!
+//! A session whose segment directory still holds a journal on startup was never
+//! finalized. Its intact segments are joined and run through the post-recording
+//! pipeline like a normal recording, with the manifest marked `recovered`.
+
+use super::journal::{self, SessionJournal};
+use super::{lifecycle, ActiveRecording, RecordingManager, RecordingPreferences};
+use crate::error::{NotariError, NotariResult};
+use crate::logger::{LogLevel, LOGGER};
+use std::path::{Path, PathBuf};
+
+/// Finalize the interrupted sessions found in `dirs` into proof packs
+///
+/// `active_session` is skipped since it is still recording. Sessions that can't
+/// be recovered are logged and left in place. Returns the proof packs created.
+pub fn recover_orphaned_recordings(
+    manager: &dyn RecordingManager,
+    dirs: &[PathBuf],
+    active_session: Option<&str>,
+) -> Vec<PathBuf> {
+    let mut journal_dirs: Vec<PathBuf> = dirs
+        .iter()
+        .flat_map(|dir| journal::find_journals(dir))
+        .collect();
+    journal_dirs.sort();
+    journal_dirs.dedup();
+
+    let mut proof_packs = Vec::new();
+    for dir in journal_dirs {
+        let journal = match SessionJournal::load(&dir) {
+            Ok(journal) => journal,
+            Err(e) => {
+                LOGGER.log(
+                    LogLevel::Warn,
+                    &format!("Skipping unreadable journal {}: {}", dir.display(), e),
+                    "recording_manager",
+                );
+                continue;
+            }
+        };
+        if active_session == Some(journal.session_id.as_str()) {
+            continue;
+        }
+
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Recovering interrupted recording: {}", journal.session_id),
+            "recording_manager",
+        );
+        match recover_session(manager, journal) {
+            Ok(Some(proof_pack_path)) => {
+                LOGGER.log(
+                    LogLevel::Info,
+                    &format!("Recovered recording: {}", proof_pack_path.display()),
+                    "recording_manager",
+                );
+                proof_packs.push(proof_pack_path);
+            }
+            Ok(None) => {}
+            Err(e) => LOGGER.log(
+                LogLevel::Warn,
+                &format!("Failed to recover {}: {}", dir.display(), e),
+                "recording_manager",
+            ),
+        }
+    }
+    proof_packs
+}
+
+/// Join the intact segments of `journal` and create its proof pack
+///
+/// Returns `None` if the session had already been packed before it was
+/// interrupted, in which case only the journal is removed.
+pub fn recover_session(
+    manager: &dyn RecordingManager,
+    mut journal: SessionJournal,
+) -> NotariResult<Option<PathBuf>> {
+    if journal.password_protected {
+        return Err(NotariError::GenericError(
+            "Password-encrypted recordings can't be recovered without the password".to_string(),
+        ));
+    }
+
+    // Interrupted after packaging, before the segments were cleaned up
+    let existing_pack = journal.output_path.with_extension("notari");
+    if existing_pack.exists() {
+        journal.remove()?;
+        return Ok(None);
+    }
+
+    // The newest segment was still being written when the session ended
+    journal.sync(false)?;
+    let segments = journal.drop_damaged_segments()?;
+    if segments.is_empty() {
+        return Err(NotariError::FileNotFound(format!(
+            "No complete segments in {}",
+            journal.dir().display()
+        )));
+    }
+    manager.join_segments(&segments, &journal.output_path)?;
+
+    let session = recovered_session(&journal);
+    let mut context =
+        lifecycle::session_context(&session, journal.recorded_duration(), &journal.pauses)?;
+    context.set_bool("recovered", true);
+
+    let result = lifecycle::post_recording_pipeline(manager.signing_key()).execute(&mut context)?;
+    if !result.success {
+        return Err(NotariError::PipelineError(
+            result.error.unwrap_or_else(|| "Unknown error".to_string()),
+        ));
+    }
+
+    let proof_pack_path = context.get_path("proof_pack_path")?;
+    journal.remove()?;
+    Ok(Some(proof_pack_path))
+}
+
+/// Rebuild the session described by `journal`, without its password
+fn recovered_session(journal: &SessionJournal) -> ActiveRecording {
+    ActiveRecording {
+        session_id: journal.session_id.clone(),
+        window_id: journal.window_id.clone(),
+        start_time: journal.started_at,
+        output_path: journal.output_path.clone(),
+        preferences: RecordingPreferences {
+            save_directory: journal.output_path.parent().map(Path::to_path_buf),
+            ..Default::default()
+        },
+        window_metadata: journal.window_metadata.clone(),
+        encryption_password: None,
+        encryption_method: journal.encryption_method.clone(),
+        encryption_recipients: journal.encryption_recipients.clone(),
+        recording_title: journal.recording_title.clone(),
+        recording_description: journal.recording_description.clone(),
+        recording_tags: journal.recording_tags.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evidence::{VerificationStatus, Verifier};
+    use crate::recording_manager::{
+        RecordingState, SharedRecordingState, SyntheticConfig, SyntheticRecordingManager,
+    };
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    /// Five frames written as segments of two, the last one treated as unfinished
+    fn manager() -> SyntheticRecordingManager {
+        SyntheticRecordingManager::new(SyntheticConfig {
+            duration: Duration::from_millis(500),
+            segment_duration: Duration::from_millis(200),
+            width: 64,
+            height: 48,
+            ..Default::default()
+        })
+    }
+
+    /// Record until the recorder exits, then drop the session as a crash would
+    fn interrupted_session(
+        manager: &SyntheticRecordingManager,
+        save_dir: &TempDir,
+        password: Option<&str>,
+    ) -> SessionJournal {
+        let state: SharedRecordingState = Arc::new(Mutex::new(RecordingState::new()));
+        let preferences = RecordingPreferences {
+            save_directory: Some(save_dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        let session = manager
+            .start_recording("synthetic_1", &preferences, None, state.clone())
+            .unwrap();
+        if let Some(password) = password {
+            let mut state_guard = state.lock().unwrap();
+            let recording = state_guard.active_recording.as_mut().unwrap();
+            recording.session.encryption_password = Some(password.to_string());
+        }
+        lifecycle::open_journal(&session.session_id, state.clone()).unwrap();
+        manager
+            .stop_recording(&session.session_id, state.clone())
+            .unwrap();
+
+        let state_guard = state.lock().unwrap();
+        state_guard
+            .active_recording
+            .as_ref()
+            .unwrap()
+            .journal
+            .clone()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_recover_interrupted_session() {
+        let save_dir = TempDir::new().unwrap();
+        let manager = manager();
+        let journal = interrupted_session(&manager, &save_dir, None);
+        assert!(!journal.output_path.exists());
+
+        let proof_packs =
+            recover_orphaned_recordings(&manager, &[save_dir.path().to_path_buf()], None);
+        assert_eq!(proof_packs.len(), 1);
+        assert!(!journal.dir().exists());
+
+        let report = Verifier::verify_proof_pack(&proof_packs[0]).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        assert!(report.verification.recording_info.recovered);
+        assert_eq!(
+            report.verification.recording_info.session_id,
+            journal.session_id
+        );
+
+        // Nothing left to recover
+        assert!(
+            recover_orphaned_recordings(&manager, &[save_dir.path().to_path_buf()], None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_skips_active_and_password_sessions() {
+        let save_dir = TempDir::new().unwrap();
+        let manager = manager();
+        let journal = interrupted_session(&manager, &save_dir, None);
+        let dirs = [save_dir.path().to_path_buf()];
+
+        assert!(recover_orphaned_recordings(&manager, &dirs, Some(&journal.session_id)).is_empty());
+        assert!(journal.dir().exists());
+
+        let locked_dir = TempDir::new().unwrap();
+        let locked = interrupted_session(&manager, &locked_dir, Some("Str0ng-Passw0rd!"));
+        assert!(locked.password_protected);
+        assert!(
+            recover_orphaned_recordings(&manager, &[locked_dir.path().to_path_buf()], None)
+                .is_empty()
+        );
+        assert!(locked.dir().exists());
+    }
+}
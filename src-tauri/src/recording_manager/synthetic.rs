@@ -0,0 +1,720 @@
+use super::{
+    journal, ActiveRecording, InternalRecordingState, RecordingInfo, RecordingManager,
+    RecordingPreferences, SharedRecordingState, WindowMetadata,
+};
+use crate::error::{NotariError, NotariResult};
+use crate::evidence::KeyManager;
+use crate::logger::{LogLevel, LOGGER};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::Utc;
+
+mod mov;
+
+/// Environment variable selecting the recording backend (`synthetic` for this one)
+pub const RECORDER_ENV: &str = "NOTARI_RECORDER";
+
+/// Length of the generated video in seconds
+pub const DURATION_ENV: &str = "NOTARI_SYNTHETIC_DURATION";
+
+/// Frame size of the generated video, as `WIDTHxHEIGHT`
+pub const SIZE_ENV: &str = "NOTARI_SYNTHETIC_SIZE";
+
+/// Frame rate of the generated video
+pub const FPS_ENV: &str = "NOTARI_SYNTHETIC_FPS";
+
+/// Length of each segment of the generated video in seconds
+pub const SEGMENT_ENV: &str = "NOTARI_SYNTHETIC_SEGMENT";
+
+/// Video file to replay instead of generating one
+pub const FIXTURE_ENV: &str = "NOTARI_SYNTHETIC_FIXTURE";
+
+/// Failure to inject: `start`, `stop`, `health` or `no-output`
+pub const FAIL_ENV: &str = "NOTARI_SYNTHETIC_FAIL";
+
+/// Failure injected by the synthetic recorder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntheticFailure {
+    /// `start_recording` fails before a session is created
+    Start,
+    /// `stop_recording` fails and writes no video
+    Stop,
+    /// Health checks report a crashed recorder while recording
+    Health,
+    /// Recording stops cleanly but no video file is written
+    MissingOutput,
+}
+
+impl FromStr for SyntheticFailure {
+    type Err = NotariError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(Self::Start),
+            "stop" => Ok(Self::Stop),
+            "health" => Ok(Self::Health),
+            "no-output" => Ok(Self::MissingOutput),
+            other => Err(NotariError::ConfigError(format!(
+                "Unknown synthetic failure '{}' (expected start, stop, health or no-output)",
+                other
+            ))),
+        }
+    }
+}
+
+/// What the synthetic recorder produces
+#[derive(Debug, Clone)]
+pub struct SyntheticConfig {
+    /// Length of the generated video; independent of how long the session runs
+    pub duration: Duration,
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    /// Length of the segments the video is written in, like a real recorder's
+    pub segment_duration: Duration,
+    /// Copy this file to the output path instead of generating a video
+    pub fixture: Option<PathBuf>,
+    pub failure: Option<SyntheticFailure>,
+}
+
+impl Default for SyntheticConfig {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(2),
+            width: 320,
+            height: 240,
+            fps: 10,
+            segment_duration: Duration::from_secs(1),
+            fixture: None,
+            failure: None,
+        }
+    }
+}
+
+impl SyntheticConfig {
+    /// Read the configuration from the `NOTARI_SYNTHETIC_*` environment variables
+    ///
+    /// Unset variables keep their defaults.
+    pub fn from_env() -> NotariResult<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> NotariResult<Self> {
+        let invalid = |name: &str, value: &str| {
+            NotariError::ConfigError(format!("Invalid {} value '{}'", name, value))
+        };
+        let mut config = Self::default();
+
+        if let Some(value) = var(DURATION_ENV) {
+            let seconds = value
+                .parse::<f64>()
+                .ok()
+                .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+                .ok_or_else(|| invalid(DURATION_ENV, &value))?;
+            config.duration = Duration::from_secs_f64(seconds);
+        }
+        if let Some(value) = var(SEGMENT_ENV) {
+            let seconds = value
+                .parse::<f64>()
+                .ok()
+                .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+                .ok_or_else(|| invalid(SEGMENT_ENV, &value))?;
+            config.segment_duration = Duration::from_secs_f64(seconds);
+        }
+        if let Some(value) = var(SIZE_ENV) {
+            let (width, height) = value
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse::<u16>().ok()?, h.parse::<u16>().ok()?)))
+                .filter(|&(w, h)| w > 0 && h > 0)
+                .ok_or_else(|| invalid(SIZE_ENV, &value))?;
+            config.width = width.into();
+            config.height = height.into();
+        }
+        if let Some(value) = var(FPS_ENV) {
+            config.fps = value
+                .parse::<u32>()
+                .ok()
+                .filter(|fps| *fps > 0)
+                .ok_or_else(|| invalid(FPS_ENV, &value))?;
+        }
+        config.fixture = var(FIXTURE_ENV).map(PathBuf::from);
+        config.failure = var(FAIL_ENV).map(|value| value.parse()).transpose()?;
+
+        Ok(config)
+    }
+
+    /// Number of frames in the generated video (at least one)
+    pub fn frame_count(&self) -> u32 {
+        ((self.duration.as_secs_f64() * self.fps as f64).round() as u32).max(1)
+    }
+
+    /// Number of frames in each segment (at least one)
+    pub fn frames_per_segment(&self) -> u32 {
+        ((self.segment_duration.as_secs_f64() * self.fps as f64).round() as u32).max(1)
+    }
+}
+
+/// Records nothing: writes a generated test pattern as segments, or a fixture
+/// file, when the recording stops
+///
+/// Used to exercise the recording commands and post-recording pipeline where no
+/// capture backend is available, such as headless CI. Recordings are signed with
+/// a key generated for this manager rather than the keychain key, so they never
+/// carry the user's signing identity.
+pub struct SyntheticRecordingManager {
+    config: SyntheticConfig,
+    signing_key: KeyManager,
+    /// Session ID and paused flag of the active recording
+    active: Mutex<Option<(String, bool)>>,
+}
+
+impl SyntheticRecordingManager {
+    pub fn new(config: SyntheticConfig) -> Self {
+        Self {
+            config,
+            signing_key: KeyManager::generate(),
+            active: Mutex::new(None),
+        }
+    }
+
+    /// Fail with `error` if `failure` is the configured failure
+    fn inject(
+        &self,
+        failure: SyntheticFailure,
+        error: fn(String) -> NotariError,
+    ) -> NotariResult<()> {
+        if self.config.failure == Some(failure) {
+            let message = format!("Injected {:?} failure", failure);
+            LOGGER.log(LogLevel::Warn, &message, "recording_manager");
+            return Err(error(message));
+        }
+        Ok(())
+    }
+
+    /// Write the video for a finished recording
+    ///
+    /// Fixtures are copied to `output_path`; the test pattern goes to the segment
+    /// directory for `lifecycle::finish_segments` to join.
+    fn write_output(&self, output_path: &Path) -> NotariResult<()> {
+        if let Some(ref fixture) = self.config.fixture {
+            std::fs::copy(fixture, output_path).map_err(|e| {
+                NotariError::RecordingStopFailed(format!(
+                    "Failed to copy fixture {}: {}",
+                    fixture.display(),
+                    e
+                ))
+            })?;
+        } else {
+            let segment_dir = journal::segment_dir(output_path);
+            std::fs::create_dir_all(&segment_dir)
+                .map_err(|e| NotariError::DirectoryCreationFailed(e.to_string()))?;
+
+            let frame_count = self.config.frame_count();
+            let frames_per_segment = self.config.frames_per_segment();
+            for (index, start) in (0..frame_count)
+                .step_by(frames_per_segment as usize)
+                .enumerate()
+            {
+                let frames = start..(start + frames_per_segment).min(frame_count);
+                mov::write_test_pattern_segment(
+                    &segment_dir.join(journal::segment_file_name(index as u32)),
+                    self.config.width,
+                    self.config.height,
+                    self.config.fps,
+                    frames,
+                    frame_count,
+                )
+                .map_err(|e| {
+                    NotariError::RecordingStopFailed(format!("Failed to write test video: {}", e))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the paused flag of the active recording
+    fn set_paused(&self, session_id: &str, paused: bool) -> NotariResult<()> {
+        let mut active_guard = self.active.lock()?;
+        let active = active_guard
+            .as_mut()
+            .ok_or(NotariError::NoActiveRecording)?;
+        if active.0 != session_id {
+            return Err(NotariError::SessionNotFound(session_id.to_string()));
+        }
+
+        active.1 = paused;
+        LOGGER.log(
+            LogLevel::Info,
+            &format!(
+                "Recording {}: {}",
+                if paused { "paused" } else { "resumed" },
+                session_id
+            ),
+            "recording_manager",
+        );
+        Ok(())
+    }
+}
+
+impl RecordingManager for SyntheticRecordingManager {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn start_recording(
+        &self,
+        window_id: &str,
+        preferences: &RecordingPreferences,
+        window_info: Option<crate::window_manager::WindowInfo>,
+        state: SharedRecordingState,
+    ) -> NotariResult<ActiveRecording> {
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Starting synthetic recording for window: {}", window_id),
+            "recording_manager",
+        );
+
+        // Check if there's already an active recording
+        {
+            let state_guard = state.lock()?;
+            if state_guard.has_active_recording() {
+                return Err(NotariError::RecordingInProgress);
+            }
+        }
+
+        self.inject(SyntheticFailure::Start, NotariError::RecordingStartFailed)?;
+
+        // Get default save directory
+        let default_dir = self.get_default_save_directory()?;
+
+        // Ensure save directory exists
+        let save_dir = preferences.save_directory.as_ref().unwrap_or(&default_dir);
+        std::fs::create_dir_all(save_dir)
+            .map_err(|e| NotariError::DirectoryCreationFailed(e.to_string()))?;
+
+        // Generate output path
+        let timestamp = Utc::now();
+        let output_path = preferences.get_output_path(&default_dir, timestamp);
+
+        // Describe the generated video unless the window was found
+        let window_metadata = match window_info {
+            Some(win_info) => WindowMetadata {
+                title: win_info.title,
+                app_name: win_info.application,
+                app_bundle_id: "unknown".to_string(),
+                width: win_info.bounds.width,
+                height: win_info.bounds.height,
+            },
+            None => WindowMetadata {
+                title: "Synthetic Window".to_string(),
+                app_name: "Notari Synthetic Recorder".to_string(),
+                app_bundle_id: "com.notari.synthetic".to_string(),
+                width: self.config.width,
+                height: self.config.height,
+            },
+        };
+
+        // Create recording session using builder
+        use crate::recording_manager::ActiveRecordingBuilder;
+        let session = ActiveRecordingBuilder::new(window_id)
+            .output_path(output_path)
+            .preferences(preferences.clone())
+            .window_metadata(window_metadata)
+            .build()?;
+
+        {
+            let mut state_guard = state.lock()?;
+
+            // Create state machine in Idle state; the command layer drives the
+            // transitions (Idle → Preparing → Recording) since it has the AppHandle
+            let state_machine = crate::state_machine::RecordingSessionState::new(
+                window_id.to_string(),
+                state_guard.preferences.to_snapshot(),
+            );
+
+            state_guard.active_recording = Some(InternalRecordingState {
+                session: session.clone(),
+                process: None,
+                last_health_check: Utc::now(),
+                state_machine,
+                journal: None,
+                checkpoints: None,
+            });
+        }
+
+        *self.active.lock()? = Some((session.session_id.clone(), false));
+
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Recording started successfully: {}", session.session_id),
+            "recording_manager",
+        );
+
+        Ok(session)
+    }
+
+    fn stop_recording(&self, session_id: &str, state: SharedRecordingState) -> NotariResult<()> {
+        LOGGER.log(
+            LogLevel::Info,
+            &format!("Stopping synthetic recording: {}", session_id),
+            "recording_manager",
+        );
+
+        let output_path = {
+            let state_guard = state.lock()?;
+            let recording = state_guard
+                .active_recording
+                .as_ref()
+                .ok_or(NotariError::NoActiveRecording)?;
+            if recording.session.session_id != session_id {
+                return Err(NotariError::SessionNotFound(session_id.to_string()));
+            }
+            recording.session.output_path.clone()
+        };
+
+        *self.active.lock()? = None;
+        self.inject(SyntheticFailure::Stop, NotariError::RecordingStopFailed)?;
+
+        if self.config.failure == Some(SyntheticFailure::MissingOutput) {
+            LOGGER.log(
+                LogLevel::Warn,
+                "Injected MissingOutput failure; no video written",
+                "recording_manager",
+            );
+        } else {
+            self.write_output(&output_path)?;
+            LOGGER.log(
+                LogLevel::Info,
+                &format!("Synthetic video written for: {}", output_path.display()),
+                "recording_manager",
+            );
+        }
+
+        // Note: We don't clear the active recording here so the frontend can still
+        // query the final status and file information.
+        Ok(())
+    }
+
+    fn pause_recording(&self, session_id: &str, _state: SharedRecordingState) -> NotariResult<()> {
+        self.set_paused(session_id, true)
+    }
+
+    fn resume_recording(&self, session_id: &str, _state: SharedRecordingState) -> NotariResult<()> {
+        self.set_paused(session_id, false)
+    }
+
+    fn get_recording_info(
+        &self,
+        session_id: &str,
+        state: SharedRecordingState,
+    ) -> NotariResult<RecordingInfo> {
+        let state_guard = state.lock()?;
+
+        if let Some(ref recording) = state_guard.active_recording {
+            if recording.session.session_id != session_id {
+                return Err(NotariError::SessionNotFound(session_id.to_string()));
+            }
+
+            let duration = (Utc::now() - recording.session.start_time)
+                .num_seconds()
+                .max(0) as u64;
+            let file_size = std::fs::metadata(&recording.session.output_path)
+                .ok()
+                .map(|metadata| metadata.len());
+
+            Ok(RecordingInfo {
+                session: recording.session.clone(),
+                duration_seconds: duration,
+                file_size_bytes: file_size,
+                estimated_final_size_bytes: file_size,
+            })
+        } else {
+            Err(NotariError::NoActiveRecording)
+        }
+    }
+
+    fn check_recording_health(&self, state: SharedRecordingState) -> NotariResult<()> {
+        if self.active.lock()?.is_none() {
+            return Ok(());
+        }
+        self.inject(SyntheticFailure::Health, NotariError::RecordingProcessError)?;
+
+        let mut state_guard = state.lock()?;
+        if let Some(ref mut recording) = state_guard.active_recording {
+            recording.last_health_check = Utc::now();
+        }
+        Ok(())
+    }
+
+    fn cleanup_orphaned_recordings(&self) -> NotariResult<()> {
+        Ok(()) // Nothing runs outside the session
+    }
+
+    fn get_default_save_directory(&self) -> NotariResult<PathBuf> {
+        // Keep generated recordings out of the user's videos
+        Ok(std::env::temp_dir().join("notari-synthetic"))
+    }
+
+    fn validate_save_directory(&self, path: &PathBuf) -> NotariResult<bool> {
+        // Check if directory exists or can be created
+        if !path.exists() {
+            std::fs::create_dir_all(path)
+                .map_err(|e| NotariError::DirectoryCreationFailed(e.to_string()))?;
+        }
+
+        // Check if directory is writable
+        let test_file = path.join(".notari_write_test");
+        match std::fs::write(&test_file, "test") {
+            Ok(_) => {
+                let _ = std::fs::remove_file(&test_file);
+                Ok(true)
+            }
+            Err(e) => Err(NotariError::DirectoryCreationFailed(format!(
+                "Directory not writable: {}",
+                e
+            ))),
+        }
+    }
+
+    fn signing_key(&self) -> Option<KeyManager> {
+        KeyManager::from_bytes(&self.signing_key.to_bytes()).ok()
+    }
+
+    fn join_segments(&self, segments: &[PathBuf], output_path: &Path) -> NotariResult<()> {
+        mov::concat(segments, output_path).map_err(|e| {
+            NotariError::RecordingStopFailed(format!("Failed to join segments: {}", e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evidence::{CheckResult, VerificationStatus, Verifier};
+    use crate::recording_manager::{checkpoints, lifecycle, RecordingState};
+    use base64::{engine::general_purpose, Engine as _};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn preferences(save_dir: &TempDir) -> RecordingPreferences {
+        RecordingPreferences {
+            save_directory: Some(save_dir.path().to_path_buf()),
+            ..Default::default()
+        }
+    }
+
+    fn small_config() -> SyntheticConfig {
+        SyntheticConfig {
+            duration: Duration::from_millis(500),
+            width: 64,
+            height: 48,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let vars: HashMap<&str, &str> = [
+            (DURATION_ENV, "1.5"),
+            (SIZE_ENV, "640x480"),
+            (FPS_ENV, "24"),
+            (SEGMENT_ENV, "0.5"),
+            (FAIL_ENV, "no-output"),
+        ]
+        .into_iter()
+        .collect();
+        let config =
+            SyntheticConfig::from_vars(|name| vars.get(name).map(|v| v.to_string())).unwrap();
+
+        assert_eq!(config.duration, Duration::from_millis(1500));
+        assert_eq!((config.width, config.height), (640, 480));
+        assert_eq!(config.fps, 24);
+        assert_eq!(config.frame_count(), 36);
+        assert_eq!(config.frames_per_segment(), 12);
+        assert!(config.fixture.is_none());
+        assert_eq!(config.failure, Some(SyntheticFailure::MissingOutput));
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        for (name, value) in [
+            (DURATION_ENV, "0"),
+            (SIZE_ENV, "640"),
+            (SIZE_ENV, "0x480"),
+            (FPS_ENV, "fast"),
+            (SEGMENT_ENV, "-1"),
+            (FAIL_ENV, "crash"),
+        ] {
+            let result = SyntheticConfig::from_vars(|n| (n == name).then(|| value.to_string()));
+            assert!(
+                matches!(result, Err(NotariError::ConfigError(_))),
+                "{}={} should be rejected",
+                name,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_record_and_process() {
+        let save_dir = TempDir::new().unwrap();
+        let manager = SyntheticRecordingManager::new(small_config());
+        let state = Arc::new(Mutex::new(RecordingState::new()));
+
+        let session = manager
+            .start_recording("synthetic_1", &preferences(&save_dir), None, state.clone())
+            .unwrap();
+        lifecycle::open_journal(&session.session_id, state.clone()).unwrap();
+        checkpoints::start(
+            &session.session_id,
+            state.clone(),
+            manager.signing_key().unwrap(),
+            Duration::from_millis(50),
+            None,
+        )
+        .unwrap();
+        std::thread::sleep(Duration::from_millis(120));
+        manager.check_recording_health(state.clone()).unwrap();
+        manager
+            .pause_recording(&session.session_id, state.clone())
+            .unwrap();
+        manager
+            .resume_recording(&session.session_id, state.clone())
+            .unwrap();
+        manager
+            .stop_recording(&session.session_id, state.clone())
+            .unwrap();
+        lifecycle::finish_segments(&manager, &session.session_id, state.clone()).unwrap();
+        assert!(session.output_path.exists());
+        {
+            let state_guard = state.lock().unwrap();
+            let journal = state_guard
+                .active_recording
+                .as_ref()
+                .unwrap()
+                .journal
+                .as_ref();
+            // 500 ms at 10 fps in 1 s segments
+            assert_eq!(journal.unwrap().segments.len(), 1);
+        }
+
+        // Run the post-recording pipeline the way `stop_recording` does, minus events
+        let mut context = lifecycle::pipeline_context(&session.session_id, state).unwrap();
+        let result = lifecycle::post_recording_pipeline(manager.signing_key())
+            .execute(&mut context)
+            .unwrap();
+        assert!(result.success, "pipeline failed: {:?}", result.error);
+
+        let proof_pack_path = context.get_path("proof_pack_path").unwrap();
+        let report = Verifier::verify_proof_pack(&proof_pack_path).unwrap();
+        assert_eq!(report.verification.status, VerificationStatus::Verified);
+        let public_key = manager.signing_key().unwrap().public_key();
+        assert_eq!(
+            report.verification.signature_info.public_key,
+            general_purpose::STANDARD.encode(public_key.as_bytes())
+        );
+
+        // Checkpoints taken while recording, the last one covering every segment
+        let checkpoints = report.verification.checks.checkpoints.unwrap();
+        assert!(checkpoints.count >= 2, "{:?}", checkpoints);
+        assert!(checkpoints.error.is_none(), "{:?}", checkpoints.error);
+        assert_eq!(checkpoints.segments_valid, CheckResult::Pass);
+        assert_eq!(checkpoints.segments_packed, CheckResult::Pass);
+        assert_eq!(checkpoints.joined_segments, 1);
+        assert_eq!(checkpoints.dropped_segments, 0);
+    }
+
+    #[test]
+    fn test_fixture_replay() {
+        let save_dir = TempDir::new().unwrap();
+        let fixture = save_dir.path().join("fixture.bin");
+        std::fs::write(&fixture, b"fixture video").unwrap();
+        let manager = SyntheticRecordingManager::new(SyntheticConfig {
+            fixture: Some(fixture),
+            ..small_config()
+        });
+        let state = Arc::new(Mutex::new(RecordingState::new()));
+
+        let session = manager
+            .start_recording("synthetic_1", &preferences(&save_dir), None, state.clone())
+            .unwrap();
+        manager.stop_recording(&session.session_id, state).unwrap();
+
+        assert_eq!(
+            std::fs::read(&session.output_path).unwrap(),
+            b"fixture video"
+        );
+    }
+
+    #[test]
+    fn test_injected_failures() {
+        let manager_with = |failure| {
+            SyntheticRecordingManager::new(SyntheticConfig {
+                failure: Some(failure),
+                ..small_config()
+            })
+        };
+        // Each case gets its own directory; output names only have second precision
+        let start = |manager: &SyntheticRecordingManager, state: SharedRecordingState| {
+            let save_dir = TempDir::new().unwrap();
+            let result =
+                manager.start_recording("synthetic_1", &preferences(&save_dir), None, state);
+            (save_dir, result)
+        };
+
+        let state = Arc::new(Mutex::new(RecordingState::new()));
+        let (_dir, result) = start(&manager_with(SyntheticFailure::Start), state.clone());
+        assert!(matches!(result, Err(NotariError::RecordingStartFailed(_))));
+        assert!(state.lock().unwrap().active_recording.is_none());
+
+        let manager = manager_with(SyntheticFailure::Health);
+        let (_dir, result) = start(&manager, state.clone());
+        let session = result.unwrap();
+        assert!(matches!(
+            manager.check_recording_health(state.clone()),
+            Err(NotariError::RecordingProcessError(_))
+        ));
+        manager
+            .stop_recording(&session.session_id, state.clone())
+            .unwrap();
+        // Nothing left to report once stopped
+        manager.check_recording_health(state).unwrap();
+
+        let state = Arc::new(Mutex::new(RecordingState::new()));
+        let manager = manager_with(SyntheticFailure::Stop);
+        let (_dir, result) = start(&manager, state.clone());
+        let session = result.unwrap();
+        assert!(matches!(
+            manager.stop_recording(&session.session_id, state),
+            Err(NotariError::RecordingStopFailed(_))
+        ));
+        assert!(!session.output_path.exists());
+
+        let state = Arc::new(Mutex::new(RecordingState::new()));
+        let manager = manager_with(SyntheticFailure::MissingOutput);
+        let (_dir, result) = start(&manager, state.clone());
+        let session = result.unwrap();
+        manager
+            .stop_recording(&session.session_id, state.clone())
+            .unwrap();
+        assert!(!session.output_path.exists());
+        assert!(matches!(
+            lifecycle::pipeline_context(&session.session_id, state)
+                .unwrap()
+                .get_path("video_path"),
+            Ok(path) if path == session.output_path
+        ));
+    }
+
+    #[test]
+    fn test_signing_key_is_stable() {
+        let manager = SyntheticRecordingManager::new(small_config());
+        let first = manager.signing_key().unwrap();
+        let second = manager.signing_key().unwrap();
+        assert_eq!(first.to_bytes(), second.to_bytes());
+    }
+}
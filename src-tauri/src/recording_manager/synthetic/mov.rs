@@ -0,0 +1,522 @@
+//! Minimal QuickTime writer for generated test videos
+//!
+//! Frames are stored uncompressed (`raw `, 32-bit ARGB) in a single chunk, so the
+//! file is a pure function of its parameters and opens in any QuickTime-capable
+//! player without an encoder.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Bytes per pixel of the stored frames (ARGB)
+const BYTES_PER_PIXEL: usize = 4;
+
+/// Movie timescale (units per second) used by `mvhd` and `tkhd`
+const MOVIE_TIMESCALE: u32 = 1000;
+
+/// Identity transformation matrix shared by `mvhd` and `tkhd`
+const UNITY_MATRIX: [u32; 9] = [0x10000, 0, 0, 0, 0x10000, 0, 0, 0, 0x4000_0000];
+
+/// Write `frame_count` frames of the test pattern to `path`
+pub fn write_test_pattern(
+    path: &Path,
+    width: u32,
+    height: u32,
+    fps: u32,
+    frame_count: u32,
+) -> io::Result<()> {
+    write_test_pattern_segment(path, width, height, fps, 0..frame_count, frame_count)
+}
+
+/// Write the frames in `frames` of a `frame_count` frame test pattern to `path`
+///
+/// Joining the segments of a pattern with [`concat`] gives the same file as
+/// writing it in one go.
+pub fn write_test_pattern_segment(
+    path: &Path,
+    width: u32,
+    height: u32,
+    fps: u32,
+    frames: Range<u32>,
+    frame_count: u32,
+) -> io::Result<()> {
+    let frame_bytes = width as usize * height as usize * BYTES_PER_PIXEL;
+    let mut frame = vec![0u8; frame_bytes];
+    write_movie(path, width, height, fps, frames.len() as u32, |out| {
+        for index in frames {
+            render_frame(&mut frame, width, height, index, frame_count);
+            out.write_all(&frame)?;
+        }
+        Ok(())
+    })
+}
+
+/// Join movies written by this module into one, in order
+///
+/// All inputs must share the same frame size and rate.
+pub fn concat(inputs: &[PathBuf], path: &Path) -> io::Result<()> {
+    let movies = inputs
+        .iter()
+        .map(|input| std::fs::read(input).and_then(|data| Movie::parse(&data)))
+        .collect::<io::Result<Vec<_>>>()?;
+    let first = movies
+        .first()
+        .ok_or_else(|| invalid_data("No movies to join"))?;
+    if movies.iter().any(|movie| movie.format() != first.format()) {
+        return Err(invalid_data("Movies differ in frame size or rate"));
+    }
+
+    let frame_count = movies.iter().map(|movie| movie.frame_count).sum();
+    write_movie(
+        path,
+        first.width,
+        first.height,
+        first.fps,
+        frame_count,
+        |out| {
+            movies
+                .iter()
+                .try_for_each(|movie| out.write_all(&movie.frames))
+        },
+    )
+}
+
+/// Write a movie of `frame_count` frames, with `write_frames` writing the frame data
+fn write_movie(
+    path: &Path,
+    width: u32,
+    height: u32,
+    fps: u32,
+    frame_count: u32,
+    write_frames: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+) -> io::Result<()> {
+    let frame_bytes = width as usize * height as usize * BYTES_PER_PIXEL;
+    let mdat_size = 8 + frame_bytes as u64 * frame_count as u64;
+    let mdat_size = u32::try_from(mdat_size).map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidInput, "Synthetic video exceeds 4 GiB")
+    })?;
+
+    let ftyp = ftyp();
+    let data_offset = ftyp.len() as u32 + 8;
+    let moov = moov(
+        width,
+        height,
+        fps,
+        frame_count,
+        frame_bytes as u32,
+        data_offset,
+    );
+
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(&ftyp)?;
+    out.write_all(&mdat_size.to_be_bytes())?;
+    out.write_all(b"mdat")?;
+    write_frames(&mut out)?;
+    out.write_all(&moov)?;
+    out.flush()
+}
+
+/// Frame data and format of a movie written by this module
+struct Movie {
+    width: u32,
+    height: u32,
+    fps: u32,
+    frame_count: u32,
+    frames: Vec<u8>,
+}
+
+impl Movie {
+    fn parse(data: &[u8]) -> io::Result<Self> {
+        let top = atoms(data)?;
+        let moov = atoms(child(&top, b"moov")?)?;
+        let trak = atoms(child(&moov, b"trak")?)?;
+        let mdia = atoms(child(&trak, b"mdia")?)?;
+        let minf = atoms(child(&mdia, b"minf")?)?;
+        let stbl = atoms(child(&minf, b"stbl")?)?;
+
+        let mdhd = child(&mdia, b"mdhd")?;
+        let stsd = child(&stbl, b"stsd")?;
+        // Timescale and duration are one tick per frame; the frame size sits in
+        // the first sample description
+        let fps = read_u32(mdhd, 12)?;
+        let frame_count = read_u32(mdhd, 16)?;
+        let width = read_u16(stsd, 40)? as u32;
+        let height = read_u16(stsd, 42)? as u32;
+
+        let frames = child(&top, b"mdat")?;
+        if frames.len() != width as usize * height as usize * BYTES_PER_PIXEL * frame_count as usize
+        {
+            return Err(invalid_data("Frame data doesn't match the movie header"));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            fps,
+            frame_count,
+            frames: frames.to_vec(),
+        })
+    }
+
+    fn format(&self) -> (u32, u32, u32) {
+        (self.width, self.height, self.fps)
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Split `data` into (type, payload) atoms
+fn atoms(mut data: &[u8]) -> io::Result<Vec<([u8; 4], &[u8])>> {
+    let mut atoms = Vec::new();
+    while !data.is_empty() {
+        let size = read_u32(data, 0)? as usize;
+        if size < 8 || size > data.len() {
+            return Err(invalid_data("Truncated atom"));
+        }
+        atoms.push((data[4..8].try_into().unwrap(), &data[8..size]));
+        data = &data[size..];
+    }
+    Ok(atoms)
+}
+
+/// Payload of the first atom of type `kind`
+fn child<'a>(atoms: &[([u8; 4], &'a [u8])], kind: &[u8; 4]) -> io::Result<&'a [u8]> {
+    atoms
+        .iter()
+        .find(|(k, _)| k == kind)
+        .map(|(_, payload)| *payload)
+        .ok_or_else(|| invalid_data("Missing atom"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> io::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes(bytes.try_into().unwrap()))
+        .ok_or_else(|| invalid_data("Truncated atom"))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> io::Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes(bytes.try_into().unwrap()))
+        .ok_or_else(|| invalid_data("Truncated atom"))
+}
+
+/// Draw frame `index`: a red/green gradient with a white bar sweeping left to right
+fn render_frame(frame: &mut [u8], width: u32, height: u32, index: u32, frame_count: u32) {
+    let bar_x = (index as u64 * width as u64 / frame_count.max(1) as u64) as u32;
+    let bar_width = (width / 16).max(1);
+    let blue = (index * 255 / frame_count.max(1)) as u8;
+
+    for (i, pixel) in frame.chunks_exact_mut(BYTES_PER_PIXEL).enumerate() {
+        let x = i as u32 % width;
+        let y = i as u32 / width;
+        let argb = if x >= bar_x && x < bar_x + bar_width {
+            [0xff, 0xff, 0xff, 0xff]
+        } else {
+            [
+                0xff,
+                (x * 255 / width.max(1)) as u8,
+                (y * 255 / height.max(1)) as u8,
+                blue,
+            ]
+        };
+        pixel.copy_from_slice(&argb);
+    }
+}
+
+/// Wrap `payload` in an atom of type `kind`
+fn atom(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+    let mut atom = Vec::with_capacity(8 + payload.len());
+    atom.extend_from_slice(&(8 + payload.len() as u32).to_be_bytes());
+    atom.extend_from_slice(kind);
+    atom.extend_from_slice(payload);
+    atom
+}
+
+/// Big-endian payload builder
+#[derive(Default)]
+struct Payload(Vec<u8>);
+
+impl Payload {
+    fn u16(mut self, value: u16) -> Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn bytes(mut self, value: &[u8]) -> Self {
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    fn matrix(self) -> Self {
+        UNITY_MATRIX.iter().fold(self, |payload, &v| payload.u32(v))
+    }
+
+    fn atom(self, kind: &[u8; 4]) -> Vec<u8> {
+        atom(kind, &self.0)
+    }
+}
+
+fn ftyp() -> Vec<u8> {
+    Payload::default()
+        .bytes(b"qt  ")
+        .u32(0x2005_0300)
+        .bytes(b"qt  ")
+        .atom(b"ftyp")
+}
+
+fn moov(
+    width: u32,
+    height: u32,
+    fps: u32,
+    frame_count: u32,
+    frame_bytes: u32,
+    data_offset: u32,
+) -> Vec<u8> {
+    let fps = fps.max(1);
+    let movie_duration = (frame_count as u64 * MOVIE_TIMESCALE as u64 / fps as u64) as u32;
+    // Creation and modification times are left at zero to keep output reproducible
+    let mvhd = Payload::default()
+        .u32(0) // version and flags
+        .u32(0) // creation time
+        .u32(0) // modification time
+        .u32(MOVIE_TIMESCALE)
+        .u32(movie_duration)
+        .u32(0x10000) // preferred rate 1.0
+        .u16(0x100) // preferred volume 1.0
+        .bytes(&[0; 10])
+        .matrix()
+        .bytes(&[0; 24]) // preview, poster, selection and current times
+        .u32(2) // next track ID
+        .atom(b"mvhd");
+
+    let tkhd = Payload::default()
+        .u32(0x0000_000f) // enabled, in movie, in preview, in poster
+        .u32(0)
+        .u32(0)
+        .u32(1) // track ID
+        .u32(0)
+        .u32(movie_duration)
+        .bytes(&[0; 8])
+        .u16(0) // layer
+        .u16(0) // alternate group
+        .u16(0) // volume
+        .u16(0)
+        .matrix()
+        .u32(width << 16)
+        .u32(height << 16)
+        .atom(b"tkhd");
+
+    let mdhd = Payload::default()
+        .u32(0)
+        .u32(0)
+        .u32(0)
+        .u32(fps) // one tick per frame
+        .u32(frame_count)
+        .u16(0) // language
+        .u16(0) // quality
+        .atom(b"mdhd");
+
+    let media_handler = handler(b"mhlr", b"vide");
+    let data_handler = handler(b"dhlr", b"alis");
+
+    let vmhd = Payload::default()
+        .u32(0x0000_0001)
+        .u16(0x40) // graphics mode: copy
+        .u16(0x8000)
+        .u16(0x8000)
+        .u16(0x8000)
+        .atom(b"vmhd");
+
+    // Media data lives in this file
+    let dref = Payload::default()
+        .u32(0)
+        .u32(1)
+        .bytes(&Payload::default().u32(0x0000_0001).atom(b"alis"))
+        .atom(b"dref");
+    let dinf = atom(b"dinf", &dref);
+
+    let mut compressor_name = [0u8; 32];
+    compressor_name[0] = 4;
+    compressor_name[1..5].copy_from_slice(b"None");
+    let sample_description = Payload::default()
+        .bytes(&[0; 6])
+        .u16(1) // data reference index
+        .u16(0) // version
+        .u16(0) // revision level
+        .u32(0) // vendor
+        .u32(0) // temporal quality
+        .u32(0x200) // spatial quality: normal
+        .u16(width as u16)
+        .u16(height as u16)
+        .u32(0x0048_0000) // 72 dpi
+        .u32(0x0048_0000)
+        .u32(0) // data size
+        .u16(1) // frames per sample
+        .bytes(&compressor_name)
+        .u16(32) // depth
+        .u16(0xffff) // no color table
+        .atom(b"raw ");
+    let stsd = Payload::default()
+        .u32(0)
+        .u32(1)
+        .bytes(&sample_description)
+        .atom(b"stsd");
+
+    let stts = Payload::default()
+        .u32(0)
+        .u32(1)
+        .u32(frame_count)
+        .u32(1)
+        .atom(b"stts");
+    // All frames in one chunk
+    let stsc = Payload::default()
+        .u32(0)
+        .u32(1)
+        .u32(1)
+        .u32(frame_count)
+        .u32(1)
+        .atom(b"stsc");
+    let stsz = Payload::default()
+        .u32(0)
+        .u32(frame_bytes)
+        .u32(frame_count)
+        .atom(b"stsz");
+    let stco = Payload::default()
+        .u32(0)
+        .u32(1)
+        .u32(data_offset)
+        .atom(b"stco");
+    let stbl = atom(b"stbl", &[stsd, stts, stsc, stsz, stco].concat());
+
+    let minf = atom(b"minf", &[vmhd, data_handler, dinf, stbl].concat());
+    let mdia = atom(b"mdia", &[mdhd, media_handler, minf].concat());
+    let trak = atom(b"trak", &[tkhd, mdia].concat());
+    atom(b"moov", &[mvhd, trak].concat())
+}
+
+/// `hdlr` atom with an empty component name
+fn handler(component_type: &[u8; 4], subtype: &[u8; 4]) -> Vec<u8> {
+    Payload::default()
+        .u32(0)
+        .bytes(component_type)
+        .bytes(subtype)
+        .u32(0) // manufacturer
+        .u32(0) // flags
+        .u32(0) // flags mask
+        .bytes(&[0]) // empty Pascal string
+        .atom(b"hdlr")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn find<'a>(atoms: &[([u8; 4], &'a [u8])], kind: &[u8; 4]) -> &'a [u8] {
+        child(atoms, kind).unwrap()
+    }
+
+    fn atoms(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        super::atoms(data).unwrap()
+    }
+
+    #[test]
+    fn test_atom_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pattern.mov");
+        write_test_pattern(&path, 16, 8, 10, 5).unwrap();
+        let data = std::fs::read(&path).unwrap();
+
+        let top = atoms(&data);
+        let kinds: Vec<_> = top.iter().map(|(kind, _)| kind).collect();
+        assert_eq!(kinds, [b"ftyp", b"mdat", b"moov"]);
+        assert_eq!(find(&top, b"mdat").len(), 16 * 8 * BYTES_PER_PIXEL * 5);
+
+        let moov = atoms(find(&top, b"moov"));
+        let trak = atoms(find(&moov, b"trak"));
+        let mdia = atoms(find(&trak, b"mdia"));
+        let minf = atoms(find(&mdia, b"minf"));
+        let stbl = atoms(find(&minf, b"stbl"));
+
+        // The chunk offset points at the first frame
+        let stco = find(&stbl, b"stco");
+        let offset = u32::from_be_bytes(stco[8..12].try_into().unwrap()) as usize;
+        assert_eq!(&data[offset - 4..offset], b"mdat");
+
+        // 5 frames at 10 fps last 500 ms
+        let mvhd = find(&moov, b"mvhd");
+        assert_eq!(u32::from_be_bytes(mvhd[16..20].try_into().unwrap()), 500);
+    }
+
+    #[test]
+    fn test_output_is_deterministic() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = temp_dir.path().join("first.mov");
+        let second = temp_dir.path().join("second.mov");
+        write_test_pattern(&first, 32, 24, 30, 3).unwrap();
+        write_test_pattern(&second, 32, 24, 30, 3).unwrap();
+
+        assert_eq!(
+            std::fs::read(&first).unwrap(),
+            std::fs::read(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_concat_segments() {
+        let temp_dir = TempDir::new().unwrap();
+        let whole = temp_dir.path().join("whole.mov");
+        write_test_pattern(&whole, 16, 8, 10, 7).unwrap();
+
+        let segments: Vec<PathBuf> = [0..3, 3..6, 6..7]
+            .into_iter()
+            .enumerate()
+            .map(|(index, frames)| {
+                let path = temp_dir.path().join(format!("segment-{}.mov", index));
+                write_test_pattern_segment(&path, 16, 8, 10, frames, 7).unwrap();
+                path
+            })
+            .collect();
+        let joined = temp_dir.path().join("joined.mov");
+        concat(&segments, &joined).unwrap();
+
+        assert_eq!(
+            std::fs::read(&joined).unwrap(),
+            std::fs::read(&whole).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_concat_rejects_mismatched_formats() {
+        let temp_dir = TempDir::new().unwrap();
+        let small = temp_dir.path().join("small.mov");
+        let large = temp_dir.path().join("large.mov");
+        write_test_pattern(&small, 16, 8, 10, 2).unwrap();
+        write_test_pattern(&large, 32, 8, 10, 2).unwrap();
+
+        let joined = temp_dir.path().join("joined.mov");
+        let error = concat(&[small, large], &joined).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(concat(&[], &joined).is_err());
+    }
+
+    #[test]
+    fn test_frames_differ() {
+        let mut first = vec![0u8; 32 * 24 * BYTES_PER_PIXEL];
+        let mut second = first.clone();
+        render_frame(&mut first, 32, 24, 0, 10);
+        render_frame(&mut second, 32, 24, 5, 10);
+
+        assert_ne!(first, second);
+        // Opaque everywhere
+        assert!(first.chunks_exact(BYTES_PER_PIXEL).all(|p| p[0] == 0xff));
+    }
+}
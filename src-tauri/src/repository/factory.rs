@@ -3,9 +3,15 @@
 //! This factory provides a centralized way to create repository instances
 //! with the appropriate backend based on configuration.
 
-use super::file::{FileAnchorRepository, FileConfigRepository, FilePreferencesRepository};
+use super::file::{
+    FileAnchorRepository, FileConfigRepository, FilePreferencesRepository,
+    FileTrustedSignerRepository,
+};
 use super::keychain::KeychainRepository;
-use super::traits::{AnchorRepository, ConfigRepository, KeyRepository, PreferencesRepository};
+use super::traits::{
+    AnchorRepository, ConfigRepository, KeyRepository, PreferencesRepository,
+    TrustedSignerRepository,
+};
 use crate::error::NotariResult;
 use once_cell::sync::Lazy;
 use std::path::PathBuf;
@@ -52,6 +58,19 @@ impl RepositoryFactory {
         Box::new(FileAnchorRepository::new(store_path))
     }
 
+    /// Create a file-based trusted signer repository
+    ///
+    /// # Arguments
+    /// * `store_path` - Path to the store file (e.g., "notari-store.json")
+    ///
+    /// # Returns
+    /// A boxed trait object implementing TrustedSignerRepository
+    pub fn create_trusted_signer_repository(
+        store_path: PathBuf,
+    ) -> Box<dyn TrustedSignerRepository> {
+        Box::new(FileTrustedSignerRepository::new(store_path))
+    }
+
     /// Create a keychain-based key repository
     ///
     /// # Returns
@@ -88,6 +107,7 @@ pub struct RepositoryManager {
     config_repo: Arc<FileConfigRepository>,
     preferences_repo: Arc<FilePreferencesRepository>,
     anchor_repo: Arc<FileAnchorRepository>,
+    trusted_signer_repo: Arc<FileTrustedSignerRepository>,
     key_repo: Arc<KeychainRepository>,
 }
 
@@ -97,12 +117,14 @@ impl RepositoryManager {
         config_repo: FileConfigRepository,
         preferences_repo: FilePreferencesRepository,
         anchor_repo: FileAnchorRepository,
+        trusted_signer_repo: FileTrustedSignerRepository,
         key_repo: KeychainRepository,
     ) -> Self {
         Self {
             config_repo: Arc::new(config_repo),
             preferences_repo: Arc::new(preferences_repo),
             anchor_repo: Arc::new(anchor_repo),
+            trusted_signer_repo: Arc::new(trusted_signer_repo),
             key_repo: Arc::new(key_repo),
         }
     }
@@ -113,7 +135,8 @@ impl RepositoryManager {
         Self::new(
             FileConfigRepository::new(store_path.clone()),
             FilePreferencesRepository::new(store_path.clone()),
-            FileAnchorRepository::new(store_path),
+            FileAnchorRepository::new(store_path.clone()),
+            FileTrustedSignerRepository::new(store_path),
             KeychainRepository::new(),
         )
     }
@@ -133,6 +156,11 @@ impl RepositoryManager {
         &self.anchor_repo
     }
 
+    /// Get the trusted signer repository
+    pub fn trusted_signers(&self) -> &FileTrustedSignerRepository {
+        &self.trusted_signer_repo
+    }
+
     /// Get the key repository
     pub fn keys(&self) -> &KeychainRepository {
         &self.key_repo
@@ -145,7 +173,8 @@ impl RepositoryManager {
         // Initialize all file-based repositories
         self.config_repo.init(app_handle.clone())?;
         self.preferences_repo.init(app_handle.clone())?;
-        self.anchor_repo.init(app_handle)?;
+        self.anchor_repo.init(app_handle.clone())?;
+        self.trusted_signer_repo.init(app_handle)?;
         Ok(())
     }
 }
@@ -175,12 +204,15 @@ mod tests {
         let prefs_repo =
             RepositoryFactory::create_preferences_repository(PathBuf::from("test.json"));
         let anchor_repo = RepositoryFactory::create_anchor_repository(PathBuf::from("test.json"));
+        let signer_repo =
+            RepositoryFactory::create_trusted_signer_repository(PathBuf::from("test.json"));
         let key_repo = RepositoryFactory::create_key_repository();
 
         // Just verify they were created (can't test much without initialization)
         assert!(config_repo.load_config().is_err()); // Not initialized
         assert!(prefs_repo.load_preferences().is_err()); // Not initialized
         assert!(anchor_repo.load_all_anchors().is_err()); // Not initialized
+        assert!(signer_repo.load_signers().is_err()); // Not initialized
 
         // Key repo should work on macOS
         #[cfg(target_os = "macos")]
@@ -215,6 +247,7 @@ mod tests {
         let _config = manager.config();
         let _prefs = manager.preferences();
         let _anchors = manager.anchors();
+        let _signers = manager.trusted_signers();
         let _keys = manager.keys();
     }
 
@@ -2,10 +2,16 @@
 //!
 //! These repositories use tauri-plugin-store for persistent JSON storage.
 
-use super::traits::{AnchorRepository, ConfigRepository, PreferencesRepository};
+use super::traits::{
+    AnchorQueueRepository, AnchorRepository, ConfigRepository, PreferencesRepository,
+    TrustedSignerRepository,
+};
 use crate::error::{NotariError, NotariResult};
-use crate::evidence::blockchain::{AnchorProof, BlockchainConfig};
+use crate::evidence::blockchain::{AnchorProof, BlockchainConfig, QueuedAnchor};
+use crate::evidence::x509::Certificate;
+use crate::evidence::TrustedSigner;
 use crate::recording_manager::RecordingPreferences;
+use base64::{engine::general_purpose, Engine as _};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -13,7 +19,10 @@ use std::sync::{Arc, Mutex};
 /// Storage keys
 const BLOCKCHAIN_CONFIG_KEY: &str = "blockchain_config";
 const MOCK_ANCHORS_KEY: &str = "mock_anchors";
+const ANCHOR_QUEUE_KEY: &str = "anchor_queue";
 const RECORDING_PREFERENCES_KEY: &str = "recording_preferences";
+const TRUSTED_SIGNERS_KEY: &str = "trusted_signers";
+const TRUST_ANCHORS_KEY: &str = "trust_anchors";
 
 /// File-based configuration repository using tauri-plugin-store
 pub struct FileConfigRepository {
@@ -241,6 +250,145 @@ impl AnchorRepository for FileAnchorRepository {
     }
 }
 
+impl AnchorQueueRepository for FileAnchorRepository {
+    fn save_queue(&self, queue: &[QueuedAnchor]) -> NotariResult<()> {
+        let store = self.get_store()?;
+        let json = serde_json::to_value(queue)?;
+        store.set(ANCHOR_QUEUE_KEY.to_string(), json);
+        store
+            .save()
+            .map_err(|e| NotariError::StorageSaveFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    fn load_queue(&self) -> NotariResult<Vec<QueuedAnchor>> {
+        let store = self.get_store()?;
+        if let Some(value) = store.get(ANCHOR_QUEUE_KEY) {
+            let queue: Vec<QueuedAnchor> = serde_json::from_value(value.clone())?;
+            Ok(queue)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+}
+
+/// File-based trusted signer repository using tauri-plugin-store
+pub struct FileTrustedSignerRepository {
+    store_path: PathBuf,
+    store: Mutex<Option<Arc<tauri_plugin_store::Store<tauri::Wry>>>>,
+}
+
+impl FileTrustedSignerRepository {
+    /// Create a new file-based trusted signer repository
+    pub fn new(store_path: PathBuf) -> Self {
+        Self {
+            store_path,
+            store: Mutex::new(None),
+        }
+    }
+
+    /// Initialize with app handle
+    pub fn init(&self, app_handle: tauri::AppHandle) -> NotariResult<()> {
+        let mut store_lock = self.store.lock()?;
+        let store = tauri_plugin_store::StoreBuilder::new(&app_handle, &self.store_path)
+            .build()
+            .map_err(|_| NotariError::StorageNotInitialized)?;
+        *store_lock = Some(store);
+        Ok(())
+    }
+
+    /// Get the store instance
+    fn get_store(&self) -> NotariResult<Arc<tauri_plugin_store::Store<tauri::Wry>>> {
+        self.store
+            .lock()?
+            .clone()
+            .ok_or_else(|| NotariError::StorageNotInitialized)
+    }
+
+    /// Replace the value stored under `key`
+    fn save_value<T: serde::Serialize + ?Sized>(&self, key: &str, value: &T) -> NotariResult<()> {
+        let store = self.get_store()?;
+        let json = serde_json::to_value(value)?;
+        store.set(key.to_string(), json);
+        store
+            .save()
+            .map_err(|e| NotariError::StorageSaveFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Load the trust anchors as stored (base64 DER)
+    fn load_encoded_trust_anchors(&self) -> NotariResult<Vec<String>> {
+        let store = self.get_store()?;
+        match store.get(TRUST_ANCHORS_KEY) {
+            Some(value) => Ok(serde_json::from_value(value.clone())?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl TrustedSignerRepository for FileTrustedSignerRepository {
+    fn save_signer(&self, signer: &TrustedSigner) -> NotariResult<()> {
+        // Load existing signers, replacing any with the same key
+        let mut signers = self.load_signers()?;
+        match signers
+            .iter_mut()
+            .find(|existing| existing.public_key == signer.public_key)
+        {
+            Some(existing) => *existing = signer.clone(),
+            None => signers.push(signer.clone()),
+        }
+
+        self.save_value(TRUSTED_SIGNERS_KEY, &signers)
+    }
+
+    fn load_signers(&self) -> NotariResult<Vec<TrustedSigner>> {
+        let store = self.get_store()?;
+        if let Some(value) = store.get(TRUSTED_SIGNERS_KEY) {
+            let signers: Vec<TrustedSigner> = serde_json::from_value(value.clone())?;
+            Ok(signers)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn delete_signer(&self, public_key: &str) -> NotariResult<()> {
+        let mut signers = self.load_signers()?;
+        signers.retain(|signer| signer.public_key != public_key);
+        self.save_value(TRUSTED_SIGNERS_KEY, &signers)
+    }
+
+    fn save_trust_anchor(&self, certificate: &Certificate) -> NotariResult<()> {
+        let encoded = general_purpose::STANDARD.encode(&certificate.der);
+        let mut anchors = self.load_encoded_trust_anchors()?;
+        if !anchors.contains(&encoded) {
+            anchors.push(encoded);
+        }
+        self.save_value(TRUST_ANCHORS_KEY, &anchors)
+    }
+
+    fn load_trust_anchors(&self) -> NotariResult<Vec<Certificate>> {
+        self.load_encoded_trust_anchors()?
+            .iter()
+            .map(|encoded| {
+                general_purpose::STANDARD
+                    .decode(encoded)
+                    .map_err(|e| NotariError::StorageLoadFailed(e.to_string()))
+                    .and_then(|der| Certificate::from_der(&der))
+            })
+            .collect()
+    }
+
+    fn delete_trust_anchor(&self, fingerprint: &str) -> NotariResult<()> {
+        let mut anchors = self.load_trust_anchors()?;
+        anchors.retain(|anchor| anchor.fingerprint() != fingerprint);
+        let encoded: Vec<String> = anchors
+            .iter()
+            .map(|anchor| general_purpose::STANDARD.encode(&anchor.der))
+            .collect();
+        self.save_value(TRUST_ANCHORS_KEY, &encoded)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,5 +403,10 @@ mod tests {
 
         let anchor_repo = FileAnchorRepository::new(PathBuf::from("test-store.json"));
         assert_eq!(anchor_repo.store_path, PathBuf::from("test-store.json"));
+        assert!(anchor_repo.load_queue().is_err()); // Not initialized
+
+        let signer_repo = FileTrustedSignerRepository::new(PathBuf::from("test-store.json"));
+        assert_eq!(signer_repo.store_path, PathBuf::from("test-store.json"));
+        assert!(signer_repo.load_signers().is_err()); // Not initialized
     }
 }
@@ -121,6 +121,9 @@ pub mod key_ids {
     /// Signing key for evidence manifests (Ed25519)
     pub const SIGNING_KEY: &str = "signing_key";
 
+    /// Prefix for signing keys retired by a rotation (followed by the public key)
+    pub const RETIRED_SIGNING_KEY_PREFIX: &str = "signing_key_retired_";
+
     /// Encryption key for video encryption (X25519)
     pub const ENCRYPTION_KEY: &str = "encryption_key";
 
@@ -131,6 +134,11 @@ pub mod key_ids {
     pub fn wallet_key(chain_id: u64) -> String {
         format!("{}{}", WALLET_KEY_PREFIX, chain_id)
     }
+
+    /// Create a key ID for a retired signing key, by its base64 public key
+    pub fn retired_signing_key(public_key: &str) -> String {
+        format!("{}{}", RETIRED_SIGNING_KEY_PREFIX, public_key)
+    }
 }
 
 #[cfg(test)]
@@ -151,6 +159,10 @@ mod tests {
         assert_eq!(key_ids::SIGNING_KEY, "signing_key");
         assert_eq!(key_ids::wallet_key(1), "wallet_1");
         assert_eq!(key_ids::wallet_key(80002), "wallet_80002");
+        assert_eq!(
+            key_ids::retired_signing_key("AAAA"),
+            "signing_key_retired_AAAA"
+        );
     }
 
     #[cfg(target_os = "macos")]
@@ -30,6 +30,12 @@ pub mod traits;
 pub use factory::{
     get_repository_manager, init_repositories, RepositoryFactory, RepositoryManager,
 };
-pub use file::{FileAnchorRepository, FileConfigRepository, FilePreferencesRepository};
+pub use file::{
+    FileAnchorRepository, FileConfigRepository, FilePreferencesRepository,
+    FileTrustedSignerRepository,
+};
 pub use keychain::KeychainRepository;
-pub use traits::{AnchorRepository, ConfigRepository, KeyRepository, PreferencesRepository};
+pub use traits::{
+    AnchorQueueRepository, AnchorRepository, ConfigRepository, KeyRepository,
+    PreferencesRepository, TrustedSignerRepository,
+};
@@ -4,7 +4,9 @@
 //! Different implementations can provide different storage backends.
 
 use crate::error::NotariResult;
-use crate::evidence::blockchain::{AnchorProof, BlockchainConfig};
+use crate::evidence::blockchain::{AnchorProof, BlockchainConfig, QueuedAnchor};
+use crate::evidence::x509::Certificate;
+use crate::evidence::TrustedSigner;
 use crate::recording_manager::RecordingPreferences;
 use std::collections::HashMap;
 
@@ -77,6 +79,50 @@ pub trait AnchorRepository: Send + Sync {
     }
 }
 
+/// Repository for recordings waiting to be anchored in the next batch
+///
+/// The queue is saved whenever it changes, so queued recordings survive a restart.
+pub trait AnchorQueueRepository: Send + Sync {
+    /// Replace the saved queue
+    fn save_queue(&self, queue: &[QueuedAnchor]) -> NotariResult<()>;
+
+    /// Load the saved queue, oldest first
+    fn load_queue(&self) -> NotariResult<Vec<QueuedAnchor>>;
+}
+
+/// Repository for the public keys and certificate authorities the user trusts
+///
+/// Signers are identified by their public key; saving a signer whose key is
+/// already trusted replaces it. CA certificates (trust anchors) are identified
+/// by their SHA-256 fingerprint.
+pub trait TrustedSignerRepository: Send + Sync {
+    /// Save a trusted signer
+    fn save_signer(&self, signer: &TrustedSigner) -> NotariResult<()>;
+
+    /// Load all trusted signers
+    fn load_signers(&self) -> NotariResult<Vec<TrustedSigner>>;
+
+    /// Delete the trusted signer with a public key
+    fn delete_signer(&self, public_key: &str) -> NotariResult<()>;
+
+    /// Find the trusted signer with a public key
+    fn find_signer(&self, public_key: &str) -> NotariResult<Option<TrustedSigner>> {
+        Ok(self
+            .load_signers()?
+            .into_iter()
+            .find(|signer| signer.public_key == public_key))
+    }
+
+    /// Save a CA certificate whose certificate chains are trusted
+    fn save_trust_anchor(&self, certificate: &Certificate) -> NotariResult<()>;
+
+    /// Load all trusted CA certificates
+    fn load_trust_anchors(&self) -> NotariResult<Vec<Certificate>>;
+
+    /// Delete the trusted CA certificate with a fingerprint
+    fn delete_trust_anchor(&self, fingerprint: &str) -> NotariResult<()>;
+}
+
 /// Repository for cryptographic keys
 ///
 /// Implementations should use secure storage (keychain, HSM, etc.)
@@ -153,6 +199,8 @@ mod tests {
             },
             auto_anchor: false,
             wallet: None,
+            opentimestamps: Default::default(),
+            tsa: Default::default(),
         };
 
         repo.save_config(&config).unwrap();
@@ -202,4 +250,46 @@ mod tests {
         assert!(!repo.has_anchor("test").unwrap());
         assert_eq!(repo.count_anchors().unwrap(), 0);
     }
+
+    #[test]
+    fn test_trusted_signer_repository_default_methods() {
+        struct MockSignerRepo {
+            signers: Vec<TrustedSigner>,
+        }
+
+        impl TrustedSignerRepository for MockSignerRepo {
+            fn save_signer(&self, _signer: &TrustedSigner) -> NotariResult<()> {
+                Ok(())
+            }
+
+            fn load_signers(&self) -> NotariResult<Vec<TrustedSigner>> {
+                Ok(self.signers.clone())
+            }
+
+            fn delete_signer(&self, _public_key: &str) -> NotariResult<()> {
+                Ok(())
+            }
+
+            fn save_trust_anchor(&self, _certificate: &Certificate) -> NotariResult<()> {
+                Ok(())
+            }
+
+            fn load_trust_anchors(&self) -> NotariResult<Vec<Certificate>> {
+                Ok(Vec::new())
+            }
+
+            fn delete_trust_anchor(&self, _fingerprint: &str) -> NotariResult<()> {
+                Ok(())
+            }
+        }
+
+        let key = "A".repeat(43) + "=";
+        let repo = MockSignerRepo {
+            signers: vec![TrustedSigner::new("Alice", &key, None).unwrap()],
+        };
+
+        // Test default implementations
+        assert_eq!(repo.find_signer(&key).unwrap().unwrap().name, "Alice");
+        assert!(repo.find_signer("unknown").unwrap().is_none());
+    }
 }
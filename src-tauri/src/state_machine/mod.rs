@@ -8,6 +8,7 @@
 /// - `Idle` - No active recording
 /// - `Preparing` - Setting up recording
 /// - `Recording` - Actively capturing video
+/// - `Paused` - Capture suspended, resumable
 /// - `Stopping` - Gracefully shutting down
 /// - `Processing` - Running post-recording pipeline
 /// - `Completed` - Recording ready for verification/anchoring
@@ -2,6 +2,7 @@
 ///
 /// Each state is a distinct type, making invalid states impossible to represent.
 /// State-specific data is stored in each state type.
+use crate::evidence::PauseInterval;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -37,6 +38,33 @@ pub struct Recording {
 
     /// Optional encryption password
     pub encryption_password: Option<String>,
+
+    /// Completed pauses so far
+    pub pauses: Vec<PauseInterval>,
+}
+
+/// Paused state - Capture suspended
+///
+/// The recording process is still running but not capturing frames.
+#[derive(Debug, Clone)]
+pub struct Paused {
+    /// When recording started
+    pub started_at: DateTime<Utc>,
+
+    /// When the current pause began
+    pub paused_at: DateTime<Utc>,
+
+    /// Output file path
+    pub output_path: PathBuf,
+
+    /// Process ID of the recording process
+    pub process_id: u32,
+
+    /// Optional encryption password
+    pub encryption_password: Option<String>,
+
+    /// Pauses completed before this one
+    pub pauses: Vec<PauseInterval>,
 }
 
 /// Stopping state - Gracefully shutting down capture
@@ -55,6 +83,9 @@ pub struct Stopping {
 
     /// Optional encryption password
     pub encryption_password: Option<String>,
+
+    /// Pauses during the recording
+    pub pauses: Vec<PauseInterval>,
 }
 
 /// Processing state - Running post-recording pipeline
@@ -82,6 +113,9 @@ pub struct Processing {
 
     /// Recording duration in seconds
     pub duration: f64,
+
+    /// Pauses during the recording
+    pub pauses: Vec<PauseInterval>,
 }
 
 /// Completed state - Recording processed and ready
@@ -112,6 +146,10 @@ pub struct Completed {
 
     /// Whether the video was encrypted
     pub encrypted: bool,
+
+    /// Pauses during the recording
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pauses: Vec<PauseInterval>,
 }
 
 /// Failed state - Error occurred
@@ -178,6 +216,23 @@ impl Recording {
             output_path,
             process_id,
             encryption_password,
+            pauses: Vec::new(),
+        }
+    }
+
+    /// Create a new Recording state from Paused, closing the current pause
+    pub fn from_paused(paused: Paused) -> Self {
+        let mut pauses = paused.pauses;
+        pauses.push(PauseInterval {
+            paused_at: paused.paused_at,
+            resumed_at: Utc::now(),
+        });
+        Self {
+            started_at: paused.started_at,
+            output_path: paused.output_path,
+            process_id: paused.process_id,
+            encryption_password: paused.encryption_password,
+            pauses,
         }
     }
 
@@ -187,6 +242,25 @@ impl Recording {
     }
 }
 
+impl Paused {
+    /// Create a new Paused state from Recording
+    pub fn from_recording(recording: Recording) -> Self {
+        Self {
+            started_at: recording.started_at,
+            paused_at: Utc::now(),
+            output_path: recording.output_path,
+            process_id: recording.process_id,
+            encryption_password: recording.encryption_password,
+            pauses: recording.pauses,
+        }
+    }
+
+    /// Get how long the recording has been paused
+    pub fn pause_duration(&self) -> chrono::Duration {
+        Utc::now() - self.paused_at
+    }
+}
+
 impl Stopping {
     /// Create a new Stopping state from Recording
     pub fn from_recording(recording: Recording) -> Self {
@@ -195,6 +269,24 @@ impl Stopping {
             stopped_at: Utc::now(),
             output_path: recording.output_path,
             encryption_password: recording.encryption_password,
+            pauses: recording.pauses,
+        }
+    }
+
+    /// Create a new Stopping state from Paused, closing the current pause at the stop time
+    pub fn from_paused(paused: Paused) -> Self {
+        let stopped_at = Utc::now();
+        let mut pauses = paused.pauses;
+        pauses.push(PauseInterval {
+            paused_at: paused.paused_at,
+            resumed_at: stopped_at,
+        });
+        Self {
+            started_at: paused.started_at,
+            stopped_at,
+            output_path: paused.output_path,
+            encryption_password: paused.encryption_password,
+            pauses,
         }
     }
 
@@ -215,6 +307,7 @@ impl Processing {
             encryption_password: stopping.encryption_password,
             file_size,
             duration,
+            pauses: stopping.pauses,
         }
     }
 }
@@ -239,9 +332,16 @@ impl Completed {
             file_size,
             duration,
             encrypted,
+            pauses: Vec::new(),
         }
     }
 
+    /// Attach the pauses recorded during the session
+    pub fn with_pauses(mut self, pauses: Vec<PauseInterval>) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
     /// Get total time from start to completion
     pub fn total_duration(&self) -> chrono::Duration {
         self.completed_at - self.started_at
@@ -307,6 +407,31 @@ mod tests {
         assert!(stopping.stopped_at >= stopping.started_at);
     }
 
+    #[test]
+    fn test_pause_and_resume_record_interval() {
+        let recording = Recording::new(PathBuf::from("/tmp/test.mp4"), 12345, None);
+
+        let paused = Paused::from_recording(recording);
+        assert!(paused.pauses.is_empty());
+        let paused_at = paused.paused_at;
+
+        let recording = Recording::from_paused(paused);
+        assert_eq!(recording.pauses.len(), 1);
+        assert_eq!(recording.pauses[0].paused_at, paused_at);
+        assert!(recording.pauses[0].resumed_at >= paused_at);
+        assert_eq!(recording.process_id, 12345);
+    }
+
+    #[test]
+    fn test_stopping_from_paused_closes_pause() {
+        let recording = Recording::new(PathBuf::from("/tmp/test.mp4"), 12345, None);
+        let paused = Paused::from_recording(recording);
+
+        let stopping = Stopping::from_paused(paused);
+        assert_eq!(stopping.pauses.len(), 1);
+        assert_eq!(stopping.pauses[0].resumed_at, stopping.stopped_at);
+    }
+
     #[test]
     fn test_completed_durations() {
         let started = Utc::now() - chrono::Duration::seconds(10);
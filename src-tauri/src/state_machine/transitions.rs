@@ -72,6 +72,15 @@ impl RecordingSession<Preparing> {
 // ============================================================================
 
 impl RecordingSession<Recording> {
+    /// Transition to Paused state
+    pub fn pause(self) -> RecordingSession<Paused> {
+        RecordingSession {
+            session_id: self.session_id,
+            state: Paused::from_recording(self.state),
+            metadata: self.metadata,
+        }
+    }
+
     /// Transition to Stopping state
     pub fn stop(self) -> RecordingSession<Stopping> {
         RecordingSession {
@@ -95,6 +104,43 @@ impl RecordingSession<Recording> {
     }
 }
 
+// ============================================================================
+// Paused State Transitions
+// ============================================================================
+
+impl RecordingSession<Paused> {
+    /// Transition back to Recording state
+    pub fn resume(self) -> RecordingSession<Recording> {
+        RecordingSession {
+            session_id: self.session_id,
+            state: Recording::from_paused(self.state),
+            metadata: self.metadata,
+        }
+    }
+
+    /// Transition to Stopping state without resuming
+    pub fn stop(self) -> RecordingSession<Stopping> {
+        RecordingSession {
+            session_id: self.session_id,
+            state: Stopping::from_paused(self.state),
+            metadata: self.metadata,
+        }
+    }
+
+    /// Transition to Failed state
+    pub fn fail(self, error: String) -> RecordingSession<Failed> {
+        RecordingSession {
+            session_id: self.session_id,
+            state: Failed::new(
+                error,
+                FailedStage::Recording,
+                Some(self.state.output_path.clone()),
+            ),
+            metadata: self.metadata,
+        }
+    }
+}
+
 // ============================================================================
 // Stopping State Transitions
 // ============================================================================
@@ -145,7 +191,8 @@ impl RecordingSession<Processing> {
                 self.state.file_size,
                 self.state.duration,
                 encrypted,
-            ),
+            )
+            .with_pauses(self.state.pauses),
             metadata: self.metadata,
         }
     }
@@ -293,6 +340,57 @@ mod tests {
         assert!(!session.state.encrypted);
     }
 
+    #[test]
+    fn test_pause_and_resume() {
+        let session = RecordingSession::new("window-1".to_string(), create_test_preferences());
+        let session = session.prepare();
+        let session = session.start(PathBuf::from("/tmp/test.mp4"), 12345, None);
+
+        let session = session.pause();
+        assert_eq!(session.state.output_path, PathBuf::from("/tmp/test.mp4"));
+
+        let session = session.resume();
+        let session = session.pause();
+        let session = session.resume();
+        assert_eq!(session.state.pauses.len(), 2);
+        assert!(session.state.pauses[0].resumed_at <= session.state.pauses[1].paused_at);
+    }
+
+    #[test]
+    fn test_pauses_carried_to_completed() {
+        let session = RecordingSession::new("window-1".to_string(), create_test_preferences());
+        let session = session.prepare();
+        let session = session.start(PathBuf::from("/tmp/test.mp4"), 12345, None);
+        let session = session.pause().resume();
+
+        // Stopping while paused closes the open pause
+        let session = session.pause().stop();
+        assert_eq!(session.state.pauses.len(), 2);
+        assert_eq!(session.state.pauses[1].resumed_at, session.state.stopped_at);
+
+        let session = session.process(1024, 10.5).complete(
+            PathBuf::from("/tmp/test.notari"),
+            "abc123".to_string(),
+            false,
+        );
+        assert_eq!(session.state.pauses.len(), 2);
+    }
+
+    #[test]
+    fn test_paused_to_failed() {
+        let session = RecordingSession::new("window-1".to_string(), create_test_preferences());
+        let session = session.prepare();
+        let session = session.start(PathBuf::from("/tmp/test.mp4"), 12345, None);
+
+        let session = session.pause().fail("Sidecar exited".to_string());
+
+        assert!(matches!(session.state.failed_stage, FailedStage::Recording));
+        assert_eq!(
+            session.state.partial_output_path,
+            Some(PathBuf::from("/tmp/test.mp4"))
+        );
+    }
+
     #[test]
     fn test_completed_to_idle() {
         let session = RecordingSession::new("window-1".to_string(), create_test_preferences());
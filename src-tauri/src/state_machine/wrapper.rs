@@ -5,6 +5,7 @@
 use super::states::*;
 use super::RecordingSession;
 use crate::events::EventEmitter;
+use crate::evidence::PauseInterval;
 use std::path::PathBuf;
 use tauri::AppHandle;
 use uuid::Uuid;
@@ -15,6 +16,7 @@ pub enum RecordingSessionState {
     Idle(RecordingSession<Idle>),
     Preparing(RecordingSession<Preparing>),
     Recording(RecordingSession<Recording>),
+    Paused(RecordingSession<Paused>),
     Stopping(RecordingSession<Stopping>),
     Processing(RecordingSession<Processing>),
     Completed(RecordingSession<Completed>),
@@ -33,6 +35,7 @@ impl RecordingSessionState {
             Self::Idle(s) => s.session_id(),
             Self::Preparing(s) => s.session_id(),
             Self::Recording(s) => s.session_id(),
+            Self::Paused(s) => s.session_id(),
             Self::Stopping(s) => s.session_id(),
             Self::Processing(s) => s.session_id(),
             Self::Completed(s) => s.session_id(),
@@ -46,6 +49,7 @@ impl RecordingSessionState {
             Self::Idle(s) => s.window_id(),
             Self::Preparing(s) => s.window_id(),
             Self::Recording(s) => s.window_id(),
+            Self::Paused(s) => s.window_id(),
             Self::Stopping(s) => s.window_id(),
             Self::Processing(s) => s.window_id(),
             Self::Completed(s) => s.window_id(),
@@ -59,6 +63,7 @@ impl RecordingSessionState {
             Self::Idle(_) => "Idle",
             Self::Preparing(_) => "Preparing",
             Self::Recording(_) => "Recording",
+            Self::Paused(_) => "Paused",
             Self::Stopping(_) => "Stopping",
             Self::Processing(_) => "Processing",
             Self::Completed(_) => "Completed",
@@ -70,7 +75,11 @@ impl RecordingSessionState {
     pub fn is_active(&self) -> bool {
         matches!(
             self,
-            Self::Preparing(_) | Self::Recording(_) | Self::Stopping(_) | Self::Processing(_)
+            Self::Preparing(_)
+                | Self::Recording(_)
+                | Self::Paused(_)
+                | Self::Stopping(_)
+                | Self::Processing(_)
         )
     }
 
@@ -112,22 +121,52 @@ impl RecordingSessionState {
         }
     }
 
-    /// Transition to Stopping state (only from Recording)
-    pub fn stop(self, app: &AppHandle) -> Result<Self, String> {
+    /// Transition to Paused state (only from Recording)
+    pub fn pause(self, app: &AppHandle) -> Result<Self, String> {
         match self {
             Self::Recording(session) => {
                 let session_id = session.session_id();
-                let session = session.stop();
+                let session = session.pause();
 
                 // Emit event
-                let _ = EventEmitter::recording_state_changed(app, session_id, "Stopping");
+                let _ = EventEmitter::recording_state_changed(app, session_id, "Paused");
 
-                Ok(Self::Stopping(session))
+                Ok(Self::Paused(session))
             }
-            _ => Err(format!("Cannot stop from {} state", self.state_name())),
+            _ => Err(format!("Cannot pause from {} state", self.state_name())),
         }
     }
 
+    /// Transition back to Recording state (only from Paused)
+    pub fn resume(self, app: &AppHandle) -> Result<Self, String> {
+        match self {
+            Self::Paused(session) => {
+                let session_id = session.session_id();
+                let session = session.resume();
+
+                // Emit event
+                let _ = EventEmitter::recording_state_changed(app, session_id, "Recording");
+
+                Ok(Self::Recording(session))
+            }
+            _ => Err(format!("Cannot resume from {} state", self.state_name())),
+        }
+    }
+
+    /// Transition to Stopping state (from Recording or Paused)
+    pub fn stop(self, app: &AppHandle) -> Result<Self, String> {
+        let (session_id, session) = match self {
+            Self::Recording(session) => (session.session_id(), session.stop()),
+            Self::Paused(session) => (session.session_id(), session.stop()),
+            _ => return Err(format!("Cannot stop from {} state", self.state_name())),
+        };
+
+        // Emit event
+        let _ = EventEmitter::recording_state_changed(app, session_id, "Stopping");
+
+        Ok(Self::Stopping(session))
+    }
+
     /// Transition to Processing state (only from Stopping)
     pub fn process(self, file_size: u64, duration: f64, app: &AppHandle) -> Result<Self, String> {
         match self {
@@ -178,6 +217,7 @@ impl RecordingSessionState {
             }),
             Self::Preparing(session) => Self::Failed(session.fail(error.clone())),
             Self::Recording(session) => Self::Failed(session.fail(error.clone())),
+            Self::Paused(session) => Self::Failed(session.fail(error.clone())),
             Self::Stopping(session) => Self::Failed(session.fail(error.clone())),
             Self::Processing(session) => Self::Failed(session.fail(error.clone())),
             Self::Completed(session) => Self::Failed(RecordingSession {
@@ -213,12 +253,27 @@ impl RecordingSessionState {
     pub fn output_path(&self) -> Option<&PathBuf> {
         match self {
             Self::Recording(s) => Some(&s.state.output_path),
+            Self::Paused(s) => Some(&s.state.output_path),
             Self::Stopping(s) => Some(&s.state.output_path),
             Self::Processing(s) => Some(&s.state.output_path),
             _ => None,
         }
     }
 
+    /// Get the pauses completed so far
+    ///
+    /// An open pause is not included until the session resumes or stops.
+    pub fn pauses(&self) -> &[PauseInterval] {
+        match self {
+            Self::Recording(s) => &s.state.pauses,
+            Self::Paused(s) => &s.state.pauses,
+            Self::Stopping(s) => &s.state.pauses,
+            Self::Processing(s) => &s.state.pauses,
+            Self::Completed(s) => &s.state.pauses,
+            _ => &[],
+        }
+    }
+
     /// Get the proof pack path if completed
     pub fn proof_pack_path(&self) -> Option<&PathBuf> {
         match self {
@@ -272,4 +327,20 @@ mod tests {
         let session = RecordingSessionState::new("window-1".to_string(), create_test_preferences());
         assert_eq!(session.state_name(), "Idle");
     }
+
+    #[test]
+    fn test_paused_session() {
+        let session = RecordingSession::new("window-1".to_string(), create_test_preferences())
+            .prepare()
+            .start(PathBuf::from("/tmp/test.mp4"), 12345, None)
+            .pause()
+            .resume()
+            .pause();
+        let session = RecordingSessionState::Paused(session);
+
+        assert_eq!(session.state_name(), "Paused");
+        assert!(session.is_active());
+        assert_eq!(session.output_path(), Some(&PathBuf::from("/tmp/test.mp4")));
+        assert_eq!(session.pauses().len(), 1);
+    }
 }
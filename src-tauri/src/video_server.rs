@@ -5,23 +5,86 @@ use axum::{
     routing::get,
     Router,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tauri::http::{Request as TauriRequest, Response as TauriResponse};
 use tokio::sync::RwLock;
+use zeroize::Zeroizing;
 
-use crate::evidence::{EncryptionInfo, VideoEncryptor};
+use crate::error::NotariResult;
+use crate::evidence::{ContentKey, EncryptionInfo, VideoEncryptor};
 use crate::logger::{LogLevel, LOGGER};
 
+/// Decrypted chunks kept per stream (1 MB each)
+pub const CHUNK_CACHE_CAPACITY: usize = 16;
+
 /// Video stream state
 pub struct VideoStream {
     pub video_path: PathBuf,
-    pub password: Option<String>,
-    pub private_key: Option<crypto_box::SecretKey>,
+    /// Key of an encrypted video, derived once when playback starts
+    pub content_key: Option<ContentKey>,
     pub encryption_info: Option<EncryptionInfo>,
     pub file_size: u64,
     pub temp_dir: PathBuf,
+    /// Recently decrypted chunks, shared by the stream's range requests
+    pub chunk_cache: ChunkCache,
+}
+
+/// Least recently used cache of decrypted chunks
+///
+/// Players request overlapping ranges and seek back and forth, so most range
+/// requests hit chunks decrypted for an earlier one.
+pub struct ChunkCache {
+    capacity: usize,
+    /// Cached chunks by index, most recently used last; wiped once evicted
+    chunks: Mutex<VecDeque<(usize, Arc<Zeroizing<Vec<u8>>>)>>,
+}
+
+impl ChunkCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            chunks: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Chunk `index`, decrypted with `decrypt` unless it is cached
+    ///
+    /// The lock isn't held while decrypting, so concurrent requests for an
+    /// uncached chunk may both decrypt it.
+    pub fn get_or_decrypt<F>(
+        &self,
+        index: usize,
+        decrypt: F,
+    ) -> NotariResult<Arc<Zeroizing<Vec<u8>>>>
+    where
+        F: FnOnce() -> NotariResult<Vec<u8>>,
+    {
+        if let Some(chunk) = self.get(index)? {
+            return Ok(chunk);
+        }
+
+        let chunk = Arc::new(Zeroizing::new(decrypt()?));
+        let mut chunks = self.chunks.lock()?;
+        chunks.retain(|(cached, _)| *cached != index);
+        if chunks.len() >= self.capacity {
+            chunks.pop_front();
+        }
+        chunks.push_back((index, Arc::clone(&chunk)));
+        Ok(chunk)
+    }
+
+    fn get(&self, index: usize) -> NotariResult<Option<Arc<Zeroizing<Vec<u8>>>>> {
+        let mut chunks = self.chunks.lock()?;
+        let Some(position) = chunks.iter().position(|(cached, _)| *cached == index) else {
+            return Ok(None);
+        };
+        let entry = chunks.remove(position).expect("position is in bounds");
+        let chunk = Arc::clone(&entry.1);
+        chunks.push_back(entry);
+        Ok(Some(chunk))
+    }
 }
 
 /// Server state
@@ -256,30 +319,22 @@ pub async fn decrypt_chunk(stream: &VideoStream, start: u64, end: u64) -> Result
     );
 
     let data = if let Some(encryption_info) = &stream.encryption_info {
-        // Encrypted video - determine decryption method
-        if let Some(password) = &stream.password {
-            // Password-based decryption
-            VideoEncryptor::decrypt_byte_range(
-                &stream.video_path,
-                start,
-                end,
-                password,
-                encryption_info,
-            )
-            .map_err(|e| format!("Failed to decrypt byte range with password: {}", e))?
-        } else if let Some(private_key) = &stream.private_key {
-            // Public key decryption
-            VideoEncryptor::decrypt_byte_range_with_private_key(
-                &stream.video_path,
-                start,
-                end,
-                private_key,
-                encryption_info,
-            )
-            .map_err(|e| format!("Failed to decrypt byte range with private key: {}", e))?
-        } else {
-            return Err("Encrypted video but no password or private key provided".to_string());
-        }
+        // Encrypted video - decrypt the chunks covering the range, using cached ones
+        let key = stream
+            .content_key
+            .as_ref()
+            .ok_or("Encrypted video but no content key provided")?;
+        VideoEncryptor::assemble_byte_range(start, end, encryption_info, |chunk_index| {
+            stream.chunk_cache.get_or_decrypt(chunk_index, || {
+                VideoEncryptor::decrypt_chunk_with_key(
+                    &stream.video_path,
+                    chunk_index,
+                    key,
+                    encryption_info,
+                )
+            })
+        })
+        .map_err(|e| format!("Failed to decrypt byte range: {}", e))?
     } else {
         // Unencrypted video - read directly
         use std::io::{Read, Seek, SeekFrom};
@@ -436,6 +491,7 @@ fn parse_range(range_header: &str) -> Option<(u64, u64)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::NotariError;
 
     #[test]
     fn test_parse_range() {
@@ -444,4 +500,39 @@ mod tests {
         assert_eq!(parse_range("bytes=0-"), Some((0, u64::MAX)));
         assert_eq!(parse_range("invalid"), None);
     }
+
+    #[test]
+    fn test_chunk_cache_evicts_least_recently_used() {
+        let cache = ChunkCache::new(2);
+        let decrypts = std::cell::Cell::new(0);
+        let get = |index: usize| {
+            cache
+                .get_or_decrypt(index, || {
+                    decrypts.set(decrypts.get() + 1);
+                    Ok(vec![index as u8])
+                })
+                .unwrap()
+        };
+
+        assert_eq!(**get(0), vec![0]);
+        assert_eq!(**get(1), vec![1]);
+        assert_eq!(**get(0), vec![0]);
+        assert_eq!(decrypts.get(), 2);
+
+        // Chunk 1 is the least recently used, so it makes room for chunk 2
+        get(2);
+        get(0);
+        assert_eq!(decrypts.get(), 3);
+        get(1);
+        assert_eq!(decrypts.get(), 4);
+    }
+
+    #[test]
+    fn test_chunk_cache_does_not_cache_failures() {
+        let cache = ChunkCache::new(2);
+        assert!(cache
+            .get_or_decrypt(0, || Err(NotariError::DecryptionFailed("bad".to_string())))
+            .is_err());
+        assert_eq!(**cache.get_or_decrypt(0, || Ok(vec![7])).unwrap(), vec![7]);
+    }
 }
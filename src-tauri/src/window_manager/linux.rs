@@ -1,6 +1,22 @@
 use super::{PermissionStatus, WindowBounds, WindowInfo, WindowManager};
 use crate::error::{NotariError, NotariResult};
-use std::collections::HashMap;
+use crate::logger::{LogLevel, LOGGER};
+
+#[cfg(feature = "x11")]
+x11rb::atom_manager! {
+    /// EWMH atoms used for window enumeration
+    Atoms: AtomsCookie {
+        _NET_CLIENT_LIST,
+        _NET_WM_NAME,
+        _NET_WM_STATE,
+        _NET_WM_STATE_HIDDEN,
+        UTF8_STRING,
+    }
+}
+
+/// Longest side of a window thumbnail in pixels
+#[cfg(feature = "x11")]
+const THUMBNAIL_MAX_DIMENSION: u32 = 300;
 
 pub struct LinuxWindowManager;
 
@@ -17,6 +33,11 @@ impl LinuxWindowManager {
         std::env::var("DISPLAY").is_ok()
     }
 
+    /// Parse window ID to extract the X11 window ID
+    fn parse_window_id(&self, window_id: &str) -> Option<u32> {
+        window_id.strip_prefix("x11_")?.parse().ok()
+    }
+
     #[cfg(feature = "x11")]
     fn get_x11_windows(&self) -> NotariResult<Vec<WindowInfo>> {
         use x11rb::connection::Connection;
@@ -29,71 +50,139 @@ impl LinuxWindowManager {
         let screen = &conn.setup().roots[screen_num];
         let root = screen.root;
 
-        // Query for all windows
-        let tree_reply = conn
-            .query_tree(root)
+        let atoms = Atoms::new(&conn)
             .map_err(|e| {
-                NotariError::WindowEnumerationFailed(format!("Failed to query window tree: {}", e))
+                NotariError::WindowEnumerationFailed(format!("Failed to intern atoms: {}", e))
             })?
             .reply()
             .map_err(|e| {
-                NotariError::WindowEnumerationFailed(format!("Failed to get tree reply: {}", e))
+                NotariError::WindowEnumerationFailed(format!("Failed to intern atoms: {}", e))
             })?;
 
-        let mut windows = Vec::new();
-
-        for &window in &tree_reply.children {
-            // Get window attributes
-            if let Ok(attrs) = conn
-                .get_window_attributes(window)
-                .and_then(|cookie| cookie.reply())
-            {
-                // Skip unmapped windows
-                if attrs.map_state != MapState::VIEWABLE {
-                    continue;
-                }
-
-                // Get window geometry
-                let geometry = conn
-                    .get_geometry(window)
-                    .and_then(|cookie| cookie.reply())
-                    .unwrap_or_else(|_| GeometryReply {
-                        depth: 0,
-                        root: 0,
-                        x: 0,
-                        y: 0,
-                        width: 800,
-                        height: 600,
-                        border_width: 0,
-                    });
-
-                // Get window title (WM_NAME property)
-                let title = conn
-                    .get_property(false, window, AtomEnum::WM_NAME, AtomEnum::STRING, 0, 1024)
-                    .and_then(|cookie| cookie.reply())
-                    .ok()
-                    .and_then(|reply| String::from_utf8(reply.value).ok())
-                    .unwrap_or_else(|| format!("Window {}", window));
-
-                let window_info = WindowInfo {
-                    id: format!("x11_{}", window),
-                    title,
-                    application: "Unknown App".to_string(), // TODO: Get WM_CLASS
-                    is_minimized: false,                    // TODO: Check _NET_WM_STATE
-                    bounds: WindowBounds {
-                        x: geometry.x as i32,
-                        y: geometry.y as i32,
-                        width: geometry.width as u32,
-                        height: geometry.height as u32,
-                    },
-                    thumbnail: None,
-                };
-
-                windows.push(window_info);
+        // An EWMH window manager lists the top-level application windows (including
+        // minimized ones) in _NET_CLIENT_LIST
+        let client_list: Option<Vec<Window>> = conn
+            .get_property(
+                false,
+                root,
+                atoms._NET_CLIENT_LIST,
+                AtomEnum::WINDOW,
+                0,
+                u32::MAX,
+            )
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .and_then(|reply| reply.value32().map(|windows| windows.collect()));
+
+        let windows = match client_list {
+            Some(windows) => windows,
+            None => {
+                // Without a window manager, fall back to the mapped children of the root
+                LOGGER.log(
+                    LogLevel::Debug,
+                    "_NET_CLIENT_LIST not available, falling back to the window tree",
+                    "backend",
+                );
+
+                let tree_reply = conn
+                    .query_tree(root)
+                    .map_err(|e| {
+                        NotariError::WindowEnumerationFailed(format!(
+                            "Failed to query window tree: {}",
+                            e
+                        ))
+                    })?
+                    .reply()
+                    .map_err(|e| {
+                        NotariError::WindowEnumerationFailed(format!(
+                            "Failed to get tree reply: {}",
+                            e
+                        ))
+                    })?;
+
+                tree_reply
+                    .children
+                    .into_iter()
+                    .filter(|&window| {
+                        conn.get_window_attributes(window)
+                            .ok()
+                            .and_then(|cookie| cookie.reply().ok())
+                            .is_some_and(|attrs| attrs.map_state == MapState::VIEWABLE)
+                    })
+                    .collect()
             }
-        }
+        };
+
+        Ok(windows
+            .into_iter()
+            .filter_map(|window| self.get_x11_window_info(&conn, &atoms, root, window))
+            .collect())
+    }
 
-        Ok(windows)
+    /// Describe a top-level window; `None` if it disappeared while being queried
+    #[cfg(feature = "x11")]
+    fn get_x11_window_info(
+        &self,
+        conn: &impl x11rb::connection::Connection,
+        atoms: &Atoms,
+        root: x11rb::protocol::xproto::Window,
+        window: x11rb::protocol::xproto::Window,
+    ) -> Option<WindowInfo> {
+        use x11rb::protocol::xproto::*;
+
+        let property = |name: Atom, type_: Atom| {
+            conn.get_property(false, window, name, type_, 0, u32::MAX)
+                .ok()
+                .and_then(|cookie| cookie.reply().ok())
+                .filter(|reply| reply.type_ == type_)
+        };
+
+        let geometry = conn.get_geometry(window).ok()?.reply().ok()?;
+
+        // Geometry is relative to the parent (usually a WM frame); report root coordinates
+        let (x, y) = conn
+            .translate_coordinates(window, root, 0, 0)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .map(|reply| (reply.dst_x as i32, reply.dst_y as i32))
+            .unwrap_or((geometry.x as i32, geometry.y as i32));
+
+        // Prefer the UTF-8 _NET_WM_NAME; WM_NAME is Latin-1
+        let title = property(atoms._NET_WM_NAME, atoms.UTF8_STRING)
+            .map(|reply| String::from_utf8_lossy(&reply.value).into_owned())
+            .filter(|title| !title.is_empty())
+            .or_else(|| {
+                property(AtomEnum::WM_NAME.into(), AtomEnum::STRING.into())
+                    .map(|reply| decode_latin1(&reply.value))
+                    .filter(|title| !title.is_empty())
+            })
+            .unwrap_or_else(|| format!("Window {}", window));
+
+        let application = property(AtomEnum::WM_CLASS.into(), AtomEnum::STRING.into())
+            .and_then(|reply| parse_wm_class(&reply.value))
+            .unwrap_or_else(|| "Unknown App".to_string());
+
+        let is_minimized = property(atoms._NET_WM_STATE, AtomEnum::ATOM.into())
+            .and_then(|reply| {
+                reply
+                    .value32()
+                    .map(|mut states| states.any(|state| state == atoms._NET_WM_STATE_HIDDEN))
+            })
+            .unwrap_or(false);
+
+        Some(WindowInfo {
+            id: format!("x11_{}", window),
+            title,
+            application,
+            is_minimized,
+            bounds: WindowBounds {
+                x,
+                y,
+                width: geometry.width as u32,
+                height: geometry.height as u32,
+            },
+            thumbnail: None,
+        })
     }
 
     #[cfg(not(feature = "x11"))]
@@ -103,31 +192,167 @@ impl LinuxWindowManager {
         ))
     }
 
+    /// Create a base64 PNG thumbnail of a window's current contents
+    ///
+    /// The window is briefly redirected with XComposite so overlapping windows do
+    /// not show up in the thumbnail. Returns `None` for unmapped (minimized) windows.
+    #[cfg(feature = "x11")]
+    fn create_window_thumbnail(&self, window: u32) -> NotariResult<Option<String>> {
+        use base64::{engine::general_purpose, Engine as _};
+        use std::io::Cursor;
+        use x11rb::connection::Connection;
+        use x11rb::protocol::composite::{ConnectionExt as _, Redirect};
+        use x11rb::protocol::xproto::*;
+
+        let (conn, _) = x11rb::connect(None).map_err(thumbnail_error)?;
+
+        let attributes = conn
+            .get_window_attributes(window)
+            .map_err(thumbnail_error)?
+            .reply()
+            .map_err(|_| NotariError::WindowNotFound(format!("x11_{}", window)))?;
+        if attributes.map_state != MapState::VIEWABLE {
+            return Ok(None);
+        }
+        let geometry = conn
+            .get_geometry(window)
+            .map_err(thumbnail_error)?
+            .reply()
+            .map_err(thumbnail_error)?;
+
+        // Grab from the window's composite pixmap if the server supports it, else
+        // straight from the window (overlapping windows may then show through)
+        let redirected = conn
+            .composite_redirect_window(window, Redirect::AUTOMATIC)
+            .ok()
+            .and_then(|cookie| cookie.check().ok())
+            .is_some();
+        let pixmap = if redirected {
+            let pixmap = conn.generate_id().map_err(thumbnail_error)?;
+            conn.composite_name_window_pixmap(window, pixmap)
+                .map_err(thumbnail_error)?;
+            Some(pixmap)
+        } else {
+            None
+        };
+
+        let (drawable, offset) = match pixmap {
+            Some(pixmap) => (pixmap, geometry.border_width as i16),
+            None => (window, 0),
+        };
+        let image = conn
+            .get_image(
+                ImageFormat::Z_PIXMAP,
+                drawable,
+                offset,
+                offset,
+                geometry.width,
+                geometry.height,
+                !0,
+            )
+            .map_err(thumbnail_error)
+            .and_then(|cookie| cookie.reply().map_err(thumbnail_error));
+
+        if let Some(pixmap) = pixmap {
+            let _ = conn.free_pixmap(pixmap);
+        }
+        if redirected {
+            let _ = conn.composite_unredirect_window(window, Redirect::AUTOMATIC);
+        }
+        let _ = conn.flush();
+        let image = image?;
+
+        let (width, height) = (geometry.width as u32, geometry.height as u32);
+        let rgba = bgrx_to_rgba(&image.data);
+        let buffer = image::RgbaImage::from_raw(width, height, rgba).ok_or_else(|| {
+            NotariError::ThumbnailCreationFailed(format!(
+                "Unsupported window pixel format (depth {})",
+                image.depth
+            ))
+        })?;
+
+        let thumbnail = image::DynamicImage::ImageRgba8(buffer)
+            .thumbnail(THUMBNAIL_MAX_DIMENSION, THUMBNAIL_MAX_DIMENSION);
+
+        // Convert to PNG and encode as base64
+        let mut png_data = Vec::new();
+        thumbnail
+            .write_to(&mut Cursor::new(&mut png_data), image::ImageFormat::Png)
+            .map_err(|e| {
+                NotariError::ThumbnailCreationFailed(format!("Failed to encode PNG: {}", e))
+            })?;
+
+        let base64_string = general_purpose::STANDARD.encode(&png_data);
+        Ok(Some(format!("data:image/png;base64,{}", base64_string)))
+    }
+
+    #[cfg(not(feature = "x11"))]
+    fn create_window_thumbnail(&self, _window: u32) -> NotariResult<Option<String>> {
+        Ok(None)
+    }
+
     fn get_wayland_windows(&self) -> NotariResult<Vec<WindowInfo>> {
-        // Wayland doesn't allow direct window enumeration for security reasons
-        // We would need to use portals, which is more complex
-        // For now, return an error suggesting the user use the portal-based approach
+        // Wayland doesn't allow direct window enumeration for security reasons; it
+        // would need the ScreenCast portal and PipeWire, which aren't implemented
         Err(NotariError::DisplayServerNotSupported(
             "Wayland window enumeration requires portal support (not yet implemented)".to_string(),
         ))
     }
 }
 
+#[cfg(feature = "x11")]
+fn thumbnail_error(e: impl std::fmt::Display) -> NotariError {
+    NotariError::ThumbnailCreationFailed(e.to_string())
+}
+
+/// Application name from a `WM_CLASS` value (`instance\0class\0`)
+///
+/// The class (e.g. "Firefox") is preferred over the instance name ("Navigator").
+#[cfg(feature = "x11")]
+fn parse_wm_class(value: &[u8]) -> Option<String> {
+    let mut parts = value
+        .split(|&b| b == 0)
+        .filter(|part| !part.is_empty())
+        .map(decode_latin1);
+    let instance = parts.next()?;
+    Some(parts.next().unwrap_or(instance))
+}
+
+/// Decode an ICCCM `STRING` property (ISO 8859-1)
+#[cfg(feature = "x11")]
+fn decode_latin1(value: &[u8]) -> String {
+    value
+        .iter()
+        .take_while(|&&b| b != 0)
+        .map(|&b| b as char)
+        .collect()
+}
+
+/// Convert 32-bit BGRX pixels (X11 ZPixmap on little-endian) to opaque RGBA
+#[cfg(feature = "x11")]
+fn bgrx_to_rgba(data: &[u8]) -> Vec<u8> {
+    data.chunks_exact(4)
+        .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], 0xff])
+        .collect()
+}
+
 impl WindowManager for LinuxWindowManager {
     fn check_permission(&self) -> PermissionStatus {
-        if self.is_wayland() {
+        // Under a Wayland session with XWayland, DISPLAY is set as well and the
+        // XWayland windows can be captured like any X11 window
+        if self.is_x11() {
             PermissionStatus {
-                granted: false,
+                granted: true,
                 can_request: true,
                 system_settings_required: false,
-                message: "Wayland requires portal-based screen capture. Permission will be requested when recording starts.".to_string(),
+                message: "X11 screen capture is available.".to_string(),
             }
-        } else if self.is_x11() {
+        } else if self.is_wayland() {
             PermissionStatus {
-                granted: true,
-                can_request: true,
+                granted: false,
+                can_request: false,
                 system_settings_required: false,
-                message: "X11 screen capture is available.".to_string(),
+                message: "Native Wayland screen capture (PipeWire portal) is not implemented. Run an X11 session or XWayland to record X11 windows.".to_string(),
             }
         } else {
             PermissionStatus {
@@ -145,10 +370,10 @@ impl WindowManager for LinuxWindowManager {
     }
 
     fn get_windows(&self) -> NotariResult<Vec<WindowInfo>> {
-        if self.is_wayland() {
-            self.get_wayland_windows()
-        } else if self.is_x11() {
+        if self.is_x11() {
             self.get_x11_windows()
+        } else if self.is_wayland() {
+            self.get_wayland_windows()
         } else {
             Err(NotariError::DisplayServerNotSupported(
                 "No supported display server detected".to_string(),
@@ -156,9 +381,20 @@ impl WindowManager for LinuxWindowManager {
         }
     }
 
-    fn get_window_thumbnail(&self, _window_id: &str) -> NotariResult<Option<String>> {
-        // TODO: Implement thumbnail generation
-        Ok(None)
+    fn get_window_thumbnail(&self, window_id: &str) -> NotariResult<Option<String>> {
+        let Some(x11_window_id) = self.parse_window_id(window_id) else {
+            LOGGER.log(
+                LogLevel::Warn,
+                &format!(
+                    "Could not parse window ID: {} (expected format: x11_<number>)",
+                    window_id
+                ),
+                "backend",
+            );
+            return Ok(None);
+        };
+
+        self.create_window_thumbnail(x11_window_id)
     }
 
     fn open_system_settings(&self) -> NotariResult<()> {
@@ -181,4 +417,94 @@ mod tests {
         println!("Wayland: {}", manager.is_wayland());
         println!("X11: {}", manager.is_x11());
     }
+
+    #[test]
+    #[cfg(feature = "x11")]
+    fn test_parse_wm_class() {
+        assert_eq!(
+            parse_wm_class(b"Navigator\0Firefox\0"),
+            Some("Firefox".to_string())
+        );
+        assert_eq!(parse_wm_class(b"xterm\0"), Some("xterm".to_string()));
+        assert_eq!(parse_wm_class(b""), None);
+    }
+
+    #[test]
+    #[cfg(feature = "x11")]
+    fn test_decode_latin1() {
+        assert_eq!(decode_latin1(b"caf\xe9\0junk"), "café");
+    }
+
+    #[test]
+    #[cfg(feature = "x11")]
+    fn test_bgrx_to_rgba() {
+        assert_eq!(
+            bgrx_to_rgba(&[0x10, 0x20, 0x30, 0x00, 0x01, 0x02, 0x03, 0x00]),
+            vec![0x30, 0x20, 0x10, 0xff, 0x03, 0x02, 0x01, 0xff]
+        );
+    }
+
+    /// Lists and thumbnails a synthetic window; run under Xvfb:
+    /// `xvfb-run cargo test --lib window_manager::linux -- --ignored`
+    #[test]
+    #[cfg(feature = "x11")]
+    #[ignore = "needs an X server (e.g. Xvfb); run with --ignored"]
+    fn test_enumerate_synthetic_window() {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::xproto::*;
+        use x11rb::wrapper::ConnectionExt as _;
+
+        let (conn, screen_num) = x11rb::connect(None).unwrap();
+        let screen = &conn.setup().roots[screen_num];
+        let atoms = Atoms::new(&conn).unwrap().reply().unwrap();
+
+        let window = conn.generate_id().unwrap();
+        conn.create_window(
+            screen.root_depth,
+            window,
+            screen.root,
+            10,
+            20,
+            320,
+            240,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            screen.root_visual,
+            &CreateWindowAux::new().background_pixel(0x3366cc),
+        )
+        .unwrap();
+        conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            atoms._NET_WM_NAME,
+            atoms.UTF8_STRING,
+            "Synthetic ✓".as_bytes(),
+        )
+        .unwrap();
+        conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            AtomEnum::WM_CLASS,
+            AtomEnum::STRING,
+            b"synthetic\0NotariTest\0",
+        )
+        .unwrap();
+        conn.map_window(window).unwrap();
+        conn.flush().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        let manager = LinuxWindowManager::new();
+        let windows = manager.get_windows().unwrap();
+        let info = windows
+            .iter()
+            .find(|w| w.id == format!("x11_{}", window))
+            .expect("synthetic window should be listed");
+        assert_eq!(info.title, "Synthetic ✓");
+        assert_eq!(info.application, "NotariTest");
+        assert!(!info.is_minimized);
+        assert_eq!((info.bounds.width, info.bounds.height), (320, 240));
+
+        let thumbnail = manager.get_window_thumbnail(&info.id).unwrap().unwrap();
+        assert!(thumbnail.starts_with("data:image/png;base64,"));
+    }
 }
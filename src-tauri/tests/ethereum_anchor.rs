@@ -0,0 +1,262 @@
+//! Integration tests for `EthereumAnchorer` against a local dev chain
+//!
+//! Each test starts an `anvil` node, compiles and deploys
+//! `contracts/NotariRegistry.sol` with `solc`, and drives the real anchor and
+//! verify paths over JSON-RPC. Both tools must be on `PATH`, so the tests are
+//! ignored by default:
+//!
+//! ```sh
+//! cargo test --test ethereum_anchor -- --ignored
+//! ```
+
+use app_lib::evidence::*;
+use chrono::Utc;
+use ethers::prelude::{
+    Address, Bytes, Http, LocalWallet, Middleware, Provider, Signer, SignerMiddleware,
+    TransactionRequest,
+};
+use ethers::utils::{Anvil, AnvilInstance};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+use tempfile::TempDir;
+use uuid::Uuid;
+
+/// A running anvil node with `NotariRegistry` deployed
+struct DevChain {
+    // Keeps the node alive until the test finishes
+    anvil: AnvilInstance,
+    contract_address: Address,
+}
+
+impl DevChain {
+    async fn start() -> Self {
+        let anvil = Anvil::new().spawn();
+        let wallet: LocalWallet = anvil.keys()[0].clone().into();
+        let provider = Provider::<Http>::try_from(anvil.endpoint())
+            .unwrap()
+            .interval(Duration::from_millis(10));
+        let client = SignerMiddleware::new(provider, wallet.with_chain_id(anvil.chain_id()));
+
+        let deploy = TransactionRequest::new().data(registry_bytecode());
+        let receipt = client
+            .send_transaction(deploy, None)
+            .await
+            .expect("deploy transaction should be accepted")
+            .await
+            .unwrap()
+            .expect("deploy transaction should be mined");
+        let contract_address = receipt
+            .contract_address
+            .expect("deploy receipt should carry the contract address");
+
+        Self {
+            anvil,
+            contract_address,
+        }
+    }
+
+    fn chain_config(&self) -> ChainConfig {
+        ChainConfig {
+            chain_id: self.anvil.chain_id(),
+            name: "Anvil".to_string(),
+            rpc_url: self.anvil.endpoint(),
+            contract_address: format!("0x{:x}", self.contract_address),
+            explorer_url: "http://localhost".to_string(),
+            currency_symbol: "ETH".to_string(),
+        }
+    }
+
+    /// Anchorer paying with anvil's first pre-funded account
+    fn anchorer(&self) -> EthereumAnchorer {
+        let chain = self.chain_config();
+        EthereumAnchorer::new(
+            &chain.rpc_url,
+            &hex::encode(self.anvil.keys()[0].to_bytes()),
+            &chain.contract_address,
+            chain.chain_id,
+            &chain.name,
+            &chain.explorer_url,
+        )
+        .unwrap()
+    }
+}
+
+/// Compile `NotariRegistry.sol` and return its creation bytecode
+fn registry_bytecode() -> Bytes {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("../contracts/NotariRegistry.sol");
+    let output = Command::new("solc")
+        .arg("--bin")
+        .arg("--optimize")
+        .arg(&source)
+        .output()
+        .expect("solc must be installed to run these tests");
+    assert!(
+        output.status.success(),
+        "solc failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Output is "======= <file>:NotariRegistry =======\nBinary:\n<hex>"
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bytecode = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Binary"))
+        .nth(1)
+        .expect("solc output should contain the contract binary");
+    hex::decode(bytecode.trim()).unwrap().into()
+}
+
+fn random_hash() -> String {
+    hex::encode(Sha256::digest(Uuid::new_v4().as_bytes()))
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and solc on PATH; run with --ignored"]
+async fn test_balance_and_cost_estimate() {
+    let chain = DevChain::start().await;
+    let anchorer = chain.anchorer();
+
+    assert!(anchorer.get_balance().await.unwrap() > 0.0);
+    let cost = anchorer.estimate_cost().await.unwrap();
+    assert!(cost > 0.0 && cost < 1.0);
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and solc on PATH; run with --ignored"]
+async fn test_anchor_and_verify() {
+    let chain = DevChain::start().await;
+    let anchorer = chain.anchorer();
+    let hash = random_hash();
+
+    let proof = anchorer.anchor(&hash).await.unwrap();
+    match &proof {
+        AnchorProof::Ethereum {
+            chain_id,
+            contract_address,
+            tx_hash,
+            block_number,
+            ..
+        } => {
+            assert_eq!(*chain_id, chain.anvil.chain_id());
+            assert_eq!(*contract_address, format!("0x{:x}", chain.contract_address));
+            assert!(tx_hash.starts_with("0x"));
+            assert!(*block_number > 0);
+        }
+        other => panic!("expected an Ethereum proof, got {:?}", other),
+    }
+
+    assert!(anchorer.verify(&hash, &proof).await.unwrap());
+    assert!(!anchorer.verify(&random_hash(), &proof).await.unwrap());
+
+    // A verifier without a wallet sees the same anchor
+    let config = chain.chain_config();
+    let verifier = EthereumAnchorer::read_only(
+        &config.rpc_url,
+        &config.contract_address,
+        config.chain_id,
+        &config.name,
+        &config.explorer_url,
+    )
+    .unwrap();
+    assert!(verifier.verify(&hash, &proof).await.unwrap());
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and solc on PATH; run with --ignored"]
+async fn test_anchoring_same_hash_twice_reverts() {
+    let chain = DevChain::start().await;
+    let anchorer = chain.anchorer();
+    let hash = random_hash();
+
+    anchorer.anchor(&hash).await.unwrap();
+    let err = anchorer.anchor(&hash).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Hash already anchored"),
+        "unexpected error: {}",
+        err
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and solc on PATH; run with --ignored"]
+async fn test_anchor_then_deep_verify() {
+    let chain = DevChain::start().await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let video_path = temp_dir.path().join("recording.mov");
+    let manifest_path = temp_dir.path().join("recording.json");
+    let video_content = b"fake video content for anchoring";
+    std::fs::write(&video_path, video_content).unwrap();
+
+    let now = Utc::now();
+    let mut manifest = EvidenceManifestBuilder::new()
+        .session_id(Uuid::new_v4())
+        .file_path(video_path.clone())
+        .file_hash(HashInfo::from_file(&video_path).unwrap())
+        .file_size(video_content.len() as u64)
+        .duration(10.0)
+        .window_title("Test Window")
+        .window_id(123)
+        .app_name("Test App")
+        .app_bundle_id("com.test.app")
+        .resolution("1920x1080")
+        .frame_rate(30)
+        .codec("h264")
+        .system(
+            "macOS",
+            "14.0",
+            "test-device-id",
+            "test-machine",
+            env!("CARGO_PKG_VERSION"),
+            "ScreenCaptureKit",
+        )
+        .timestamps_from_dates(now, now)
+        .build()
+        .unwrap();
+    let key_manager = KeyManager::generate();
+    manifest.sign(&key_manager);
+
+    // Anchor the way `anchor_recording` does: hash without anchors, then re-sign
+    let anchored_hash = manifest.compute_pre_anchor_hash();
+    let proof = chain.anchorer().anchor(&anchored_hash).await.unwrap();
+    manifest.add_anchor(BlockchainAnchor {
+        anchored_at: Utc::now(),
+        anchored_hash: anchored_hash.clone(),
+        manifest_hash: anchored_hash,
+        proof,
+    });
+    manifest.sign(&key_manager);
+    manifest.save(&manifest_path).unwrap();
+
+    let config = BlockchainConfig {
+        enabled: true,
+        environment: BlockchainEnvironment::Testnet,
+        chain: chain.chain_config(),
+        ..Default::default()
+    };
+    let report = Verifier::verify_deep(&manifest_path, &video_path, None, |proof| {
+        BlockchainAnchorerFactory::create_verifier(proof, &config)
+            .map_err(|e| app_lib::error::NotariError::BlockchainNotConfigured(e.to_string()))
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(report.verification.status, VerificationStatus::Verified);
+    let anchors = &report.verification.checks.anchors;
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(
+        anchors[0].service,
+        format!(
+            "eip155:{}:0x{:x}",
+            chain.anvil.chain_id(),
+            chain.contract_address
+        )
+    );
+    let on_chain = anchors[0]
+        .on_chain_verified
+        .as_ref()
+        .expect("deep verification should query the chain");
+    assert!(on_chain.verified, "on-chain check failed: {:?}", on_chain);
+}
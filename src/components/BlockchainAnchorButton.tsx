@@ -16,6 +16,7 @@ type BlockchainConfig = {
 	auto_anchor: boolean;
 	has_wallet: boolean;
 	wallet_address?: string;
+	service_id: string;
 };
 
 type AnchorProof =
@@ -36,7 +37,23 @@ type AnchorProof =
 	| {
 			type: "OpenTimestamps";
 			ots_proof: string;
-			bitcoin_block: number;
+			bitcoin_block?: number;
+	  }
+	| {
+			type: "Rfc3161";
+			tsa_url: string;
+			tsa_name: string;
+			token: string;
+			gen_time: string;
+			serial_number: string;
+	  }
+	| {
+			type: "MerkleBatch";
+			merkle_root: string;
+			leaf_index: number;
+			leaf_count: number;
+			path: { sibling: string; side: "left" | "right" }[];
+			root_proof: AnchorProof;
 	  };
 
 type AnchorResult = {
@@ -47,23 +64,21 @@ type AnchorResult = {
 
 type BlockchainAnchorButtonProps = {
 	manifestPath: string;
-	anchor?: BlockchainAnchorInfo;
+	anchors?: BlockchainAnchorInfo[];
 	onAnchored?: () => void;
 };
 
 export default function BlockchainAnchorButton({
 	manifestPath,
-	anchor,
+	anchors = [],
 	onAnchored,
 }: BlockchainAnchorButtonProps) {
 	const [isAnchoring, setIsAnchoring] = useState(false);
 	const [error, setError] = useState<string | null>(null);
 	const [localAnchor, setLocalAnchor] = useState<BlockchainAnchorInfo | null>(null);
+	const [isQueued, setIsQueued] = useState(false);
 	const toast = useToast();
 
-	// Use local anchor if available (optimistic update), otherwise use prop
-	const displayAnchor = localAnchor || anchor;
-
 	// Load blockchain config with React Query (will auto-refresh when invalidated)
 	const { data: blockchainConfig, isLoading: configLoading } = useQuery({
 		queryKey: ["blockchain", "config"],
@@ -74,6 +89,15 @@ export default function BlockchainAnchorButton({
 		refetchOnWindowFocus: true,
 	});
 
+	// While anchoring is enabled, only an anchor on the configured chain or service counts
+	// as done; anchors elsewhere can be complemented. Use local anchor if available
+	// (optimistic update).
+	const displayAnchor =
+		localAnchor ||
+		(blockchainConfig?.enabled
+			? anchors.find((a) => a.service_id === blockchainConfig.service_id)
+			: anchors[0]);
+
 	const handleAnchor = async () => {
 		setIsAnchoring(true);
 		setError(null);
@@ -92,9 +116,12 @@ export default function BlockchainAnchorButton({
 							? result.proof.chain_name
 							: result.proof.type === "Mock"
 								? "Mock"
-								: "Bitcoin (OpenTimestamps)",
+								: result.proof.type === "Rfc3161"
+									? `RFC 3161 (${result.proof.tsa_name})`
+									: "Bitcoin (OpenTimestamps)",
 					tx_hash: result.proof.type === "Ethereum" ? result.proof.tx_hash : undefined,
 					explorer_url: result.proof.type === "Ethereum" ? result.proof.explorer_url : undefined,
+					service_id: blockchainConfig?.service_id ?? "",
 				};
 				setLocalAnchor(anchorInfo);
 
@@ -118,6 +145,20 @@ export default function BlockchainAnchorButton({
 		}
 	};
 
+	const handleQueue = async () => {
+		try {
+			const waiting = await invoke<number>("queue_anchor", { manifestPath });
+			setIsQueued(true);
+			toast.success(
+				"Queued for Batch Anchoring",
+				`${waiting} recording${waiting === 1 ? "" : "s"} waiting. Anchor the batch from Settings → Blockchain.`
+			);
+		} catch (err) {
+			const errorMessage = err instanceof Error ? err.message : String(err);
+			toast.error("Queueing Failed", errorMessage);
+		}
+	};
+
 	const openExplorer = () => {
 		if (displayAnchor?.explorer_url && displayAnchor?.tx_hash) {
 			const url = `${displayAnchor.explorer_url}/tx/${displayAnchor.tx_hash}`;
@@ -271,28 +312,65 @@ export default function BlockchainAnchorButton({
 		);
 	}
 
-	// Show anchor button
-	return (
-		<Tooltip
-			content={
-				<div className="p-2">
-					<p className="text-xs font-semibold mb-1">Anchor to Blockchain</p>
-					<p className="text-xs text-foreground-400">
-						Create an immutable timestamp for this recording on the blockchain
-					</p>
-				</div>
-			}
-		>
-			<Button
+	if (isQueued) {
+		return (
+			<Chip
 				size="sm"
 				variant="flat"
-				color="primary"
-				onPress={handleAnchor}
+				color="default"
 				startContent={<Anchor className="w-3.5 h-3.5" />}
-				className="h-6 px-2 min-w-0"
+				classNames={{
+					base: "h-6 px-2",
+					content: "text-xs font-medium",
+				}}
 			>
-				<span className="text-xs font-medium">Anchor</span>
-			</Button>
-		</Tooltip>
+				Queued
+			</Chip>
+		);
+	}
+
+	// Show anchor and queue buttons
+	return (
+		<div className="flex items-center gap-1.5">
+			<Tooltip
+				content={
+					<div className="p-2">
+						<p className="text-xs font-semibold mb-1">
+							{anchors.length > 0 ? "Add Another Anchor" : "Anchor to Blockchain"}
+						</p>
+						<p className="text-xs text-foreground-400">
+							{anchors.length > 0
+								? `Also anchor this recording to ${blockchainConfig?.chain_name ?? "the configured service"} for an independent timestamp`
+								: "Create an immutable timestamp for this recording on the blockchain"}
+						</p>
+					</div>
+				}
+			>
+				<Button
+					size="sm"
+					variant="flat"
+					color="primary"
+					onPress={handleAnchor}
+					startContent={<Anchor className="w-3.5 h-3.5" />}
+					className="h-6 px-2 min-w-0"
+				>
+					<span className="text-xs font-medium">Anchor</span>
+				</Button>
+			</Tooltip>
+			<Tooltip
+				content={
+					<div className="p-2">
+						<p className="text-xs font-semibold mb-1">Queue for Batch</p>
+						<p className="text-xs text-foreground-400">
+							Anchor later together with other recordings under one Merkle root
+						</p>
+					</div>
+				}
+			>
+				<Button size="sm" variant="light" onPress={handleQueue} className="h-6 px-2 min-w-0">
+					<span className="text-xs font-medium">Queue</span>
+				</Button>
+			</Tooltip>
+		</div>
 	);
 }
@@ -20,6 +20,19 @@ type BlockchainConfig = {
 	wallet_address: string | null;
 };
 
+type QueuedAnchor = {
+	manifest_path: string;
+	manifest_hash: string;
+	queued_at: string;
+};
+
+type BatchAnchorResult = {
+	merkle_root: string;
+	anchored_at: string;
+	anchored: string[];
+	failed: { manifest_path: string; error: string }[];
+};
+
 type ChainInfo = {
 	chain_id: number;
 	name: string;
@@ -54,6 +67,10 @@ export default function BlockchainSettings() {
 	const [testingConnection, setTestingConnection] = useState(false);
 	const [connectionResult, setConnectionResult] = useState("");
 
+	// Batch anchoring
+	const [anchorQueue, setAnchorQueue] = useState<QueuedAnchor[]>([]);
+	const [flushingQueue, setFlushingQueue] = useState(false);
+
 	const loadConfig = useCallback(async () => {
 		try {
 			const cfg = await invoke<BlockchainConfig>("get_blockchain_config");
@@ -74,10 +91,19 @@ export default function BlockchainSettings() {
 		}
 	}, []);
 
+	const loadAnchorQueue = useCallback(async () => {
+		try {
+			setAnchorQueue(await invoke<QueuedAnchor[]>("get_anchor_queue"));
+		} catch (error) {
+			console.error("Failed to load anchor queue:", error);
+		}
+	}, []);
+
 	useEffect(() => {
 		loadConfig();
 		loadChains();
-	}, [loadConfig, loadChains]);
+		loadAnchorQueue();
+	}, [loadConfig, loadChains, loadAnchorQueue]);
 
 	const handleToggleEnabled = async (enabled: boolean) => {
 		if (!config) return;
@@ -271,6 +297,30 @@ export default function BlockchainSettings() {
 		}
 	};
 
+	const handleFlushQueue = async () => {
+		setFlushingQueue(true);
+		try {
+			const result = await invoke<BatchAnchorResult>("flush_anchor_queue");
+			if (result.failed.length > 0) {
+				toast.warning(
+					"Batch Partially Anchored",
+					`${result.anchored.length} anchored, ${result.failed.length} failed: ${result.failed[0].error}`
+				);
+			} else {
+				toast.success(
+					"Batch Anchored",
+					`${result.anchored.length} recordings anchored under one Merkle root`
+				);
+			}
+			queryClient.invalidateQueries({ queryKey: ["recordings", "list"] });
+		} catch (error) {
+			toast.error("Batch Anchoring Failed", `${error}`);
+		} finally {
+			await loadAnchorQueue();
+			setFlushingQueue(false);
+		}
+	};
+
 	if (loading) {
 		return (
 			<div className="flex items-center justify-center py-8">
@@ -284,6 +334,8 @@ export default function BlockchainSettings() {
 	}
 
 	const selectedChain = chains.find((c) => c.chain_id === config.chain_id);
+	// Mock, OpenTimestamps and RFC 3161 need neither a chain nor a wallet
+	const usesWallet = config.environment === "Testnet" || config.environment === "Mainnet";
 
 	return (
 		<div className="space-y-4">
@@ -324,12 +376,14 @@ export default function BlockchainSettings() {
 								<SelectItem key="Mock">Mock (Development)</SelectItem>
 								<SelectItem key="Testnet">Testnet (Free Testing)</SelectItem>
 								<SelectItem key="Mainnet">Mainnet (Production)</SelectItem>
+								<SelectItem key="OpenTimestamps">OpenTimestamps (Bitcoin, No Wallet)</SelectItem>
+								<SelectItem key="Rfc3161">RFC 3161 Timestamp Authority (No Wallet)</SelectItem>
 							</Select>
 						</CardBody>
 					</Card>
 
 					{/* Chain Selection */}
-					{config.environment !== "Mock" && (
+					{usesWallet && (
 						<Card className="bg-content1">
 							<CardHeader>
 								<h4 className="text-sm font-medium text-foreground">Blockchain Network</h4>
@@ -370,7 +424,7 @@ export default function BlockchainSettings() {
 					)}
 
 					{/* Wallet Configuration */}
-					{config.environment !== "Mock" && (
+					{usesWallet && (
 						<Card className="bg-content1">
 							<CardHeader>
 								<div className="flex items-center space-x-2">
@@ -544,6 +598,33 @@ export default function BlockchainSettings() {
 						</CardBody>
 					</Card>
 
+					{/* Batch Anchoring */}
+					<Card className="bg-content1">
+						<CardHeader>
+							<h4 className="text-sm font-medium text-foreground">Batch Anchoring</h4>
+						</CardHeader>
+						<Divider />
+						<CardBody className="space-y-3">
+							<p className="text-xs text-foreground-500">
+								Queued recordings are anchored together under a single Merkle root, so one
+								transaction covers the whole batch. Each recording keeps its own inclusion proof.
+							</p>
+							<Button
+								size="sm"
+								variant="flat"
+								color="primary"
+								onPress={handleFlushQueue}
+								isLoading={flushingQueue}
+								isDisabled={anchorQueue.length === 0}
+								className="w-full"
+							>
+								{anchorQueue.length === 0
+									? "No Recordings Queued"
+									: `Anchor ${anchorQueue.length} Queued Recording${anchorQueue.length === 1 ? "" : "s"}`}
+							</Button>
+						</CardBody>
+					</Card>
+
 					{/* Connection Test */}
 					{config.has_wallet && (
 						<Card className="bg-content1">
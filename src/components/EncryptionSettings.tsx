@@ -7,7 +7,7 @@ import { Eye, EyeOff, Lock, Plus, Trash2, UserPlus, Users } from "lucide-react";
 import { useState } from "react";
 import { logger } from "../utils/logger";
 
-export type EncryptionMethod = "password" | "public-key";
+export type EncryptionMethod = "password" | "public-key" | "hybrid";
 
 export type Recipient = {
 	id: string;
@@ -19,12 +19,12 @@ type EncryptionSettingsProps = {
 	onEncryptionEnabledChange: (enabled: boolean) => void;
 	encryptionMethod: EncryptionMethod;
 	onEncryptionMethodChange: (method: EncryptionMethod) => void;
-	// Password-based encryption
+	// Password-based (or hybrid) encryption
 	password: string;
 	onPasswordChange: (password: string) => void;
 	passwordError: string | null;
 	onPasswordErrorChange: (error: string | null) => void;
-	// Public key encryption
+	// Public key (or hybrid) encryption
 	recipients: Recipient[];
 	onRecipientsChange: (recipients: Recipient[]) => void;
 };
@@ -173,10 +173,18 @@ export default function EncryptionSettings({
 								</p>
 							</div>
 						</Radio>
+						<Radio value="hybrid">
+							<div>
+								<p className="text-sm font-medium">Recipients and Password</p>
+								<p className="text-xs text-foreground-500">
+									Recipients open it with their keys, and the password opens it too
+								</p>
+							</div>
+						</Radio>
 					</RadioGroup>
 
 					{/* Password Input */}
-					{encryptionMethod === "password" && (
+					{(encryptionMethod === "password" || encryptionMethod === "hybrid") && (
 						<div className="space-y-2">
 							<Input
 								type={showPassword ? "text" : "password"}
@@ -211,7 +219,7 @@ export default function EncryptionSettings({
 					)}
 
 					{/* Public Key Recipients */}
-					{encryptionMethod === "public-key" && (
+					{(encryptionMethod === "public-key" || encryptionMethod === "hybrid") && (
 						<div className="space-y-3">
 							<div className="flex items-center space-x-2 text-sm font-medium">
 								<Users className="w-4 h-4" />
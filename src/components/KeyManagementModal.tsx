@@ -1,14 +1,37 @@
 import { Button } from "@heroui/button";
 import { Card, CardBody } from "@heroui/card";
 import { Chip } from "@heroui/chip";
+import { Input } from "@heroui/input";
 import { Modal, ModalBody, ModalContent, ModalFooter, ModalHeader } from "@heroui/modal";
 import { invoke } from "@tauri-apps/api/core";
-import { AlertCircle, CheckCircle, Copy, Key, Shield, XCircle } from "lucide-react";
+import { open } from "@tauri-apps/plugin-dialog";
+import {
+	AlertCircle,
+	BadgeCheck,
+	CheckCircle,
+	Copy,
+	FileKey,
+	Key,
+	RefreshCw,
+	Shield,
+	Trash2,
+	UserCheck,
+	XCircle,
+} from "lucide-react";
 import { useEffect, useState } from "react";
 import {
 	formatPublicKeyFingerprint,
+	useAddTrustAnchorMutation,
+	useAddTrustedSignerMutation,
+	useClearSignerCertificateMutation,
 	useHasSigningKeyQuery,
 	usePublicKeyQuery,
+	useRemoveTrustAnchorMutation,
+	useRemoveTrustedSignerMutation,
+	useSetSignerCertificateMutation,
+	useSignerCertificateQuery,
+	useTrustAnchorsQuery,
+	useTrustedSignersQuery,
 } from "../hooks/useEvidence";
 
 type KeyManagementModalProps = {
@@ -21,7 +44,20 @@ export default function KeyManagementModal({ isOpen, onClose }: KeyManagementMod
 	const { data: publicKey, refetch: refetchPublicKey } = usePublicKeyQuery();
 	const [isGenerating, setIsGenerating] = useState(false);
 	const [generateError, setGenerateError] = useState<string | null>(null);
+	const [isRotating, setIsRotating] = useState(false);
+	const [rotateError, setRotateError] = useState<string | null>(null);
 	const [copySuccess, setCopySuccess] = useState(false);
+	const { data: trustedSigners } = useTrustedSignersQuery();
+	const addTrustedSigner = useAddTrustedSignerMutation();
+	const removeTrustedSigner = useRemoveTrustedSignerMutation();
+	const [signerName, setSignerName] = useState("");
+	const [signerKey, setSignerKey] = useState("");
+	const { data: signerCertificate } = useSignerCertificateQuery();
+	const setSignerCertificate = useSetSignerCertificateMutation();
+	const clearSignerCertificate = useClearSignerCertificateMutation();
+	const { data: trustAnchors } = useTrustAnchorsQuery();
+	const addTrustAnchor = useAddTrustAnchorMutation();
+	const removeTrustAnchor = useRemoveTrustAnchorMutation();
 
 	// Refetch when modal opens
 	useEffect(() => {
@@ -46,6 +82,64 @@ export default function KeyManagementModal({ isOpen, onClose }: KeyManagementMod
 		}
 	};
 
+	const handleRotateKey = async () => {
+		setIsRotating(true);
+		setRotateError(null);
+
+		try {
+			await invoke("rotate_signing_key", { reason: null });
+			await refetchPublicKey();
+		} catch (error) {
+			setRotateError(error instanceof Error ? error.message : String(error));
+		} finally {
+			setIsRotating(false);
+		}
+	};
+
+	const handleAddTrustedSigner = async () => {
+		try {
+			await addTrustedSigner.mutateAsync({ name: signerName, publicKey: signerKey });
+			setSignerName("");
+			setSignerKey("");
+		} catch (error) {
+			console.error("Failed to add trusted signer:", error);
+		}
+	};
+
+	// Pick a PEM or DER certificate file
+	const selectCertificateFile = async (): Promise<string | null> => {
+		// Push popover guard to prevent popover from closing when dialog opens
+		await invoke("popover_guard_push");
+
+		try {
+			const selected = await open({
+				multiple: false,
+				filters: [{ name: "Certificate", extensions: ["pem", "crt", "cer", "der"] }],
+			});
+			return typeof selected === "string" ? selected : null;
+		} finally {
+			await invoke("popover_guard_pop").catch(() => {});
+		}
+	};
+
+	const handleSetSignerCertificate = async () => {
+		try {
+			const path = await selectCertificateFile();
+			if (path) await setSignerCertificate.mutateAsync(path);
+		} catch (error) {
+			console.error("Failed to set signer certificate:", error);
+		}
+	};
+
+	const handleAddTrustAnchor = async () => {
+		try {
+			const path = await selectCertificateFile();
+			if (path) await addTrustAnchor.mutateAsync(path);
+		} catch (error) {
+			console.error("Failed to add trusted CA:", error);
+		}
+	};
+
 	const handleCopyPublicKey = async () => {
 		if (!publicKey) return;
 
@@ -213,6 +307,203 @@ export default function KeyManagementModal({ isOpen, onClose }: KeyManagementMod
 							</Card>
 						)}
 
+						{/* Key Rotation */}
+						{hasSigningKey && (
+							<Card>
+								<CardBody className="p-4">
+									<h4 className="font-semibold mb-3">Rotate Signing Key</h4>
+									<p className="text-sm text-foreground-500 mb-4">
+										Replace your key with a new one, signed over by the current key. Recordings
+										signed with either key verify against your original public key. Rotate at
+										least once a year.
+									</p>
+									<Button
+										color="primary"
+										variant="flat"
+										onPress={handleRotateKey}
+										isLoading={isRotating}
+										startContent={<RefreshCw className="w-4 h-4" />}
+									>
+										Rotate Key
+									</Button>
+									{rotateError && (
+										<div className="mt-3 p-3 bg-danger-50 dark:bg-danger-900/20 rounded-lg border border-danger-200 dark:border-danger-800">
+											<div className="flex items-start space-x-2">
+												<XCircle className="w-4 h-4 text-danger flex-shrink-0 mt-0.5" />
+												<span className="text-sm text-danger">{rotateError}</span>
+											</div>
+										</div>
+									)}
+								</CardBody>
+							</Card>
+						)}
+
+						{/* Trusted Signers */}
+						<Card>
+							<CardBody className="p-4">
+								<h4 className="font-semibold mb-3 flex items-center space-x-2">
+									<UserCheck className="w-4 h-4" />
+									<span>Trusted Signers</span>
+								</h4>
+								<p className="text-sm text-foreground-500 mb-4">
+									Anyone can sign a recording with a key of their own. Add the public keys of people
+									you know, and verification names them instead of reporting an unknown signer.
+								</p>
+								{trustedSigners && trustedSigners.length > 0 && (
+									<div className="space-y-2 mb-4">
+										{trustedSigners.map((signer) => (
+											<div
+												key={signer.public_key}
+												className="flex items-center justify-between p-2 bg-content2 rounded-lg"
+											>
+												<div>
+													<div className="text-sm font-medium">{signer.name}</div>
+													<div className="text-xs text-foreground-500 font-mono">
+														{formatPublicKeyFingerprint(signer.public_key)}
+													</div>
+												</div>
+												<Button
+													isIconOnly
+													size="sm"
+													variant="light"
+													color="danger"
+													aria-label={`Remove ${signer.name}`}
+													onPress={() => removeTrustedSigner.mutate(signer.public_key)}
+												>
+													<Trash2 className="w-4 h-4" />
+												</Button>
+											</div>
+										))}
+									</div>
+								)}
+								<div className="space-y-2">
+									<Input size="sm" label="Name" value={signerName} onValueChange={setSignerName} />
+									<Input
+										size="sm"
+										label="Public key (base64)"
+										value={signerKey}
+										onValueChange={setSignerKey}
+										classNames={{ input: "font-mono text-xs" }}
+									/>
+									<Button
+										color="primary"
+										variant="flat"
+										onPress={handleAddTrustedSigner}
+										isLoading={addTrustedSigner.isPending}
+										isDisabled={!signerName.trim() || !signerKey.trim()}
+										startContent={<UserCheck className="w-4 h-4" />}
+									>
+										Trust Key
+									</Button>
+								</div>
+								{addTrustedSigner.error && (
+									<div className="mt-3 p-3 bg-danger-50 dark:bg-danger-900/20 rounded-lg border border-danger-200 dark:border-danger-800">
+										<div className="flex items-start space-x-2">
+											<XCircle className="w-4 h-4 text-danger flex-shrink-0 mt-0.5" />
+											<span className="text-sm text-danger">{String(addTrustedSigner.error)}</span>
+										</div>
+									</div>
+								)}
+							</CardBody>
+						</Card>
+
+						{/* Certificates */}
+						<Card>
+							<CardBody className="p-4">
+								<h4 className="font-semibold mb-3 flex items-center space-x-2">
+									<BadgeCheck className="w-4 h-4" />
+									<span>Certificates</span>
+								</h4>
+								<p className="text-sm text-foreground-500 mb-4">
+									If your organisation certifies your signing key, recordings carry the certificate
+									chain. Recordings whose chain leads to a trusted CA verify as signed by the
+									certificate's subject.
+								</p>
+								{signerCertificate && signerCertificate.length > 0 ? (
+									<div className="flex items-center justify-between p-2 bg-content2 rounded-lg mb-2">
+										<div>
+											<div className="text-sm font-medium">{signerCertificate[0].subject}</div>
+											<div className="text-xs text-foreground-500">
+												Issued by {signerCertificate[0].issuer}, expires{" "}
+												{new Date(signerCertificate[0].not_after).toLocaleDateString()}
+											</div>
+										</div>
+										<Button
+											isIconOnly
+											size="sm"
+											variant="light"
+											color="danger"
+											aria-label="Stop signing with certificate"
+											onPress={() => clearSignerCertificate.mutate()}
+										>
+											<Trash2 className="w-4 h-4" />
+										</Button>
+									</div>
+								) : (
+									<Button
+										className="mb-2"
+										color="primary"
+										variant="flat"
+										size="sm"
+										onPress={handleSetSignerCertificate}
+										isLoading={setSignerCertificate.isPending}
+										isDisabled={!hasSigningKey}
+										startContent={<FileKey className="w-4 h-4" />}
+									>
+										Sign With Certificate
+									</Button>
+								)}
+								<div className="text-sm font-medium mt-3 mb-2">Trusted CAs</div>
+								{trustAnchors && trustAnchors.length > 0 && (
+									<div className="space-y-2 mb-2">
+										{trustAnchors.map((anchor) => (
+											<div
+												key={anchor.fingerprint}
+												className="flex items-center justify-between p-2 bg-content2 rounded-lg"
+											>
+												<div>
+													<div className="text-sm font-medium">{anchor.subject}</div>
+													<div className="text-xs text-foreground-500 font-mono">
+														{anchor.fingerprint.slice(0, 16)}
+													</div>
+												</div>
+												<Button
+													isIconOnly
+													size="sm"
+													variant="light"
+													color="danger"
+													aria-label={`Remove ${anchor.subject}`}
+													onPress={() => removeTrustAnchor.mutate(anchor.fingerprint)}
+												>
+													<Trash2 className="w-4 h-4" />
+												</Button>
+											</div>
+										))}
+									</div>
+								)}
+								<Button
+									color="primary"
+									variant="flat"
+									size="sm"
+									onPress={handleAddTrustAnchor}
+									isLoading={addTrustAnchor.isPending}
+									startContent={<BadgeCheck className="w-4 h-4" />}
+								>
+									Trust CA Certificate
+								</Button>
+								{(setSignerCertificate.error || addTrustAnchor.error) && (
+									<div className="mt-3 p-3 bg-danger-50 dark:bg-danger-900/20 rounded-lg border border-danger-200 dark:border-danger-800">
+										<div className="flex items-start space-x-2">
+											<XCircle className="w-4 h-4 text-danger flex-shrink-0 mt-0.5" />
+											<span className="text-sm text-danger">
+												{String(setSignerCertificate.error ?? addTrustAnchor.error)}
+											</span>
+										</div>
+									</div>
+								)}
+							</CardBody>
+						</Card>
+
 						{/* Warning for Regeneration */}
 						{hasSigningKey && (
 							<Card className="bg-warning-50 dark:bg-warning-900/20 border-warning-200 dark:border-warning-800">
@@ -222,9 +513,10 @@ export default function KeyManagementModal({ isOpen, onClose }: KeyManagementMod
 										<div>
 											<h4 className="font-semibold text-warning mb-1">Warning</h4>
 											<p className="text-sm text-foreground-600 dark:text-foreground-400">
-												Generating a new key will replace your current key. Previous recordings
-												signed with the old key will still be valid, but you won't be able to sign
-												new recordings with the old key.
+												Generating a new key will replace your current key without linking it to
+												the old one. Previous recordings signed with the old key will still be
+												valid, but new recordings won't verify against your old public key. Rotate
+												the key instead to keep them linked.
 											</p>
 										</div>
 									</div>
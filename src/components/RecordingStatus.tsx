@@ -14,6 +14,7 @@ import {
 	Eye,
 	EyeOff,
 	Lock,
+	Pause,
 	Play,
 	Shield,
 	Square,
@@ -151,6 +152,8 @@ export default function RecordingStatus({ className = "", compact = false }: Rec
 				return "primary";
 			case "Recording":
 				return "success";
+			case "Paused":
+				return "warning";
 			case "Stopping":
 				return "warning";
 			case "Processing":
@@ -173,6 +176,8 @@ export default function RecordingStatus({ className = "", compact = false }: Rec
 				return <Clock className="w-4 h-4" />;
 			case "Recording":
 				return <Circle className="w-4 h-4 fill-current" />;
+			case "Paused":
+				return <Pause className="w-4 h-4" />;
 			case "Stopping":
 				return <Square className="w-4 h-4" />;
 			case "Processing":
@@ -44,6 +44,7 @@ import { VideoPlayer } from "./VideoPlayer";
 
 type BlockchainAnchorCheck = {
 	present: boolean;
+	service: string;
 	algorithm: string;
 	anchored_at: string;
 	explorer_url?: string;
@@ -57,7 +58,12 @@ type VerificationReport = {
 			manifest_structure: "PASS" | "FAIL" | "SKIP";
 			signature_valid: "PASS" | "FAIL" | "SKIP";
 			hash_match: "PASS" | "FAIL" | "SKIP";
-			blockchain_anchor?: BlockchainAnchorCheck;
+			anchors?: BlockchainAnchorCheck[];
+			proof_pack?: {
+				format_version: string;
+				index_signature: "PASS" | "FAIL" | "SKIP";
+				files_match: "PASS" | "FAIL" | "SKIP";
+			};
 		};
 		recording_info: {
 			session_id: string;
@@ -88,6 +94,9 @@ type EvidenceManifest = {
 				algorithm: string;
 				iterations: number;
 				salt: string;
+				memory_kib?: number;
+				parallelism?: number;
+				wrapped_key?: string;
 			};
 			nonce: string;
 		};
@@ -154,7 +163,13 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 					algorithm: encryptionInfo?.algorithm,
 				});
 
-				if (encryptionInfo?.encrypted_keys) {
+				// A recording with a password slot as well only plays directly if our key has a slot
+				const myPublicKey = await invoke<string>("export_encryption_public_key").catch(() => null);
+				const hasMyKeySlot = encryptionInfo?.encrypted_keys?.some(
+					(slot: { recipient_public_key: string }) => slot.recipient_public_key === myPublicKey
+				);
+
+				if (encryptionInfo?.encrypted_keys && (hasMyKeySlot || !encryptionInfo.key_derivation)) {
 					// Public key encryption - play directly (no password needed)
 					logger.info("RecordingsLibrary", "Public key encrypted video, playing directly");
 					setVideoPlayerRecording(recording);
@@ -417,7 +432,7 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 					!blockchainBannerDismissed &&
 					blockchainConfig &&
 					!blockchainConfig.enabled &&
-					recordings.some((r) => r.has_manifest && !r.blockchain_anchor) && (
+					recordings.some((r) => r.has_manifest && r.anchors.length === 0) && (
 						<Alert
 							color="primary"
 							variant="flat"
@@ -509,30 +524,34 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 
 								{/* Blockchain Anchoring Section */}
 								{recording.has_manifest &&
-									(recording.blockchain_anchor || blockchainConfig?.enabled) &&
-									(recording.blockchain_anchor ? (
-										// Anchored - Show detailed section
+									(recording.anchors.length > 0 || blockchainConfig?.enabled) &&
+									(recording.anchors.length > 0 ? (
+										// Anchored - Show detailed section, one line per anchor
 										<div className="pt-2 mt-2 border-t border-divider">
 											<div className="flex items-start justify-between gap-3">
 												<div className="flex-1 min-w-0">
 													<div className="flex items-center gap-1.5 mb-1">
 														<Anchor className="w-3.5 h-3.5 text-foreground-500 flex-shrink-0" />
 														<span className="text-xs font-semibold text-foreground-600 dark:text-foreground-400">
-															Blockchain Timestamp
+															{recording.anchors.length > 1
+																? `Blockchain Timestamps (${recording.anchors.length})`
+																: "Blockchain Timestamp"}
 														</span>
 													</div>
-													<p className="text-xs text-foreground-500 leading-relaxed">
-														Anchored to {recording.blockchain_anchor.chain_name} on{" "}
-														{new Date(recording.blockchain_anchor.anchored_at).toLocaleDateString()}{" "}
-														at{" "}
-														{new Date(recording.blockchain_anchor.anchored_at).toLocaleTimeString()}
-													</p>
-													{recording.blockchain_anchor.tx_hash && (
-														<p className="text-xs text-foreground-400 font-mono mt-1">
-															TX: {recording.blockchain_anchor.tx_hash.slice(0, 10)}...
-															{recording.blockchain_anchor.tx_hash.slice(-8)}
-														</p>
-													)}
+													{recording.anchors.map((anchor) => (
+														<div key={`${anchor.service_id}-${anchor.anchored_at}`}>
+															<p className="text-xs text-foreground-500 leading-relaxed">
+																Anchored to {anchor.chain_name} on{" "}
+																{new Date(anchor.anchored_at).toLocaleDateString()} at{" "}
+																{new Date(anchor.anchored_at).toLocaleTimeString()}
+															</p>
+															{anchor.tx_hash && (
+																<p className="text-xs text-foreground-400 font-mono mt-1">
+																	TX: {anchor.tx_hash.slice(0, 10)}...{anchor.tx_hash.slice(-8)}
+																</p>
+															)}
+														</div>
+													))}
 												</div>
 												{/* biome-ignore lint/a11y/noStaticElementInteractions: wrapper div to stop event propagation */}
 												<div
@@ -543,7 +562,7 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 												>
 													<BlockchainAnchorButton
 														manifestPath={recording.manifest_path}
-														anchor={recording.blockchain_anchor}
+														anchors={recording.anchors}
 														onAnchored={refetch}
 													/>
 												</div>
@@ -559,7 +578,7 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 										>
 											<BlockchainAnchorButton
 												manifestPath={recording.manifest_path}
-												anchor={recording.blockchain_anchor}
+												anchors={recording.anchors}
 												onAnchored={refetch}
 											/>
 										</div>
@@ -782,37 +801,31 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 										</Chip>
 									</div>
 
-									{/* Blockchain Anchor Check */}
-									{verifyResult.verification.checks.blockchain_anchor && (
-										<div className="flex items-center justify-between p-3 bg-content2 rounded-lg">
+									{/* Blockchain Anchor Checks */}
+									{verifyResult.verification.checks.anchors?.map((anchor) => (
+										<div
+											key={`${anchor.service}-${anchor.anchored_at}`}
+											className="flex items-center justify-between p-3 bg-content2 rounded-lg"
+										>
 											<div className="flex flex-col gap-1">
 												<span className="text-sm font-medium">Blockchain Anchor</span>
+												<span className="text-xs text-foreground-500">{anchor.algorithm}</span>
 												<span className="text-xs text-foreground-500">
-													{verifyResult.verification.checks.blockchain_anchor.algorithm}
-												</span>
-												<span className="text-xs text-foreground-500">
-													{new Date(
-														verifyResult.verification.checks.blockchain_anchor.anchored_at
-													).toLocaleString()}
+													{new Date(anchor.anchored_at).toLocaleString()}
 												</span>
 											</div>
 											<div className="flex items-center gap-2">
 												<Chip color="success" size="sm" variant="flat">
 													✓ Present
 												</Chip>
-												{verifyResult.verification.checks.blockchain_anchor.explorer_url && (
+												{anchor.explorer_url && (
 													<Button
 														isIconOnly
 														size="sm"
 														variant="light"
 														onPress={() => {
-															if (
-																verifyResult.verification.checks.blockchain_anchor?.explorer_url
-															) {
-																window.open(
-																	verifyResult.verification.checks.blockchain_anchor.explorer_url,
-																	"_blank"
-																);
+															if (anchor.explorer_url) {
+																window.open(anchor.explorer_url, "_blank");
 															}
 														}}
 														aria-label="View on blockchain explorer"
@@ -822,7 +835,7 @@ export default function RecordingsLibrary({ onSettings }: RecordingsLibraryProps
 												)}
 											</div>
 										</div>
-									)}
+									))}
 								</div>
 
 								{/* Custom Metadata */}
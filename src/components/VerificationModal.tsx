@@ -224,6 +224,18 @@ function VerificationResults({ report }: { report: VerificationReport }) {
 							result={verification.checks.signature_valid}
 						/>
 						<VerificationCheck label="File Integrity" result={verification.checks.hash_match} />
+						{verification.checks.proof_pack && (
+							<>
+								<VerificationCheck
+									label="Pack Index Signature"
+									result={verification.checks.proof_pack.index_signature}
+								/>
+								<VerificationCheck
+									label="Pack Contents"
+									result={verification.checks.proof_pack.files_match}
+								/>
+							</>
+						)}
 					</div>
 				</CardBody>
 			</Card>
@@ -271,8 +283,12 @@ function VerificationResults({ report }: { report: VerificationReport }) {
 							</span>
 						</div>
 						<div className="flex gap-2">
-							<span className="text-foreground-500">Verified By:</span>
-							<span>{verification.signature_info.verified_by}</span>
+							<span className="text-foreground-500">Signer:</span>
+							<span
+								className={verification.signature_info.trusted_signer ? "text-success" : undefined}
+							>
+								{verification.signature_info.verified_by}
+							</span>
 						</div>
 					</div>
 				</CardBody>
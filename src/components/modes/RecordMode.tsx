@@ -59,10 +59,10 @@ export default function RecordMode({ onStartRecording }: RecordModeProps) {
 		try {
 			// Prepare encryption parameters based on enabled state and method
 			const password =
-				encryptionEnabled && encryptionMethod === "password" ? encryptionPassword || null : null;
+				encryptionEnabled && encryptionMethod !== "public-key" ? encryptionPassword || null : null;
 			const method = encryptionEnabled ? encryptionMethod : null;
 			const recipientsList =
-				encryptionEnabled && encryptionMethod === "public-key" && recipients.length > 0
+				encryptionEnabled && encryptionMethod !== "password" && recipients.length > 0
 					? recipients
 					: null;
 
@@ -88,17 +88,16 @@ export default function RecordingTab({
 
 		// Validate encryption settings if enabled
 		if (encryptionEnabled) {
-			if (encryptionMethod === "password") {
+			if (encryptionMethod !== "password" && recipients.length === 0) {
+				setPasswordError("At least one recipient is required for public key encryption");
+				return;
+			}
+			if (encryptionMethod !== "public-key") {
 				const error = validatePassword(encryptionPassword);
 				if (error) {
 					setPasswordError(error);
 					return;
 				}
-			} else if (encryptionMethod === "public-key") {
-				if (recipients.length === 0) {
-					setPasswordError("At least one recipient is required for public key encryption");
-					return;
-				}
 			}
 		}
 
@@ -18,6 +18,7 @@ import {
 } from "lucide-react";
 import { useState } from "react";
 
+import { formatPublicKeyFingerprint } from "../../hooks/useEvidence";
 import { logger } from "../../utils/logger";
 import { VideoPlayer } from "../VideoPlayer";
 
@@ -28,12 +29,44 @@ type OnChainVerificationResult = {
 	error?: string;
 };
 
+type TimestampTokenCheck = {
+	verified: boolean;
+	trusted: "PASS" | "FAIL" | "SKIP";
+	gen_time?: string;
+	tsa_name?: string;
+	tsa_certificate_sha256?: string;
+	trust_anchor?: string;
+	error?: string;
+};
+
+type MerkleInclusionCheck = {
+	verified: boolean;
+	merkle_root: string;
+	leaf_index: number;
+	leaf_count: number;
+	error?: string;
+};
+
+type BitcoinAttestationCheck = {
+	complete: boolean;
+	header_match: "PASS" | "FAIL" | "SKIP";
+	height?: number;
+	block_hash?: string;
+	block_time?: string;
+	error?: string;
+};
+
 type BlockchainAnchorCheck = {
 	present: boolean;
+	service: string;
 	algorithm: string;
 	anchored_at: string;
+	hash_match: "PASS" | "FAIL" | "SKIP";
 	explorer_url?: string;
 	on_chain_verified?: OnChainVerificationResult;
+	timestamp_token?: TimestampTokenCheck;
+	merkle_inclusion?: MerkleInclusionCheck;
+	bitcoin_attestation?: BitcoinAttestationCheck;
 };
 
 type VerificationReport = {
@@ -44,7 +77,44 @@ type VerificationReport = {
 			manifest_structure: "PASS" | "FAIL" | "SKIP";
 			signature_valid: "PASS" | "FAIL" | "SKIP";
 			hash_match: "PASS" | "FAIL" | "SKIP";
-			blockchain_anchor?: BlockchainAnchorCheck;
+			chunk_hashes?: {
+				root_valid: "PASS" | "FAIL" | "SKIP";
+				chunks_match: "PASS" | "FAIL" | "SKIP";
+				chunk_size: number;
+				chunk_count: number;
+				first_mismatch?: number;
+				first_mismatch_offset?: number;
+			};
+			anchors?: BlockchainAnchorCheck[];
+			proof_pack?: {
+				format_version: string;
+				index_signature: "PASS" | "FAIL" | "SKIP";
+				files_match: "PASS" | "FAIL" | "SKIP";
+			};
+			checkpoints?: {
+				count: number;
+				timestamped: number;
+				segments_valid: "PASS" | "FAIL" | "SKIP";
+				segments_packed: "PASS" | "FAIL" | "SKIP";
+				joined_segments: number;
+				dropped_segments: number;
+				max_gap_seconds: number;
+				error?: string;
+			};
+			key_history?: {
+				identity_key: string;
+				rotations: number;
+				error?: string;
+			};
+			certificate?: {
+				chain_valid: "PASS" | "FAIL" | "SKIP";
+				valid_at_signing: "PASS" | "FAIL" | "SKIP";
+				trusted: "PASS" | "FAIL" | "SKIP";
+				subject: string;
+				issuer: string;
+				trust_anchor?: string;
+				error?: string;
+			};
 		};
 		recording_info: {
 			session_id: string;
@@ -54,11 +124,14 @@ type VerificationReport = {
 			title?: string;
 			description?: string;
 			tags?: string[];
+			pauses?: { paused_at: string; resumed_at: string }[];
+			recovered?: boolean;
 		};
 		signature_info: {
 			algorithm: string;
 			public_key: string;
 			verified_by: string;
+			trusted_signer?: string;
 		};
 	};
 };
@@ -69,6 +142,7 @@ export default function VerifyTab() {
 	const [verificationResult, setVerificationResult] = useState<VerificationReport | null>(null);
 	const [error, setError] = useState<string | null>(null);
 	const [isVerifyingOnChain, setIsVerifyingOnChain] = useState(false);
+	const [blockHeader, setBlockHeader] = useState("");
 
 	// Video playback state
 	const [showVideoPlayer, setShowVideoPlayer] = useState(false);
@@ -76,6 +150,15 @@ export default function VerifyTab() {
 	const [showPasswordModal, setShowPasswordModal] = useState(false);
 	const [passwordInput, setPasswordInput] = useState("");
 
+	// One check per anchor; a recording may be anchored to several chains or services
+	const anchorChecks = verificationResult?.verification.checks.anchors ?? [];
+	const chunkCheck = verificationResult?.verification.checks.chunk_hashes;
+	const checkpointCheck = verificationResult?.verification.checks.checkpoints;
+	const keyHistoryCheck = verificationResult?.verification.checks.key_history;
+	const certificateCheck = verificationResult?.verification.checks.certificate;
+	const certificateValid =
+		certificateCheck?.chain_valid === "PASS" && certificateCheck.valid_at_signing === "PASS";
+
 	const handleSelectFile = async () => {
 		try {
 			// Push popover guard to prevent popover from closing when dialog opens
@@ -139,6 +222,7 @@ export default function VerifyTab() {
 			const result = await invoke<VerificationReport>("verify_recording_deep", {
 				manifestPath: selectedFile,
 				videoPath: selectedFile,
+				blockHeader: blockHeader.trim() || null,
 			});
 
 			// Update verification result with on-chain data
@@ -188,7 +272,13 @@ export default function VerifyTab() {
 			const manifest = JSON.parse(manifestContent);
 			const encryptionInfo = manifest.recording?.encryption;
 
-			if (encryptionInfo?.encrypted_keys) {
+			// A recording with a password slot as well only plays directly if our key has a slot
+			const myPublicKey = await invoke<string>("export_encryption_public_key").catch(() => null);
+			const hasMyKeySlot = encryptionInfo?.encrypted_keys?.some(
+				(slot: { recipient_public_key: string }) => slot.recipient_public_key === myPublicKey
+			);
+
+			if (encryptionInfo?.encrypted_keys && (hasMyKeySlot || !encryptionInfo.key_derivation)) {
 				// Public key encryption - play directly (no password needed)
 				logger.info("VerifyTab", "Public key encrypted video, playing directly");
 				setVideoPlayerPassword(""); // Empty password for public key encryption
@@ -348,6 +438,75 @@ export default function VerifyTab() {
 											: "Invalid"}
 									</Chip>
 								</div>
+								{chunkCheck?.first_mismatch !== undefined && (
+									<div className="flex items-center justify-between text-sm">
+										<span className="text-foreground-500">First Altered Chunk</span>
+										<Chip size="sm" color="danger" variant="flat">
+											#{chunkCheck.first_mismatch} at byte {chunkCheck.first_mismatch_offset}
+										</Chip>
+									</div>
+								)}
+								{checkpointCheck && (
+									<div className="flex items-center justify-between text-sm">
+										<span className="text-foreground-500">Live Checkpoints</span>
+										<Chip
+											size="sm"
+											color={
+												checkpointCheck.error
+													? "danger"
+													: checkpointCheck.dropped_segments > 0
+														? "warning"
+														: "success"
+											}
+											variant="flat"
+											title={checkpointCheck.error}
+										>
+											{checkpointCheck.error
+												? "Invalid"
+												: checkpointCheck.dropped_segments > 0
+													? `${checkpointCheck.dropped_segments} segment(s) missing from video`
+													: `${checkpointCheck.count} signed, max gap ${checkpointCheck.max_gap_seconds}s`}
+										</Chip>
+									</div>
+								)}
+								{keyHistoryCheck && (
+									<div className="flex items-center justify-between text-sm">
+										<span className="text-foreground-500">Key History</span>
+										<Chip
+											size="sm"
+											color={keyHistoryCheck.error ? "danger" : "success"}
+											variant="flat"
+											title={keyHistoryCheck.error ?? keyHistoryCheck.identity_key}
+										>
+											{keyHistoryCheck.error
+												? "Invalid"
+												: `${keyHistoryCheck.rotations} rotation(s) from ${formatPublicKeyFingerprint(keyHistoryCheck.identity_key)}`}
+										</Chip>
+									</div>
+								)}
+								{certificateCheck && (
+									<div className="flex items-center justify-between text-sm">
+										<span className="text-foreground-500">Certificate</span>
+										<Chip
+											size="sm"
+											color={
+												!certificateValid
+													? "danger"
+													: certificateCheck.trusted === "PASS"
+														? "success"
+														: "warning"
+											}
+											variant="flat"
+											title={certificateCheck.error ?? `Issued by ${certificateCheck.issuer}`}
+										>
+											{!certificateValid
+												? "Invalid"
+												: certificateCheck.trusted === "PASS"
+													? `${certificateCheck.subject} (${certificateCheck.trust_anchor})`
+													: `${certificateCheck.subject} (untrusted CA)`}
+										</Chip>
+									</div>
+								)}
 								<div className="flex items-center justify-between text-sm">
 									<span className="text-foreground-500">Manifest Structure</span>
 									<Chip
@@ -365,43 +524,35 @@ export default function VerifyTab() {
 									</Chip>
 								</div>
 
-								{/* Blockchain Anchor Check */}
-								{verificationResult.verification.checks.blockchain_anchor && (
-									<div className="flex flex-col gap-2 pt-2 border-t border-divider">
+								{/* Blockchain Anchor Checks */}
+								{anchorChecks.map((anchor) => (
+									<div
+										key={`${anchor.service}-${anchor.anchored_at}`}
+										className="flex flex-col gap-2 pt-2 border-t border-divider"
+									>
 										<div className="flex items-center justify-between text-sm">
 											<div className="flex flex-col gap-1">
 												<div className="flex items-center gap-2">
 													<Anchor className="w-3.5 h-3.5 text-foreground-500" />
 													<span className="text-foreground-500">Blockchain Anchor</span>
 												</div>
+												<span className="text-xs text-foreground-400 ml-5">{anchor.algorithm}</span>
 												<span className="text-xs text-foreground-400 ml-5">
-													{verificationResult.verification.checks.blockchain_anchor.algorithm}
-												</span>
-												<span className="text-xs text-foreground-400 ml-5">
-													{new Date(
-														verificationResult.verification.checks.blockchain_anchor.anchored_at
-													).toLocaleString()}
+													{new Date(anchor.anchored_at).toLocaleString()}
 												</span>
 											</div>
 											<div className="flex items-center gap-2">
 												<Chip size="sm" color="success" variant="flat">
 													Present
 												</Chip>
-												{verificationResult.verification.checks.blockchain_anchor.explorer_url && (
+												{anchor.explorer_url && (
 													<Button
 														isIconOnly
 														size="sm"
 														variant="light"
 														onPress={() => {
-															if (
-																verificationResult.verification.checks.blockchain_anchor
-																	?.explorer_url
-															) {
-																window.open(
-																	verificationResult.verification.checks.blockchain_anchor
-																		.explorer_url,
-																	"_blank"
-																);
+															if (anchor.explorer_url) {
+																window.open(anchor.explorer_url, "_blank");
 															}
 														}}
 														aria-label="View on blockchain explorer"
@@ -412,29 +563,146 @@ export default function VerifyTab() {
 											</div>
 										</div>
 
-										{/* Verify On-Chain Button */}
-										{!verificationResult.verification.checks.blockchain_anchor
-											.on_chain_verified && (
-											<div className="mt-3">
-												<Button
-													size="lg"
-													color="primary"
-													className="w-full"
-													onPress={handleVerifyOnChain}
-													isLoading={isVerifyingOnChain}
-													isDisabled={isVerifyingOnChain}
-													startContent={!isVerifyingOnChain && <Anchor className="w-5 h-5" />}
-												>
-													{isVerifyingOnChain ? "Verifying On-Chain..." : "Verify On-Chain"}
-												</Button>
-												<p className="text-xs text-foreground-400 text-center mt-2">
-													Query the blockchain to confirm this anchor exists on-chain
-												</p>
+										{anchor.hash_match === "FAIL" && (
+											<div className="ml-5 text-xs text-danger">
+												Anchored hash doesn't match this manifest
+											</div>
+										)}
+
+										{/* Batch Inclusion Result (checked offline) */}
+										{anchor.merkle_inclusion && (
+											<div className="ml-5 p-2 rounded-lg bg-content2">
+												<div className="flex items-center gap-2">
+													<span className="text-xs font-medium text-foreground-500">
+														Batch Inclusion:
+													</span>
+													<Chip
+														size="sm"
+														color={anchor.merkle_inclusion.verified ? "success" : "danger"}
+														variant="flat"
+													>
+														{anchor.merkle_inclusion.verified ? "Verified" : "Failed"}
+													</Chip>
+												</div>
+												<div className="mt-1 text-xs text-foreground-400 space-y-0.5">
+													<div>
+														Recording {anchor.merkle_inclusion.leaf_index + 1} of{" "}
+														{anchor.merkle_inclusion.leaf_count}
+													</div>
+													<div className="font-mono text-[10px] break-all">
+														Merkle root: {anchor.merkle_inclusion.merkle_root}
+													</div>
+													{anchor.merkle_inclusion.error && (
+														<div className="text-danger">Error: {anchor.merkle_inclusion.error}</div>
+													)}
+												</div>
+											</div>
+										)}
+
+										{/* RFC 3161 Token Result (checked offline) */}
+										{anchor.timestamp_token && (
+											<div className="ml-5 p-2 rounded-lg bg-content2">
+												<div className="flex items-center gap-2">
+													<span className="text-xs font-medium text-foreground-500">
+														Timestamp Token:
+													</span>
+													<Chip
+														size="sm"
+														color={
+															!anchor.timestamp_token.verified
+																? "danger"
+																: anchor.timestamp_token.trusted === "PASS"
+																	? "success"
+																	: "warning"
+														}
+														variant="flat"
+													>
+														{!anchor.timestamp_token.verified
+															? "Failed"
+															: anchor.timestamp_token.trusted === "PASS"
+																? "Verified"
+																: "Untrusted TSA"}
+													</Chip>
+												</div>
+												<div className="mt-1 text-xs text-foreground-400 space-y-0.5">
+													{anchor.timestamp_token.tsa_name && (
+														<div>TSA: {anchor.timestamp_token.tsa_name}</div>
+													)}
+													{anchor.timestamp_token.trust_anchor && (
+														<div>Trusted via: {anchor.timestamp_token.trust_anchor}</div>
+													)}
+													{anchor.timestamp_token.tsa_certificate_sha256 && (
+														<div className="font-mono text-[10px] break-all">
+															Certificate SHA-256: {anchor.timestamp_token.tsa_certificate_sha256}
+														</div>
+													)}
+													{anchor.timestamp_token.error && (
+														<div
+															className={
+																anchor.timestamp_token.verified ? "text-warning" : "text-danger"
+															}
+														>
+															{anchor.timestamp_token.verified ? "" : "Error: "}
+															{anchor.timestamp_token.error}
+														</div>
+													)}
+												</div>
+											</div>
+										)}
+
+										{/* OpenTimestamps Bitcoin Attestation */}
+										{anchor.bitcoin_attestation && (
+											<div className="ml-5 p-2 rounded-lg bg-content2">
+												<div className="flex items-center gap-2">
+													<span className="text-xs font-medium text-foreground-500">
+														Bitcoin Attestation:
+													</span>
+													<Chip
+														size="sm"
+														color={
+															anchor.bitcoin_attestation.header_match === "PASS"
+																? "success"
+																: anchor.bitcoin_attestation.header_match === "FAIL"
+																	? "danger"
+																	: "warning"
+														}
+														variant="flat"
+													>
+														{anchor.bitcoin_attestation.header_match === "PASS"
+															? "Verified"
+															: anchor.bitcoin_attestation.header_match === "FAIL"
+																? "Failed"
+																: anchor.bitcoin_attestation.complete
+																	? "Complete, attestation not checked"
+																	: "Pending"}
+													</Chip>
+												</div>
+												<div className="mt-1 text-xs text-foreground-400 space-y-0.5">
+													{anchor.bitcoin_attestation.height !== undefined && (
+														<div>Block height: {anchor.bitcoin_attestation.height}</div>
+													)}
+													{anchor.bitcoin_attestation.block_time && (
+														<div>
+															Block time:{" "}
+															{new Date(anchor.bitcoin_attestation.block_time).toLocaleString()}
+														</div>
+													)}
+													{anchor.bitcoin_attestation.block_hash && (
+														<div className="font-mono text-[10px] break-all">
+															Block hash: {anchor.bitcoin_attestation.block_hash}
+														</div>
+													)}
+													{anchor.bitcoin_attestation.error && (
+														<div className="text-danger">
+															Error: {anchor.bitcoin_attestation.error}
+														</div>
+													)}
+												</div>
 											</div>
 										)}
 
 										{/* On-chain Verification Result */}
-										{verificationResult.verification.checks.blockchain_anchor.on_chain_verified && (
+										{anchor.on_chain_verified && (
 											<div className="ml-5 p-2 rounded-lg bg-content2">
 												<div className="flex items-center justify-between">
 													<div className="flex items-center gap-2">
@@ -443,60 +711,67 @@ export default function VerifyTab() {
 														</span>
 														<Chip
 															size="sm"
-															color={
-																verificationResult.verification.checks.blockchain_anchor
-																	.on_chain_verified.verified
-																	? "success"
-																	: "danger"
-															}
+															color={anchor.on_chain_verified.verified ? "success" : "danger"}
 															variant="flat"
 															startContent={
-																verificationResult.verification.checks.blockchain_anchor
-																	.on_chain_verified.verified ? (
+																anchor.on_chain_verified.verified ? (
 																	<CheckCircle className="w-3 h-3" />
 																) : (
 																	<XCircle className="w-3 h-3" />
 																)
 															}
 														>
-															{verificationResult.verification.checks.blockchain_anchor
-																.on_chain_verified.verified
-																? "Verified"
-																: "Failed"}
+															{anchor.on_chain_verified.verified ? "Verified" : "Failed"}
 														</Chip>
 													</div>
 												</div>
 												<div className="mt-1 text-xs text-foreground-400 space-y-0.5">
-													<div>
-														Chain:{" "}
-														{
-															verificationResult.verification.checks.blockchain_anchor
-																.on_chain_verified.chain_name
-														}
-													</div>
+													<div>Chain: {anchor.on_chain_verified.chain_name}</div>
 													<div className="font-mono text-[10px]">
-														Contract:{" "}
-														{verificationResult.verification.checks.blockchain_anchor.on_chain_verified.contract_address.slice(
-															0,
-															10
-														)}
-														...
+														Contract: {anchor.on_chain_verified.contract_address.slice(0, 10)}...
 													</div>
-													{verificationResult.verification.checks.blockchain_anchor
-														.on_chain_verified.error && (
-														<div className="text-danger">
-															Error:{" "}
-															{
-																verificationResult.verification.checks.blockchain_anchor
-																	.on_chain_verified.error
-															}
-														</div>
+													{anchor.on_chain_verified.error && (
+														<div className="text-danger">Error: {anchor.on_chain_verified.error}</div>
 													)}
 												</div>
 											</div>
 										)}
 									</div>
-								)}
+								))}
+
+								{/* Verify On-Chain Button (checks every anchor) */}
+								{anchorChecks.length > 0 &&
+									!anchorChecks.some((anchor) => anchor.on_chain_verified) && (
+										<div className="mt-3">
+											{anchorChecks.some((anchor) => anchor.bitcoin_attestation?.complete) && (
+												<Input
+													size="sm"
+													label="Bitcoin block header (hex)"
+													placeholder="bitcoin-cli getblockheader <hash> false"
+													description="The attested block's 80-byte header, from a source you trust"
+													value={blockHeader}
+													onChange={(e) => setBlockHeader(e.target.value)}
+													className="mb-2"
+												/>
+											)}
+											<Button
+												size="lg"
+												color="primary"
+												className="w-full"
+												onPress={handleVerifyOnChain}
+												isLoading={isVerifyingOnChain}
+												isDisabled={isVerifyingOnChain}
+												startContent={!isVerifyingOnChain && <Anchor className="w-5 h-5" />}
+											>
+												{isVerifyingOnChain ? "Verifying On-Chain..." : "Verify On-Chain"}
+											</Button>
+											<p className="text-xs text-foreground-400 text-center mt-2">
+												{anchorChecks.length > 1
+													? "Query each chain and service to confirm these anchors exist"
+													: "Query the blockchain to confirm this anchor exists on-chain"}
+											</p>
+										</div>
+									)}
 							</div>
 						</CardBody>
 					</Card>
@@ -559,9 +834,41 @@ export default function VerifyTab() {
 										{verificationResult.verification.recording_info.duration_seconds.toFixed(1)}s
 									</span>
 								</div>
+								{verificationResult.verification.recording_info.pauses &&
+									verificationResult.verification.recording_info.pauses.length > 0 && (
+										<div className="flex gap-1">
+											<span className="text-foreground-500">Paused:</span>
+											<span className="text-foreground">
+												{verificationResult.verification.recording_info.pauses.length} gap(s),{" "}
+												{verificationResult.verification.recording_info.pauses
+													.reduce(
+														(total, pause) =>
+															total +
+															(Date.parse(pause.resumed_at) - Date.parse(pause.paused_at)) / 1000,
+														0
+													)
+													.toFixed(1)}
+												s not in video
+											</span>
+										</div>
+									)}
+								{verificationResult.verification.recording_info.recovered && (
+									<div className="flex gap-1">
+										<span className="text-foreground-500">Recovered:</span>
+										<span className="text-foreground">
+											Finalized after the app exited mid-recording
+										</span>
+									</div>
+								)}
 								<div className="flex gap-1">
-									<span className="text-foreground-500">Verified By:</span>
-									<span className="text-foreground text-xs">
+									<span className="text-foreground-500">Signer:</span>
+									<span
+										className={`text-xs ${
+											verificationResult.verification.signature_info.trusted_signer
+												? "text-success"
+												: "text-foreground"
+										}`}
+									>
 										{verificationResult.verification.signature_info.verified_by}
 									</span>
 								</div>
@@ -1,4 +1,4 @@
-import { useQuery } from "@tanstack/react-query";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import { invoke } from "@tauri-apps/api/core";
 
 // Evidence types (must match Rust enum with UPPERCASE serialization)
@@ -9,6 +9,64 @@ export type VerificationChecks = {
 	manifest_structure: CheckResult;
 	signature_valid: CheckResult;
 	hash_match: CheckResult;
+	chunk_hashes?: ChunkHashCheck;
+	proof_pack?: ProofPackCheck;
+	checkpoints?: CheckpointCheck;
+	key_history?: KeyHistoryCheck;
+	certificate?: CertificateCheck;
+};
+
+export type ChunkHashCheck = {
+	root_valid: CheckResult;
+	chunks_match: CheckResult;
+	chunk_size: number;
+	chunk_count: number;
+	first_mismatch?: number;
+	first_mismatch_offset?: number;
+};
+
+export type ProofPackCheck = {
+	format_version: string;
+	index_signature: CheckResult;
+	files_match: CheckResult;
+	unlisted_files?: string[];
+	modified_files?: string[];
+	missing_files?: string[];
+};
+
+export type CheckpointCheck = {
+	log_match: CheckResult;
+	signatures_valid: CheckResult;
+	chain_valid: CheckResult;
+	times_valid: CheckResult;
+	timestamps_valid: CheckResult;
+	count: number;
+	timestamped: number;
+	first_at?: string;
+	last_at?: string;
+	max_gap_seconds: number;
+	error?: string;
+};
+
+export type KeyHistoryCheck = {
+	chain_valid: CheckResult;
+	links_signer: CheckResult;
+	identity_key: string;
+	rotations: number;
+	rotated_at?: string;
+	identity_pinned: CheckResult;
+	error?: string;
+};
+
+export type CertificateCheck = {
+	chain_valid: CheckResult;
+	valid_at_signing: CheckResult;
+	trusted: CheckResult;
+	subject: string;
+	issuer: string;
+	fingerprint: string;
+	trust_anchor?: string;
+	error?: string;
 };
 
 export type RecordingInfoSummary = {
@@ -22,6 +80,7 @@ export type SignatureInfoSummary = {
 	algorithm: string;
 	public_key: string;
 	verified_by: string;
+	trusted_signer?: string;
 };
 
 export type VerificationInfo = {
@@ -36,10 +95,29 @@ export type VerificationReport = {
 	verification: VerificationInfo;
 };
 
+export type TrustedSigner = {
+	name: string;
+	public_key: string;
+	added_at: string;
+	note?: string;
+};
+
+export type CertificateSummary = {
+	subject: string;
+	issuer: string;
+	fingerprint: string;
+	not_before: string;
+	not_after: string;
+	is_ca: boolean;
+};
+
 // Query keys
 const EVIDENCE_QUERY_KEYS = {
 	hasSigningKey: ["evidence", "hasSigningKey"] as const,
 	publicKey: ["evidence", "publicKey"] as const,
+	trustedSigners: ["evidence", "trustedSigners"] as const,
+	trustAnchors: ["evidence", "trustAnchors"] as const,
+	signerCertificate: ["evidence", "signerCertificate"] as const,
 	verifyRecording: (manifestPath: string, videoPath: string) =>
 		["evidence", "verify", manifestPath, videoPath] as const,
 };
@@ -71,6 +149,118 @@ export function usePublicKeyQuery() {
 	});
 }
 
+// Get trusted signers
+export function useTrustedSignersQuery() {
+	return useQuery({
+		queryKey: EVIDENCE_QUERY_KEYS.trustedSigners,
+		queryFn: async (): Promise<TrustedSigner[]> => {
+			return await invoke("list_trusted_signers");
+		},
+	});
+}
+
+export function useAddTrustedSignerMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async (signer: { name: string; publicKey: string; note?: string }) => {
+			return await invoke<TrustedSigner>("add_trusted_signer", {
+				name: signer.name,
+				publicKey: signer.publicKey,
+				note: signer.note ?? null,
+			});
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.trustedSigners });
+		},
+	});
+}
+
+export function useRemoveTrustedSignerMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async (publicKey: string) => {
+			return await invoke("remove_trusted_signer", { publicKey });
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.trustedSigners });
+		},
+	});
+}
+
+// Get trusted CA certificates
+export function useTrustAnchorsQuery() {
+	return useQuery({
+		queryKey: EVIDENCE_QUERY_KEYS.trustAnchors,
+		queryFn: async (): Promise<CertificateSummary[]> => {
+			return await invoke("list_trust_anchors");
+		},
+	});
+}
+
+export function useAddTrustAnchorMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async (path: string) => {
+			return await invoke<CertificateSummary[]>("add_trust_anchor", { path });
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.trustAnchors });
+		},
+	});
+}
+
+export function useRemoveTrustAnchorMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async (fingerprint: string) => {
+			return await invoke("remove_trust_anchor", { fingerprint });
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.trustAnchors });
+		},
+	});
+}
+
+// Get the certificate chain recordings are signed with, leaf first
+export function useSignerCertificateQuery() {
+	return useQuery({
+		queryKey: EVIDENCE_QUERY_KEYS.signerCertificate,
+		queryFn: async (): Promise<CertificateSummary[] | null> => {
+			return await invoke("get_signer_certificate");
+		},
+	});
+}
+
+export function useSetSignerCertificateMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async (path: string) => {
+			return await invoke<CertificateSummary[]>("set_signer_certificate", { path });
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.signerCertificate });
+		},
+	});
+}
+
+export function useClearSignerCertificateMutation() {
+	const queryClient = useQueryClient();
+
+	return useMutation({
+		mutationFn: async () => {
+			return await invoke("clear_signer_certificate");
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: EVIDENCE_QUERY_KEYS.signerCertificate });
+		},
+	});
+}
+
 // Verify a recording
 export function useVerifyRecordingQuery(manifestPath: string | null, videoPath: string | null) {
 	return useQuery({
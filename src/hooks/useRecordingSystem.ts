@@ -303,6 +303,7 @@ export type BlockchainAnchorInfo = {
 	chain_name: string;
 	tx_hash?: string;
 	explorer_url?: string;
+	service_id: string;
 };
 
 export type RecordingEntry = {
@@ -316,7 +317,7 @@ export type RecordingEntry = {
 	title?: string;
 	description?: string;
 	tags?: string[];
-	blockchain_anchor?: BlockchainAnchorInfo;
+	anchors: BlockchainAnchorInfo[];
 };
 
 export function useRecordingsQuery() {
@@ -360,3 +361,111 @@ export function useDeleteRecordingMutation() {
 		},
 	});
 }
+
+// Re-key a password-encrypted recording from PBKDF2 to Argon2id (video isn't re-encrypted)
+export function useUpgradeRecordingEncryptionMutation() {
+	const queryClient = useQueryClient();
+	const toast = useToast();
+
+	return useMutation({
+		mutationFn: async ({ notariPath, password }: { notariPath: string; password: string }) => {
+			await invoke("upgrade_recording_encryption", { notariPath, password });
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: RECORDING_QUERY_KEYS.recordings });
+			toast.success("Encryption Upgraded", "The recording's password now uses Argon2id");
+		},
+		onError: (error) => {
+			recordingLogger.error(
+				"Upgrade recording encryption failed",
+				error instanceof Error ? error : new Error(String(error))
+			);
+			toast.error(
+				"Failed to Upgrade Encryption",
+				error instanceof Error ? error.message : "Unknown error occurred"
+			);
+		},
+	});
+}
+
+// Give another recipient a key slot on an encrypted recording (video isn't re-encrypted)
+export function useAddRecordingRecipientMutation() {
+	const queryClient = useQueryClient();
+	const toast = useToast();
+
+	return useMutation({
+		mutationFn: async ({
+			notariPath,
+			recipientId,
+			publicKey,
+			password,
+		}: {
+			notariPath: string;
+			recipientId: string;
+			publicKey: string;
+			password?: string;
+		}) => {
+			await invoke("add_recording_recipient", { notariPath, recipientId, publicKey, password });
+		},
+		onSuccess: (_, { recipientId }) => {
+			queryClient.invalidateQueries({ queryKey: RECORDING_QUERY_KEYS.recordings });
+			toast.success("Recipient Added", `${recipientId} can now open the recording`);
+		},
+		onError: (error) => {
+			recordingLogger.error(
+				"Add recording recipient failed",
+				error instanceof Error ? error : new Error(String(error))
+			);
+			toast.error(
+				"Failed to Add Recipient",
+				error instanceof Error ? error.message : "Unknown error occurred"
+			);
+		},
+	});
+}
+
+// Remove a recipient's key slot; with rotateKey the video is re-encrypted under a new key
+export function useRemoveRecordingRecipientMutation() {
+	const queryClient = useQueryClient();
+	const toast = useToast();
+
+	return useMutation({
+		mutationFn: async ({
+			notariPath,
+			recipientPublicKey,
+			rotateKey,
+			password,
+		}: {
+			notariPath: string;
+			recipientPublicKey: string;
+			rotateKey: boolean;
+			password?: string;
+		}) => {
+			await invoke("remove_recording_recipient", {
+				notariPath,
+				recipientPublicKey,
+				rotateKey,
+				password,
+			});
+		},
+		onSuccess: (_, { rotateKey }) => {
+			queryClient.invalidateQueries({ queryKey: RECORDING_QUERY_KEYS.recordings });
+			toast.success(
+				"Recipient Removed",
+				rotateKey
+					? "The recording was re-encrypted under a new key"
+					: "Their key slot was removed from the recording"
+			);
+		},
+		onError: (error) => {
+			recordingLogger.error(
+				"Remove recording recipient failed",
+				error instanceof Error ? error : new Error(String(error))
+			);
+			toast.error(
+				"Failed to Remove Recipient",
+				error instanceof Error ? error.message : "Unknown error occurred"
+			);
+		},
+	});
+}
@@ -9,6 +9,7 @@ export const EVENT_NAMES = {
 	RECORDING_ERROR: "recording:error",
 	PIPELINE_STARTED: "pipeline:started",
 	PIPELINE_STAGE_STARTED: "pipeline:stage-started",
+	PIPELINE_STAGE_PROGRESS: "pipeline:stage-progress",
 	PIPELINE_STAGE_COMPLETED: "pipeline:stage-completed",
 	PIPELINE_STAGE_SKIPPED: "pipeline:stage-skipped",
 	PIPELINE_COMPLETED: "pipeline:completed",
@@ -23,7 +24,15 @@ export const EVENT_NAMES = {
 
 export type RecordingStateChangedEvent = {
 	sessionId: string;
-	status: "Idle" | "Preparing" | "Recording" | "Stopping" | "Processing" | "Completed" | "Failed";
+	status:
+		| "Idle"
+		| "Preparing"
+		| "Recording"
+		| "Paused"
+		| "Stopping"
+		| "Processing"
+		| "Completed"
+		| "Failed";
 	timestamp: string;
 };
 
@@ -56,6 +65,18 @@ export type PipelineStageStartedEvent = {
 	timestamp: string;
 };
 
+export type PipelineStageProgressEvent = {
+	sessionId: string;
+	pipelineName: string;
+	stageName: string;
+	stageIndex: number;
+	totalStages: number;
+	processed: number;
+	total: number;
+	percent: number;
+	timestamp: string;
+};
+
 export type PipelineStageCompletedEvent = {
 	sessionId: string;
 	pipelineName: string;
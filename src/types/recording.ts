@@ -13,6 +13,7 @@ export type RecordingStatus =
 	| "Idle"
 	| "Preparing"
 	| "Recording"
+	| "Paused"
 	| "Stopping"
 	| "Processing"
 	| "Completed"
@@ -66,6 +67,7 @@ export function isRecordingActive(status: RecordingStatus): boolean {
 	return (
 		status === "Preparing" ||
 		status === "Recording" ||
+		status === "Paused" ||
 		status === "Stopping" ||
 		status === "Processing"
 	);
@@ -100,6 +102,8 @@ export function getRecordingStatusColor(status: RecordingStatus): string {
 			return "blue";
 		case "Recording":
 			return "red";
+		case "Paused":
+			return "yellow";
 		case "Stopping":
 			return "orange";
 		case "Processing":